@@ -0,0 +1,266 @@
+#![no_std]
+
+//! Shared on-chain registry of Poseidon / Poseidon2 parameter sets.
+//!
+//! The registry stores parameter sets keyed by (algorithm, field symbol, `T`)
+//! in persistent storage. The admin installs them once, and hashing contracts
+//! load them with `PoseidonSponge::from_registry` /
+//! `Poseidon2Sponge::from_registry`, so a single deployment serves all of
+//! them.
+//!
+//! Entries are write-once, so a set cannot be swapped out under the contracts
+//! that use it. Every read and write extends the TTL of the entry and of the
+//! contract instance by [`TTL_EXTEND_TO`] ledgers once it drops below
+//! [`TTL_THRESHOLD`], so sets in use stay live; an unused set can still be
+//! archived and must then be restored before it is read.
+//!
+//! The constants are passed in by the admin rather than embedded, which keeps
+//! this contract's WASM small. Each parameter set is checked against `T`, but
+//! not against the reference values, so the admin must install the sets
+//! produced by [`PoseidonSponge::params`] / [`Poseidon2Sponge::params`].
+
+use soroban_poseidon::{ParamsRegistryInterface, Poseidon2Params, PoseidonParams};
+#[cfg(doc)]
+use soroban_poseidon::{Poseidon2Sponge, PoseidonSponge};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, TryFromVal, Val};
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Entries whose TTL drops below this many ledgers are extended when accessed.
+pub const TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+
+/// The TTL, in ledgers, that accessed entries are extended to.
+pub const TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Poseidon(Symbol, u32),
+    Poseidon2(Symbol, u32),
+}
+
+#[contract]
+pub struct ParamsRegistry;
+
+#[contractimpl]
+impl ParamsRegistry {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Installs the Poseidon parameter set for `field` and state size `t`.
+    /// Requires the admin's authorization.
+    ///
+    /// # Panics
+    /// - if `params` does not match the state size `t`.
+    /// - if a set is already installed for `field` and `t`.
+    pub fn set_poseidon_params(env: Env, field: Symbol, t: u32, params: PoseidonParams) {
+        Self::admin(&env).require_auth();
+        assert!(
+            params.is_valid_for(t),
+            "Poseidon: parameters do not match the state size T"
+        );
+        let key = DataKey::Poseidon(field, t);
+        let storage = env.storage().persistent();
+        assert!(
+            !storage.has(&key),
+            "Poseidon: parameters already installed in registry"
+        );
+        storage.set(&key, &params);
+        storage.extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        Self::extend_instance_ttl(&env);
+    }
+
+    /// Installs the Poseidon2 parameter set for `field` and state size `t`.
+    /// Requires the admin's authorization.
+    ///
+    /// # Panics
+    /// - if `params` does not match the state size `t`.
+    /// - if a set is already installed for `field` and `t`.
+    pub fn set_poseidon2_params(env: Env, field: Symbol, t: u32, params: Poseidon2Params) {
+        Self::admin(&env).require_auth();
+        assert!(
+            params.is_valid_for(t),
+            "Poseidon2: parameters do not match the state size T"
+        );
+        let key = DataKey::Poseidon2(field, t);
+        let storage = env.storage().persistent();
+        assert!(
+            !storage.has(&key),
+            "Poseidon2: parameters already installed in registry"
+        );
+        storage.set(&key, &params);
+        storage.extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        Self::extend_instance_ttl(&env);
+    }
+
+    fn admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("registry admin not set")
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+
+    // Reads the entry under `key`, extending its TTL if it exists
+    fn read<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        Self::extend_instance_ttl(env);
+        let storage = env.storage().persistent();
+        let value = storage.get(key);
+        if value.is_some() {
+            storage.extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        }
+        value
+    }
+}
+
+#[contractimpl]
+impl ParamsRegistryInterface for ParamsRegistry {
+    fn poseidon_params(env: Env, field: Symbol, t: u32) -> Option<PoseidonParams> {
+        Self::read(&env, &DataKey::Poseidon(field, t))
+    }
+
+    fn poseidon2_params(env: Env, field: Symbol, t: u32) -> Option<Poseidon2Params> {
+        Self::read(&env, &DataKey::Poseidon2(field, t))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_poseidon::{poseidon2_hash, poseidon_hash, Field, Poseidon2Sponge, PoseidonSponge};
+    use soroban_sdk::{
+        crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+        testutils::{storage::Persistent as _, Address as _, Ledger as _},
+        vec, Address, Env, U256,
+    };
+
+    use crate::{DataKey, ParamsRegistry, ParamsRegistryClient, TTL_EXTEND_TO, TTL_THRESHOLD};
+
+    fn setup(env: &Env) -> (Address, ParamsRegistryClient<'_>) {
+        let admin = Address::generate(env);
+        let contract_id = env.register(ParamsRegistry, (&admin,));
+        (
+            contract_id.clone(),
+            ParamsRegistryClient::new(env, &contract_id),
+        )
+    }
+
+    #[test]
+    fn test_poseidon_from_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (registry, client) = setup(&env);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_poseidon_params(&Bn254Fr::symbol(), &3, &params);
+        assert_eq!(client.poseidon_params(&Bn254Fr::symbol(), &3), Some(params));
+
+        let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+        let mut sponge = PoseidonSponge::<3, Bn254Fr>::from_registry(&env, &registry);
+        assert_eq!(
+            sponge.compute_hash(&inputs),
+            poseidon_hash::<3, Bn254Fr>(&env, &inputs)
+        );
+    }
+
+    #[test]
+    fn test_poseidon2_from_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (registry, client) = setup(&env);
+
+        let params = Poseidon2Sponge::<4, Bls12381Fr>::new(&env).params().clone();
+        client.set_poseidon2_params(&Bls12381Fr::symbol(), &4, &params);
+
+        let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+        let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::from_registry(&env, &registry);
+        assert_eq!(
+            sponge.compute_hash(&inputs),
+            poseidon2_hash::<4, Bls12381Fr>(&env, &inputs)
+        );
+    }
+
+    #[test]
+    fn test_params_keyed_by_algorithm_field_and_t() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = setup(&env);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_poseidon_params(&Bn254Fr::symbol(), &3, &params);
+
+        assert_eq!(client.poseidon_params(&Bn254Fr::symbol(), &4), None);
+        assert_eq!(client.poseidon_params(&Bls12381Fr::symbol(), &3), None);
+        assert_eq!(client.poseidon2_params(&Bn254Fr::symbol(), &3), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon: parameters not found in registry")]
+    fn test_from_registry_missing_params() {
+        let env = Env::default();
+        let (registry, _) = setup(&env);
+        let _ = PoseidonSponge::<3, Bn254Fr>::from_registry(&env, &registry);
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon: parameters do not match the state size T")]
+    fn test_set_params_wrong_size() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = setup(&env);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_poseidon_params(&Bn254Fr::symbol(), &4, &params);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_params_requires_admin() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_poseidon_params(&Bn254Fr::symbol(), &3, &params);
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon: parameters already installed in registry")]
+    fn test_set_params_only_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = setup(&env);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_poseidon_params(&Bn254Fr::symbol(), &3, &params);
+        client.set_poseidon_params(&Bn254Fr::symbol(), &3, &params);
+    }
+
+    #[test]
+    fn test_read_extends_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (registry, client) = setup(&env);
+
+        let params = Poseidon2Sponge::<4, Bls12381Fr>::new(&env).params().clone();
+        client.set_poseidon2_params(&Bls12381Fr::symbol(), &4, &params);
+
+        let key = DataKey::Poseidon2(Bls12381Fr::symbol(), 4);
+        let ttl = || env.as_contract(&registry, || env.storage().persistent().get_ttl(&key));
+        assert_eq!(ttl(), TTL_EXTEND_TO);
+
+        env.ledger()
+            .with_mut(|l| l.sequence_number += TTL_EXTEND_TO - TTL_THRESHOLD + 1);
+        assert_eq!(ttl(), TTL_THRESHOLD - 1);
+        assert_eq!(
+            client.poseidon2_params(&Bls12381Fr::symbol(), &4),
+            Some(params)
+        );
+        assert_eq!(ttl(), TTL_EXTEND_TO);
+    }
+}
@@ -0,0 +1,58 @@
+#!/usr/bin/env bash
+# Builds each test contract against several feature combinations of
+# soroban-poseidon and reports the resulting WASM sizes.
+#
+# Each contract only uses the parameter sets of its own features, so enabling
+# any other parameter set must not change its size. The script fails if it
+# does, i.e. if unused constants are not stripped from the contract.
+#
+# test_poseidon_storage_contract depends on soroban-poseidon without any
+# parameter feature and loads its parameters from storage, so its row shows
+# the size of a contract that does not embed the constants, next to
+# test_poseidon_contract which does.
+set -euo pipefail
+
+TARGET=wasm32v1-none
+OUT_DIR=target/$TARGET/release
+
+# Extra soroban-poseidon features to enable on top of each contract's own.
+COMBINATIONS=(
+  ""
+  "poseidon-wide"
+  "poseidon-bls12-381"
+  "poseidon2-bn254,poseidon2-bls12-381,poseidon2-wide"
+  "poseidon-bn254,poseidon-bls12-381,poseidon-wide"
+  "default"
+)
+
+CONTRACTS=(test_poseidon_contract test_poseidon2_contract test_poseidon_storage_contract)
+
+report="| Contract | Extra features | WASM size (bytes) |"$'\n'
+report+="|----------|----------------|-------------------|"$'\n'
+failed=0
+
+for contract in "${CONTRACTS[@]}"; do
+  baseline=""
+  for combination in "${COMBINATIONS[@]}"; do
+    features=""
+    if [ -n "$combination" ]; then
+      features="--features $(echo "$combination" | sed 's/\([^,]*\)/soroban-poseidon\/\1/g')"
+    fi
+    # shellcheck disable=SC2086
+    cargo build --quiet --release --target "$TARGET" --package "$contract" $features
+    size=$(wc -c < "$OUT_DIR/$contract.wasm" | tr -d ' ')
+    report+="| $contract | ${combination:-(none)} | $size |"$'\n'
+    if [ -z "$baseline" ]; then
+      baseline=$size
+    elif [ "$size" != "$baseline" ]; then
+      echo "error: $contract is $size bytes with '$combination' (expected $baseline)" >&2
+      failed=1
+    fi
+  done
+done
+
+echo "$report"
+if [ -n "${GITHUB_STEP_SUMMARY:-}" ]; then
+  echo "$report" >> "$GITHUB_STEP_SUMMARY"
+fi
+exit $failed
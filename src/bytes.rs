@@ -0,0 +1,29 @@
+//! Packing of byte strings into field elements.
+
+use soroban_sdk::{Bytes, Env, Vec, U256};
+
+/// The number of bytes packed into each field element. Any 31-byte integer is
+/// below both moduli.
+pub(crate) const CHUNK_SIZE: u32 = 31;
+
+// Splits `msg` into 31-byte chunks and reads each one as an integer, either
+// big-endian (iden3) or little-endian (Aztec). The last chunk is zero-padded
+// at the end to 31 bytes, which only changes its value when big-endian.
+pub(crate) fn pack_bytes(env: &Env, msg: &Bytes, little_endian: bool) -> Vec<U256> {
+    let mut chunks = Vec::new(env);
+    let mut start = 0;
+    while start < msg.len() {
+        let end = (start + CHUNK_SIZE).min(msg.len());
+        // `buf[0]` stays 0, so the chunk is read from a 32-byte big-endian
+        // buffer
+        let mut buf = [0u8; 32];
+        msg.slice(start..end)
+            .copy_into_slice(&mut buf[1..=(end - start) as usize]);
+        if little_endian {
+            buf[1..].reverse();
+        }
+        chunks.push_back(U256::from_be_bytes(env, &Bytes::from_array(env, &buf)));
+        start = end;
+    }
+    chunks
+}
@@ -0,0 +1,69 @@
+//! Decoding of the built-in parameter sets.
+//!
+//! Each parameter set is stored as one `&'static [u8]` blob of 32-byte
+//! big-endian field elements: vectors element by element, matrices row by row.
+//! Every element becomes a host `U256` with a single host call, and every
+//! vector is created from all its elements at once, so building a parameter
+//! set costs one host call per constant plus one per row.
+//!
+//! Storing each set as an XDR-encoded `Vec<Vec<U256>>` and decoding it with a
+//! single `from_xdr` call was measured too (see `src/tests/budget.rs`), and
+//! costs about three times the CPU and twice the memory of this decoding, so
+//! the blobs are decoded constant by constant.
+
+use soroban_sdk::{
+    unwrap::UnwrapInfallible, Env, EnvBase, IntoVal, TryFromVal, Val, Vec, VecObject, U256,
+};
+
+/// Size in bytes of one encoded constant.
+const ELEMENT_SIZE: usize = 32;
+
+/// Upper bound on the length of a decoded vector. The longest are the round
+/// constant matrices, with at most 78 rows (Poseidon, T = 14).
+const MAX_LEN: usize = 128;
+
+fn decode_u256(e: &Env, bytes: &[u8; ELEMENT_SIZE]) -> U256 {
+    let (limbs, _) = bytes.as_chunks::<8>();
+    let limb = |i: usize| u64::from_be_bytes(limbs[i]);
+    U256::from_parts(e, limb(0), limb(1), limb(2), limb(3))
+}
+
+fn vec_from_vals<T>(e: &Env, vals: impl Iterator<Item = Val>) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut buf = [Val::VOID.to_val(); MAX_LEN];
+    let mut len = 0;
+    for v in vals {
+        assert!(len < MAX_LEN, "parameter blob too long");
+        buf[len] = v;
+        len += 1;
+    }
+    let obj: VecObject = e.vec_new_from_slice(&buf[..len]).unwrap_infallible();
+    Vec::<T>::try_from_val(e, &obj).unwrap_infallible()
+}
+
+/// Decodes a blob of field elements into a vector.
+pub(crate) fn u256_vec(e: &Env, bytes: &[u8]) -> Vec<U256> {
+    debug_assert_eq!(bytes.len() % ELEMENT_SIZE, 0);
+    vec_from_vals(
+        e,
+        bytes
+            .as_chunks::<ELEMENT_SIZE>()
+            .0
+            .iter()
+            .map(|c| decode_u256(e, c).to_val()),
+    )
+}
+
+/// Decodes a blob of field elements into a matrix with rows of `row_len`
+/// elements.
+pub(crate) fn u256_matrix(e: &Env, bytes: &[u8], row_len: u32) -> Vec<Vec<U256>> {
+    debug_assert_eq!(bytes.len() % (ELEMENT_SIZE * row_len as usize), 0);
+    vec_from_vals(
+        e,
+        bytes
+            .chunks_exact(ELEMENT_SIZE * row_len as usize)
+            .map(|row| u256_vec(e, row).to_val()),
+    )
+}
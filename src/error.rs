@@ -0,0 +1,26 @@
+//! Errors returned by the fallible (`try_`) hashing API.
+
+use soroban_sdk::contracterror;
+
+/// Why a `try_` hash could not be computed.
+///
+/// The panicking API traps on the same conditions, which aborts the
+/// invocation without a reason the caller can inspect. As a
+/// `#[contracterror]`, this can be returned from a contract function (or
+/// mapped into the contract's own error type) so the failure reaches the
+/// caller as a structured `Error(Contract, #code)`.
+///
+/// Contract errors are plain `u32` codes, so the variants carry no data
+/// (e.g. the position of the offending input); the codes are stable.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PoseidonError {
+    /// An input is greater than or equal to the field modulus.
+    InputNotInField = 1,
+    /// The number of inputs does not match what the hash requires, e.g.
+    /// Poseidon's `compute_hash` takes exactly `RATE` inputs.
+    WrongArity = 2,
+    /// The parameter set does not match the state size `T`.
+    UnsupportedConfig = 3,
+}
@@ -0,0 +1,187 @@
+//! Canonical field elements.
+
+use crate::{Field, PoseidonError};
+use core::{fmt, marker::PhantomData};
+#[cfg(doc)]
+use soroban_sdk::Vec;
+use soroban_sdk::{
+    contracttype,
+    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+    BytesN, ConversionError, Env, TryFromVal, Val, U256,
+};
+
+/// An element of the field `F`, i.e. a [`U256`] that is known to be less
+/// than `F::modulus()`.
+///
+/// A `FieldElement` can only be built through a checked constructor, so the
+/// sponges' `*_elements` methods (e.g.
+/// [`PoseidonSponge::compute_hash_elements`](crate::PoseidonSponge::compute_hash_elements))
+/// absorb it without comparing it against the modulus again.
+///
+/// The hashing APIs take a slice, which can only hold elements built by
+/// these constructors. A [`Vec`] of field elements is a plain vector of
+/// `U256` on the host and can hold any value (e.g. one read from storage), so
+/// its elements are checked again when they are read out of it.
+///
+/// It converts to and from a [`Val`] as a plain `U256`, so it can be stored
+/// or passed to another contract. Converting from a `Val` rejects
+/// non-canonical values. Contract functions cannot declare generic
+/// types in their interface, so they take a [`Bn254FieldElement`] or
+/// [`Bls12381FieldElement`] and convert it with `try_into`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_poseidon::{poseidon_hash_elements, FieldElement};
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, Env, U256};
+///
+/// let env = Env::default();
+/// let a = FieldElement::<Bn254Fr>::from_u32(&env, 1);
+/// let b = FieldElement::<Bn254Fr>::new_checked(&env, U256::from_u32(&env, 2)).unwrap();
+/// let hash = poseidon_hash_elements::<3, Bn254Fr>(&env, &[a, b]);
+/// # }
+/// ```
+pub struct FieldElement<F: Field> {
+    value: U256,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: Field> FieldElement<F> {
+    // Callers must ensure `value < F::modulus()`
+    pub(crate) fn from_canonical(value: U256) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns `value` as a field element.
+    ///
+    /// # Errors
+    /// - [`PoseidonError::InputNotInField`] if `value` is greater than or
+    ///   equal to the field modulus.
+    pub fn new_checked(env: &Env, value: U256) -> Result<Self, PoseidonError> {
+        if !F::is_canonical(env, &value) {
+            return Err(PoseidonError::InputNotInField);
+        }
+        Ok(Self::from_canonical(value))
+    }
+
+    /// Returns `value` as a field element. Every supported modulus is larger
+    /// than `2^32`, so no check is needed.
+    pub fn from_u32(env: &Env, value: u32) -> Self {
+        Self::from_canonical(U256::from_u32(env, value))
+    }
+
+    /// Interprets `bytes` as a big-endian integer and reduces it modulo the
+    /// field modulus, e.g. to turn a SHA-256 digest into a field element.
+    ///
+    /// The reduction is slightly biased towards small values, since neither
+    /// modulus divides `2^256`.
+    pub fn from_be_bytes_reduced(env: &Env, bytes: &BytesN<32>) -> Self {
+        let value = U256::from_be_bytes(env, &bytes.into());
+        Self::from_canonical(F::reduce(env, value))
+    }
+
+    /// Returns the underlying integer.
+    pub fn as_u256(&self) -> &U256 {
+        &self.value
+    }
+
+    /// Returns the underlying integer.
+    pub fn to_u256(&self) -> U256 {
+        self.value.clone()
+    }
+}
+
+impl<F: Field> Clone for FieldElement<F> {
+    fn clone(&self) -> Self {
+        Self::from_canonical(self.value.clone())
+    }
+}
+
+impl<F: Field> fmt::Debug for FieldElement<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldElement").field(&self.value).finish()
+    }
+}
+
+impl<F: Field> PartialEq for FieldElement<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F: Field> Eq for FieldElement<F> {}
+
+impl<F: Field> From<FieldElement<F>> for U256 {
+    fn from(element: FieldElement<F>) -> Self {
+        element.value
+    }
+}
+
+impl<F: Field> TryFromVal<Env, Val> for FieldElement<F> {
+    type Error = ConversionError;
+
+    fn try_from_val(env: &Env, val: &Val) -> Result<Self, Self::Error> {
+        let value = U256::try_from_val(env, val)?;
+        Self::new_checked(env, value).map_err(|_| ConversionError)
+    }
+}
+
+impl<F: Field> TryFromVal<Env, FieldElement<F>> for Val {
+    type Error = ConversionError;
+
+    fn try_from_val(env: &Env, element: &FieldElement<F>) -> Result<Self, Self::Error> {
+        Val::try_from_val(env, &element.value)
+    }
+}
+
+/// A BN254 scalar field element in a contract interface.
+///
+/// Contract types cannot be generic, so contract functions take this type
+/// and convert it into a [`FieldElement<Bn254Fr>`] with `try_into`, which
+/// checks it against the modulus once. Decoding it from a `Val` does not
+/// check it.
+///
+/// It is encoded as a one-element tuple, not as a plain `U256` like
+/// [`FieldElement`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bn254FieldElement(U256);
+
+/// A BLS12-381 scalar field element in a contract interface.
+///
+/// See [`Bn254FieldElement`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bls12381FieldElement(U256);
+
+macro_rules! impl_contract_field_element {
+    ($name:ident, $field:ty) => {
+        impl $name {
+            /// Returns the underlying integer.
+            pub fn to_u256(&self) -> U256 {
+                self.0.clone()
+            }
+        }
+
+        impl From<FieldElement<$field>> for $name {
+            fn from(element: FieldElement<$field>) -> Self {
+                Self(element.value)
+            }
+        }
+
+        impl TryFrom<$name> for FieldElement<$field> {
+            type Error = PoseidonError;
+
+            fn try_from(element: $name) -> Result<Self, Self::Error> {
+                let env = element.0.env().clone();
+                Self::new_checked(&env, element.0)
+            }
+        }
+    };
+}
+
+impl_contract_field_element!(Bn254FieldElement, Bn254Fr);
+impl_contract_field_element!(Bls12381FieldElement, Bls12381Fr);
@@ -3,22 +3,86 @@
 use soroban_sdk::{
     bytesn,
     crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
-    symbol_short, Env, Symbol, Vec, U256,
+    symbol_short, Bytes, Env, Symbol, Vec, U256,
 };
 
+pub(crate) mod bytes;
+#[cfg(any(
+    feature = "poseidon-bn254",
+    feature = "poseidon-bls12-381",
+    feature = "poseidon2-bn254",
+    feature = "poseidon2-bls12-381"
+))]
+pub(crate) mod constants;
+pub(crate) mod error;
+pub(crate) mod field_element;
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+pub(crate) mod merkle;
 pub(crate) mod poseidon;
 pub(crate) mod poseidon2;
+pub(crate) mod registry;
+pub(crate) mod safe;
 
 #[cfg(test)]
 mod tests;
 
-pub use poseidon::{PoseidonConfig, PoseidonSponge};
-pub use poseidon2::{Poseidon2Config, Poseidon2Sponge};
+pub use error::PoseidonError;
+pub use field_element::{Bls12381FieldElement, Bn254FieldElement, FieldElement};
+#[cfg(feature = "poseidon-bn254")]
+pub use merkle::{
+    verify_merkle_proof, verify_sparse_merkle_exclusion, verify_sparse_merkle_inclusion,
+    IncrementalMerkleTree, LeanIncrementalMerkleTree,
+};
+#[cfg(feature = "poseidon2-bn254")]
+pub use merkle::{verify_merkle_proof_poseidon2, IndexedLeaf, IndexedMerkleTree};
+pub use poseidon::{PoseidonConfig, PoseidonParams, PoseidonSponge};
+pub use poseidon2::{Poseidon2Config, Poseidon2Params, Poseidon2Sponge};
+pub use registry::{ParamsRegistryClient, ParamsRegistryInterface};
+pub use safe::{SafeCall, SafePermutation, SafeSponge};
 
 pub trait Field {
     fn symbol() -> Symbol;
     /// Returns the field modulus. Inputs to Poseidon/Poseidon2 must be less than this value.
     fn modulus(env: &Env) -> U256;
+
+    /// Returns `value` reduced modulo the field modulus.
+    fn reduce(env: &Env, value: U256) -> U256 {
+        value.rem_euclid(&Self::modulus(env))
+    }
+
+    /// Returns whether `value` is a field element, i.e. less than the
+    /// modulus, and can be hashed as is.
+    fn is_canonical(env: &Env, value: &U256) -> bool {
+        *value < Self::modulus(env)
+    }
+
+    /// Interprets `bytes` as a big-endian integer of any length and reduces
+    /// it modulo the field modulus, like arkworks' `from_be_bytes_mod_order`.
+    /// This turns e.g. a SHA-256 digest or an address hash into a valid
+    /// input.
+    ///
+    /// Up to 32 bytes are reduced with a single [`reduce`](Self::reduce).
+    /// Every further byte costs eight modular doublings, so long inputs are
+    /// better hashed down to 32 bytes first. The result is slightly biased
+    /// towards small values, since the modulus does not divide `2^256`.
+    fn from_be_bytes_mod_order(env: &Env, bytes: &Bytes) -> U256 {
+        let head = bytes.len().min(32);
+        let mut padded = Bytes::from_array(env, &[0u8; 32]).slice(head..);
+        padded.append(&bytes.slice(..head));
+        let mut value = Self::reduce(env, U256::from_be_bytes(env, &padded));
+
+        // Both moduli are below 2^255, so doubling a reduced value cannot
+        // overflow
+        let modulus = Self::modulus(env);
+        let reduce_once = |v: U256| if v >= modulus { v.sub(&modulus) } else { v };
+        for byte in bytes.slice(head..).iter() {
+            for _ in 0..8 {
+                value = reduce_once(value.shl(1));
+            }
+            value = reduce_once(value.add(&U256::from_u32(env, byte as u32)));
+        }
+        value
+    }
 }
 
 impl Field for Bn254Fr {
@@ -57,6 +121,56 @@ impl Field for Bls12381Fr {
     }
 }
 
+/// A hash function over field elements, implemented by [`PoseidonSponge`]
+/// and [`Poseidon2Sponge`] for every supported configuration.
+///
+/// Code written against this trait (Merkle trees, commitments, nullifiers,
+/// ...) can switch between Poseidon and Poseidon2 with a type parameter.
+///
+/// # Arity
+///
+/// Every implementation accepts [`RATE`](Self::RATE) inputs. Poseidon
+/// (circom) requires exactly `RATE` inputs and panics otherwise, while
+/// Poseidon2 (noir) accepts any number of inputs, so generic code should hash
+/// `RATE` elements at a time.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "poseidon-bn254", feature = "poseidon2-bn254"))] {
+/// use soroban_poseidon::{FieldHasher, Poseidon2Sponge, PoseidonSponge};
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+///
+/// fn commit<H: FieldHasher>(env: &Env, value: U256, salt: U256) -> U256 {
+///     H::new(env).hash(&vec![env, value, salt])
+/// }
+///
+/// let env = Env::default();
+/// let (value, salt) = (U256::from_u32(&env, 1), U256::from_u32(&env, 2));
+/// let circom = commit::<PoseidonSponge<3, Bn254Fr>>(&env, value.clone(), salt.clone());
+/// let noir = commit::<Poseidon2Sponge<3, Bn254Fr>>(&env, value, salt);
+/// assert_ne!(circom, noir);
+/// # }
+/// ```
+pub trait FieldHasher {
+    /// The field of the inputs and the output.
+    type Field: Field;
+
+    /// The number of inputs absorbed per permutation (`T - 1`).
+    const RATE: u32;
+
+    /// Creates a hasher with the built-in parameters.
+    fn new(env: &Env) -> Self;
+
+    /// Computes a fresh hash of `inputs`. The hasher can be reused for any
+    /// number of independent hashes.
+    ///
+    /// # Panics
+    /// - if `inputs` has a length the implementation does not support (see
+    ///   [Arity](Self#arity)).
+    /// - if any input is greater than or equal to the field modulus.
+    fn hash(&mut self, inputs: &Vec<U256>) -> U256;
+}
+
 /// Computes a Poseidon hash matching circom's
 /// [implementation](https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom).
 ///
@@ -67,9 +181,13 @@ impl Field for Bls12381Fr {
 ///
 /// # Supported Configurations
 ///
-/// - BN254: `T` ∈ {2, 3, 4, 5, 6} (i.e., 1–5 inputs)
+/// - BN254: `T` ∈ {2, ..., 17} (i.e., 1–16 inputs, as in circomlib)
 /// - BLS12-381: `T` ∈ {2, 3, 4, 5, 6} (i.e., 1–5 inputs)
 ///
+/// Each field requires its `poseidon-bn254` / `poseidon-bls12-381` cargo
+/// feature, and `T` ≥ 7 also requires `poseidon-wide` (all enabled by
+/// default).
+///
 /// # Panics
 ///
 /// - if `inputs.len() != T - 1`
@@ -78,6 +196,7 @@ impl Field for Bls12381Fr {
 /// # Example
 ///
 /// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
 /// use soroban_sdk::{bytesn, crypto::bn254::Bn254Fr, vec, Env, U256};
 /// use soroban_poseidon::poseidon_hash;
 ///
@@ -97,6 +216,7 @@ impl Field for Bls12381Fr {
 ///     &bytesn!(&env, 0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a).into(),
 /// );
 /// assert_eq!(hash, expected);
+/// # }
 /// ```
 ///
 /// # Repeated Hashing
@@ -107,6 +227,7 @@ impl Field for Bls12381Fr {
 /// i.e. the sponge state is reset between calls:
 ///
 /// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
 /// # use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
 /// # use soroban_poseidon::PoseidonSponge;
 /// # let env = Env::default();
@@ -117,6 +238,7 @@ impl Field for Bls12381Fr {
 ///
 /// let h1 = sponge.compute_hash(&inputs1); // fresh hash
 /// let h2 = sponge.compute_hash(&inputs2); // another fresh hash (state was reset)
+/// # }
 /// ```
 pub fn poseidon_hash<const T: u32, F: Field>(env: &Env, inputs: &Vec<U256>) -> U256
 where
@@ -126,34 +248,303 @@ where
     sponge.compute_hash(inputs)
 }
 
+/// Computes the same hash as [`poseidon_hash`], but returns an error instead
+/// of panicking on invalid inputs.
+///
+/// # Errors
+///
+/// - [`PoseidonError::WrongArity`] if `inputs.len() != T - 1`
+/// - [`PoseidonError::InputNotInField`] if any input value ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+/// use soroban_poseidon::{try_poseidon_hash, PoseidonError};
+///
+/// let env = Env::default();
+/// let inputs = vec![&env, U256::from_u32(&env, 1)];
+/// assert_eq!(
+///     try_poseidon_hash::<3, Bn254Fr>(&env, &inputs),
+///     Err(PoseidonError::WrongArity)
+/// );
+/// # }
+/// ```
+pub fn try_poseidon_hash<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &Vec<U256>,
+) -> Result<U256, PoseidonError>
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.try_compute_hash(inputs)
+}
+
+/// Computes the same hash as [`poseidon_hash`] of inputs that are already
+/// known to be field elements, without comparing them against the modulus
+/// again.
+///
+/// # Panics
+///
+/// - if `inputs.len() != T - 1`
+pub fn poseidon_hash_elements<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &[FieldElement<F>],
+) -> FieldElement<F>
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_hash_elements(inputs)
+}
+
+/// Computes a variable-length Poseidon hash of any non-empty number of inputs.
+///
+/// The inputs are absorbed `T - 1` elements at a time, chaining permutations,
+/// with the input length committed in the capacity element. See
+/// [`PoseidonSponge::compute_hash_varlen`] for the construction and its
+/// collision-resistance argument. The result is **not** compatible with
+/// circom's `Poseidon(nInputs)` (use [`poseidon_hash`] for that).
+///
+/// # Type Parameters
+///
+/// - `T`: State size. Any supported `T` works for any input length; larger
+///   `T` means fewer permutations.
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for BLS12-381.
+///
+/// # Panics
+///
+/// - if `inputs` is empty
+/// - if any input value ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, Env, Vec, U256};
+/// use soroban_poseidon::poseidon_hash_varlen;
+///
+/// let env = Env::default();
+///
+/// // Hash 7 field elements with t=3 (rate=2): 4 permutations
+/// let mut inputs = Vec::new(&env);
+/// for i in 1..=7 {
+///     inputs.push_back(U256::from_u32(&env, i));
+/// }
+/// let hash = poseidon_hash_varlen::<3, Bn254Fr>(&env, &inputs);
+/// # }
+/// ```
+pub fn poseidon_hash_varlen<const T: u32, F: Field>(env: &Env, inputs: &Vec<U256>) -> U256
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_hash_varlen(inputs)
+}
+
+/// Computes a Poseidon hash of an arbitrary byte string, matching
+/// go-iden3-crypto's `poseidon.HashBytesX(msg, T - 1)`.
+///
+/// The bytes are packed into 31-byte big-endian chunks, which are hashed
+/// `T - 1` at a time, chaining each hash into the next frame. See
+/// [`PoseidonSponge::compute_hash_bytes`] for the scheme and its limits: the
+/// byte length is not committed, so messages that differ by trailing zero
+/// bytes (even whole zero chunks) can collide. Only hash fixed-length or
+/// self-delimiting payloads, or use [`poseidon_hash_bytes_varlen`]. A `String`
+/// can be hashed through `String::to_bytes`.
+///
+/// # Type Parameters
+///
+/// - `T`: State size, at least 3. `T = 17` (with the `poseidon-wide`
+///   feature) is iden3's `poseidon.HashBytes`.
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for BLS12-381.
+///
+/// # Panics
+///
+/// - if `msg` is empty
+/// - if `T < 3`
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, Bytes, Env};
+/// use soroban_poseidon::poseidon_hash_bytes;
+///
+/// let env = Env::default();
+/// let msg = Bytes::from_slice(&env, b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+/// let hash = poseidon_hash_bytes::<6, Bn254Fr>(&env, &msg);
+/// # }
+/// ```
+pub fn poseidon_hash_bytes<const T: u32, F: Field>(env: &Env, msg: &Bytes) -> U256
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_hash_bytes(msg)
+}
+
+/// Computes a variable-length Poseidon hash of an arbitrary byte string that
+/// commits to its length.
+///
+/// The bytes are packed into 31-byte big-endian chunks, followed by the byte
+/// length, and hashed with [`poseidon_hash_varlen`]. Unlike
+/// [`poseidon_hash_bytes`], distinct messages never collide by construction,
+/// but the result is not compatible with iden3's `HashBytes`. See
+/// [`PoseidonSponge::compute_hash_bytes_varlen`].
+///
+/// # Type Parameters
+///
+/// - `T`: State size (rate = T-1, capacity = 1).
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for BLS12-381.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, Bytes, Env};
+/// use soroban_poseidon::poseidon_hash_bytes_varlen;
+///
+/// let env = Env::default();
+/// let msg = Bytes::from_slice(&env, b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+/// let hash = poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &msg);
+/// # }
+/// ```
+pub fn poseidon_hash_bytes_varlen<const T: u32, F: Field>(env: &Env, msg: &Bytes) -> U256
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_hash_bytes_varlen(msg)
+}
+
+/// Computes a multi-output Poseidon hash with an explicit initial state,
+/// matching circom's
+/// [`PoseidonEx(nInputs, nOuts)`](https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom).
+///
+/// The capacity element is initialized to `initial_state` (circom's
+/// `initialState`) and the first `n_outs` elements of the permuted state are
+/// returned. `poseidon_ex(env, inputs, 0, 1)` equals `[poseidon_hash(env,
+/// inputs)]`.
+///
+/// # Type Parameters
+///
+/// - `T`: State size. Must equal `inputs.len() + 1` (rate = T-1, capacity = 1).
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for BLS12-381.
+///
+/// # Panics
+///
+/// - if `inputs.len() != T - 1`
+/// - if `n_outs` is 0 or greater than `T`
+/// - if `initial_state` or any input value ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+/// use soroban_poseidon::{poseidon_ex, poseidon_hash};
+///
+/// let env = Env::default();
+/// let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+///
+/// // Matches circom's PoseidonEx(2, 3) with initialState = 0
+/// let outs = poseidon_ex::<3, Bn254Fr>(&env, &inputs, U256::from_u32(&env, 0), 3);
+/// assert_eq!(outs.len(), 3);
+/// assert_eq!(outs.get_unchecked(0), poseidon_hash::<3, Bn254Fr>(&env, &inputs));
+/// # }
+/// ```
+pub fn poseidon_ex<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &Vec<U256>,
+    initial_state: U256,
+    n_outs: u32,
+) -> Vec<U256>
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_hash_ex(inputs, initial_state, n_outs)
+}
+
+/// Applies the raw Poseidon permutation `P(state) -> state` to a full
+/// `T`-element state.
+///
+/// No IV, padding or output convention is applied. This is the building block
+/// for custom modes (e.g. Jive compression or transcripts); for hashing, use
+/// [`poseidon_hash`] instead.
+///
+/// # Type Parameters
+///
+/// - `T`: State size. Must equal `state.len()`.
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for BLS12-381.
+///
+/// # Panics
+///
+/// - if `state.len() != T`
+/// - if any state element ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+/// use soroban_poseidon::{poseidon_hash, poseidon_permutation};
+///
+/// let env = Env::default();
+/// let zero = U256::from_u32(&env, 0);
+/// let state = vec![&env, zero, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+///
+/// // Hashing is the permutation of [0, inputs...], read at position 0
+/// let permuted = poseidon_permutation::<3, Bn254Fr>(&env, &state);
+/// let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+/// assert_eq!(permuted.get_unchecked(0), poseidon_hash::<3, Bn254Fr>(&env, &inputs));
+/// # }
+/// ```
+pub fn poseidon_permutation<const T: u32, F: Field>(env: &Env, state: &Vec<U256>) -> Vec<U256>
+where
+    PoseidonSponge<T, F>: PoseidonConfig<T, F>,
+{
+    let mut sponge = PoseidonSponge::<T, F>::new(env);
+    sponge.compute_permutation(state)
+}
+
 /// Computes a Poseidon2 hash matching noir's
 /// [implementation](https://github.com/noir-lang/noir/blob/master/noir_stdlib/src/hash/poseidon2.nr).
 ///
 /// # Type Parameters
 ///
-/// - `T`: State size. Must be ≥ `inputs.len() + 1`. Common usage is `T=4`
-///   (rate=3) matching noir's default.
+/// - `T`: State size. Common usage is `T=4` (rate=3) matching noir's default.
+///   Inputs longer than the rate `T - 1` are absorbed in chunks of `T - 1`
+///   elements, one permutation per chunk.
 /// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for
 ///   BLS12-381.
 ///
 /// # Supported Configurations
 ///
-/// - BN254: `T` ∈ {2, 3, 4} (i.e., rate = 1, 2, or 3)
-/// - BLS12-381: `T` ∈ {2, 3, 4} (i.e., rate = 1, 2, or 3)
+/// - BN254: `T` ∈ {2, 3, 4, 8, 12, 16} (i.e., rate = 1, 2, 3, 7, 11, or 15)
+/// - BLS12-381: `T` ∈ {2, 3, 4, 8, 12, 16} (i.e., rate = 1, 2, 3, 7, 11, or 15)
+///
+/// Each field requires its `poseidon2-bn254` / `poseidon2-bls12-381` cargo
+/// feature, and `T` ≥ 8 also requires `poseidon2-wide` (all enabled by
+/// default).
 ///
 /// # Panics
 ///
-/// - if `inputs.len() > T - 1` (rate exceeded)
 /// - if any input value ≥ the field modulus (inputs must be valid field elements)
 ///
 /// # Capacity Initialization
 ///
 /// The capacity element is initialized to `inputs.len() << 64`, matching noir's
-/// Poseidon2 implementation.
+/// Poseidon2 implementation. The result equals noir's
+/// `Poseidon2::hash(inputs, inputs.len())` for inputs of any length.
 ///
 /// # Example
 ///
 /// ```
+/// # #[cfg(feature = "poseidon2-bn254")] {
 /// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
 /// use soroban_poseidon::poseidon2_hash;
 ///
@@ -167,6 +558,7 @@ where
 ///     U256::from_u32(&env, 3),
 /// ];
 /// let hash = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
+/// # }
 /// ```
 ///
 /// # Repeated Hashing
@@ -177,6 +569,7 @@ where
 /// independent, i.e. the sponge state is reset between calls:
 ///
 /// ```
+/// # #[cfg(feature = "poseidon2-bn254")] {
 /// # use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
 /// # use soroban_poseidon::Poseidon2Sponge;
 /// # let env = Env::default();
@@ -187,6 +580,7 @@ where
 ///
 /// let h1 = sponge.compute_hash(&inputs1); // fresh hash
 /// let h2 = sponge.compute_hash(&inputs2); // another fresh hash (state was reset)
+/// # }
 /// ```
 pub fn poseidon2_hash<const T: u32, F: Field>(env: &Env, inputs: &Vec<U256>) -> U256
 where
@@ -195,3 +589,157 @@ where
     let mut sponge = Poseidon2Sponge::<T, F>::new(env);
     sponge.compute_hash(inputs)
 }
+
+/// Computes a Poseidon2 hash of an arbitrary byte string, matching Aztec's
+/// `poseidon2_hash_bytes` for `T = 4` over BN254.
+///
+/// The bytes are packed into 31-byte little-endian chunks, which are hashed
+/// with [`poseidon2_hash`]. See [`Poseidon2Sponge::compute_hash_bytes`] for
+/// the scheme and its limits: the byte length is not committed, so only hash
+/// fixed-length or self-delimiting payloads. A `String` can be hashed through
+/// `String::to_bytes`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon2-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, Bytes, Env};
+/// use soroban_poseidon::poseidon2_hash_bytes;
+///
+/// let env = Env::default();
+/// let msg = Bytes::from_slice(&env, b"hello world");
+/// let hash = poseidon2_hash_bytes::<4, Bn254Fr>(&env, &msg);
+/// # }
+/// ```
+pub fn poseidon2_hash_bytes<const T: u32, F: Field>(env: &Env, msg: &Bytes) -> U256
+where
+    Poseidon2Sponge<T, F>: Poseidon2Config<T, F>,
+{
+    let mut sponge = Poseidon2Sponge::<T, F>::new(env);
+    sponge.compute_hash_bytes(msg)
+}
+
+/// Computes the same hash as [`poseidon2_hash`], but returns an error instead
+/// of panicking on invalid inputs.
+///
+/// # Errors
+///
+/// - [`PoseidonError::InputNotInField`] if any input value ≥ the field modulus
+pub fn try_poseidon2_hash<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &Vec<U256>,
+) -> Result<U256, PoseidonError>
+where
+    Poseidon2Sponge<T, F>: Poseidon2Config<T, F>,
+{
+    let mut sponge = Poseidon2Sponge::<T, F>::new(env);
+    sponge.try_compute_hash(inputs)
+}
+
+/// Computes the same hash as [`poseidon2_hash`] of inputs that are already
+/// known to be field elements, without comparing them against the modulus
+/// again.
+pub fn poseidon2_hash_elements<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &[FieldElement<F>],
+) -> FieldElement<F>
+where
+    Poseidon2Sponge<T, F>: Poseidon2Config<T, F>,
+{
+    let mut sponge = Poseidon2Sponge::<T, F>::new(env);
+    sponge.compute_hash_elements(inputs)
+}
+
+/// Computes the Poseidon2 two-to-one compression function (truncated
+/// permutation with feed-forward), as used by HorizenLabs / Plonky3 style
+/// Merkle trees.
+///
+/// Unlike [`poseidon2_hash`], no sponge or IV is involved: the `T` inputs are
+/// the full initial state, and the first `n_outs` elements of
+/// `P(inputs) + inputs` are returned. For a binary Merkle tree with `T = 2`,
+/// `poseidon2_compress(env, [left, right], 1)[0]` is `P(left || right)[0] +
+/// left`.
+///
+/// # Type Parameters
+///
+/// - `T`: State size. Must equal `inputs.len()`.
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for
+///   BLS12-381.
+///
+/// # Panics
+///
+/// - if `inputs.len() != T`
+/// - if `n_outs` is 0 or greater than `T`
+/// - if any input value ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon2-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+/// use soroban_poseidon::poseidon2_compress;
+///
+/// let env = Env::default();
+/// let left = U256::from_u32(&env, 1);
+/// let right = U256::from_u32(&env, 2);
+///
+/// let parent = poseidon2_compress::<2, Bn254Fr>(&env, &vec![&env, left, right], 1);
+/// assert_eq!(parent.len(), 1);
+/// # }
+/// ```
+pub fn poseidon2_compress<const T: u32, F: Field>(
+    env: &Env,
+    inputs: &Vec<U256>,
+    n_outs: u32,
+) -> Vec<U256>
+where
+    Poseidon2Sponge<T, F>: Poseidon2Config<T, F>,
+{
+    let mut sponge = Poseidon2Sponge::<T, F>::new(env);
+    sponge.compute_compress(inputs, n_outs)
+}
+
+/// Applies the raw Poseidon2 permutation `P(state) -> state` to a full
+/// `T`-element state.
+///
+/// No IV, padding or output convention is applied. This is the building block
+/// for custom modes (e.g. Jive compression or transcripts); for hashing, use
+/// [`poseidon2_hash`] instead.
+///
+/// # Type Parameters
+///
+/// - `T`: State size. Must equal `state.len()`.
+/// - `F`: Field type. Use [`Bn254Fr`] for BN254 or [`Bls12381Fr`] for
+///   BLS12-381.
+///
+/// # Panics
+///
+/// - if `state.len() != T`
+/// - if any state element ≥ the field modulus
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "poseidon2-bn254")] {
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+/// use soroban_poseidon::poseidon2_permutation;
+///
+/// let env = Env::default();
+/// let state = vec![
+///     &env,
+///     U256::from_u32(&env, 0),
+///     U256::from_u32(&env, 1),
+///     U256::from_u32(&env, 2),
+///     U256::from_u32(&env, 3),
+/// ];
+/// let permuted = poseidon2_permutation::<4, Bn254Fr>(&env, &state);
+/// assert_eq!(permuted.len(), 4);
+/// # }
+/// ```
+pub fn poseidon2_permutation<const T: u32, F: Field>(env: &Env, state: &Vec<U256>) -> Vec<U256>
+where
+    Poseidon2Sponge<T, F>: Poseidon2Config<T, F>,
+{
+    let mut sponge = Poseidon2Sponge::<T, F>::new(env);
+    sponge.compute_permutation(state)
+}
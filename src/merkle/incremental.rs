@@ -0,0 +1,176 @@
+use super::{init_state, load_state, save_state, zero_hashes};
+use crate::PoseidonSponge;
+use soroban_sdk::{contracttype, crypto::bn254::Bn254Fr, vec, Env, IntoVal, Val, Vec, U256};
+
+/// The persisted state of an [`IncrementalMerkleTree`].
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct TreeState {
+    depth: u32,
+    next_index: u64,
+    // `filled_subtrees[i]` is the last left node inserted at level `i`
+    filled_subtrees: Vec<U256>,
+    // `zeros[i]` is the root of an empty subtree of height `i`, for `i` in
+    // `0..=depth`
+    zeros: Vec<U256>,
+    // Ring buffer of the last `ROOT_HISTORY_SIZE` roots
+    roots: Vec<U256>,
+    current_root_index: u32,
+}
+
+/// An append-only Merkle tree kept in contract storage, in the style of
+/// Tornado Cash's `MerkleTreeWithHistory`.
+///
+/// Only the frontier (the last left node of each level), the empty subtree
+/// roots and a history of recent roots are stored, so inserting a leaf costs
+/// `depth` hashes regardless of the number of leaves. Empty leaves are `0`,
+/// and node `i` at each level is `Poseidon([left, right])` of its children
+/// `2i` and `2i + 1`, which matches circomlib's `MerkleTreeInclusionProof`
+/// (a path index bit of 0 means the node is a left child).
+///
+/// Recent roots stay valid after later insertions (see
+/// [`is_known_root`](Self::is_known_root)), so a proof generated against the
+/// root at the time is not invalidated by concurrent deposits.
+///
+/// # Example
+/// ```ignore
+/// // In the contract's constructor
+/// IncrementalMerkleTree::init(&env, &symbol_short!("TREE"), 20);
+///
+/// // When depositing
+/// let mut tree = IncrementalMerkleTree::load(&env, &symbol_short!("TREE"));
+/// let index = tree.insert(commitment);
+/// let root = tree.root();
+/// ```
+pub struct IncrementalMerkleTree {
+    env: Env,
+    key: Val,
+    state: TreeState,
+    sponge: PoseidonSponge<3, Bn254Fr>,
+}
+
+impl IncrementalMerkleTree {
+    /// The maximum depth of a tree, i.e. at most 2^32 leaves.
+    pub const MAX_DEPTH: u32 = 32;
+
+    /// The number of most recent roots accepted by
+    /// [`is_known_root`](Self::is_known_root).
+    pub const ROOT_HISTORY_SIZE: u32 = 30;
+
+    /// Creates an empty tree of the given depth and stores it under `key`.
+    ///
+    /// # Panics
+    /// - if `depth` is 0 or greater than [`MAX_DEPTH`](Self::MAX_DEPTH).
+    /// - if a tree is already stored under `key`.
+    pub fn init<K: IntoVal<Env, Val>>(env: &Env, key: &K, depth: u32) -> Self {
+        assert!(
+            (1..=Self::MAX_DEPTH).contains(&depth),
+            "Merkle: depth must be between 1 and 32"
+        );
+        let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(env);
+        let zeros = zero_hashes(env, &mut sponge, depth);
+        let state = TreeState {
+            depth,
+            next_index: 0,
+            filled_subtrees: zeros.slice(0..depth),
+            roots: vec![env, zeros.get_unchecked(depth)],
+            zeros,
+            current_root_index: 0,
+        };
+
+        Self {
+            env: env.clone(),
+            key: init_state(env, key, &state),
+            state,
+            sponge,
+        }
+    }
+
+    /// Loads the tree stored under `key` by [`init`](Self::init).
+    ///
+    /// # Panics
+    /// - if no tree is stored under `key`.
+    pub fn load<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let (key, state) = load_state(env, key);
+        Self {
+            env: env.clone(),
+            key,
+            state,
+            sponge: PoseidonSponge::<3, Bn254Fr>::new(env),
+        }
+    }
+
+    fn save(&self) {
+        save_state(&self.env, &self.key, &self.state);
+    }
+
+    /// Appends a leaf, stores the updated tree and returns the leaf's index.
+    ///
+    /// # Panics
+    /// - if the tree is full (it holds 2^depth leaves).
+    /// - if `leaf` is greater than or equal to the BN254 field modulus.
+    pub fn insert(&mut self, leaf: U256) -> u64 {
+        let index = self.state.next_index;
+        assert!(index < 1u64 << self.state.depth, "Merkle: tree is full");
+
+        let mut current_index = index;
+        let mut current = leaf;
+        for level in 0..self.state.depth {
+            let (left, right) = if current_index.is_multiple_of(2) {
+                self.state.filled_subtrees.set(level, current.clone());
+                (current, self.state.zeros.get_unchecked(level))
+            } else {
+                (self.state.filled_subtrees.get_unchecked(level), current)
+            };
+            current = self.sponge.compute_hash(&vec![&self.env, left, right]);
+            current_index /= 2;
+        }
+
+        if self.state.roots.len() < Self::ROOT_HISTORY_SIZE {
+            self.state.roots.push_back(current);
+            self.state.current_root_index = self.state.roots.len() - 1;
+        } else {
+            let next = (self.state.current_root_index + 1) % Self::ROOT_HISTORY_SIZE;
+            self.state.roots.set(next, current);
+            self.state.current_root_index = next;
+        }
+        self.state.next_index = index + 1;
+        self.save();
+        index
+    }
+
+    /// Returns the current root.
+    pub fn root(&self) -> U256 {
+        self.state
+            .roots
+            .get_unchecked(self.state.current_root_index)
+    }
+
+    /// Returns whether `root` is one of the last
+    /// [`ROOT_HISTORY_SIZE`](Self::ROOT_HISTORY_SIZE) roots, including the
+    /// root of the empty tree until it is evicted.
+    pub fn is_known_root(&self, root: &U256) -> bool {
+        self.state.roots.contains(root)
+    }
+
+    /// Returns the depth of the tree.
+    pub fn depth(&self) -> u32 {
+        self.state.depth
+    }
+
+    /// Returns the number of leaves inserted so far, i.e. the index of the
+    /// next leaf.
+    pub fn next_index(&self) -> u64 {
+        self.state.next_index
+    }
+
+    /// Returns the root of an empty subtree of height `level` (the empty leaf
+    /// for `level = 0`), i.e. the sibling used for missing nodes in a proof.
+    ///
+    /// # Panics
+    /// - if `level` is greater than the depth of the tree.
+    pub fn zero(&self, level: u32) -> U256 {
+        assert!(level <= self.state.depth, "Merkle: level exceeds depth");
+        self.state.zeros.get_unchecked(level)
+    }
+}
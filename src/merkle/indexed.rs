@@ -0,0 +1,305 @@
+use super::{init_state, load_state, proof::compute_root, save_state, zero_hashes};
+use crate::{Field, Poseidon2Sponge};
+use soroban_sdk::{contracttype, crypto::bn254::Bn254Fr, vec, Env, IntoVal, Val, Vec, U256};
+
+/// The preimage of a leaf of an [`IndexedMerkleTree`]: a value and a pointer
+/// to the next larger value in the tree.
+///
+/// `next_value` is `0` (and `next_index` is `0`) for the leaf holding the
+/// largest value.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexedLeaf {
+    pub value: U256,
+    pub next_value: U256,
+    pub next_index: u64,
+}
+
+impl IndexedLeaf {
+    /// Returns the leaf hash, `poseidon2_hash::<4, Bn254Fr>([value,
+    /// next_value, next_index])`, or `0` for the empty preimage, as Aztec's
+    /// `NullifierLeafPreimage`.
+    ///
+    /// # Panics
+    /// - if `value` or `next_value` is greater than or equal to the BN254
+    ///   field modulus.
+    pub fn hash(&self, env: &Env) -> U256 {
+        self.hash_with(env, &mut Poseidon2Sponge::<4, Bn254Fr>::new(env))
+    }
+
+    fn hash_with(&self, env: &Env, sponge: &mut Poseidon2Sponge<4, Bn254Fr>) -> U256 {
+        let zero = U256::from_u32(env, 0);
+        if self.value == zero && self.next_value == zero && self.next_index == 0 {
+            return zero;
+        }
+        sponge.compute_hash(&vec![
+            env,
+            self.value.clone(),
+            self.next_value.clone(),
+            U256::from_u128(env, self.next_index as u128),
+        ])
+    }
+
+    // Whether `value` falls strictly between this leaf's value and the next
+    fn bounds(&self, value: &U256) -> bool {
+        self.value < *value
+            && (self.next_value > *value || self.next_value == U256::from_u32(value.env(), 0))
+    }
+}
+
+/// The persisted state of an [`IndexedMerkleTree`].
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct IndexedTreeState {
+    depth: u32,
+    next_index: u64,
+    root: U256,
+    // `filled_subtrees[i]` is the last left node at level `i`
+    filled_subtrees: Vec<U256>,
+    // `zeros[i]` is the root of an empty subtree of height `i`, for `i` in
+    // `0..=depth`
+    zeros: Vec<U256>,
+}
+
+/// An append-only indexed Merkle tree in the style of Aztec's nullifier
+/// tree, for cheap non-membership proofs.
+///
+/// Each leaf is an [`IndexedLeaf`], and the leaves form a linked list sorted
+/// by value. A value is absent if a "low leaf" in the tree has a smaller
+/// value and points to a larger one (or to nothing). Leaves are hashed as
+/// [`IndexedLeaf::hash`] and nodes as `poseidon2_hash::<4, Bn254Fr>([left,
+/// right])` (Aztec's `merkle_hash`), with a single
+/// [`Poseidon2Sponge`](crate::Poseidon2Sponge) for everything. Empty leaves
+/// are `0`, and leaf 0 is the empty preimage, which starts the list.
+///
+/// Only the frontier, the empty subtree roots and the root are stored.
+/// Inserting a value takes the low leaf and its sibling path as a witness,
+/// like Aztec's circuits, and costs `3 * (depth + 1)` hashes.
+///
+/// # Example
+/// ```ignore
+/// // In the contract's constructor
+/// IndexedMerkleTree::init(&env, &symbol_short!("NULLS"), 32);
+///
+/// // When spending, with the low leaf of `nullifier` found off-chain
+/// let mut tree = IndexedMerkleTree::load(&env, &symbol_short!("NULLS"));
+/// tree.insert(nullifier, low_leaf, low_index, &low_siblings);
+/// ```
+pub struct IndexedMerkleTree {
+    env: Env,
+    key: Val,
+    state: IndexedTreeState,
+    sponge: Poseidon2Sponge<4, Bn254Fr>,
+}
+
+impl IndexedMerkleTree {
+    /// The maximum depth of a tree, i.e. at most 2^32 leaves.
+    pub const MAX_DEPTH: u32 = 32;
+
+    /// Creates a tree of the given depth holding only the empty leaf 0 and
+    /// stores it under `key`.
+    ///
+    /// # Panics
+    /// - if `depth` is 0 or greater than [`MAX_DEPTH`](Self::MAX_DEPTH).
+    /// - if a tree is already stored under `key`.
+    pub fn init<K: IntoVal<Env, Val>>(env: &Env, key: &K, depth: u32) -> Self {
+        assert!(
+            (1..=Self::MAX_DEPTH).contains(&depth),
+            "Merkle: depth must be between 1 and 32"
+        );
+        let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(env);
+        let zeros = zero_hashes(env, &mut sponge, depth);
+        // Leaf 0 hashes to 0, so the tree starts out as the empty tree
+        let state = IndexedTreeState {
+            depth,
+            next_index: 1,
+            root: zeros.get_unchecked(depth),
+            filled_subtrees: zeros.slice(0..depth),
+            zeros,
+        };
+
+        Self {
+            env: env.clone(),
+            key: init_state(env, key, &state),
+            state,
+            sponge,
+        }
+    }
+
+    /// Loads the tree stored under `key` by [`init`](Self::init).
+    ///
+    /// # Panics
+    /// - if no tree is stored under `key`.
+    pub fn load<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let (key, state) = load_state(env, key);
+        Self {
+            env: env.clone(),
+            key,
+            state,
+            sponge: Poseidon2Sponge::<4, Bn254Fr>::new(env),
+        }
+    }
+
+    fn save(&self) {
+        save_state(&self.env, &self.key, &self.state);
+    }
+
+    fn hash(&mut self, left: U256, right: U256) -> U256 {
+        self.sponge.compute_hash(&vec![&self.env, left, right])
+    }
+
+    fn check_value(&self, value: &U256) {
+        assert!(
+            Bn254Fr::is_canonical(&self.env, value),
+            "input exceeds field modulus"
+        );
+    }
+
+    /// Inserts `value`, stores the updated tree and returns the index of its
+    /// leaf.
+    ///
+    /// `low_leaf` is the leaf at `low_index` with the largest value below
+    /// `value`, and `low_siblings` its sibling path (leaf level first)
+    /// against the current root. The low leaf is updated to point to the new
+    /// leaf, which takes over the low leaf's old pointer.
+    ///
+    /// # Panics
+    /// - if the tree is full (it holds 2^depth leaves).
+    /// - if `low_leaf` does not bound `value`, e.g. because `value` is
+    ///   already in the tree or is `0`.
+    /// - if `low_leaf` is not at `low_index` in the tree, or
+    ///   `low_siblings` does not have `depth` elements.
+    /// - if `value` or any sibling is greater than or equal to the BN254
+    ///   field modulus.
+    pub fn insert(
+        &mut self,
+        value: U256,
+        low_leaf: IndexedLeaf,
+        low_index: u64,
+        low_siblings: &Vec<U256>,
+    ) -> u64 {
+        let index = self.state.next_index;
+        assert!(index < 1u64 << self.state.depth, "Merkle: tree is full");
+        self.check_value(&value);
+        assert!(
+            low_leaf.bounds(&value),
+            "Merkle: low leaf does not bound the value"
+        );
+        assert!(low_index < index, "Merkle: low leaf is not in the tree");
+
+        let leaf = IndexedLeaf {
+            value: value.clone(),
+            next_value: low_leaf.next_value.clone(),
+            next_index: low_leaf.next_index,
+        };
+        let updated_low_leaf = IndexedLeaf {
+            value: low_leaf.value.clone(),
+            next_value: value,
+            next_index: index,
+        };
+        self.update_leaf(low_index, &low_leaf, &updated_low_leaf, low_siblings);
+        self.append(&leaf);
+        self.save();
+        index
+    }
+
+    // Replaces `old_leaf` at `index` with `new_leaf`, checking `siblings`
+    // against the current root and keeping the frontier in sync
+    fn update_leaf(
+        &mut self,
+        index: u64,
+        old_leaf: &IndexedLeaf,
+        new_leaf: &IndexedLeaf,
+        siblings: &Vec<U256>,
+    ) {
+        assert!(
+            siblings.len() == self.state.depth,
+            "Merkle: wrong number of siblings"
+        );
+        let mut old_node = old_leaf.hash_with(&self.env, &mut self.sponge);
+        let mut node = new_leaf.hash_with(&self.env, &mut self.sponge);
+        let last_index = self.state.next_index - 1;
+        for (level, sibling) in siblings.iter().enumerate() {
+            let level = level as u32;
+            let position = index >> level;
+            if position == ((last_index >> level) & !1) {
+                self.state.filled_subtrees.set(level, node.clone());
+            }
+            if position & 1 == 1 {
+                old_node = self.hash(sibling.clone(), old_node);
+                node = self.hash(sibling, node);
+            } else {
+                old_node = self.hash(old_node, sibling.clone());
+                node = self.hash(node, sibling);
+            }
+        }
+        assert!(
+            old_node == self.state.root,
+            "Merkle: low leaf is not in the tree"
+        );
+        self.state.root = node;
+    }
+
+    fn append(&mut self, leaf: &IndexedLeaf) {
+        let index = self.state.next_index;
+        let mut current_index = index;
+        let mut current = leaf.hash_with(&self.env, &mut self.sponge);
+        for level in 0..self.state.depth {
+            let (left, right) = if current_index.is_multiple_of(2) {
+                self.state.filled_subtrees.set(level, current.clone());
+                (current, self.state.zeros.get_unchecked(level))
+            } else {
+                (self.state.filled_subtrees.get_unchecked(level), current)
+            };
+            current = self.hash(left, right);
+            current_index /= 2;
+        }
+        self.state.root = current;
+        self.state.next_index = index + 1;
+    }
+
+    /// Returns whether `low_leaf`, at `low_index` with sibling path
+    /// `siblings` (leaf level first), proves that `value` is not in the tree.
+    ///
+    /// # Panics
+    /// - if `siblings` does not have `depth` elements.
+    /// - if `value` or any sibling is greater than or equal to the BN254
+    ///   field modulus.
+    pub fn verify_non_membership(
+        &mut self,
+        value: &U256,
+        low_leaf: &IndexedLeaf,
+        low_index: u64,
+        siblings: &Vec<U256>,
+    ) -> bool {
+        self.check_value(value);
+        assert!(
+            siblings.len() == self.state.depth,
+            "Merkle: wrong number of siblings"
+        );
+        // Leaves past `next_index` are empty and would hash like leaf 0
+        if low_index >= self.state.next_index || !low_leaf.bounds(value) {
+            return false;
+        }
+        let env = self.env.clone();
+        let leaf = low_leaf.hash_with(&env, &mut self.sponge);
+        let root = compute_root(&env, &mut self.sponge, &leaf, siblings, low_index);
+        root == self.state.root
+    }
+
+    /// Returns the current root.
+    pub fn root(&self) -> U256 {
+        self.state.root.clone()
+    }
+
+    /// Returns the depth of the tree.
+    pub fn depth(&self) -> u32 {
+        self.state.depth
+    }
+
+    /// Returns the number of leaves, including leaf 0, i.e. the index of the
+    /// next leaf.
+    pub fn next_index(&self) -> u64 {
+        self.state.next_index
+    }
+}
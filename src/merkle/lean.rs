@@ -0,0 +1,299 @@
+use super::{init_state, load_state, save_state};
+use crate::{verify_merkle_proof, Field, PoseidonSponge};
+use soroban_sdk::{contracttype, crypto::bn254::Bn254Fr, vec, Env, IntoVal, Val, Vec, U256};
+
+/// The persisted state of a [`LeanIncrementalMerkleTree`].
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LeanTreeState {
+    size: u64,
+    // `side_nodes[i]` is the last node with an even index at level `i`, for
+    // `i` in `0..depth`, and `side_nodes[depth]` is the root
+    side_nodes: Vec<U256>,
+}
+
+/// An append-only Merkle tree with a dynamic depth, compatible with zk-kit's
+/// LeanIMT (`@zk-kit/lean-imt`, used by Semaphore v4).
+///
+/// Unlike [`IncrementalMerkleTree`](crate::IncrementalMerkleTree), there are
+/// no empty leaves: a node without a right sibling is carried up unhashed,
+/// and the depth is the smallest one that fits the leaves (a single leaf is
+/// its own root). Nodes are `poseidon_hash::<3, Bn254Fr>([left, right])`.
+///
+/// Only the size and one side node per level are stored, so inserting a leaf
+/// costs at most `depth` hashes. As in LeanIMT, inserted leaves cannot be `0`,
+/// but unlike LeanIMT's `LeafAlreadyExists` check, duplicate leaves are not
+/// detected because no leaf index is stored. Contracts that need unique
+/// leaves must track them separately.
+///
+/// # Example
+/// ```ignore
+/// // In the contract's constructor
+/// LeanIncrementalMerkleTree::init(&env, &symbol_short!("GROUP"));
+///
+/// // When adding members
+/// let mut tree = LeanIncrementalMerkleTree::load(&env, &symbol_short!("GROUP"));
+/// tree.insert_many(&commitments);
+/// let root = tree.root();
+/// ```
+pub struct LeanIncrementalMerkleTree {
+    env: Env,
+    key: Val,
+    state: LeanTreeState,
+    sponge: PoseidonSponge<3, Bn254Fr>,
+}
+
+impl LeanIncrementalMerkleTree {
+    /// The maximum depth of a tree, i.e. at most 2^32 leaves.
+    pub const MAX_DEPTH: u32 = 32;
+
+    /// Creates an empty tree and stores it under `key`.
+    ///
+    /// # Panics
+    /// - if a tree is already stored under `key`.
+    pub fn init<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let state = LeanTreeState {
+            size: 0,
+            side_nodes: vec![env, U256::from_u32(env, 0)],
+        };
+        Self {
+            env: env.clone(),
+            key: init_state(env, key, &state),
+            state,
+            sponge: PoseidonSponge::<3, Bn254Fr>::new(env),
+        }
+    }
+
+    /// Loads the tree stored under `key` by [`init`](Self::init).
+    ///
+    /// # Panics
+    /// - if no tree is stored under `key`.
+    pub fn load<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let (key, state) = load_state(env, key);
+        Self {
+            env: env.clone(),
+            key,
+            state,
+            sponge: PoseidonSponge::<3, Bn254Fr>::new(env),
+        }
+    }
+
+    fn save(&self) {
+        save_state(&self.env, &self.key, &self.state);
+    }
+
+    fn hash(&mut self, left: U256, right: U256) -> U256 {
+        self.sponge.compute_hash(&vec![&self.env, left, right])
+    }
+
+    // Leaves are only hashed once a right sibling arrives, so they are
+    // checked up front
+    fn check_leaf(&self, leaf: &U256) {
+        assert!(
+            Bn254Fr::is_canonical(&self.env, leaf),
+            "input exceeds field modulus"
+        );
+    }
+
+    // `0` marks a removed leaf, so it can only be set through `update`
+    fn check_new_leaf(&self, leaf: &U256) {
+        self.check_leaf(leaf);
+        assert!(
+            *leaf != U256::from_u32(&self.env, 0),
+            "Merkle: leaf cannot be zero"
+        );
+    }
+
+    // Grows the tree to the smallest depth that holds `size` leaves. The old
+    // root stays in place as the left node of its level.
+    fn grow(&mut self, size: u64) {
+        assert!(size <= 1u64 << Self::MAX_DEPTH, "Merkle: tree is full");
+        while 1u64 << self.depth() < size {
+            self.state
+                .side_nodes
+                .push_back(U256::from_u32(&self.env, 0));
+        }
+    }
+
+    /// Appends a leaf, stores the updated tree and returns the leaf's index.
+    ///
+    /// # Panics
+    /// - if the tree is full (it holds 2^32 leaves).
+    /// - if `leaf` is `0`.
+    /// - if `leaf` is greater than or equal to the BN254 field modulus.
+    pub fn insert(&mut self, leaf: U256) -> u64 {
+        self.check_new_leaf(&leaf);
+        let index = self.state.size;
+        self.grow(index + 1);
+
+        let depth = self.depth();
+        let mut node = leaf;
+        for level in 0..depth {
+            if (index >> level) & 1 == 1 {
+                let left = self.state.side_nodes.get_unchecked(level);
+                node = self.hash(left, node);
+            } else {
+                self.state.side_nodes.set(level, node.clone());
+            }
+        }
+        self.state.side_nodes.set(depth, node);
+        self.state.size = index + 1;
+        self.save();
+        index
+    }
+
+    /// Appends `leaves` in order, stores the updated tree and returns the
+    /// index of the first one.
+    ///
+    /// Produces the same tree as inserting the leaves one by one, but hashes
+    /// each affected node only once.
+    ///
+    /// # Panics
+    /// - if the leaves do not fit in the tree (at most 2^32 leaves).
+    /// - if any leaf is `0`.
+    /// - if any leaf is greater than or equal to the BN254 field modulus.
+    pub fn insert_many(&mut self, leaves: &Vec<U256>) -> u64 {
+        let start = self.state.size;
+        if leaves.is_empty() {
+            return start;
+        }
+        for leaf in leaves.iter() {
+            self.check_new_leaf(&leaf);
+        }
+        let size = start + leaves.len() as u64;
+        self.grow(size);
+
+        // `nodes` holds the nodes at positions `level_start..level_size` of
+        // the current level, i.e. the ones changed by the insertion
+        let depth = self.depth();
+        let mut nodes = leaves.clone();
+        let mut level_start = start;
+        let mut level_size = size;
+        for level in 0..depth {
+            let node = |position: u64| nodes.get_unchecked((position - level_start) as u32);
+            let next_start = level_start >> 1;
+            let next_size = ((level_size - 1) >> 1) + 1;
+            let mut next = Vec::new(&self.env);
+            for position in next_start..next_size {
+                // Only the left node can predate the insertion, in which case
+                // it is the side node
+                let left = if 2 * position < level_start {
+                    self.state.side_nodes.get_unchecked(level)
+                } else {
+                    node(2 * position)
+                };
+                let parent = if 2 * position + 1 < level_size {
+                    let right = node(2 * position + 1);
+                    self.hash(left, right)
+                } else {
+                    left
+                };
+                next.push_back(parent);
+            }
+
+            let last_left = (level_size - 1) & !1;
+            if last_left >= level_start {
+                self.state.side_nodes.set(level, node(last_left));
+            }
+            nodes = next;
+            level_start = next_start;
+            level_size = next_size;
+        }
+        self.state.side_nodes.set(depth, nodes.get_unchecked(0));
+        self.state.size = size;
+        self.save();
+        start
+    }
+
+    /// Replaces the leaf at `index`, which must currently be `old_leaf`, with
+    /// `new_leaf` and stores the updated tree.
+    ///
+    /// `siblings` is the leaf's proof as returned by `@zk-kit/lean-imt`'s
+    /// `generateProof`: the siblings from the leaf level up, skipping the
+    /// levels where the node has no sibling. Setting a leaf to `0` is how
+    /// Semaphore removes a member.
+    ///
+    /// # Panics
+    /// - if `index` is not less than [`size`](Self::size).
+    /// - if `siblings` has the wrong length or does not lead from `old_leaf`
+    ///   to the current root.
+    /// - if `new_leaf` or any sibling is greater than or equal to the BN254
+    ///   field modulus.
+    pub fn update(&mut self, index: u64, old_leaf: U256, new_leaf: U256, siblings: &Vec<U256>) {
+        assert!(index < self.state.size, "Merkle: leaf index out of bounds");
+        self.check_leaf(&new_leaf);
+
+        let depth = self.depth();
+        let last_index = self.state.size - 1;
+        let mut old_node = old_leaf;
+        let mut node = new_leaf;
+        let mut used = 0;
+        for level in 0..depth {
+            let position = index >> level;
+            let last_position = last_index >> level;
+            if position == (last_position & !1) {
+                self.state.side_nodes.set(level, node.clone());
+            }
+            if position & 1 == 0 && position == last_position {
+                // No right sibling: the node is carried up unhashed
+                continue;
+            }
+            let sibling = siblings
+                .get(used)
+                .expect("Merkle: wrong number of siblings");
+            used += 1;
+            if position & 1 == 1 {
+                old_node = self.hash(sibling.clone(), old_node);
+                node = self.hash(sibling, node);
+            } else {
+                old_node = self.hash(old_node, sibling.clone());
+                node = self.hash(node, sibling);
+            }
+        }
+        assert!(used == siblings.len(), "Merkle: wrong number of siblings");
+        assert!(
+            old_node == self.root(),
+            "Merkle: siblings do not match the tree"
+        );
+
+        self.state.side_nodes.set(depth, node);
+        self.save();
+    }
+
+    /// Returns the current root, or `0` if the tree is empty.
+    pub fn root(&self) -> U256 {
+        self.state.side_nodes.get_unchecked(self.depth())
+    }
+
+    /// Returns the number of leaves inserted so far, i.e. the index of the
+    /// next leaf.
+    pub fn size(&self) -> u64 {
+        self.state.size
+    }
+
+    /// Returns the current depth of the tree, `ceil(log2(size))`.
+    pub fn depth(&self) -> u32 {
+        self.state.side_nodes.len() - 1
+    }
+
+    /// Verifies a LeanIMT inclusion proof against `root`, like
+    /// `@zk-kit/lean-imt`'s `verifyProof`.
+    ///
+    /// `siblings` and `index` are the `siblings` and `index` fields of the
+    /// proof returned by `generateProof`: levels without a sibling are
+    /// skipped, and bit `i` of `index` is 1 when the node is the right child
+    /// at the `i`-th level that has a sibling. The root is computed with
+    /// [`verify_merkle_proof`](crate::verify_merkle_proof).
+    ///
+    /// # Panics
+    /// Same conditions as [`verify_merkle_proof`](crate::verify_merkle_proof).
+    pub fn verify_proof(
+        env: &Env,
+        root: &U256,
+        leaf: &U256,
+        siblings: &Vec<U256>,
+        index: u64,
+    ) -> bool {
+        verify_merkle_proof(env, leaf, siblings, index) == *root
+    }
+}
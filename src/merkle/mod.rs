@@ -0,0 +1,84 @@
+//! Merkle trees hashed with Poseidon and Poseidon2.
+//!
+//! Nodes are hashed as `poseidon_hash::<3, Bn254Fr>([left, right])`, matching
+//! circomlib-based circuits such as `MerkleTreeInclusionProof`, or as
+//! `poseidon2_hash::<3, Bn254Fr>([left, right])` for circuits that use a
+//! width-3 Poseidon2 (not noir's width-4 `Poseidon2::hash`).
+//! Sparse Merkle trees follow iden3's go-merkletree, and indexed Merkle trees
+//! Aztec's nullifier tree.
+//!
+//! The storage-backed trees ([`IncrementalMerkleTree`],
+//! [`LeanIncrementalMerkleTree`] and [`IndexedMerkleTree`]) each keep their
+//! state in a single persistent entry under a caller-chosen key. `init`
+//! creates the entry, every insertion rewrites it and `load` reads it. None
+//! of them extends the entry's TTL: the contract must do so itself (e.g.
+//! with `env.storage().persistent().extend_ttl(&key, ..)` after each
+//! insertion), or the tree is archived and must be restored before it can
+//! be loaded again.
+
+#[cfg(feature = "poseidon-bn254")]
+mod incremental;
+#[cfg(feature = "poseidon2-bn254")]
+mod indexed;
+#[cfg(feature = "poseidon-bn254")]
+mod lean;
+mod proof;
+#[cfg(feature = "poseidon-bn254")]
+mod sparse;
+
+#[cfg(feature = "poseidon-bn254")]
+pub use incremental::IncrementalMerkleTree;
+#[cfg(feature = "poseidon2-bn254")]
+pub use indexed::{IndexedLeaf, IndexedMerkleTree};
+#[cfg(feature = "poseidon-bn254")]
+pub use lean::LeanIncrementalMerkleTree;
+#[cfg(feature = "poseidon-bn254")]
+pub use proof::verify_merkle_proof;
+#[cfg(feature = "poseidon2-bn254")]
+pub use proof::verify_merkle_proof_poseidon2;
+#[cfg(feature = "poseidon-bn254")]
+pub use sparse::{verify_sparse_merkle_exclusion, verify_sparse_merkle_inclusion};
+
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+use crate::FieldHasher;
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+use soroban_sdk::{vec, Env, IntoVal, TryFromVal, Val, Vec, U256};
+
+// Stores the initial `state` of a tree under `key` and returns the key
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+fn init_state<K: IntoVal<Env, Val>, S: IntoVal<Env, Val>>(env: &Env, key: &K, state: &S) -> Val {
+    let key = key.into_val(env);
+    let storage = env.storage().persistent();
+    assert!(!storage.has(&key), "Merkle: tree already initialized");
+    storage.set(&key, state);
+    key
+}
+
+// Reads the state of the tree stored under `key` by `init_state`
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+fn load_state<K: IntoVal<Env, Val>, S: TryFromVal<Env, Val>>(env: &Env, key: &K) -> (Val, S) {
+    let key = key.into_val(env);
+    let state = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("Merkle: tree not found in storage");
+    (key, state)
+}
+
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+fn save_state<S: IntoVal<Env, Val>>(env: &Env, key: &Val, state: &S) {
+    env.storage().persistent().set(key, state);
+}
+
+// Returns the roots of empty subtrees of height `0..=depth`, with empty
+// leaves being 0
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+fn zero_hashes<H: FieldHasher>(env: &Env, hasher: &mut H, depth: u32) -> Vec<U256> {
+    let mut zeros = vec![env, U256::from_u32(env, 0)];
+    for i in 0..depth {
+        let zero = zeros.get_unchecked(i);
+        zeros.push_back(hasher.hash(&vec![env, zero.clone(), zero]));
+    }
+    zeros
+}
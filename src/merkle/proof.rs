@@ -0,0 +1,99 @@
+use crate::FieldHasher;
+use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, Vec, U256};
+
+// Folds `leaf` up the path, hashing with `hasher.hash([left, right])`. Bit
+// `i` of `path_indices` is 1 when the node at level `i` is a right child.
+pub(super) fn compute_root<H: FieldHasher>(
+    env: &Env,
+    hasher: &mut H,
+    leaf: &U256,
+    siblings: &Vec<U256>,
+    path_indices: u64,
+) -> U256 {
+    let depth = siblings.len();
+    assert!(depth <= 64, "Merkle: proof depth must be at most 64");
+    assert!(
+        depth == 64 || path_indices >> depth == 0,
+        "Merkle: path indices exceed the proof depth"
+    );
+    let mut current = leaf.clone();
+    for (level, sibling) in siblings.iter().enumerate() {
+        let pair = if (path_indices >> level) & 1 == 0 {
+            vec![env, current, sibling]
+        } else {
+            vec![env, sibling, current]
+        };
+        current = hasher.hash(&pair);
+    }
+    current
+}
+
+/// Computes the Merkle root implied by a Poseidon inclusion proof, matching
+/// circomlib's `MerkleTreeInclusionProof`.
+///
+/// Nodes are hashed as `poseidon_hash::<3, Bn254Fr>([left, right])`, with a
+/// single [`PoseidonSponge`](crate::PoseidonSponge) reused for the whole
+/// path. `siblings[i]` is the sibling at level `i` (leaf level first) and bit
+/// `i` of `path_indices` is circom's `pathIndices[i]`: 1 when the node at
+/// level `i` is a right child. The bitmask is therefore the leaf index, and
+/// either can be passed.
+///
+/// Compare the result against a trusted root (e.g.
+/// [`IncrementalMerkleTree::is_known_root`](crate::IncrementalMerkleTree::is_known_root))
+/// to verify the proof.
+///
+/// # Panics
+/// - if `siblings` has more than 64 elements.
+/// - if `path_indices` has bits set at or above `siblings.len()`, so that
+///   every leaf position has exactly one encoding.
+/// - if `leaf` or any sibling is greater than or equal to the BN254 field
+///   modulus.
+///
+/// # Example
+/// ```
+/// use soroban_poseidon::{poseidon_hash, verify_merkle_proof};
+/// use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, U256};
+///
+/// let env = Env::default();
+/// let (leaf, sibling) = (U256::from_u32(&env, 1), U256::from_u32(&env, 2));
+///
+/// // The leaf is the right child (index 1) of a depth-1 tree
+/// let root = verify_merkle_proof(&env, &leaf, &vec![&env, sibling.clone()], 1);
+/// assert_eq!(root, poseidon_hash::<3, Bn254Fr>(&env, &vec![&env, sibling, leaf]));
+/// ```
+#[cfg(feature = "poseidon-bn254")]
+pub fn verify_merkle_proof(
+    env: &Env,
+    leaf: &U256,
+    siblings: &Vec<U256>,
+    path_indices: u64,
+) -> U256 {
+    let mut sponge = crate::PoseidonSponge::<3, Bn254Fr>::new(env);
+    compute_root(env, &mut sponge, leaf, siblings, path_indices)
+}
+
+/// Computes the Merkle root implied by a Poseidon2 inclusion proof.
+///
+/// Nodes are hashed as `poseidon2_hash::<3, Bn254Fr>([left, right])`, with a
+/// single [`Poseidon2Sponge`](crate::Poseidon2Sponge) reused for the whole
+/// path. The path is encoded as in
+/// [`verify_merkle_proof`](crate::verify_merkle_proof): bit `i` of
+/// `path_indices` (i.e. of the leaf index) is 1 when the node at level `i`
+/// is a right child.
+///
+/// A circuit must hash nodes with the same width-3 Poseidon2 instance
+/// (`[left, right]` absorbed with the IV `2 << 64`). Noir's standard library
+/// `Poseidon2::hash` uses width 4 and gives different nodes.
+///
+/// # Panics
+/// Same conditions as [`verify_merkle_proof`](crate::verify_merkle_proof).
+#[cfg(feature = "poseidon2-bn254")]
+pub fn verify_merkle_proof_poseidon2(
+    env: &Env,
+    leaf: &U256,
+    siblings: &Vec<U256>,
+    path_indices: u64,
+) -> U256 {
+    let mut sponge = crate::Poseidon2Sponge::<3, Bn254Fr>::new(env);
+    compute_root(env, &mut sponge, leaf, siblings, path_indices)
+}
@@ -0,0 +1,120 @@
+use crate::{poseidon_hash, PoseidonSponge};
+use soroban_sdk::{crypto::bn254::Bn254Fr, vec, Env, Vec, U256};
+
+/// The maximum number of siblings in a sparse Merkle proof, one per bit of
+/// the key.
+const MAX_DEPTH: u32 = 256;
+
+// iden3 leaf hash: Poseidon([key, value, 1])
+fn leaf_hash(env: &Env, key: &U256, value: &U256) -> U256 {
+    let inputs = vec![env, key.clone(), value.clone(), U256::from_u32(env, 1)];
+    poseidon_hash::<4, Bn254Fr>(env, &inputs)
+}
+
+// Folds `node`, found at the end of `key`'s path, up to the root.
+// `siblings[i]` is the sibling at depth `i + 1` (root first); trailing zeros
+// are padding and are skipped.
+fn root_from_path(env: &Env, key: &U256, node: U256, siblings: &Vec<U256>) -> U256 {
+    assert!(
+        siblings.len() <= MAX_DEPTH,
+        "Merkle: proof depth must be at most 256"
+    );
+    let zero = U256::from_u32(env, 0);
+    let mut depth = siblings.len();
+    while depth > 0 && siblings.get_unchecked(depth - 1) == zero {
+        depth -= 1;
+    }
+
+    // Bit `i` of the key (least significant first) is 1 when the path goes
+    // right at depth `i`
+    let mut key_bytes = [0u8; 32];
+    key.to_be_bytes().copy_into_slice(&mut key_bytes);
+    let goes_right = |level: u32| (key_bytes[31 - (level / 8) as usize] >> (level % 8)) & 1 == 1;
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(env);
+    let mut current = node;
+    for level in (0..depth).rev() {
+        let sibling = siblings.get_unchecked(level);
+        let pair = if goes_right(level) {
+            vec![env, sibling, current]
+        } else {
+            vec![env, current, sibling]
+        };
+        current = sponge.compute_hash(&pair);
+    }
+    current
+}
+
+/// Verifies that `key` maps to `value` in an iden3 sparse Merkle tree with
+/// the given root.
+///
+/// The tree is the one of go-merkletree / circomlib's `SMTVerifier`: leaves
+/// are `poseidon_hash::<4, Bn254Fr>([key, value, 1])`, middle nodes are
+/// `poseidon_hash::<3, Bn254Fr>([left, right])`, empty nodes are `0`, and
+/// bit `i` of the key (least significant first) selects the right child at
+/// depth `i`. `siblings` lists the siblings along the path from the root
+/// down, and may be padded with trailing zeros to a fixed length as for
+/// `SMTVerifier`.
+///
+/// # Panics
+/// - if `siblings` has more than 256 elements.
+/// - if `key`, `value` or any sibling is greater than or equal to the BN254
+///   field modulus.
+///
+/// # Example
+/// ```
+/// use soroban_poseidon::verify_sparse_merkle_inclusion;
+/// use soroban_sdk::{bytesn, vec, Env, U256};
+///
+/// let env = Env::default();
+/// let (key, value) = (U256::from_u32(&env, 1), U256::from_u32(&env, 2));
+///
+/// // A tree holding a single leaf has the leaf's hash as its root
+/// let root = U256::from_be_bytes(
+///     &env,
+///     &bytesn!(
+///         &env,
+///         0x1e05682c815341647510bf582454cca025584699f2419cbdea3205afb3506e5b
+///     )
+///     .into(),
+/// );
+/// assert!(verify_sparse_merkle_inclusion(&env, &root, &key, &value, &vec![&env]));
+/// ```
+pub fn verify_sparse_merkle_inclusion(
+    env: &Env,
+    root: &U256,
+    key: &U256,
+    value: &U256,
+    siblings: &Vec<U256>,
+) -> bool {
+    let leaf = leaf_hash(env, key, value);
+    root_from_path(env, key, leaf, siblings) == *root
+}
+
+/// Verifies that `key` is absent from an iden3 sparse Merkle tree with the
+/// given root.
+///
+/// The tree and `siblings` are as in [`verify_sparse_merkle_inclusion`].
+/// The path of `key` either ends at an empty node (`aux` is `None`) or at
+/// the leaf of another key sharing the same path prefix, given as
+/// `aux = Some((aux_key, aux_value))` (go-merkletree's `NodeAux`, circom's
+/// `oldKey` / `oldValue`). Returns `false` if `aux_key` equals `key`.
+///
+/// # Panics
+/// - if `siblings` has more than 256 elements.
+/// - if an auxiliary key or value, or any sibling, is greater than or equal
+///   to the BN254 field modulus.
+pub fn verify_sparse_merkle_exclusion(
+    env: &Env,
+    root: &U256,
+    key: &U256,
+    siblings: &Vec<U256>,
+    aux: Option<(U256, U256)>,
+) -> bool {
+    let node = match aux {
+        Some((aux_key, _)) if aux_key == *key => return false,
+        Some((aux_key, aux_value)) => leaf_hash(env, &aux_key, &aux_value),
+        None => U256::from_u32(env, 0),
+    };
+    root_from_path(env, key, node, siblings) == *root
+}
@@ -1,17 +1,35 @@
-use crate::{
-    poseidon::params::{
-        get_mds_bls12_381_t_2, get_mds_bls12_381_t_3, get_mds_bls12_381_t_4, get_mds_bls12_381_t_5,
-        get_mds_bls12_381_t_6, get_mds_bn254_t_2, get_mds_bn254_t_3, get_mds_bn254_t_4,
-        get_mds_bn254_t_5, get_mds_bn254_t_6, get_rc_bls12_381_t_2, get_rc_bls12_381_t_3,
-        get_rc_bls12_381_t_4, get_rc_bls12_381_t_5, get_rc_bls12_381_t_6, get_rc_bn254_t_2,
-        get_rc_bn254_t_3, get_rc_bn254_t_4, get_rc_bn254_t_5, get_rc_bn254_t_6, SBOX_D,
-    },
-    Field,
+#[cfg(any(feature = "poseidon-bn254", feature = "poseidon-bls12-381"))]
+use crate::constants::u256_matrix;
+#[cfg(feature = "poseidon-bls12-381")]
+use crate::poseidon::params::{
+    MDS_BLS12_381_T_2, MDS_BLS12_381_T_3, MDS_BLS12_381_T_4, MDS_BLS12_381_T_5, MDS_BLS12_381_T_6,
+    RC_BLS12_381_T_2, RC_BLS12_381_T_3, RC_BLS12_381_T_4, RC_BLS12_381_T_5, RC_BLS12_381_T_6,
+};
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+use crate::poseidon::params::{
+    MDS_BN254_T_10, MDS_BN254_T_11, MDS_BN254_T_12, MDS_BN254_T_13, MDS_BN254_T_14, MDS_BN254_T_15,
+    MDS_BN254_T_16, MDS_BN254_T_17, MDS_BN254_T_7, MDS_BN254_T_8, MDS_BN254_T_9, RC_BN254_T_10,
+    RC_BN254_T_11, RC_BN254_T_12, RC_BN254_T_13, RC_BN254_T_14, RC_BN254_T_15, RC_BN254_T_16,
+    RC_BN254_T_17, RC_BN254_T_7, RC_BN254_T_8, RC_BN254_T_9,
 };
-use soroban_sdk::{
-    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
-    vec, Env, Vec, U256,
+#[cfg(feature = "poseidon-bn254")]
+use crate::poseidon::params::{
+    MDS_BN254_T_2, MDS_BN254_T_3, MDS_BN254_T_4, MDS_BN254_T_5, MDS_BN254_T_6, RC_BN254_T_2,
+    RC_BN254_T_3, RC_BN254_T_4, RC_BN254_T_5, RC_BN254_T_6,
+};
+use crate::{
+    bytes::pack_bytes,
+    field_element::FieldElement,
+    poseidon::params::SBOX_D,
+    registry::ParamsRegistryClient,
+    safe::{sealed, SafePermutation},
+    Field, PoseidonError,
 };
+#[cfg(feature = "poseidon-bls12-381")]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
+#[cfg(feature = "poseidon-bn254")]
+use soroban_sdk::crypto::bn254::Bn254Fr;
+use soroban_sdk::{contracttype, vec, Address, Bytes, Env, IntoVal, Val, Vec, U256};
 
 const CAPACITY: u32 = 1;
 
@@ -23,16 +41,34 @@ pub trait PoseidonConfig<const T: u32, F: Field> {
     fn get_rc(e: &Env) -> Vec<Vec<U256>>;
 }
 
-// Internal struct storing the Poseidon constants, in the future we can make
-// this a #[contracttype], which can be stored as contract data (to reduce the
-// actual contract size)
-pub(crate) struct PoseidonParams {
+/// The constants of a Poseidon parameter set.
+///
+/// This is a `#[contracttype]`, so a parameter set can be passed to a contract
+/// and kept in contract storage instead of being embedded in the contract's
+/// WASM. See [`PoseidonSponge::from_params`] and
+/// [`PoseidonSponge::from_storage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoseidonParams {
     pub rounds_f: u32,
     pub rounds_p: u32,
+    /// The MDS matrix (`T` rows of `T` elements).
     pub mds: Vec<Vec<U256>>,
+    /// Round constants, one row of `T` elements per round.
     pub rc: Vec<Vec<U256>>,
 }
 
+impl PoseidonParams {
+    /// Returns whether the matrix and round constant dimensions match the
+    /// state size `t` and the round counts.
+    pub fn is_valid_for(&self, t: u32) -> bool {
+        self.mds.len() == t
+            && self.mds.iter().all(|row| row.len() == t)
+            && self.rc.len() == self.rounds_f + self.rounds_p
+            && self.rc.iter().all(|row| row.len() == t)
+    }
+}
+
 /// A Poseidon sponge configured for a specific state size `T` and field `F`.
 ///
 /// This is a single-absorb, single-squeeze sponge. The primary benefit of
@@ -44,6 +80,14 @@ pub(crate) struct PoseidonParams {
 /// internal state and computes a fresh hash. The sponge does not accumulate
 /// state between calls.
 ///
+/// # Streaming
+///
+/// Inputs can also be fed one at a time with
+/// [`absorb_one`](Self::absorb_one) / [`absorb_many`](Self::absorb_many) and
+/// the digest obtained with [`finalize`](Self::finalize). Exactly `RATE`
+/// elements must be absorbed before finalizing, so streaming produces the same
+/// digest as [`compute_hash`](Self::compute_hash) on the concatenated inputs.
+///
 /// # Example
 /// ```ignore
 /// // Create sponge once (initializes parameters)
@@ -52,123 +96,276 @@ pub(crate) struct PoseidonParams {
 /// // Compute multiple independent hashes, reusing the same parameters
 /// let hash1 = sponge.compute_hash(&inputs1);
 /// let hash2 = sponge.compute_hash(&inputs2);
+///
+/// // Stream the inputs instead of building a `Vec` up front
+/// sponge.absorb_one(a);
+/// sponge.absorb_one(b);
+/// let hash3 = sponge.finalize(); // == compute_hash(&vec![&env, a, b])
 /// ```
 pub struct PoseidonSponge<const T: u32, F: Field> {
     env: Env,
     state: Vec<U256>,
     params: PoseidonParams,
+    // Built once, so hashing does not rebuild the modulus or the initial state
+    modulus: U256,
+    zero_state: Vec<U256>,
+    // Number of elements absorbed since the last reset
+    absorbed: u32,
+    squeezed: bool,
     _phantom: core::marker::PhantomData<F>,
 }
 
 // BN254 implementations
+#[cfg(feature = "poseidon-bn254")]
 impl PoseidonConfig<2, Bn254Fr> for PoseidonSponge<2, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bn254_t_2(e)
+        u256_matrix(e, MDS_BN254_T_2, 2)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_2(e)
+        u256_matrix(e, RC_BN254_T_2, 2)
     }
 }
 
+#[cfg(feature = "poseidon-bn254")]
 impl PoseidonConfig<3, Bn254Fr> for PoseidonSponge<3, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 57;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bn254_t_3(e)
+        u256_matrix(e, MDS_BN254_T_3, 3)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_3(e)
+        u256_matrix(e, RC_BN254_T_3, 3)
     }
 }
 
+#[cfg(feature = "poseidon-bn254")]
 impl PoseidonConfig<4, Bn254Fr> for PoseidonSponge<4, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bn254_t_4(e)
+        u256_matrix(e, MDS_BN254_T_4, 4)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_4(e)
+        u256_matrix(e, RC_BN254_T_4, 4)
     }
 }
 
+#[cfg(feature = "poseidon-bn254")]
 impl PoseidonConfig<5, Bn254Fr> for PoseidonSponge<5, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 60;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bn254_t_5(e)
+        u256_matrix(e, MDS_BN254_T_5, 5)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_5(e)
+        u256_matrix(e, RC_BN254_T_5, 5)
     }
 }
 
+#[cfg(feature = "poseidon-bn254")]
 impl PoseidonConfig<6, Bn254Fr> for PoseidonSponge<6, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 60;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bn254_t_6(e)
+        u256_matrix(e, MDS_BN254_T_6, 6)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_6, 6)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<7, Bn254Fr> for PoseidonSponge<7, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 63;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_7, 7)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_7, 7)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<8, Bn254Fr> for PoseidonSponge<8, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 64;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_8, 8)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_8, 8)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<9, Bn254Fr> for PoseidonSponge<9, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 63;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_9, 9)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_9, 9)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<10, Bn254Fr> for PoseidonSponge<10, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 60;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_10, 10)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_10, 10)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<11, Bn254Fr> for PoseidonSponge<11, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 66;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_11, 11)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_11, 11)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<12, Bn254Fr> for PoseidonSponge<12, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 60;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_12, 12)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_12, 12)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<13, Bn254Fr> for PoseidonSponge<13, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 65;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_13, 13)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_6(e)
+        u256_matrix(e, RC_BN254_T_13, 13)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<14, Bn254Fr> for PoseidonSponge<14, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 70;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_14, 14)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_14, 14)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<15, Bn254Fr> for PoseidonSponge<15, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 60;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_15, 15)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_15, 15)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<16, Bn254Fr> for PoseidonSponge<16, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 64;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_16, 16)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_16, 16)
+    }
+}
+
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon-wide"))]
+impl PoseidonConfig<17, Bn254Fr> for PoseidonSponge<17, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 68;
+    fn get_mds(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, MDS_BN254_T_17, 17)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_17, 17)
     }
 }
 
 // BLS12-381 implementations
+#[cfg(feature = "poseidon-bls12-381")]
 impl PoseidonConfig<2, Bls12381Fr> for PoseidonSponge<2, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bls12_381_t_2(e)
+        u256_matrix(e, MDS_BLS12_381_T_2, 2)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_2(e)
+        u256_matrix(e, RC_BLS12_381_T_2, 2)
     }
 }
 
+#[cfg(feature = "poseidon-bls12-381")]
 impl PoseidonConfig<3, Bls12381Fr> for PoseidonSponge<3, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bls12_381_t_3(e)
+        u256_matrix(e, MDS_BLS12_381_T_3, 3)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_3(e)
+        u256_matrix(e, RC_BLS12_381_T_3, 3)
     }
 }
 
+#[cfg(feature = "poseidon-bls12-381")]
 impl PoseidonConfig<4, Bls12381Fr> for PoseidonSponge<4, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bls12_381_t_4(e)
+        u256_matrix(e, MDS_BLS12_381_T_4, 4)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_4(e)
+        u256_matrix(e, RC_BLS12_381_T_4, 4)
     }
 }
 
+#[cfg(feature = "poseidon-bls12-381")]
 impl PoseidonConfig<5, Bls12381Fr> for PoseidonSponge<5, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bls12_381_t_5(e)
+        u256_matrix(e, MDS_BLS12_381_T_5, 5)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_5(e)
+        u256_matrix(e, RC_BLS12_381_T_5, 5)
     }
 }
 
+#[cfg(feature = "poseidon-bls12-381")]
 impl PoseidonConfig<6, Bls12381Fr> for PoseidonSponge<6, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 57;
     fn get_mds(e: &Env) -> Vec<Vec<U256>> {
-        get_mds_bls12_381_t_6(e)
+        u256_matrix(e, MDS_BLS12_381_T_6, 6)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_6(e)
+        u256_matrix(e, RC_BLS12_381_T_6, 6)
     }
 }
 
@@ -176,16 +373,8 @@ impl<const T: u32, F: Field> PoseidonSponge<T, F>
 where
     Self: PoseidonConfig<T, F>,
 {
-    fn reset_state(&mut self) {
-        // initialize the state with CAPACITY elements (CAPACITY = 1 in our sponge) at the 0-th element
-        // The initial value is 0 for standard Poseidon
-        let iv = U256::from_u32(&self.env, 0);
-        self.state = vec![&self.env, iv];
-        for _ in 0..Self::RATE {
-            self.state.push_back(U256::from_u32(&self.env, 0));
-        }
-    }
-
+    /// Creates a sponge with the built-in parameter set for `T` and `F`,
+    /// which requires the feature that compiles it.
     pub fn new(env: &Env) -> Self {
         let params = PoseidonParams {
             rounds_f: <Self as PoseidonConfig<T, F>>::ROUNDS_F,
@@ -193,13 +382,133 @@ where
             mds: <Self as PoseidonConfig<T, F>>::get_mds(env),
             rc: <Self as PoseidonConfig<T, F>>::get_rc(env),
         };
+        Self::with_params(env, params)
+    }
+}
+
+// The parameter-driven constructors and the hashing paths only need `T` and
+// `F`, so they are available without any built-in parameter feature.
+impl<const T: u32, F: Field> PoseidonSponge<T, F> {
+    /// The number of inputs absorbed per permutation, `T - 1`.
+    pub const RATE: u32 = T - CAPACITY;
+
+    fn reset_state(&mut self, iv: U256) {
+        // initialize the state with CAPACITY elements (CAPACITY = 1 in our sponge) at the 0-th element
+        // The initial value is 0 for standard Poseidon, or circom's `initialState`
+        // for PoseidonEx
+        self.state = self.zero_state.clone();
+        self.state.set(0, iv);
+        self.absorbed = 0;
+        self.squeezed = false;
+    }
+
+    /// Creates a sponge from an explicit parameter set, e.g. one passed to the
+    /// contract as an argument.
+    ///
+    /// This does not need a built-in parameter feature, so a contract that
+    /// only constructs sponges this way (or with
+    /// [`from_storage`](Self::from_storage) / [`from_registry`](Self::from_registry))
+    /// can depend on this crate with `default-features = false` and does not
+    /// compile the built-in constants at all.
+    ///
+    /// Only the dimensions of `params` are checked, not their security; see
+    /// [`store_params`](Self::store_params).
+    ///
+    /// # Panics
+    /// - if `params` does not match the state size `T` (the MDS matrix must be
+    ///   `T × T`, and `rc` must hold `rounds_f + rounds_p` rows of `T`
+    ///   elements).
+    pub fn from_params(env: &Env, params: PoseidonParams) -> Self {
+        assert!(
+            params.is_valid_for(T),
+            "Poseidon: parameters do not match the state size T"
+        );
+        Self::with_params(env, params)
+    }
+
+    /// Creates a sponge from an explicit parameter set, like
+    /// [`from_params`](Self::from_params), but returns an error instead of
+    /// panicking.
+    ///
+    /// # Errors
+    /// - [`PoseidonError::UnsupportedConfig`] if `params` does not match the
+    ///   state size `T`.
+    pub fn try_from_params(env: &Env, params: PoseidonParams) -> Result<Self, PoseidonError> {
+        if !params.is_valid_for(T) {
+            return Err(PoseidonError::UnsupportedConfig);
+        }
+        Ok(Self::with_params(env, params))
+    }
+
+    /// Creates a sponge from a parameter set previously written to persistent
+    /// contract storage under `key` with [`store_params`](Self::store_params).
+    ///
+    /// Persistent storage is used rather than instance storage, so the
+    /// constants are only loaded by the invocations that hash. Extending the
+    /// entry's TTL is left to the caller.
+    ///
+    /// # Panics
+    /// - if no parameter set is stored under `key`.
+    /// - same conditions as [`from_params`](Self::from_params).
+    pub fn from_storage<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let params: PoseidonParams = env
+            .storage()
+            .persistent()
+            .get(key)
+            .expect("Poseidon: parameters not found in storage");
+        Self::from_params(env, params)
+    }
+
+    /// Creates a sponge from the parameter set for `F` and `T` installed in the
+    /// shared parameter registry contract at `registry` (see
+    /// [`ParamsRegistryInterface`](crate::ParamsRegistryInterface)), fetched
+    /// with a cross-contract call.
+    ///
+    /// # Panics
+    /// - if the registry has no parameter set for `F` and `T`.
+    /// - same conditions as [`from_params`](Self::from_params).
+    pub fn from_registry(env: &Env, registry: &Address) -> Self {
+        let params = ParamsRegistryClient::new(env, registry)
+            .poseidon_params(&F::symbol(), &T)
+            .expect("Poseidon: parameters not found in registry");
+        Self::from_params(env, params)
+    }
+
+    /// Writes this sponge's parameter set to persistent contract storage under
+    /// `key`, to be loaded later with [`from_storage`](Self::from_storage).
+    ///
+    /// The entry is write-once, so whoever stores it first fixes the hash
+    /// for the contract's lifetime. [`from_params`](Self::from_params) only
+    /// checks the dimensions of the set, not that it is secure (e.g. it
+    /// accepts `rounds_p = 0`), so parameters supplied by a caller must be
+    /// compared against a pinned digest of the reference set, such as the
+    /// SHA-256 of its XDR encoding, before they are stored.
+    ///
+    /// # Panics
+    /// - if a parameter set is already stored under `key`.
+    pub fn store_params<K: IntoVal<Env, Val>>(&self, key: &K) {
+        let storage = self.env.storage().persistent();
+        assert!(!storage.has(key), "Poseidon: parameters already stored");
+        storage.set(key, &self.params);
+    }
+
+    /// Returns this sponge's parameter set.
+    pub fn params(&self) -> &PoseidonParams {
+        &self.params
+    }
+
+    fn with_params(env: &Env, params: PoseidonParams) -> Self {
         let mut inner = Self {
             env: env.clone(),
             state: vec![env],
             params,
+            modulus: F::modulus(env),
+            zero_state: Vec::from_iter(env, (0..T).map(|_| U256::from_u32(env, 0))),
+            absorbed: 0,
+            squeezed: false,
             _phantom: core::marker::PhantomData,
         };
-        inner.reset_state();
+        inner.reset_state(U256::from_u32(env, 0));
         inner
     }
 
@@ -216,24 +525,76 @@ where
         );
     }
 
-    pub(crate) fn absorb(&mut self, inputs: &Vec<U256>) {
+    // `canonical` skips the modulus check for inputs that are already known
+    // to be field elements
+    fn absorb_checked(&mut self, input: U256, canonical: bool) {
+        assert!(!self.squeezed, "Poseidon: cannot absorb after squeeze");
         assert!(
-            inputs.len() == Self::RATE,
+            self.absorbed < Self::RATE,
             "Poseidon: inputs.len() must equal rate (T - 1)"
         );
-        let modulus = F::modulus(&self.env);
-        for i in 0..inputs.len() {
-            let v = inputs.get_unchecked(i);
-            assert!(v < modulus, "input exceeds field modulus");
-            self.state.set(i + CAPACITY, v);
+        assert!(
+            canonical || input < self.modulus,
+            "input exceeds field modulus"
+        );
+        self.state.set(self.absorbed + CAPACITY, input);
+        self.absorbed += 1;
+    }
+
+    /// Absorbs a single field element into the sponge.
+    ///
+    /// # Panics
+    /// - if `RATE` elements have already been absorbed since the last reset.
+    /// - if the sponge has already been squeezed.
+    /// - if `input` is greater than or equal to the field modulus.
+    pub fn absorb_one(&mut self, input: U256) {
+        self.absorb_checked(input, false);
+    }
+
+    /// Absorbs each element of `inputs` in order, equivalent to calling
+    /// [`absorb_one`](Self::absorb_one) on each of them.
+    ///
+    /// # Panics
+    /// Same conditions as [`absorb_one`](Self::absorb_one).
+    pub fn absorb_many(&mut self, inputs: &Vec<U256>) {
+        for v in inputs.iter() {
+            self.absorb_checked(v, false);
         }
     }
 
-    pub(crate) fn squeeze(&mut self) -> U256 {
+    /// Applies the permutation to the absorbed elements and returns the
+    /// output (`state[0]`, as in circom).
+    ///
+    /// The sponge is left in squeezing mode; call
+    /// [`finalize`](Self::finalize) instead to also reset it for the next
+    /// message.
+    ///
+    /// # Panics
+    /// - if fewer than `RATE` elements have been absorbed. Implicit
+    ///   zero-padding is not allowed, see [`compute_hash`](Self::compute_hash).
+    /// - if the sponge has already been squeezed.
+    pub fn squeeze(&mut self) -> U256 {
+        assert!(!self.squeezed, "Poseidon: sponge already squeezed");
+        assert!(
+            self.absorbed == Self::RATE,
+            "Poseidon: inputs.len() must equal rate (T - 1)"
+        );
         self.perform_duplex();
+        self.squeezed = true;
         self.state.get_unchecked(0)
     }
 
+    /// Squeezes the output and resets the sponge, so it is ready to absorb
+    /// the next message.
+    ///
+    /// # Panics
+    /// Same conditions as [`squeeze`](Self::squeeze).
+    pub fn finalize(&mut self) -> U256 {
+        let output = self.squeeze();
+        self.reset_state(U256::from_u32(&self.env, 0));
+        output
+    }
+
     /// Computes a fresh Poseidon hash of the inputs.
     ///
     /// This method resets the sponge state and computes a new hash from
@@ -253,8 +614,311 @@ where
     /// - if any input value is greater than or equal to the field modulus.
     ///   All inputs must be valid field elements (i.e., less than the modulus).
     pub fn compute_hash(&mut self, inputs: &Vec<U256>) -> U256 {
-        self.reset_state();
-        self.absorb(inputs);
-        self.squeeze()
+        self.reset_state(U256::from_u32(&self.env, 0));
+        self.absorb_many(inputs);
+        self.finalize()
+    }
+
+    /// Computes the same hash as [`compute_hash`](Self::compute_hash) of
+    /// inputs that are already known to be field elements, skipping the
+    /// per-input comparison against the modulus.
+    ///
+    /// The output is itself a field element, so it can be fed into the next
+    /// hash (e.g. up a Merkle path) unchecked.
+    ///
+    /// # Panics
+    /// - if `inputs.len() != RATE`.
+    pub fn compute_hash_elements(&mut self, inputs: &[FieldElement<F>]) -> FieldElement<F> {
+        self.reset_state(U256::from_u32(&self.env, 0));
+        for v in inputs {
+            self.absorb_checked(v.to_u256(), true);
+        }
+        FieldElement::from_canonical(self.finalize())
+    }
+
+    /// Computes the same hash as [`compute_hash`](Self::compute_hash), but
+    /// returns an error instead of panicking on invalid inputs.
+    ///
+    /// The inputs are checked before anything is absorbed, so the sponge is
+    /// left ready for the next message either way.
+    ///
+    /// # Errors
+    /// - [`PoseidonError::WrongArity`] if `inputs.len() != RATE`.
+    /// - [`PoseidonError::InputNotInField`] if any input value is greater than
+    ///   or equal to the field modulus.
+    pub fn try_compute_hash(&mut self, inputs: &Vec<U256>) -> Result<U256, PoseidonError> {
+        if inputs.len() != Self::RATE {
+            return Err(PoseidonError::WrongArity);
+        }
+        if inputs.iter().any(|v| v >= self.modulus) {
+            return Err(PoseidonError::InputNotInField);
+        }
+        Ok(self.compute_hash(inputs))
+    }
+
+    /// Computes [`compute_hash`](Self::compute_hash) of each element of
+    /// `inputs` and returns the hashes in order.
+    ///
+    /// The parameters, the field modulus and the initial state are set up
+    /// once per sponge, so hashing many messages (e.g. the leaf pairs of a
+    /// Merkle tree) in one call only pays for the permutations.
+    ///
+    /// # Panics
+    /// Same conditions as [`compute_hash`](Self::compute_hash), for every
+    /// element of `inputs`.
+    pub fn compute_hash_batch(&mut self, inputs: &Vec<Vec<U256>>) -> Vec<U256> {
+        let mut outputs = vec![&self.env];
+        for message in inputs.iter() {
+            outputs.push_back(self.compute_hash(&message));
+        }
+        outputs
+    }
+
+    /// Computes a fresh variable-length Poseidon hash of the inputs.
+    ///
+    /// Unlike [`compute_hash`](Self::compute_hash), `inputs` may have any
+    /// non-zero length. This uses the length-in-capacity construction from
+    /// the [Poseidon paper](https://eprint.iacr.org/2019/458.pdf) (§4.2),
+    /// also used by neptune's `ConstantLength` hash type:
+    ///
+    /// - the capacity element is initialized to `inputs.len() << 64`;
+    /// - inputs are absorbed `RATE` elements at a time, with one permutation
+    ///   per chunk, and the last chunk is implicitly zero-padded;
+    /// - the output is the first rate element (`state[1]`) of the final
+    ///   state.
+    ///
+    /// This is **not** compatible with circom's `Poseidon(nInputs)`; use
+    /// [`compute_hash`](Self::compute_hash) for that.
+    ///
+    /// # Collision resistance
+    ///
+    /// Zero-padding alone would make `[x]` and `[x, 0]` collide, which is why
+    /// [`compute_hash`](Self::compute_hash) requires a full rate. Here the
+    /// length is committed in the capacity instead: messages of different
+    /// lengths start from different initial states, and messages of the same
+    /// length are padded identically, so the padding is injective and any
+    /// collision is a collision of the underlying sponge. Since the length is
+    /// at least 1, the capacity is never 0, which separates this mode from
+    /// [`compute_hash`](Self::compute_hash).
+    ///
+    /// # Panics
+    /// - if `inputs` is empty.
+    /// - if any input value is greater than or equal to the field modulus.
+    pub fn compute_hash_varlen(&mut self, inputs: &Vec<U256>) -> U256 {
+        assert!(!inputs.is_empty(), "Poseidon: inputs must not be empty");
+        let iv = U256::from_u128(&self.env, (inputs.len() as u128) << 64);
+        self.reset_state(iv);
+        let mut pos = 0;
+        for v in inputs.iter() {
+            assert!(v < self.modulus, "input exceeds field modulus");
+            if pos == Self::RATE {
+                self.perform_duplex();
+                pos = 0;
+            }
+            let s = self
+                .state
+                .get_unchecked(pos + CAPACITY)
+                .add(&v)
+                .rem_euclid(&self.modulus);
+            self.state.set(pos + CAPACITY, s);
+            pos += 1;
+        }
+        self.perform_duplex();
+        let output = self.state.get_unchecked(CAPACITY);
+        self.reset_state(U256::from_u32(&self.env, 0));
+        output
+    }
+
+    /// Computes a Poseidon hash of an arbitrary byte string, matching
+    /// go-iden3-crypto's `poseidon.HashBytesX(msg, T - 1)` (and
+    /// `poseidon.HashBytes` for `T = 17`).
+    ///
+    /// `msg` is split into 31-byte chunks, each read as a big-endian integer,
+    /// with the last chunk zero-padded at the end to 31 bytes. The chunks fill
+    /// frames of `RATE` inputs that are hashed with
+    /// [`compute_hash`](Self::compute_hash); every frame after the first
+    /// starts with the previous frame's hash, and the last frame is
+    /// zero-filled.
+    ///
+    /// # Collision resistance
+    ///
+    /// Neither the byte length nor the number of chunks is committed, and the
+    /// zero padding of the last chunk and of the last frame cannot be told
+    /// apart from zero bytes in the message. Messages that only differ by
+    /// trailing zero bytes collide, within the last chunk (e.g. `[1]` and
+    /// `[1, 0]`) or across whole chunks that fit in the last frame: with
+    /// `T = 3`, `a` and `a ‖ 0x00 * 31` both hash to `H(a, 0)`. Only use this
+    /// for iden3 compatibility with fixed-length or self-delimiting payloads;
+    /// otherwise use [`compute_hash_bytes_varlen`](Self::compute_hash_bytes_varlen).
+    ///
+    /// # Panics
+    /// - if `msg` is empty.
+    /// - if `T < 3`, since a frame must hold the previous hash and a chunk.
+    pub fn compute_hash_bytes(&mut self, msg: &Bytes) -> U256 {
+        assert!(
+            Self::RATE >= 2,
+            "Poseidon: byte hashing requires at least 2 inputs per hash (T >= 3)"
+        );
+        assert!(!msg.is_empty(), "Poseidon: inputs must not be empty");
+        let zero = U256::from_u32(&self.env, 0);
+        let empty_frame = Vec::from_iter(&self.env, (0..Self::RATE).map(|_| zero.clone()));
+        let mut frame = empty_frame.clone();
+        let mut hash = zero;
+        let mut pending = false;
+        let mut k = 0;
+        for chunk in pack_bytes(&self.env, msg, false).iter() {
+            frame.set(k, chunk);
+            if k == Self::RATE - 1 {
+                hash = self.compute_hash(&frame);
+                pending = false;
+                frame = empty_frame.clone();
+                frame.set(0, hash.clone());
+                k = 1;
+            } else {
+                pending = true;
+                k += 1;
+            }
+        }
+        if pending {
+            hash = self.compute_hash(&frame);
+        }
+        hash
+    }
+
+    /// Computes a variable-length Poseidon hash of an arbitrary byte string
+    /// that commits to its length.
+    ///
+    /// `msg` is packed into 31-byte big-endian chunks as in
+    /// [`compute_hash_bytes`](Self::compute_hash_bytes), the byte length of
+    /// `msg` is appended as a final element, and the elements are hashed with
+    /// [`compute_hash_varlen`](Self::compute_hash_varlen). This is not
+    /// compatible with iden3's `HashBytes`.
+    ///
+    /// # Collision resistance
+    ///
+    /// The last element gives the byte length, which fixes the number of
+    /// chunks and the padding of the last one, so distinct messages give
+    /// distinct element sequences. `compute_hash_varlen` commits to the
+    /// number of elements, so any collision is a collision of the underlying
+    /// sponge.
+    pub fn compute_hash_bytes_varlen(&mut self, msg: &Bytes) -> U256 {
+        let mut elements = pack_bytes(&self.env, msg, false);
+        elements.push_back(U256::from_u32(&self.env, msg.len()));
+        self.compute_hash_varlen(&elements)
+    }
+
+    /// Computes a fresh Poseidon hash with an explicit initial capacity value
+    /// and `n_outs` outputs.
+    ///
+    /// This matches circom's `PoseidonEx(nInputs, nOuts)`: the capacity
+    /// element is set to `initial_state`, the inputs fill the rate, and the
+    /// first `n_outs` elements of the permuted state are returned.
+    /// [`compute_hash`](Self::compute_hash) is the special case
+    /// `initial_state = 0`, `n_outs = 1`.
+    ///
+    /// # Panics
+    /// - if `n_outs` is 0 or greater than `T`.
+    /// - if `initial_state` is greater than or equal to the field modulus.
+    /// - same conditions as [`compute_hash`](Self::compute_hash) for `inputs`.
+    pub fn compute_hash_ex(
+        &mut self,
+        inputs: &Vec<U256>,
+        initial_state: U256,
+        n_outs: u32,
+    ) -> Vec<U256> {
+        assert!(
+            (1..=T).contains(&n_outs),
+            "Poseidon: n_outs must be between 1 and T"
+        );
+        assert!(
+            initial_state < self.modulus,
+            "initial state exceeds field modulus"
+        );
+        self.reset_state(initial_state);
+        self.absorb_many(inputs);
+        self.squeeze();
+        let outputs = self.state.slice(0..n_outs);
+        self.reset_state(U256::from_u32(&self.env, 0));
+        outputs
+    }
+
+    /// Applies the raw Poseidon permutation to a full `T`-element state and
+    /// returns the permuted state.
+    ///
+    /// No IV or padding convention is applied, which makes this the building
+    /// block for custom modes (e.g. Jive compression or transcripts). The
+    /// sponge is reset afterwards, as with [`compute_hash`](Self::compute_hash).
+    ///
+    /// # Panics
+    /// - if `state.len() != T`.
+    /// - if any state element is greater than or equal to the field modulus.
+    pub fn compute_permutation(&mut self, state: &Vec<U256>) -> Vec<U256> {
+        assert!(
+            state.len() == T,
+            "Poseidon: permutation state must have exactly T elements"
+        );
+        for v in state.iter() {
+            assert!(v < self.modulus, "input exceeds field modulus");
+        }
+        self.state = state.clone();
+        self.perform_duplex();
+        let output = self.state.clone();
+        self.reset_state(U256::from_u32(&self.env, 0));
+        output
+    }
+}
+
+impl<const T: u32, F: Field> sealed::Permutation for PoseidonSponge<T, F>
+where
+    Self: PoseidonConfig<T, F>,
+{
+    fn env(&self) -> &Env {
+        &self.env
+    }
+
+    fn modulus(&self) -> U256 {
+        self.modulus.clone()
+    }
+
+    fn rate(&self) -> u32 {
+        Self::RATE
+    }
+
+    fn reset_with_tag(&mut self, tag: U256) {
+        self.reset_state(tag);
+    }
+
+    fn rate_element(&self, i: u32) -> U256 {
+        self.state.get_unchecked(i + CAPACITY)
+    }
+
+    fn set_rate_element(&mut self, i: u32, v: U256) {
+        self.state.set(i + CAPACITY, v);
+    }
+
+    fn permute(&mut self) {
+        self.perform_duplex();
+    }
+}
+
+impl<const T: u32, F: Field> SafePermutation for PoseidonSponge<T, F> where
+    Self: PoseidonConfig<T, F>
+{
+}
+
+impl<const T: u32, F: Field> crate::FieldHasher for PoseidonSponge<T, F>
+where
+    Self: PoseidonConfig<T, F>,
+{
+    type Field = F;
+
+    const RATE: u32 = <Self as PoseidonConfig<T, F>>::RATE;
+
+    fn new(env: &Env) -> Self {
+        Self::new(env)
+    }
+
+    fn hash(&mut self, inputs: &Vec<U256>) -> U256 {
+        self.compute_hash(inputs)
     }
 }
@@ -1,2151 +1,6030 @@
-use soroban_sdk::{bytesn, vec, Env, Vec, U256};
-
 // The sbox degree do not change for BN254 and BLS12_381
 pub const SBOX_D: u32 = 5;
 
+// Each parameter set is a blob of 32-byte big-endian field elements (see
+// `crate::constants`): matrices row by row, round constants round by round with
+// T elements per round.
+
 // These parameters matches reference parameters generated with the reference sage script (t = 4, field = BN254):
 // https://github.com/HorizenLabs/poseidon2/blob/055bde3f4782731ba5f5ce5888a440a94327eaf3/poseidon2_rust_params.sage
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bn254_t_2(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000002).into()),
-    ]
-}
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const MAT_DIAG_BN254_T_2: &[u8] = b"\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\
+";
+
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const MAT_DIAG_BN254_T_3: &[u8] = b"\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\
+";
+
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const MAT_DIAG_BN254_T_4: &[u8] = b"\
+    \x10\xdc\x6e\x9c\x00\x6e\xa3\x8b\x04\xb1\xe0\x3b\x4b\xd9\x49\x0c\x0d\x03\xf9\x89\x29\xca\x1d\x7f\xb5\x68\x21\xfd\x19\xd3\xb6\xe7\
+    \x0c\x28\x14\x5b\x6a\x44\xdf\x3e\x01\x49\xb3\xd0\xa3\x0b\x3b\xb5\x99\xdf\x97\x56\xd4\xdd\x9b\x84\xa8\x6b\x38\xcf\xb4\x5a\x74\x0b\
+    \x00\x54\x4b\x83\x38\x79\x15\x18\xb2\xc7\x64\x5a\x50\x39\x27\x98\xb2\x1f\x75\xbb\x60\xe3\x59\x61\x70\x06\x7d\x00\x14\x1c\xac\x15\
+    \x22\x2c\x01\x17\x57\x18\x38\x6f\x2e\x2e\x82\xeb\x12\x27\x89\xe3\x52\xe1\x05\xa3\xb8\xfa\x85\x26\x13\xbc\x53\x44\x33\xee\x42\x8b\
+";
+
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const RC_BN254_T_2: &[u8] = b"\
+    \x09\xc4\x6e\x9e\xc6\x8e\x9b\xd4\xfe\x1f\xaa\xba\x29\x4c\xba\x38\xa7\x1a\xa1\x77\x53\x4c\xdd\x1b\x6c\x7d\xc0\xdb\xd0\xab\xd7\xa7\
+    \x0c\x03\x56\x53\x08\x96\xee\xc4\x2a\x97\xed\x93\x7f\x31\x35\xcf\xc5\x14\x2b\x3a\xe4\x05\xb8\x34\x3c\x1d\x83\xff\xa6\x04\xcb\x81\
+    \x1e\x28\xa1\xd9\x35\x69\x8a\xd1\x14\x2e\x51\x18\x2b\xb5\x4c\xf4\xa0\x0e\xa5\xaa\xbd\x62\x68\xbd\x31\x7e\xa9\x77\xcc\x15\x4a\x30\
+    \x27\xaf\x2d\x83\x1a\x9d\x27\x48\x08\x09\x65\xdb\x30\xe2\x98\xe4\x0e\x57\x57\xc3\xe0\x08\xdb\x96\x4c\xf9\xe2\xb1\x2b\x91\x25\x1f\
+    \x1e\x6f\x11\xce\x60\xfc\x8f\x51\x3a\x6a\x3c\xfe\x16\xae\x17\x5a\x41\x29\x14\x62\xf2\x14\xcd\x08\x79\xaa\xf4\x35\x45\xb7\x4e\x03\
+    \x2a\x67\x38\x4d\x3b\xbd\x5e\x43\x85\x41\x81\x9c\xb6\x81\xf0\xbe\x04\x46\x2e\xd1\x4c\x36\x13\xd8\xf7\x19\x20\x62\x68\xd1\x42\xd3\
+    \x0b\x66\xfd\xf3\x56\x09\x3a\x61\x16\x09\xf8\xe1\x2f\xbf\xec\xf0\xb9\x85\xe3\x81\xf0\x25\x18\x89\x36\x40\x8f\x5d\x5c\x9f\x45\xd0\
+    \x01\x2e\xe3\xec\x1e\x78\xd4\x70\x83\x0c\x61\x09\x3c\x2a\xde\x37\x0b\x26\xc8\x3c\xc5\xce\xbe\xed\xda\xa6\x85\x2d\xbd\xb0\x9e\x21\
+    \x02\x52\xba\x5f\x67\x60\xbf\xbd\xfd\x88\xf6\x7f\x81\x75\xe3\xfd\x6c\xd1\xc4\x31\xb0\x99\xb6\xbb\x2d\x10\x8e\x7b\x44\x5b\xb1\xb9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x94\x74\xcc\xec\xa5\xff\x67\x6c\x6b\xec\x3c\xef\x54\x29\x63\x54\x39\x1a\x89\x35\xff\x71\xd6\xef\x5a\xea\xad\x7c\xa9\x32\xf1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x24\x26\x13\x79\xa5\x1b\xfa\x92\x28\xff\x4a\x50\x3f\xd4\xed\x9c\x1f\x97\x4a\x26\x49\x69\xb3\x7e\x1a\x25\x89\xbb\xed\x2b\x91\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xc1\xd7\xb6\x26\x92\xe6\x3e\xac\x2f\x28\x8b\xd0\x69\x5b\x43\xc2\xf6\x3f\x50\x01\xfc\x0f\xc5\x53\xe6\x6c\x05\x51\x80\x1b\x05\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x50\x59\x30\x1a\xad\xa9\x8b\xb2\xed\x55\xf8\x52\x97\x9e\x96\x00\x78\x4d\xbf\x17\xfb\xac\xd0\x5d\x9e\xff\x5f\xd9\xc9\x1b\x56\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x43\x7b\xe3\xac\x1c\xb2\xe4\x79\xe1\xf5\xc0\xec\xcd\x32\xb3\xae\xa2\x42\x34\x97\x0a\x81\x93\xb1\x1c\x29\xce\x7e\x59\xef\xd9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x21\x6a\x44\x2f\x2e\x1f\x71\x1c\xa4\xfa\x6b\x53\x76\x6e\xb1\x18\x54\x8d\xa8\xfb\x4f\x78\xd4\x33\x87\x62\xc3\x7f\x5f\x20\x43\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x1f\x47\xcd\x17\xfa\x5a\xdf\x1f\x39\xf4\xe7\x05\x6d\xd0\x3f\xee\xe1\xef\xce\x03\x09\x45\x81\x13\x1f\x23\x77\x32\x34\x82\xc9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\xab\xad\x02\xb7\xa5\xeb\xc4\x86\x32\xbc\xc9\x35\x6c\xeb\x7d\xd9\xda\xfc\xa2\x76\x63\x8a\x63\x64\x6b\x85\x66\xa6\x21\xaf\xc9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\x30\x26\x46\x01\xff\xdf\x29\x27\x5b\x33\xff\xaa\xb5\x1d\xfe\x94\x29\xf9\x08\x80\xa6\x9c\xd1\x37\xda\x0c\x4d\x15\xf9\x6c\x3c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\xc9\x73\x05\x4e\x51\xd9\x05\xa0\xf1\x68\x65\x64\x97\xca\x40\xa8\x64\x41\x45\x57\xee\x28\x9e\x71\x7e\x5d\x66\x89\x9a\xa0\xa9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\x1c\x22\xf9\x64\x43\x50\x08\x20\x6c\x31\x57\xe8\x63\x41\xed\xd2\x49\xaf\xf5\xc2\xd8\x42\x1f\x2a\x6b\x22\x28\x8f\x0a\x67\xfc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x24\xf3\x8d\xf6\x7c\x53\x78\x12\x1c\x1d\x5f\x46\x1b\xbc\x50\x9e\x8e\xa1\x59\x8e\x46\xc9\xf7\xa7\x04\x52\xbc\x2b\xba\x86\xb8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\xe4\xe6\x9d\x8b\xa5\x9e\x51\x92\x80\xb4\xbd\x9e\xd0\x06\x8f\xd7\xbf\xe8\xcd\x9d\xfe\xda\x19\x69\xd2\x98\x91\x86\xcd\xe2\x0e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x1e\xcc\xc3\x4a\xab\xa0\x13\x7f\x5d\xf8\x1f\xc0\x4f\xf3\xee\x4f\x19\xee\x36\x4e\x65\x3f\x07\x6d\x47\xe9\x73\x5d\x98\x01\x8e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x72\xad\x3d\x70\x9a\x35\x39\x74\x26\x6c\x30\x39\xa9\xa7\x31\x14\x24\x44\x80\x32\xcd\x18\x19\xea\xcb\x8a\x4d\x42\x84\xf5\x82\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x3e\x3f\xdc\x2c\x6e\x42\x0c\x56\xf4\x4a\xf5\x19\x2b\x4a\xe9\xcd\xa6\x96\x1f\x28\x4d\x24\x99\x1d\x2e\xd6\x02\xdf\x8c\x8f\xc7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x2a\x3d\x12\x0c\x55\x0e\xcf\xd0\xdb\x09\x57\x17\x0f\xa0\x13\x68\x37\x51\xf8\xfd\xff\x59\xd6\x61\x4f\xbd\x69\xff\x39\x4b\xcc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x6f\x84\x87\x7a\xac\x61\x72\xf7\x89\x7a\x73\x23\x45\x6e\xfe\x14\x3a\x9a\x43\x77\x3e\xa6\xf2\x96\xcb\x6b\x81\x77\x65\x3f\xbd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x0d\x27\x2b\xec\xf2\xa7\x57\x64\xba\x7e\x8e\x3e\x28\xd1\x2b\xce\xaa\x47\xea\x61\xca\x59\xa4\x11\xa1\xf5\x15\x52\xf9\x47\x88\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\xe3\x42\x99\x86\x5c\x0e\x28\x48\x4e\xe7\xa7\x4c\x45\x4e\x9f\x17\x0a\x54\x80\xab\xe0\x50\x8f\xcb\x4a\x6c\x3d\x89\x54\x6f\x43\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x5c\xeb\xa5\x99\xe9\x6f\x5b\x37\x5a\x23\x2a\x6f\xb9\xcc\x71\x77\x20\x47\x76\x58\x02\x29\x0f\x48\xcd\x93\x97\x55\x48\x8f\xc5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x75\x94\x44\x0d\xc4\x8c\x16\xfe\xad\x9e\x17\x58\xb0\x28\x06\x6a\xa4\x10\xbf\xbc\x35\x4f\x54\xd8\xc5\xff\xbb\x44\xa1\xee\x32\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x3c\x29\xbc\x39\xf2\x1b\xb5\xc4\x66\xdb\x7d\x7e\xb6\xfd\x8f\x76\x0e\x20\x01\x3c\xcf\x91\x2c\x92\x47\x98\x82\xd9\x19\xfd\x8d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\xcf\xdd\x90\x6f\x34\x26\xe5\xc0\x98\x6e\xa0\x49\xb2\x53\x40\x08\x55\xd3\x49\x07\x4f\x5a\x66\x95\xc8\xee\xab\xcd\x22\xe6\x8f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x14\xf6\xbc\x81\xd9\xf1\x86\xf6\x2b\xdb\x47\x5c\xe6\xc9\x41\x18\x66\xa7\xa8\xa3\xfd\x06\x5b\x3c\xe0\xe6\x99\xb6\x7d\xd9\xe7\x96\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\x62\xb8\x27\x89\xfb\x3d\x12\x97\x02\xca\x70\xb2\xf6\xc5\xaa\xcc\x09\x98\x10\xc9\xc4\x95\xc8\x88\xed\xeb\x73\x86\xb9\x70\x52\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x88\x0a\xf7\x07\x4d\x18\xb3\xbf\x20\xc7\x9d\xe2\x51\x27\xbc\x13\x28\x4a\xb0\x1e\xf0\x25\x75\xaf\xef\x0c\x8f\x6a\x31\xa8\x6d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\xcb\xa1\x84\x19\xa6\xa3\x32\xcd\x5e\x77\xf0\x21\x1c\x15\x4b\x20\xaf\x29\x24\xfc\x20\xff\x3f\x4c\x30\x12\xbb\x7a\xe9\x31\x1b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x7e\x62\xa9\xa8\xf8\x9b\x3e\xbd\xc7\x6b\xa6\x3a\x9e\xac\xa8\xfa\x27\xb7\x31\x9c\xae\x34\x06\x75\x6a\x28\x49\xf3\x02\xf1\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x7c\x97\x1d\xe9\x1d\xc0\xab\xd4\x4a\xdf\x53\x84\xb4\x98\x8c\xb9\x61\x30\x3b\xbf\x65\xcf\xf5\xaf\xa0\x41\x3b\x44\x28\x0c\xee\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xdf\x33\x88\xaf\x16\x87\xbb\xb3\xbc\xa9\xda\x0c\xca\x90\x8f\x1e\x56\x2b\xc4\x6d\x4a\xba\x4e\x6f\x7f\x79\x60\xe3\x06\x89\x1d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\xe5\xc8\x87\xd2\x5b\xce\x70\x3e\x25\xcc\x97\x4d\x09\x34\xcd\x78\x9d\xf8\xf7\x0b\x49\x8f\xd8\x3e\xff\x8b\x56\x0e\x16\x82\xb3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x8d\xa3\x6f\x76\xe5\x68\xfb\x68\x11\x71\x75\xce\xa2\xcd\x0d\xd2\xcb\x5d\x42\xfd\xa5\xac\xea\x48\xd5\x9c\x27\x06\xa0\xd5\xc1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x17\xab\x09\x1f\x6e\xae\x50\xc6\x09\xbe\xaf\x55\x10\xec\xec\xc5\xd8\xbb\x74\x13\x5e\xbd\x05\xbd\x06\x46\x0c\xc2\x6a\x5e\xd6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xd7\x27\xe7\x28\xff\xa0\xa6\x7a\xee\x53\x5a\xb0\x74\xa4\x30\x91\xef\x62\xd8\xcf\x83\xd2\x70\x04\x0f\x5c\xaa\x1f\x62\xaf\x40\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\xdb\xd7\xbf\x9c\x29\x34\x15\x81\xb5\x49\x76\x2b\xc0\x22\xed\x33\x70\x2a\xc1\x0f\x1b\xfd\x86\x2b\x15\x41\x7d\x7e\x39\xca\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x90\xeb\x33\x51\x62\x17\x52\x76\x81\x62\xe8\x29\x89\xc6\xc2\x34\xf5\xb0\xd1\xd3\xaf\x9b\x58\x8a\x29\xc4\x9c\x87\x89\x65\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x45\x7c\x60\x1a\x63\xb7\x3e\x44\x71\x95\x01\x93\xd8\xa5\x70\x39\x5f\x3d\x9a\xb8\xb2\xfd\x09\x84\xb7\x64\x20\x61\x42\xf9\xe9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xae\x64\x30\x1d\xca\x96\x25\x63\x8d\x6a\xb2\xbb\xe7\x13\x5f\xfa\x90\xec\xd0\xc4\x3f\xf9\x1f\xc4\xc6\x86\xfc\x46\xe0\x91\xb0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\x79\xf6\x3c\x8c\xe3\x46\x8d\x4d\xa2\x93\x16\x6f\x49\x49\x28\x85\x4b\xe9\xe3\x43\x2e\x09\x55\x58\x58\x53\x4e\xed\x8d\x35\x0b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x2d\x56\x42\x03\x59\xd0\x26\x6a\x74\x4a\x08\x08\x09\xe0\x54\xca\x0e\x49\x21\xa4\x66\x86\xac\x8c\x9f\x58\xa3\x24\xc3\x50\x49\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x31\x58\xe5\x96\x5b\x5d\x9b\x1d\x68\xb3\xcd\x32\xe1\x0b\xbe\xda\x8d\x62\x45\x9e\x21\xf4\x09\x0f\xc2\xc5\xaf\x96\x35\x15\xa6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\xe2\x9f\xc4\x08\x47\xa9\x41\x66\x1d\x14\xbb\xf6\xcb\xe0\x42\x0f\xbb\x2b\x6f\x52\x83\x6d\x4e\x60\xc8\x0e\xb4\x9c\xad\x9e\xc1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\xc9\x69\x91\xde\xc2\xbb\x05\x57\x71\x61\x42\x01\x5a\x45\x3c\x36\xdb\x9d\x85\x9c\xad\x5f\x9a\x23\x38\x02\xf2\x4f\xdf\x4c\x1a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x96\x44\x3f\x76\x3d\xbc\xc2\x5f\x49\x64\xfc\x61\xd2\x3b\x3e\x5e\x12\xc9\xfa\x97\xf1\x8a\x92\x51\xca\x33\x55\xbc\xb0\x62\x7e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xe0\xbc\xd3\x65\x4b\xdf\xa7\x6b\x28\x61\xd4\xec\x3a\xea\xe0\xf1\x85\x7d\x9f\x17\xe7\x15\xae\xd6\xd0\x49\xea\xe3\xba\x32\x12\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\xc9\x2b\x4f\x1b\xbe\xa8\x2b\x9e\xa7\x3d\x4a\xf9\xaf\x2a\x50\xce\xab\xac\x7f\x37\x15\x4b\x19\x04\xe6\xc7\x6c\x7c\xf9\x64\xba\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x9c\x0b\x16\x10\x44\x64\x42\xd6\xf2\xe5\x92\xa8\x01\x3f\x40\xb1\x4f\x7c\x77\x22\x23\x6f\x4f\x9c\x7e\x96\x52\x33\x87\x27\x62\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\xbd\x74\x24\x4a\xe7\x26\x75\xf8\xcd\xe0\x61\x57\xa7\x82\xf4\x05\x0d\x91\x4d\xa3\x8b\x4c\x05\x8d\x15\x9f\x64\x3d\xbb\xf4\xd3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xb7\xf0\xed\x39\xe1\x6e\x9f\x69\xa9\xfa\xfd\x4a\xb9\x51\xc0\x3b\x06\x71\xe9\x73\x46\xee\x39\x7a\x83\x98\x39\xdc\xcf\xc6\xd1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x9d\x6e\x2e\xcf\xf0\x22\xcc\x56\x05\x44\x3e\xe4\x1b\xab\x20\xce\x76\x1d\x05\x14\xce\x52\x66\x90\xc7\x2b\xca\x73\x52\xd9\xbf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\x11\x54\x39\x60\x7f\x33\x5a\x5e\xa8\x3c\x3b\xc4\x4a\x93\x31\xd0\xc1\x33\x26\xa9\xa7\xba\x30\x87\xda\x18\x2d\x64\x8e\xc7\x2f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xf9\xb6\x52\x9b\x5d\x04\x0d\x15\xb8\xfa\x7a\xee\x3e\x34\x10\xe7\x38\xb5\x63\x05\xcd\x44\xf2\x95\x35\xc1\x15\xc5\xa4\xc0\x60\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x87\x2c\x16\xdb\x0f\x72\xa2\x24\x9a\xc6\xba\x48\x4b\xb9\xc3\xa3\xce\x97\xc1\x6d\x58\xb6\x8b\x26\x0e\xb9\x39\xf0\xe6\xe8\xa7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x00\xbd\xee\x08\xbb\x78\x24\xca\x20\xfb\x80\x11\x80\x75\xf4\x02\x19\xb6\x15\x1d\x55\xb5\xc5\x2b\x62\x4a\x7c\xde\xdd\xf6\xa7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\xb9\xb6\x3d\x2f\x10\x8e\x17\xe6\x38\x17\x86\x3a\x8f\x6c\x28\x8d\x7a\xd2\x99\x16\xd9\x8c\xb1\x07\x2e\x4e\x7b\x7d\x52\xb3\x76\
+    \x01\x5b\xee\x13\x57\xe3\xc0\x15\xb5\xbd\xa2\x37\x66\x85\x22\xf6\x13\xd1\xc8\x87\x26\xb5\xec\x42\x24\xa2\x01\x28\x48\x1b\x4f\x7f\
+    \x29\x53\x73\x6e\x94\xbb\x6b\x9f\x1b\x97\x07\xa4\xf1\x61\x5e\x4e\xfe\x1e\x1c\xe4\xba\xb2\x18\xcb\xea\x92\xc7\x85\xb1\x28\xff\xd1\
+    \x0b\x06\x93\x53\xba\x09\x16\x18\x86\x2f\x80\x61\x80\xc0\x38\x5f\x85\x1b\x98\xd3\x72\xb4\x5f\x54\x4c\xe7\x26\x6e\xd6\x60\x8d\xfc\
+    \x30\x4f\x74\xd4\x61\xcc\xc1\x31\x15\xe4\xe0\xbc\xfb\x93\x81\x7e\x55\xae\xb7\xeb\x93\x06\xb6\x4e\x4f\x58\x8a\xc9\x7d\x81\xf4\x29\
+    \x15\xbb\xf1\x46\xce\x9b\xca\x09\xe8\xa3\x3f\x5e\x77\xdf\xe4\xf5\xaa\xd2\xa1\x64\xa4\x61\x7a\x4c\xb8\xee\x54\x15\xcd\xe9\x13\xfc\
+    \x0a\xb4\xdf\xe0\xc2\x74\x2c\xde\x44\x90\x10\x31\x48\x79\x64\xed\x9b\x8f\x4b\x85\x04\x05\xc1\x0c\xa9\xff\x23\x85\x95\x72\xc8\xc6\
+    \x0e\x32\xdb\x32\x0a\x04\x4e\x31\x97\xf4\x5f\x76\x49\xa1\x96\x75\xef\x5e\xed\xfe\xa5\x46\xde\xa9\x25\x1d\xe3\x9f\x96\x39\x77\x9a\
+";
+
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const RC_BN254_T_3: &[u8] = b"\
+    \x1d\x06\x6a\x25\x55\x17\xb7\xfd\x8b\xdd\xd3\xa9\x3f\x78\x04\xef\x7f\x8f\xcd\xe4\x8b\xb4\xc3\x7a\x59\xa0\x9a\x1a\x97\x05\x28\x16\
+    \x29\xda\xef\xb5\x5f\x6f\x2d\xc6\xac\x3f\x08\x9c\xeb\xcc\x61\x20\xb7\xc6\xfe\xf3\x13\x67\xb6\x8e\xb7\x23\x85\x47\xd3\x2c\x16\x10\
+    \x1f\x2c\xb1\x62\x4a\x78\xee\x00\x1e\xcb\xd8\x8a\xd9\x59\xd7\x01\x25\x72\xd7\x6f\x08\xec\x5c\x4f\x9e\x8b\x7a\xd7\xb0\xb4\xe1\xd1\
+    \x0a\xad\x2e\x79\xf1\x57\x35\xf2\xbd\x77\xc0\xed\x3d\x14\xaa\x27\xb1\x1f\x09\x2a\x53\xbb\xc6\xe1\xdb\x06\x72\xde\xd8\x4f\x31\xe5\
+    \x22\x52\x62\x4f\x86\x17\x73\x8c\xd6\xf6\x61\xdd\x40\x94\x37\x5f\x37\x02\x8a\x98\xf1\xde\xce\x66\x09\x1c\xcf\x15\x95\xb4\x3f\x28\
+    \x1a\x24\x91\x3a\x92\x8b\x38\x48\x5a\x65\xa8\x4a\x29\x1d\xa1\xff\x91\xc2\x06\x26\x52\x4b\x2b\x87\xd4\x9f\x4f\x2c\x90\x18\xd7\x35\
+    \x22\xfc\x46\x8f\x17\x59\xb7\x4d\x7b\xfc\x42\x7b\x5f\x11\xeb\xb1\x0a\x41\x51\x5d\xdf\xf4\x97\xb1\x4f\xd6\xda\xe1\x50\x8f\xc4\x7a\
+    \x10\x59\xca\x78\x7f\x1f\x89\xed\x9c\xd0\x26\xe9\xc9\xca\x10\x7a\xe6\x19\x56\xff\x0b\x41\x21\xd5\xef\xd6\x55\x15\x61\x7f\x6e\x4d\
+    \x02\xbe\x94\x73\x35\x84\x61\xd8\xf6\x1f\x35\x36\xd8\x77\xde\x98\x21\x23\x01\x1f\x0b\xf6\xf1\x55\xa4\x5c\xbb\xfa\xe8\xb9\x81\xce\
+    \x0e\xc9\x6c\x8e\x32\x96\x2d\x46\x27\x78\xa7\x49\xc8\x2e\xd6\x23\xab\xa9\xb6\x69\xac\x5b\x87\x36\xa1\xff\x3a\x44\x1a\x50\x84\xa4\
+    \x29\x2f\x90\x6e\x07\x36\x77\x40\x54\x42\xd9\x55\x3c\x45\xfa\x3f\x5a\x47\xa7\xcd\xb8\xc9\x9f\x96\x48\xfb\x2e\x4d\x81\x4d\xf5\x7e\
+    \x27\x49\x82\x44\x41\x57\xb8\x67\x26\xc1\x1b\x9a\x0f\x5e\x39\xa5\xcc\x61\x11\x60\xa3\x94\xea\x46\x0c\x63\xf0\xb2\xff\xe5\x65\x7e\
+    \x1a\x1d\x06\x3e\x54\xb1\xe7\x64\xb6\x3e\x18\x55\xbf\xf0\x15\xb8\xce\xdd\x19\x2f\x47\x30\x87\x31\x49\x95\x73\xf2\x35\x97\xd4\xb5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\xab\xc6\x6f\x3f\xdf\x8e\x68\x83\x9d\x10\x95\x62\x59\x06\x37\x08\x23\x5d\xcc\xc1\xaa\x37\x93\xb9\x1b\x00\x2c\x5b\x25\x7c\x37\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x7c\x64\xa9\xd8\x87\x38\x53\x81\xa5\x78\xcf\xed\x5a\xed\x37\x07\x54\x42\x7a\xab\xca\x92\xa7\x0b\x3c\x2b\x12\xff\x4d\x7b\xe8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xf5\x99\x87\x69\xe9\xfa\xb7\x9e\x17\xf0\xb6\xd0\x8b\x2d\x1e\xba\x2e\xba\xc3\x0d\xc3\x86\xb0\xed\xd3\x83\x83\x13\x54\xb4\x95\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x5e\x3a\x85\x66\xbe\x31\xb7\x56\x4c\xa6\x04\x61\xe9\xe0\x8b\x19\x82\x87\x64\xa9\x66\x9b\xc1\x7a\xba\x0b\x97\xe6\x6b\x01\x09\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\xdf\x6a\x9d\x19\xea\x90\xd8\x95\xe6\x0e\x4d\xb0\x79\x4a\x01\xf3\x59\xa5\x3a\x18\x0b\x7d\x4b\x42\xbf\x3d\x7a\x53\x1c\x97\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xf7\xbf\x2c\x5c\x05\x38\xac\x6e\x4b\x78\x2c\x3c\x6e\x60\x1a\xd0\xea\x1d\x3a\x3b\x9d\x25\xef\x4e\x32\x40\x55\xfa\x31\x23\xdc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x29\xc7\x6c\xe2\x22\x55\x20\x6e\x3c\x40\x05\x85\x23\x74\x85\x31\xe7\x70\xc0\x58\x4a\xa2\x32\x8c\xe5\x5d\x54\x62\x8b\x89\xeb\xe6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\x8d\x42\x5a\x45\xb7\x8e\x85\xc0\x53\x65\x9a\xb4\x34\x7f\x5d\x65\xb1\xb8\xe9\xc6\x10\x8d\xbe\x00\xe0\xe9\x45\xdb\xc5\xff\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xee\x27\xab\x62\x96\xcd\x5e\x6a\xf3\xcc\x79\xc5\x98\xa1\xda\xa7\xff\x7f\x68\x78\xb3\xc4\x9d\x49\xd3\xa9\xa9\x0c\x3f\xdf\x74\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x8e\xa8\xe0\xaf\x41\xa1\xe0\x24\x56\x10\x01\xc0\xb6\xeb\x15\x05\x84\x5d\x7d\x0c\x55\xb1\xb2\xc0\xf8\x86\x87\xa9\x6d\x13\x81\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x30\x61\x97\xfb\x3f\xab\x67\x1e\xf6\xe7\xc2\xcb\xa2\xee\xfd\x0e\x42\x85\x1b\x5b\x98\x11\xf2\xca\x40\x13\x37\x0a\x01\xd9\x56\x87\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x0c\x7d\x52\xdc\x32\xa4\x43\x2b\x66\xf0\xb4\x89\x4d\x4f\x1a\x21\xdb\x75\x65\xe5\xb4\x25\x04\x86\x41\x9e\xaf\x00\xe8\xf6\x20\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x46\xb4\x18\xde\x80\x91\x5f\x3f\xf8\x6a\x8e\x5c\x8b\xdf\xcc\xeb\xfb\xe5\xf5\x51\x63\xcd\x6c\xaa\x52\x99\x7d\xa2\xc5\x4a\x9f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xd3\xe0\xdc\x00\x85\x87\x37\x01\xf8\xb7\x77\xb9\x67\x3a\xf9\x61\x3a\x1a\xf5\xdb\x48\xe0\x5b\xfb\x46\xe3\x12\xb5\x82\x9f\x64\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x33\x90\xcf\x74\xdc\x3a\x88\x70\xf5\x00\x2e\xd2\x1d\x08\x9f\xfb\x2b\xf7\x68\x23\x0f\x64\x8d\xba\x33\x8a\x5c\xb1\x9b\x3a\x1f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\x14\xf3\x3a\x5f\xe6\x68\xa6\x0a\xc8\x84\xb4\xca\x60\x7a\xd0\xf8\xab\xb5\xaf\x40\xf9\x6f\x1d\x7d\x54\x3d\xb5\x2b\x00\x3d\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\xea\xd9\xc5\x86\x51\x3e\xab\x1a\x5e\x86\x50\x9d\x68\xb2\xda\x27\xbe\x3a\x4f\x01\x17\x1a\x1d\xd8\x47\xdf\x82\x9b\xc6\x83\xb9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x6a\xb1\xc3\x28\xc3\xc6\x43\x09\x72\x03\x1f\x1b\xdb\x2a\xc9\x88\x8f\x0e\xa1\xab\xe7\x1c\xff\xea\x16\xcd\xa6\xe1\xa7\x41\x6c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\xc7\xe7\x1b\xc0\xb8\x19\x79\x2b\x25\x00\x23\x9f\x7f\x8d\xe0\x4f\x6d\xec\xd6\x08\xcb\x98\xa9\x32\x34\x60\x15\xc5\xb4\x2c\x94\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\xe1\x07\xeb\x3a\x42\xb2\xec\xe3\x80\xe0\xd8\x60\x29\x8f\x17\xc0\xc1\xe1\x97\xc9\x52\x65\x0e\xe6\xdd\x85\xb9\x3a\x0d\xda\xa8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x35\x4a\x25\x1f\x38\x1a\x46\x69\xc0\xd5\x2b\xf8\x8b\x77\x2c\x46\x45\x2c\xa5\x7c\x08\x69\x7f\x45\x45\x05\xf6\x94\x1d\x78\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\x4a\xf8\x8a\xb0\x5d\x94\xba\xf6\x87\xef\x14\xbc\x56\x6d\x1c\x52\x25\x51\xd6\x16\x06\xed\xa3\xd1\x4b\x46\x06\x82\x6f\x79\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\x70\x5b\x78\x3b\xf3\xd2\xdc\x19\xbc\xae\xab\xf0\x2f\x8c\xa5\xe1\xab\x5b\x6f\x2e\x31\x95\xa9\xd5\x2b\x2d\x24\x9d\x13\x96\xf7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\xbf\x4a\xcc\x3a\x8b\xce\x3f\x1f\xcc\x33\xfe\xe5\x4f\xc5\xb2\x87\x23\xb1\x6b\x7d\x74\x0a\x3e\x60\xce\xf6\x85\x22\x71\x20\x0e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\x03\xf8\x20\x0d\xb6\x01\x3c\x50\xf8\x3c\x0c\x8f\xab\x62\x84\x34\x13\x73\x2f\x30\x1f\x70\x58\x54\x3a\x07\x3f\x3f\x3b\x5e\x4e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x80\xaf\xb5\x04\x62\x44\xde\x30\x59\x5b\x16\x0b\x8d\x1f\x38\xbf\x6f\xb0\x2d\x44\x54\xc0\xad\xd4\x1f\x7f\xef\x2f\xaf\x3e\x5c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x6e\xe1\xf8\x50\x4f\x15\xc3\xd7\x7f\x00\x88\xc1\xcf\xc9\x64\xab\xcf\xcf\x64\x3f\x4a\x6f\xea\x7d\xc3\xf9\x82\x19\x52\x9d\x78\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xc2\x03\xd1\x0c\xfc\xc6\x0f\x69\xbf\xb3\xd9\x19\x55\x2c\xa1\x0f\xfb\x4e\xe6\x31\x75\xdd\xf8\xef\x86\xf9\x91\xd7\xd0\xa5\x91\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\x2a\xe1\x5d\x8b\x14\x37\x09\xec\x0d\x09\x70\x5f\xa3\xa6\x30\x3d\xec\x1e\xe4\xee\xc2\xcf\x74\x7c\x5a\x33\x9f\x77\x44\xfb\x94\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\xb6\x0d\xee\x58\x6e\xd6\xef\x47\xe5\xc3\x81\xab\x63\x43\xec\xc3\xd3\xb3\x00\x6c\xb4\x61\xbb\xb6\xb5\xd8\x90\x81\x97\x0b\x2b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x31\x6b\x55\x9b\xe3\xed\xfd\x88\x5d\x95\xc4\x94\xc1\xae\x3d\x8a\x98\xa3\x20\xba\xa7\xd1\x52\x13\x2c\xfe\x58\x3c\x93\x11\xbd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x5c\x49\xba\x15\x7c\x32\xb8\xd8\x93\x7c\xb2\xd3\xf8\x43\x11\xef\x83\x4c\xc2\xa7\x43\xed\x66\x2f\x5f\x9a\xf0\xc0\x34\x2e\x76\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x8b\x12\x4e\x78\x16\x3b\x2f\x33\x27\x74\xe0\xb8\x50\xb5\xec\x09\xc0\x1b\xf6\x97\x99\x38\xf6\x7c\x24\xbd\x59\x40\x96\x84\x88\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x68\x43\xa5\x45\x74\x16\xb6\xdc\x5b\x7a\xa0\x9a\x9c\xe2\x1b\x1d\x4c\xba\x65\x54\xe5\x1d\x84\x66\x5f\x75\x26\x01\x13\xb3\xd5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\xcd\xf0\x0a\x35\xf6\x50\xc5\x5f\xca\x25\xc9\x92\x9c\x8a\xd9\xa6\x8d\xaf\x9a\xc6\xa1\x89\xab\x1f\x5b\xc7\x9f\x21\x64\x1d\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x63\x2d\xe3\xd3\xbb\xc5\xe4\x2e\xf3\x6e\x58\x81\x58\xd6\xd4\x60\x8b\x28\x15\xc7\x73\x55\xb7\xe8\x2b\x5b\x9b\x7e\xb5\x60\xbc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\xe6\x25\x75\x84\x52\xef\xbd\x97\xb2\x70\x25\xfb\xd2\x45\xe0\x25\x5a\xe4\x8e\xf2\xa3\x29\xe4\x49\xd7\xb5\xc5\x1c\x18\x49\x8a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\xd2\x53\xc0\x53\xe7\x52\x13\xe2\xfe\xbf\xd4\xd9\x76\xcc\x01\xdd\x9e\x1e\x1c\x6f\x0f\xb6\xb0\x9b\x09\x54\x6b\xa0\x83\x80\x98\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x6b\x16\x9e\xd6\x38\x72\xdc\x6e\xc7\x68\x1e\xc3\x9b\x3b\xe9\x3d\xd4\x9c\xdd\x13\xc8\x13\xb7\xd3\x57\x02\xe3\x8d\x60\xb0\x77\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x60\xb7\x40\xa1\x43\x66\x4b\xb9\x12\x7c\x49\x41\xb6\x7f\xed\x0b\xe3\xea\x70\xa2\x4d\x55\x68\xc3\xa5\x4e\x70\x6c\xfe\xf7\xfe\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x65\xa9\x2d\x1d\xe8\x1f\x34\x11\x4f\x4c\xa2\xde\xef\x76\xe0\xce\xac\xdd\xdb\x12\xcf\x87\x90\x96\xa2\x9f\x10\x37\x6c\xcb\xfe\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x11\xf0\x65\x20\x25\x35\x98\x73\x67\xf8\x23\xda\x7d\x67\x2c\x35\x3e\xbe\x2c\xcb\xc4\x86\x9b\xcf\x30\xd5\x0a\x58\x71\x04\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x59\x6f\x5c\x5d\xd5\xa5\xd1\xb4\x37\xce\x7b\x14\xa2\xc3\xdd\x3b\xd1\xd1\xa3\x9b\x67\x59\xba\x11\x08\x52\xd1\x7d\xf0\x69\x3e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\xf4\x9b\xc7\x27\xe4\x5a\x2f\x7b\xf3\x05\x6e\xfc\xf8\xb6\xd3\x85\x39\xc4\x16\x3a\x5f\x1e\x70\x67\x43\xdb\x15\xaf\x91\x86\x0f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\xbe\x1d\xeb\x45\xb3\xe3\x11\x99\x54\x17\x5e\xfb\x33\x1b\xf4\x56\x8f\xea\xf7\xea\x8b\x3d\xc5\xe1\xa4\xe7\x43\x8d\xd3\x9e\x5f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x42\x6c\xca\xb6\x69\x84\xd1\xd8\x99\x3a\x74\xca\x54\x8b\x77\x9f\x5d\xb9\x2a\xae\xc5\xf1\x02\x02\x0d\x34\xae\xa1\x5f\xba\x59\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x7c\x30\xc2\xe2\xe8\x95\x7f\x49\x33\xbd\x19\x42\x05\x3f\x1f\x00\x71\x68\x4b\x90\x2d\x53\x4f\xa8\x41\x92\x43\x03\xf6\xa6\xc6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x12\xa0\x17\xca\x92\xcf\x0a\x16\x22\x70\x8f\xc7\xed\xff\x1d\x61\x66\xde\xd6\xe3\x52\x8e\xad\x4c\x76\xe1\xf3\x1d\x3f\xc6\x9d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xa5\xad\xe3\xdf\x2b\xc1\xb5\xbb\xa9\x49\xd1\xdb\x96\x04\x00\x68\xaf\xe5\x02\x6e\xdd\x7a\x9c\x2e\x27\x6b\x47\xcf\x01\x0d\x54\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\xf3\x03\x54\x63\x81\x6c\x84\xad\x71\x1b\xf1\xa0\x58\xc6\xc6\xbd\x10\x19\x45\xf5\x0e\x5a\xfe\x72\xb1\xa5\x23\x3f\x87\x49\xce\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x11\x55\x72\xf0\x38\xc0\xe2\x02\x8c\x2a\xaf\xc2\xd0\x6a\x5e\x8b\xf2\xf9\x39\x8d\xbd\x0f\xdf\x4d\xca\xa8\x2b\x0f\x0c\x1c\x8b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x38\xec\x0b\x99\xb6\x2f\xd4\xf0\xef\x25\x55\x43\xf5\x0d\x2e\x27\xfc\x24\xdb\x42\xbc\x91\x0a\x34\x60\x61\x3b\x6e\xf5\x9e\x2f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x89\xc6\xd9\x66\x62\x72\xe8\x42\x5c\x3f\xf1\xf4\xac\x73\x7b\x2f\x5d\x31\x46\x06\xa2\x97\xd4\xb1\xd0\xb2\x54\xd8\x80\xc5\x3e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\x32\x6e\x64\x35\x80\x35\x6b\xf6\xd4\x40\x08\xae\x4c\x04\x2a\x21\xad\x48\x80\x09\x7a\x5e\xb3\x8b\x71\xe2\x31\x1b\xb8\x8f\x8f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x80\x76\xb0\x05\x4f\xb7\x3f\x67\xce\xe9\xea\x0e\x51\xe3\xad\x50\xf2\x7a\x64\x34\xb5\xdc\xeb\x5b\xdd\xe2\x29\x99\x10\xa4\xc9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\xcd\x63\xc6\x7f\xbc\x9a\xb1\x62\x6e\xd9\x34\x91\xbd\xa3\x2e\x5d\xa1\x8e\xa9\xd8\xe4\xf1\x01\x78\xd0\x4a\xa6\xf8\x74\x7a\xd0\
+    \x19\xf8\xa5\xd6\x70\xe8\xab\x66\xc4\xe3\x14\x4b\xe5\x8e\xf6\x90\x1b\xf9\x33\x75\xe2\x32\x3e\xc3\xca\x8c\x86\xcd\x2a\x28\xb5\xa5\
+    \x1c\x0d\xc4\x43\x51\x9a\xd7\xa8\x6e\xfa\x40\xd2\xdf\x10\xa0\x11\x06\x81\x93\xea\x51\xf6\xc9\x2a\xe1\xcf\xbb\x5f\x7b\x9b\x68\x93\
+    \x14\xb3\x9e\x7a\xa4\x06\x8d\xbe\x50\xfe\x71\x90\xe4\x21\xdc\x19\xfb\xea\xb3\x3c\xb4\xf6\xa2\xc4\x18\x0e\x4c\x32\x24\x98\x7d\x3d\
+    \x1d\x44\x9b\x71\xbd\x82\x6e\xc5\x8f\x28\xc6\x3e\xa6\xc5\x61\xb7\xb8\x20\xfc\x51\x9f\x01\xf0\x21\xaf\xb1\xe3\x5e\x28\xb0\x79\x5e\
+    \x1e\xa2\xc9\xa8\x9b\xaa\xdd\xbb\x60\xfa\x97\xfe\x60\xfe\x9d\x8e\x89\xde\x14\x16\x89\xd1\x25\x22\x76\x52\x4d\xc0\xa9\xe9\x87\xfc\
+    \x04\x78\xd6\x6d\x43\x53\x5a\x8c\xb5\x7e\x9c\x1c\x3d\x6a\x2b\xd7\x59\x1f\x9a\x46\xa0\xe9\xc0\x58\x13\x4d\x5c\xef\xdb\x3c\x7f\xf1\
+    \x19\x27\x2d\xb7\x1e\xec\xe6\xa6\xf6\x08\xf3\xb2\x71\x7f\x9c\xd2\x66\x2e\x26\xad\x86\xc4\x00\xb2\x1c\xde\x5e\x4a\x7b\x00\xbe\xbe\
+    \x14\x22\x65\x37\x33\x5c\xab\x33\xc7\x49\xc7\x46\xf0\x92\x08\xab\xb2\xdd\x1b\xd6\x6a\x87\xef\x75\x03\x9b\xe8\x46\xaf\x13\x41\x66\
+    \x01\xfd\x6a\xf1\x59\x56\x29\x4f\x9d\xfe\x38\xc0\xd9\x76\xa0\x88\xb2\x1c\x21\xe4\xa1\xc2\xe8\x23\xf9\x12\xf4\x49\x61\xf9\xa9\xce\
+    \x18\xe5\xab\xed\xd6\x26\xec\x30\x7b\xca\x19\x0b\x8b\x2c\xab\x1a\xae\xe2\xe6\x2e\xd2\x29\xba\x5a\x5a\xd8\x51\x8d\x4e\x5f\x2a\x57\
+    \x0f\xc1\xbb\xce\xba\x05\x90\xf5\xab\xbd\xff\xa6\xd3\xb3\x5e\x32\x97\xc0\x21\xa3\xa4\x09\x92\x6d\x0e\x2d\x54\xdc\x1c\x84\xfd\xa6\
+";
+
+#[cfg(feature = "poseidon2-bn254")]
+pub(crate) const RC_BN254_T_4: &[u8] = b"\
+    \x19\xb8\x49\xf6\x94\x50\xb0\x68\x48\xda\x1d\x39\xbd\x5e\x4a\x43\x02\xbb\x86\x74\x4e\xdc\x26\x23\x8b\x08\x78\xe2\x69\xed\x23\xe5\
+    \x26\x5d\xdf\xe1\x27\xdd\x51\xbd\x72\x39\x34\x7b\x75\x8f\x0a\x13\x20\xeb\x2c\xc7\x45\x0a\xcc\x1d\xad\x47\xf8\x0c\x8d\xcf\x34\xd6\
+    \x19\x97\x50\xec\x47\x2f\x18\x09\xe0\xf6\x6a\x54\x5e\x1e\x51\x62\x41\x08\xac\x84\x50\x15\xc2\xaa\x3d\xfc\x36\xba\xb4\x97\xd8\xaa\
+    \x15\x7f\xf3\xfe\x65\xac\x72\x08\x11\x0f\x06\xa5\xf7\x43\x02\xb1\x4d\x74\x3e\xa2\x50\x67\xf0\xff\xd0\x32\xf7\x87\xc7\xf1\xcd\xf8\
+    \x2e\x49\xc4\x3c\x45\x69\xdd\x9c\x5f\xd3\x5a\xc4\x5f\xca\x33\xf1\x0b\x15\xc5\x90\x69\x2f\x8b\xee\xfe\x18\xf4\x89\x6a\xc9\x49\x02\
+    \x0e\x35\xfb\x89\x98\x18\x90\x52\x0d\x4a\xef\x2b\x6d\x65\x06\xc3\xcb\x2f\x0b\x69\x73\xc2\x4f\xa8\x27\x31\x34\x5f\xfa\x2d\x1f\x1e\
+    \x25\x1a\xd4\x7c\xb1\x5c\x4f\x11\x05\xf1\x09\xae\x5e\x94\x4f\x1b\xa9\xd9\xe7\x80\x6d\x66\x7f\xfe\xc6\xfe\x72\x30\x02\xe0\xb9\x96\
+    \x13\xda\x07\xdc\x64\xd4\x28\x36\x98\x73\xe9\x71\x60\x23\x46\x41\xf8\xbe\xb5\x6f\xdd\x05\xe5\xf3\x56\x3f\xa3\x9d\x9c\x22\xdf\x4e\
+    \x0c\x00\x9b\x84\xe6\x50\xe6\xd2\x3d\xc0\x0c\x7d\xcc\xef\x74\x83\xa5\x53\x93\x96\x89\xd3\x50\xcd\x46\xe7\xb8\x90\x55\xfd\x47\x38\
+    \x01\x1f\x16\xb1\xc6\x3a\x85\x4f\x01\x99\x2e\x39\x56\xf4\x2d\x8b\x04\xeb\x65\x0c\x6d\x53\x5e\xb0\x20\x3d\xec\x74\xbe\xfd\xca\x06\
+    \x0e\xd6\x9e\x5e\x38\x3a\x68\x8f\x20\x9d\x9a\x56\x1d\xaa\x79\x61\x2f\x3f\x78\xd0\x46\x7a\xd4\x54\x85\xdf\x07\x09\x3f\x36\x75\x49\
+    \x04\xdb\xa9\x4a\x7b\x0c\xe9\xe2\x21\xac\xad\x41\x47\x2b\x6b\xbe\x3a\xec\x50\x7f\x5e\xb3\xd3\x3f\x46\x36\x72\x26\x4c\x9f\x78\x9b\
+    \x0a\x3f\x26\x37\xd8\x40\xf3\xa1\x6e\xb0\x94\x27\x1c\x9d\x23\x7b\x60\x36\x75\x7d\x4b\xb5\x0b\xf7\xce\x73\x2f\xf1\xd4\xfa\x28\xe8\
+    \x25\x9a\x66\x6f\x12\x9e\xea\x19\x8f\x8a\x1c\x50\x2f\xdb\x38\xfa\x39\xb1\xf0\x75\x56\x95\x64\xb6\xe5\x4a\x48\x5d\x11\x82\x32\x3f\
+    \x28\xbf\x74\x59\xc9\xb2\xf4\xc6\xd8\xe7\xd0\x6a\x4e\xe3\xa4\x7f\x77\x45\xd4\x27\x10\x38\xe5\x15\x7a\x32\xfd\xf7\xed\xe0\xd6\xa1\
+    \x0a\x1c\xa9\x41\xf0\x57\x03\x75\x26\xea\x20\x0f\x48\x9b\xe8\xd4\xc3\x7c\x85\xbb\xcc\xe6\xa2\xae\xec\x91\xbd\x69\x41\x43\x24\x47\
+    \x0c\x6f\x8f\x95\x8b\xe0\xe9\x30\x53\xd7\xfd\x4f\xc5\x45\x12\x85\x55\x35\xed\x15\x39\xf0\x51\xdc\xb4\x3a\x26\xfd\x92\x63\x61\xcf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x31\x06\xa9\x3c\xd1\x75\x78\xd4\x26\xe8\x12\x8a\xc9\xd9\x0a\xa9\xe8\xa0\x07\x08\xe2\x96\xe0\x84\xdd\x57\xe6\x9c\xaa\xf8\x11\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\xe1\xba\x52\xad\x92\x85\xd9\x7d\xd3\xab\x52\xf8\xe8\x40\x08\x5e\x8f\xa8\x3f\xf1\xe8\xf1\x87\x7b\x07\x48\x67\xcd\x2d\xee\x75\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xb5\x5c\xad\x7b\xd1\x33\xde\x18\xa6\x4c\x5c\x47\xb9\xc9\x7c\xbe\x4d\x8b\x7b\xf9\xe0\x95\x86\x44\x71\x53\x7e\x6a\x4a\xe2\xc5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\xcd\x73\xe4\x6a\xcd\x8f\x8e\x0e\x2c\x7c\xe0\x4b\xde\x7f\x6d\x2a\x53\x04\x3d\x50\x60\xa4\x1c\x71\x43\xf0\x8e\x6e\x90\x55\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\x10\x03\xe3\x2f\x6d\x9c\x66\xf5\x85\x2f\x05\x47\x4a\x4d\xef\x0c\xda\x29\x4a\x0e\xb4\xe9\xb9\xb1\x2b\x9b\xb4\x51\x2e\x55\x74\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x1e\x80\x9a\xc1\xd1\x0a\xb2\x9a\xd5\xf2\x0d\x03\xa5\x7d\xfe\xba\xdf\xe5\x90\x3f\x58\xba\xfe\xd7\xc5\x08\xdd\x22\x87\xae\x8c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x39\xde\x17\x85\xb7\x35\x99\x9f\xb4\xda\xc3\x5e\xe1\x7e\xd0\xef\x99\x5d\x05\xab\x2f\xc5\xfa\xea\xa6\x9a\xe8\x7b\xce\xc0\xa5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x24\x6c\x5a\x2e\xf8\xee\x01\x26\x49\x7f\x22\x2b\x3e\x0a\x0e\xf4\xe1\xc3\xd4\x1c\x86\xd4\x6e\x43\x98\x2c\xb1\x1d\x77\x95\x1d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\x20\x89\xc4\x97\x4f\x68\xe9\x54\x08\x14\x8f\x7c\x06\x32\xed\xbb\x09\xe6\xa6\xad\x1a\x1c\x2f\x3f\x03\x05\xf5\xd0\x3b\x52\x7b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\xae\x0a\xd8\xab\x68\xb2\xf0\x6a\x0e\xe3\x6e\xeb\x0d\x0c\x05\x85\x29\x09\x7d\x91\x09\x6b\x75\x6d\x8f\xdc\x2f\xb5\xa6\x0d\x85\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x91\x90\xe5\xd0\xe2\x21\x79\xe4\x6f\x82\x82\x87\x2a\xbc\x88\xdb\x6e\x2f\xdc\x0d\xee\x99\xe6\x97\x68\xbd\x98\xc5\xd0\x6b\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x29\xbb\x9e\x2c\x90\x76\x73\x25\x76\xe9\xa8\x1c\x7a\xc4\xb8\x32\x14\x52\x8f\x7d\xb0\x0f\x31\xbf\x6c\xaf\xe7\x94\xa9\xb3\xcd\x1c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x5d\x39\x4e\x42\x20\x75\x99\x40\x3e\xfd\x0c\x24\x64\xa9\x0d\x52\x65\x26\x45\x88\x2a\xac\x35\xb1\x0e\x59\x0e\x6e\x69\x1e\x08\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\x47\x60\x62\x3c\x25\xc8\xcf\x75\x3d\x23\x80\x55\xb4\x44\x53\x2b\xe1\x35\x57\x45\x1c\x08\x7d\xe0\x9e\xfd\x45\x4b\x23\xfd\x59\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\xba\x3a\x0e\x01\xdf\x92\xe8\x7f\x30\x1c\x4b\x71\x6d\x8a\x39\x4d\x67\xf4\xbf\x42\xa7\x5c\x10\x92\x29\x10\xa7\x8f\x6b\x5b\x87\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x07\x0b\xf5\x3f\x84\x51\xb2\x4f\x9c\x6e\x96\xb0\xc2\xa8\x01\xcb\x51\x1b\xc0\xc2\x42\xeb\x9d\x36\x1b\x77\x69\x3f\x21\x47\x1c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\x94\xcd\x61\xb0\x51\xb0\x4d\xd3\x97\x55\xff\x93\x82\x1a\x73\xcc\xd6\xcb\x11\xd2\x49\x1d\x8a\xa7\xf9\x21\x01\x4d\xe2\x52\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x7c\xb3\x9b\xaf\xb8\xc7\x44\xe1\x48\x78\x7a\x2e\x70\x23\x0f\x9d\x4e\x91\x7d\x57\x13\xbb\x05\x04\x87\xb5\xaa\x7d\x74\x07\x0b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\xc9\x31\x89\xbd\x1a\xb4\xf6\x91\x17\xd0\xfe\x98\x0c\x80\xff\x87\x85\xc2\x96\x18\x29\xf7\x01\xbb\x74\xac\x1f\x30\x3b\x17\xdb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\xb3\x66\xbf\xdd\x36\xd2\x77\xa6\x92\xbb\x82\x5b\x86\x27\x5b\xea\xc4\x04\xa1\x9a\xe0\x7a\x90\x82\xea\x46\xbd\x83\x51\x79\x26\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\x21\x00\xeb\x48\x5d\xb0\x62\x69\x65\x5c\xf1\x86\xa6\x85\x32\x98\x52\x75\x42\x84\x50\x35\x9a\xdc\x99\xce\xc6\x96\x07\x11\xb8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x61\xd3\x3c\x66\x61\x4a\xaa\x57\x0e\x7f\x1e\x82\x44\xca\x11\x20\x24\x3f\x92\xfa\x59\xe4\xf9\x00\xc5\x67\xbf\x41\xf5\xa5\x9b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\xfc\x41\x1a\x11\x4d\x13\x99\x2c\x27\x05\xaa\x03\x4e\x3f\x31\x5d\x78\x60\x8a\x0f\x7d\xe4\xcc\xf7\xa7\x2e\x49\x48\x55\xad\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xb5\xc0\x04\xa4\xbd\xfc\xb5\xad\xd9\xec\x4e\x9a\xb2\x19\xba\x10\x2c\x67\xe8\xb3\xef\xfb\x5f\xc3\xa3\x0f\x31\x72\x50\xbc\x5a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xb1\x82\x2d\x27\x8e\xd6\x32\xa4\x94\xe5\x8f\x6d\xf6\xf5\xed\x03\x8b\x18\x6d\x84\x74\x15\x5a\xd8\x7e\x7d\xff\x62\xb3\x7f\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x73\x4b\x4c\x5c\x3f\x94\x93\x60\x6c\x4b\xa9\x01\x24\x99\xbf\x0f\x14\xd1\x3b\xfc\xfc\xcc\xaa\x16\x10\x2a\x29\xcc\x2f\x69\xe0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\xc0\xc8\xfe\x09\xeb\x30\xb7\xe2\x7a\x74\xdc\x33\x49\x23\x47\xe5\xbd\xff\x40\x9a\xa3\x61\x02\x54\x41\x3d\x3f\xad\x79\x5c\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x0d\xd0\xcc\xb6\xbd\x7b\xba\xe8\x8e\xac\x03\xfa\x1f\xbb\x26\x19\x6b\xe3\x08\x3a\x80\x98\x29\xbb\xd6\x26\xdf\x34\x8c\xca\xd9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xb6\x59\x5b\xdb\x32\x9b\x6f\xb0\x43\xba\x78\xbb\x28\xc3\xbe\xc2\xc0\xa6\xde\x46\xd8\xc5\xad\x60\x67\xc4\xeb\xfd\x42\x50\xda\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x8d\x97\xd7\xf7\x62\x83\xd6\x3b\xec\x30\xe7\xa5\x87\x6c\x11\xc0\x6f\xca\x9b\x27\x5c\x67\x1c\x5e\x33\xd9\x5b\xb7\xe8\xd7\x29\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x30\x6d\x43\x9d\x46\x3b\x08\x16\xfc\x6f\xd6\x4c\xc9\x39\x31\x8b\x45\xeb\x75\x9d\xdd\xe4\xaa\x10\x6d\x15\xd9\xbd\x9b\xaa\xaa\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\xa8\xf8\x37\x2e\x3c\x38\xda\xce\xd7\xc0\x04\x21\xcb\x46\x21\xf4\xf1\xb5\x4d\xdc\x27\x82\x1b\x0d\x62\xd3\xd6\xec\x7c\x56\xcf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x94\x97\x57\x17\xf9\xa8\xa8\xbb\x35\x15\x2f\x24\xd4\x32\x94\x07\x1c\xe3\x20\xc8\x29\xf3\x88\xbc\x85\x21\x83\xe1\xe2\xce\x7e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xd5\xee\x4c\x3a\xa7\x8f\x7d\x80\xfd\xe6\x0d\x71\x64\x80\xd3\x59\x3f\x74\xd4\xf6\x53\xae\x83\xf4\x10\x32\x46\xdb\x2e\x8d\x65\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\x6c\xf5\xe9\xaa\x03\xd4\x33\x63\x49\xad\x6f\xb8\xed\x22\x69\xc7\xbe\xf5\x4b\x88\x22\xcc\x76\xd0\x84\x95\xc1\x2e\xfd\xe1\x87\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x04\xd3\x1e\xaa\xb9\x60\xba\x92\x74\xda\x43\xe1\x9d\xde\xb7\xf7\x92\x18\x08\x08\xfd\x6e\x43\xba\xae\x48\xd7\xef\xcb\xa3\xf3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\xfd\x9a\xc8\x65\xa4\xb2\xa6\xd5\xe7\x00\x97\x85\x81\x72\x49\xbf\xf0\x8a\x7e\x07\x26\xfc\xb4\xe1\xc1\x1d\x39\xd1\x99\xf0\xb0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xb7\x25\x8d\xed\x52\xbb\xda\x22\x48\x40\x4d\x55\xee\x50\x44\x79\x8a\xfc\x3a\x20\x91\x93\x07\x3f\x79\x54\xd4\xd6\x3b\x0b\x64\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x9f\x81\xad\xa0\x77\x17\x99\xec\x38\xfc\xa2\xd4\xbf\x65\xeb\xb1\x3d\x3a\x74\xf3\x29\x8d\xb3\x62\x72\xc5\xca\x65\xe9\x2d\x9a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\xf9\x0e\x67\x43\x7f\xbc\x85\x50\x23\x7a\x75\xbc\x28\xe3\xbb\x90\x00\x13\x0e\xa2\x5f\x0c\x54\x71\xe1\x44\xcf\x42\x64\x43\x1f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x65\xf8\x38\x51\x5e\x5f\xf0\x19\x6b\x49\xaa\x41\xa2\xd2\x56\x8d\xf7\x39\xbc\x17\x6b\x08\xec\x95\xa7\x9e\xd8\x29\x32\xe3\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x1b\x04\x5d\xef\x3a\x16\x6c\xec\x6c\xe7\x68\xd0\x79\xba\x74\xb1\x8c\x84\x4e\x57\x0e\x1f\x82\x65\x75\xc1\x06\x8c\x94\xc3\x3f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x32\xe5\x75\x3c\xeb\x0f\xf6\x40\x25\x43\xb1\x10\x92\x29\xc1\x65\xdc\x2d\x73\xbe\xf7\x15\xe3\xf1\xc6\xe0\x7c\x16\x8b\xb1\x73\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\xf6\x14\xe9\xce\xdf\xb3\xdc\x6b\x76\x2a\xe0\xa3\x7d\x41\xba\xb1\xb8\x41\xc2\xe8\xb6\x45\x1b\xc5\xa8\xe3\xc3\x90\xb6\xad\x16\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x24\x27\xd3\x8b\xd4\x6a\x60\xdd\x64\x0b\x8e\x36\x2c\xad\x96\x73\x70\xeb\xb7\x77\xbe\xdf\xf4\x0f\x6a\x0b\xe2\x7e\x7e\xd7\x05\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\x93\x63\x0b\x7c\x67\x0b\x6d\xeb\x7c\x84\xd4\x14\xe7\xce\x79\x04\x9f\x0e\xc0\x98\xc3\xc7\xc5\x07\x68\xbb\xe2\x92\x14\xa5\x3a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\xea\xd1\x00\xe8\xe4\x82\x67\x4d\xec\xda\xb1\x70\x66\xc5\xa2\x6b\xb1\x51\x53\x55\xd5\x46\x1a\x3d\xc0\x6c\xc8\x53\x27\xce\xa9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xb3\xe5\x6e\x65\x5b\x42\xcd\xaa\xe2\x62\x6e\xd2\x55\x4d\x48\x58\x3f\x1a\xe3\x56\x26\xd0\x4d\xe5\x08\x4e\x0b\x6d\x2a\x6f\x16\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x32\x75\x2a\xda\x88\x36\xef\x58\x37\xa6\xcd\xe8\xff\x13\xdb\xb5\x99\xc3\x36\x34\x9e\x4c\x58\x4b\x4f\xdc\x0a\x0c\xf6\xf9\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\xa2\xa8\x71\xc1\x5a\x38\x7c\xc5\x0f\x68\xf6\xf3\xc3\x45\x5b\x23\xc0\x09\x95\xf0\x50\x78\xf6\x72\xa9\x86\x40\x74\xd4\x12\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x56\x9b\x8a\x9a\x44\x24\xc9\x27\x8e\x1d\xb7\x31\x1e\x88\x9f\x54\xcc\xbf\x10\x66\x1b\xab\x7f\xcd\x18\xe7\xc7\xa7\xd8\x35\x05\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\x4c\xb4\x55\x11\x0a\x8f\xdd\x53\x1a\xde\x53\x02\x34\xc5\x18\xa7\xdf\x93\xf7\x33\x2f\xfd\x21\x44\x16\x53\x74\xb2\x46\xb4\x3d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x78\x08\xde\x93\x90\x6d\x5d\x42\x02\x46\x15\x7f\x2e\x42\xb1\x91\xfe\x8c\x90\xad\xfe\x11\x81\x78\xdd\xc7\x23\xa5\x31\x90\x25\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\xfc\xca\x29\x34\xe0\x46\xbc\x62\x3a\xde\xad\x87\x35\x79\x86\x5d\x03\x78\x1a\xe0\x90\xad\x4a\x85\x79\xd2\xe7\xa6\x80\x03\x55\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\xf9\x15\xf0\xac\x12\x0b\x87\x6a\xbc\xcc\xeb\x34\x4a\x1d\x36\xba\xd3\xf3\xc5\xab\x91\xa8\xdd\xcb\xec\x2e\x06\x0d\x8b\xef\xac\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x97\x13\x0f\x4b\x7a\x3e\x17\x77\xeb\x75\x7b\xc6\xf2\x87\xf6\xab\x0f\xb8\x5f\x6b\xe6\x3b\x09\xf3\xb1\x6e\xf2\xb1\x40\x5d\x38\
+    \x0a\x76\x22\x5d\xc0\x41\x70\xae\x33\x06\xc8\x5a\xba\xb5\x9e\x60\x8c\x7f\x49\x7c\x20\x15\x6d\x4d\x36\xc6\x68\x55\x5d\xec\xc6\xe5\
+    \x1f\xff\xb9\xec\x19\x92\xd6\x6b\xa1\xe7\x7a\x7b\x93\x20\x9a\xf6\xf8\xfa\x76\xd4\x8a\xcb\x66\x47\x96\x17\x4b\x53\x26\xa3\x1a\x5c\
+    \x25\x72\x1c\x4f\xc1\x5a\x3f\x28\x53\xb5\x7c\x33\x8f\xa5\x38\xd8\x5f\x8f\xbb\xa6\xc6\xb9\xc6\x09\x06\x11\x88\x9b\x79\x7b\x9c\x5f\
+    \x0c\x81\x7f\xd4\x2d\x5f\x7a\x41\x21\x5e\x3d\x07\xba\x19\x72\x16\xad\xb4\xc3\x79\x07\x05\xda\x95\xeb\x63\xb9\x82\xbf\xca\xf7\x5a\
+    \x13\xab\xe3\xf5\x23\x99\x15\xd3\x9f\x7e\x13\xc2\xc2\x49\x70\xb6\xdf\x8c\xf8\x6c\xe0\x0a\x22\x00\x2b\xc1\x58\x66\xe5\x2b\x5a\x96\
+    \x21\x06\xfe\xea\x54\x62\x24\xea\x12\xef\x7f\x39\x98\x7a\x46\xc8\x5c\x1b\xc3\xdc\x29\xbd\xbd\x7a\x92\xcd\x60\xac\xb4\xd3\x91\xce\
+    \x21\xca\x85\x94\x68\xa7\x46\xb6\xaa\xa7\x94\x74\xa3\x7d\xab\x49\xf1\xca\x5a\x28\xc7\x48\xbc\x71\x57\xe1\xb3\x34\x5b\xb0\xf9\x59\
+    \x05\xcc\xd6\x25\x5c\x1e\x6f\x0c\x5c\xf1\xf0\xdf\x93\x41\x94\xc6\x29\x11\xd1\x4d\x03\x21\x66\x2a\x8f\x1a\x48\x99\x9e\x34\x18\x5b\
+    \x0f\x0e\x34\xa6\x4b\x70\xa6\x26\xe4\x64\xd8\x46\x67\x4c\x4c\x88\x16\xc4\xfb\x26\x7f\xe4\x4f\xe6\xea\x28\x67\x8c\xb0\x94\x90\xa4\
+    \x05\x58\x53\x1a\x4e\x25\x47\x0c\x61\x57\x79\x4c\xa3\x6d\x0e\x96\x47\xdb\xfc\xfe\x35\x0d\x64\x83\x8f\x5b\x1a\x8a\x2d\xe0\xd4\xbf\
+    \x09\xd3\xdc\xa9\x17\x3e\xd2\xfa\xce\xea\x12\x51\x57\x68\x3d\x18\x92\x4c\xad\xad\x3f\x65\x5a\x60\xb7\x2f\x58\x64\x96\x1f\x14\x55\
+    \x03\x28\xcb\xd5\x4e\x8c\x09\x13\x49\x3f\x86\x6e\xd0\x3d\x21\x8b\xf2\x3f\x92\xd6\x8a\xae\xc4\x86\x17\xd4\xc7\x22\xe5\xbd\x43\x35\
+    \x2b\xf0\x72\x16\xe2\xaf\xf0\xa2\x23\xa4\x87\xb1\xa7\x09\x4e\x07\xe7\x9e\x7b\xcc\x97\x98\xc6\x48\xee\x33\x47\xdd\x53\x29\xd3\x4b\
+    \x1d\xaf\x34\x5a\x58\x00\x6b\x73\x64\x99\xc5\x83\xcb\x76\xc3\x16\xd6\xf7\x8e\xd6\xa6\xdf\xfc\x82\x11\x1e\x11\xa6\x3f\xe4\x12\xdf\
+    \x17\x65\x63\x47\x24\x56\xaa\xa7\x46\xb6\x94\xc6\x0e\x18\x23\x61\x1e\xf3\x90\x39\xb2\xed\xc7\xff\x39\x1e\x6f\x22\x93\xd2\xc4\x04\
+";
+
+// Wider parameter sets (t = 8, 12, 16), generated with the same reference sage script.
+// For these widths the script computes rounds_f = 8 and rounds_p = 57.
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BN254_T_8: &[u8] = b"\
+    \x05\xbf\xfb\x5e\x30\x1d\x8c\x46\x8c\x35\xe2\x4e\xb2\x16\x5b\x6b\x71\x72\x5f\xb7\xac\x9a\x48\xef\xe5\xce\x04\x1b\xdb\x05\x67\x6d\
+    \x2a\xa7\xa8\x18\x12\x68\x83\x43\xfc\x6d\x78\x07\x33\x12\x99\x6d\x75\xf4\xc5\x50\x5d\xb0\xed\x22\xaf\x5e\xc0\xdf\x78\x88\xcd\xc7\
+    \x2f\x58\x56\xfd\x71\xda\xb6\x0d\x78\xcc\x3a\xf1\x5a\x89\xc1\xe4\xd6\x1b\xa1\x89\x84\x9a\x4c\xea\x10\xac\xc1\xdd\x22\x8f\xaf\x00\
+    \x12\x29\x9a\x26\x09\x99\xac\x95\xd2\x71\xe1\x84\x96\x8c\xda\x40\xbd\x43\x58\x87\x7a\x6d\xcf\x43\xd7\x79\x25\x1f\xff\xa6\x13\x48\
+    \x14\x43\xaa\xd4\x69\x3d\x69\x2a\x62\xa8\xe2\x1f\x03\xd5\x64\x3a\x12\x3f\x0c\x87\x83\xa3\xd2\x7c\x27\x5f\x9d\x01\x08\x96\x85\xfb\
+    \x21\x56\x1b\x02\x04\xa4\x44\x88\x08\x2e\x31\x47\x2f\x58\x85\xa3\xad\xc1\x79\xbb\x27\x82\x33\xae\xdc\x4b\x31\x63\x69\xec\x99\x37\
+    \x0c\x7c\xc2\xaf\xa5\x3f\x98\x98\xf3\x0a\x69\xb2\x94\xa4\xe2\x4f\x6b\x21\x76\xe1\xae\x0c\xa4\x9b\x02\x17\x92\xd5\x5e\x34\xe9\x7d\
+    \x2d\xd2\x21\x09\x60\x53\xde\x38\x9f\xae\x88\xe7\xca\xa5\xc4\x3a\xb5\x5e\x22\xae\xb7\x58\xee\x13\x0d\x12\x46\xc1\xdf\xf4\x7b\x53\
+";
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const RC_BN254_T_8: &[u8] = b"\
+    \x0d\xad\x22\xd0\x8a\x6b\x8d\x81\xd4\xa5\xff\xc3\x4b\x96\x77\xa7\xc5\x25\x4c\x85\xe9\x53\x55\x1f\x9e\xba\x9a\x0a\x97\x59\x0c\x10\
+    \x03\xcf\xc4\x41\x11\x1f\x1b\xab\x6e\x75\x95\x7b\x9f\x02\x74\xe9\x2d\x17\xae\xfc\x2d\x8d\xa4\x60\x43\x5d\xfa\xf2\x59\xbd\x10\x39\
+    \x27\xc6\xd1\xb8\xa2\xe2\xce\x67\x03\x76\xcd\x29\x70\x19\x2a\x2a\x08\xe4\x29\x08\x83\xf6\xca\x49\xe7\x97\x6c\x6c\x44\x7d\x63\x92\
+    \x28\xf2\x88\x2b\x9a\xbf\xda\x8e\xfc\x2d\x12\x1d\xa0\xc8\x71\xd2\xd5\x31\x3a\x53\xc6\x7d\x04\x07\xee\xf4\xe9\x3e\xe2\x3e\x6c\x2d\
+    \x0a\x39\x43\x39\x0f\xe4\x93\x9f\xa2\xe9\x31\xe1\x80\x94\xc7\xc9\x3a\xa1\x07\xce\xd2\xbf\xae\xfc\xfe\x5e\x61\x3a\x3f\x12\x62\xd5\
+    \x2a\x8f\x37\xd8\xda\x8f\x23\x19\xe1\x88\xf8\x90\x90\x07\x40\x8b\x57\xfb\xa3\x55\x5b\xb7\x84\xc1\xad\x6c\x7b\x07\x55\x80\x54\x56\
+    \x0c\xef\x86\x58\xe3\x3f\x20\xc3\x96\x49\x52\x8f\x35\x3e\xf5\xd0\x31\x98\xc7\x56\x83\xf8\x49\x35\x34\xad\xd6\x93\xa4\xff\x4f\x0f\
+    \x1c\xdc\x2a\x6f\x0f\x0e\x7c\x6c\x62\xee\x1c\x0c\xad\x4f\xd1\x13\x63\xa4\x8d\xb7\x09\x51\x71\xfa\xaf\x9a\x7b\x6e\x01\xc3\xe5\x2b\
+    \x0a\x2e\x68\x1a\x2d\xb1\x9c\x8d\x1c\xfb\x0c\x28\xf0\xb7\xb3\xd9\x83\x0d\x35\xfc\xa8\xc0\xb0\x76\xb4\xe6\x43\x28\x06\x01\x39\xd6\
+    \x16\xf0\x13\xcd\x70\x3b\xe7\xde\x16\xb4\x36\x1c\xe5\xc6\x7c\xc2\x99\x7f\x85\x8e\x8a\x80\x88\x85\xda\x67\xfb\xaf\xd3\x0d\x27\x17\
+    \x29\xaf\x57\x13\x1c\x9c\xf5\x48\xbe\x66\x9f\xe7\x6c\x67\x5e\x6d\x90\xbe\xe1\x7a\x76\xcc\xcf\x4d\xdc\x5d\x35\xf9\xa9\x36\xeb\xb8\
+    \x21\x80\x7b\x1e\xc6\x25\x8b\xb1\x55\xed\x15\x9d\xa9\x0b\x81\x5d\xee\x11\x9e\xae\x81\x38\xb1\xbd\xf9\x50\x11\x1b\xf5\x20\x5b\x3b\
+    \x07\xc0\x0d\xb8\xc5\x0a\x8c\x86\x0f\xbc\xb8\xeb\xd2\x34\xa5\x43\x50\xbd\xbc\xb8\x74\x78\x91\xa4\xe5\xad\xab\x1c\x4a\xbc\x69\x09\
+    \x22\xbc\x08\xfc\x05\x4d\x08\xa3\xdb\xb6\x09\x43\x96\xd0\xde\x37\x3a\x4c\x33\x20\x5c\x49\xa1\x25\x4c\xf2\x5e\xbe\xc1\x74\x77\x65\
+    \x2d\xad\x58\xbd\xf3\xe2\x4c\x78\xf2\x11\x46\xde\x08\x4d\x9b\x8a\xfa\xd5\x89\x56\xfe\xdc\xe6\x7d\x43\xf7\xd3\x75\xa8\x13\xbb\x2e\
+    \x29\x2c\xee\x6b\xc1\x9a\x64\xdc\x5a\x8a\x74\x03\x6e\x67\x6e\x23\x8f\x60\xb4\x57\x7d\xb8\x62\x13\xce\xd4\x17\x90\x6f\x1d\x6e\x91\
+    \x27\xd6\x55\xae\x12\x59\x28\xad\x6b\x48\xbb\xa5\x45\x93\xb7\x4c\xa0\xc2\x30\x02\xb3\x2a\x0a\x9e\xf3\x75\x93\x9a\x22\x38\x7a\x8c\
+    \x0e\x0e\x2e\x78\x1f\xe9\xac\x0a\x97\xf0\xd9\x61\xc7\xb3\x59\xa4\x15\xd1\xea\xd4\x1c\x99\xec\xce\xe9\x0e\x56\xb6\x8f\xbf\xa2\xa8\
+    \x13\xfe\xc2\x3e\x67\x8d\x1c\x99\x43\xb6\xda\xed\x8e\xd8\x21\x22\x89\x11\x6a\xb9\x63\x44\xde\xd7\xce\xd8\xaa\xc2\xf0\x9f\x16\x72\
+    \x27\x55\x0d\xd2\x16\x47\xe3\x7f\xc3\x16\x40\x68\x4e\x02\xcb\x07\x81\x06\x69\xb1\xc1\x54\x54\x43\xfd\x58\x20\x4b\x2c\xde\x73\xc2\
+    \x1d\xe6\xd5\xf3\x56\xa9\x2c\x48\xc7\xb9\xf6\xaa\x45\xc8\xa6\x21\x6e\x0a\x6a\xdb\xc7\x99\xc0\xbe\xc2\x42\x05\x46\x74\xcb\x8f\x09\
+    \x14\xc5\x34\x7c\x45\x80\x36\x3f\x74\xc8\x85\xd1\xfb\xb3\x78\xc6\x1d\x95\x3e\x49\x08\x92\xb1\x0b\xaf\x84\xf6\xe1\xf3\xa3\xf3\x9d\
+    \x27\xf2\x76\x80\x23\xc4\xdf\x79\x52\xca\x0d\x96\x7f\x14\x03\x4e\x98\xc4\xca\x76\x97\x3a\xe1\xcd\xdf\xcc\xd2\x12\xcb\xd7\x86\x83\
+    \x1f\x4f\x49\x9f\xc4\x85\x3f\x7e\x18\x9a\xd6\xd2\x26\x9c\x46\x7f\x77\xe1\x5b\xbd\x28\x96\x3c\x51\x6b\x4f\xbf\x1b\xa7\xb8\x8f\x95\
+    \x0d\x62\xba\x9a\x8d\xe3\xb9\x7c\x72\xa5\x42\x5a\x97\xa7\xf0\x27\xfb\xad\x23\xf2\x99\xff\x46\xba\xfa\x12\xdc\xff\xb0\xe7\x2b\xf1\
+    \x22\xb6\x49\xae\x46\x8f\x20\xc7\xcb\xfb\x43\x5d\x93\x2b\xa9\xba\x4b\xe1\x4e\xa4\x0c\x0c\x54\xe6\xbe\xc9\x70\xab\x4c\x51\x3f\x04\
+    \x03\xa1\xaa\xe4\x39\xe4\x7e\x7e\x14\x18\x6d\x55\x13\xda\x5e\x26\x3e\x06\x3a\x3d\xa6\xd0\x42\xc1\x6e\x55\xca\xb2\xe8\x1a\x8e\x95\
+    \x25\xc7\xee\xe1\xfc\xe4\x22\x60\x0f\x5b\x1e\x78\x32\x3f\x2e\x99\xcc\xee\xe8\x03\xf6\x27\x63\x21\x7e\xad\xbd\x04\x6c\x1e\x0e\x6c\
+    \x1c\x66\x25\x8f\x10\x3a\x95\x52\x74\xed\x71\xde\x56\x16\x9b\xba\x34\xff\x6a\xea\x97\xa2\xc3\x2b\x98\x80\x5d\xc3\xb0\x11\xa3\x83\
+    \x29\x40\x3b\x00\x75\x7a\x64\x76\x71\xa3\x69\x10\x94\xae\xf4\x20\xad\x83\x0f\x95\x74\x3d\x45\x89\x4d\x6d\x7f\x40\xaa\x35\x6e\x4b\
+    \x13\x49\x63\x63\xef\x5f\x7d\x0e\x91\xfd\x13\x19\xfd\x5d\xc0\x26\x9c\x51\x90\xd0\x5d\x8a\x0a\xd2\x8a\x3c\xe0\x63\x8a\x13\x3e\x0e\
+    \x1c\x6a\x45\x86\xc1\x5c\x46\x38\xa1\x68\x69\x21\xd7\xc2\x04\xe2\xb6\xe1\xa8\x8c\x1f\xc4\x9d\xcf\xb8\x7d\xed\x2a\x1e\xd7\xed\xc5\
+    \x0d\xa2\x3d\xf1\x2d\x0b\xf4\x7b\xb9\x2f\x15\xee\x18\x93\x86\xc2\x0f\x55\x2d\x0b\xc6\x6a\xe4\x92\xb9\x2d\x69\x55\x6b\x2f\xe7\x5a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x5b\xe8\x8b\xc5\xdd\xf8\xe0\xc6\xc7\x41\x28\x6c\xdd\x5d\xdd\x55\x92\x79\xa4\x55\xb9\xbb\xb6\x1d\x5a\x0b\x00\x4e\x54\x5d\x5e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x53\xfb\xb5\xb3\x2d\xb8\x60\xe0\xbb\x12\xce\xf3\xaa\xb4\xe0\xdb\xbd\xb1\xea\x87\x04\xd3\x35\xa1\x8f\xa8\xb4\xdf\x11\x2c\x36\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xf6\xa3\xa6\x1a\x31\x39\x22\x1c\xb5\x87\x5c\xf4\x55\xb9\x42\x58\x0c\x00\x94\x24\x99\x93\x2d\xfc\xac\xf6\xf7\x5c\xb8\x0a\xd6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\xaf\x9e\x95\x45\x77\x81\xb3\xc6\x1d\xe8\xa1\xfa\xad\xda\x67\xf7\x6f\x52\xbb\x81\x25\x5f\xac\x88\x19\xbc\xc1\x04\x07\x1e\x05\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x76\x39\xcb\x68\xd3\xcf\xa6\x9f\x3d\x79\x5d\x1a\x1d\xd4\xeb\xa1\x76\xd0\xc2\x97\x01\xe7\xb6\x77\xea\x07\x50\xb4\xcc\xd1\xdb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\x9d\x85\xa4\x62\x81\xf8\x15\x02\xfb\xcf\xb4\x30\xf4\xd0\x8a\xb5\x04\xc5\x02\xd2\xfe\xae\x2a\xb4\xa2\x6d\x87\xbe\xbe\xa0\xdd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xa9\xb7\x07\x4e\xa1\x1d\x99\x31\xcf\x4a\xf1\x69\x52\xf3\x98\x77\x7a\xaa\x36\x41\x3d\xf1\x4c\xed\xc1\x01\xc7\x69\x38\x12\xd3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\xfa\xb3\x49\xdd\xb7\xd6\x2a\x5e\xdc\x0b\x6a\x62\xb3\x00\x71\x56\x34\xe5\xdc\xca\xb8\x08\xd8\xfa\x6a\xe6\x2b\x40\x67\x05\xa3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\xf8\xf3\xaf\xbd\x6f\xe6\xbd\xa7\xd0\x55\xa5\x36\xab\x3d\xe9\xc4\x0c\x81\x55\xb3\x1d\x39\x7f\x63\xef\x21\xac\x97\x4c\xf5\x2d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x07\xc5\x69\xf8\xf4\x98\x99\x80\x5d\x22\xa1\x93\x08\x83\x96\x8a\x3c\xf0\x58\x99\x3e\x0a\xdf\xd0\x6d\xa4\x37\xae\x50\xe8\x30\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\xdf\xb1\x8b\xf1\xb6\x08\x7c\x0e\x63\x8f\x21\x89\xa7\xb3\x45\x34\x54\x9f\xfe\x8f\xca\x5a\xfc\xcf\x7e\x52\x8a\x33\x38\xcc\x92\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x40\xca\xa0\x62\xd2\xfa\x72\x72\x2a\xd4\x8f\xb7\x1b\xbc\x9f\x53\x40\x43\xda\xb9\x0d\xfa\x24\xb9\x66\x64\xea\xa2\xf0\xac\x6a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\xc0\x6f\xe4\xe5\x39\x8d\x47\x04\x27\x1b\x84\xe6\x02\x3b\x07\xb1\x89\xdd\x1d\x20\x23\xdd\x59\x9b\x33\xde\xfe\x42\x51\xb7\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x75\xf9\x0b\xae\x5f\x8f\x81\x57\xeb\x73\xbb\xc7\xae\x69\xb7\x4d\x44\x76\x1c\xf0\xdf\x3b\x46\x66\xee\xb2\x86\x08\x78\xc0\xdf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\x25\xbe\xfb\x64\xb7\x58\xb8\x37\xa4\x30\x5e\x10\x4d\x5e\x51\x0d\x89\x36\x53\x55\x9d\x87\x68\xdc\x79\x32\x5f\xf8\x9d\x6b\x9f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\x1a\x73\x02\x45\xd9\x22\x7f\x12\x32\xcc\x02\xf4\x86\xc1\x8c\xfa\x1f\xba\x40\x66\x5e\xd3\xd1\xbb\xbd\xcd\x19\xb0\x3c\xac\x4f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x8b\x63\xb1\x2a\x66\x47\xf8\x0c\x68\x6a\xaa\xb1\x5b\x05\x63\xc1\xa3\x3f\x47\x5d\xc1\x8c\xaa\x43\x4a\xb6\x4f\x1e\x29\x0a\xcb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\x34\xce\x81\x53\xd4\xe2\xe1\x15\x8d\xe0\x5d\x27\x65\x30\xcf\x3c\x74\x9c\x16\xc7\xe0\x9f\x3b\x67\x1a\x97\x3b\x0e\xfb\x08\x39\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x09\xc5\xd3\x23\x66\x55\x13\xc8\xc4\xa9\x6e\xa4\xdc\x08\xe3\xab\xa4\x36\x99\xbc\xa1\x19\x8f\x2b\x55\x99\x15\x08\x24\x95\xce\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xb9\x29\xe7\xd7\x1f\xe4\x25\xf3\x6f\x32\x97\x5e\x9a\x68\xbb\xf8\x3c\x16\x62\x26\x78\xf0\x61\xed\x78\xa0\xc9\xce\x31\x8b\xe3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xa1\xa9\x3d\x02\xae\x44\x8a\xb7\x73\xf9\x5c\x6b\x88\xc0\xd0\x1f\x89\xc0\xc3\xd2\x5a\xd6\x25\x04\x27\x71\xdd\x5a\x7e\x74\xa9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\xc7\x38\x1c\xcd\xfd\x20\x9a\x8c\xba\x56\x27\xa0\x58\xc3\x3f\x47\x2b\x24\xd2\xb1\xa5\x5d\x9d\xd4\x7f\x60\xdc\x7b\x47\x4e\x7e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xbe\x1a\x29\xbb\x66\x8a\x25\xea\xed\xff\xcc\xef\x6c\x01\xc9\x06\x88\x22\x9e\x8f\xc4\xbe\x98\x12\xe3\x71\xd4\xd6\x1c\x30\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x82\x1b\xfb\xd5\xc4\x15\xfc\x07\x6f\xc8\x38\x95\xed\xab\x9e\x52\x56\xee\x82\x69\x46\xa8\x65\xb8\xc8\x2f\x01\x89\xaf\x46\x26\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x8f\xf5\xb2\x52\x18\x2f\x6a\x15\xcf\xf9\x01\x44\xc1\xe7\xac\x16\x78\x73\x98\x82\xc9\x0f\xe4\x6d\x0a\x29\xdb\x7a\x0c\xba\x77\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x0e\x94\xb1\x53\x06\xba\xf1\xb0\x17\x62\x38\x88\xb8\xd4\xc5\x40\x2e\xe8\x1f\xd2\x13\xc4\xa5\xe4\x2f\x52\x72\x9d\xeb\x7d\x5c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\x31\xde\xb0\xf1\x3c\xe1\x7a\x5b\x44\xed\xba\xcf\x5a\x04\x10\xe2\x33\x48\xc2\xc7\x65\x73\x83\xa8\xff\xde\xb5\xc5\xca\x1c\xd4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x34\xd1\x4f\x44\xe8\xeb\x98\xac\x07\x8e\xc9\x0e\xb7\x18\xc3\x03\x42\x49\xfb\xae\xb1\x3d\x24\xf3\xd5\xb6\xa4\x00\x20\x18\xac\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\x10\xf1\xb7\x6f\x8b\x1c\xc4\x29\x82\x00\x30\xc5\x8b\x3d\x23\xeb\xfb\x77\x5a\x43\xb0\x79\x28\xb9\x86\x88\x91\xc2\xcb\x4c\xca\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x35\xbf\x4f\x7f\xb7\x63\xf9\x81\xd2\xc0\xc7\xec\xd2\x23\x7a\x46\x1a\xc3\x85\xe2\x23\x5f\xb3\x52\x22\xb5\x12\xf1\xdb\xd1\xb7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xcc\xb3\xfb\xff\xef\xf7\x4f\xb7\xce\x3b\x99\x43\xf6\x09\xdc\x0d\x7f\xe3\x4e\x3b\x5e\x6b\x19\x96\x28\xe8\x6d\x55\x76\xcc\x18\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x44\xcd\x78\x22\x0a\x2e\x66\xbe\x6c\x9e\xb3\xbc\xff\xc4\x98\xa4\x7f\x22\xc3\xeb\x47\x65\x91\xcf\x45\x6d\x51\x8e\x3e\x12\x6c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x60\xe9\xe3\x44\x4a\x74\x8b\x8c\x22\xd7\xd1\x0e\x82\x5c\x53\x62\x90\xb7\x23\xfc\xdf\xa0\x46\x43\x63\x53\xd6\xf9\xab\xc0\x73\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\xa3\xcc\xc6\x7b\xd0\x14\xef\x25\x44\x92\x7f\x9b\x96\xff\x58\xe3\x48\x95\x4e\x40\xaf\x67\x86\x1a\xb8\x8d\x78\xff\x4b\x66\x88\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\xe5\xb1\x95\x89\x6e\x9e\xc2\x3b\x9d\x3e\xb8\x98\x73\x4c\x38\x52\x72\xed\x64\xbd\xcb\x23\x62\x23\xf5\xa3\x43\x69\x86\xdc\x6d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x64\x27\xdd\x07\xa0\xb4\x6a\x8e\xba\xca\xa8\x2e\x8c\x04\xde\x8e\x7e\x94\xa1\x88\x49\xc9\x27\x5b\x8b\x48\x27\xa8\x73\xcd\xab\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x09\xa4\x03\xf9\x46\xb6\x92\x70\x4f\xe4\xd5\xae\x69\x62\x54\x41\xc9\x3a\x7f\x93\xdc\xb4\xf8\xf7\xd3\x37\xba\xa7\x35\xad\x9a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\x07\x54\x05\x11\x59\x34\x88\xe0\x84\xb7\x29\x7b\x6f\xf6\x7b\x74\x8c\x71\x73\x1b\x6c\xa6\x4a\xa8\xd3\x82\x3e\x81\xf4\x1b\x37\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x1d\xaa\xb7\xa7\x02\xac\x80\xd1\x08\xb2\x48\x45\xbd\xa0\x4b\x73\x2f\x35\x64\xeb\xe5\x73\x92\xe4\x70\x38\x01\x99\x52\x2e\x1d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x97\xd0\xf7\x2b\xf0\xb4\x7a\x45\xce\xaf\x82\xdf\x26\x14\x39\xbc\x5e\x3b\x0a\xe5\x5c\xf1\x06\x97\x33\xd5\xa0\xb5\x17\x65\xca\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\xdb\xb9\xb4\x00\x36\x31\xc5\xe3\xd0\x2c\xe9\x59\x93\x0c\x75\xd4\xd2\x52\x81\xc8\x6f\xeb\xc6\x1d\xfd\xf3\x5e\x00\xcf\xbd\xad\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x47\xad\x87\x39\x3e\xf9\x28\xd2\x80\x0f\xa2\xa5\x48\x32\x8e\xf9\xbd\xdf\x56\x46\xda\x79\xd5\x07\xd2\xf2\x05\x5d\xb3\x35\xd6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x65\x8e\x02\xd3\x1e\x2c\x20\x0d\xad\xf6\xa9\x85\x65\x2e\xcc\xd3\x0c\xb9\x59\x16\x8c\xde\x7b\x74\xc7\x23\x95\x39\xd2\xdf\x1e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\xf0\x0d\xc2\x8b\xce\xd1\x2a\x74\xc3\xce\x68\x7d\xd3\xfa\x99\x6d\x6f\x8b\xb2\x9b\xc6\x0c\xda\x4f\x3a\xed\x19\x1a\x6f\xe8\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x4e\xbc\xc2\x30\x24\x4b\x68\xb9\xa8\xf5\x1a\x56\xff\x05\x41\xa9\x2e\xa2\xcb\xb3\xe7\x7b\x26\x3c\xb0\x3a\x8b\xd8\x94\x91\xf7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x65\xf7\x4b\x52\x3a\x8c\x87\x58\x85\x37\x8e\x93\xd1\x94\x6c\x7a\x3f\x81\x0e\x2e\xec\xde\xa9\x4b\x80\xc4\xf7\x41\xea\x6c\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x04\xa5\x59\xa4\xbd\x23\xed\x68\x9a\xcc\x9b\x6c\xb4\x06\x24\x79\xa6\xb2\x96\x92\xe2\xb8\xcc\xe4\x00\x58\xda\xf0\x50\x57\x78\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x49\x6d\x99\x26\xe4\xe5\x65\xef\x86\x0e\x52\x39\x6a\x29\x35\x68\x9c\x87\xca\x99\xab\xd0\x46\xbc\x27\x9c\xe1\xbf\xc4\x32\x06\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x9c\xcd\x22\xb1\x43\xf4\xd8\xd4\x83\x84\x6d\x19\x60\xe7\x14\x4a\x5c\x93\x54\xea\x48\xcd\x1a\x71\xbd\xc8\x2f\xbb\x92\x98\x38\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x9b\xd0\x2e\x1b\x06\x4a\x8d\xb3\x21\xa1\xcd\x21\x2a\x7a\x6d\xc4\x72\x74\xf6\xeb\x5f\xcf\xa4\xf1\x7d\x6f\x69\x0d\xdd\xf8\xc4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x21\xf0\x00\x41\x18\x38\x2f\x32\xfe\x44\x1f\xbf\x36\xc0\xa3\x8a\xe4\x18\x73\xae\x42\xd3\xb6\xea\x5f\x0c\x68\x16\xe0\x04\x72\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\x75\x6e\xfb\x49\x18\x52\x58\x70\x59\xf3\xe4\x8b\x60\x5a\x3b\xeb\xab\xaa\xb0\xa1\xee\x5f\xf1\x75\x8e\xb3\x06\x23\xb7\x60\x2c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x75\x74\xa3\x88\x23\x88\x6d\x1f\x48\x41\xb5\x40\xe2\x9d\x67\xef\xe3\x5a\xa8\xb3\xc6\x85\xf9\x69\xb6\xbc\x6c\xba\xb5\x06\x4a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x75\x1d\x62\x0e\xd2\xa3\xdb\x43\x82\x26\x5b\x8c\x33\xb6\x66\xb8\x5b\xea\xe7\xaa\x87\xf3\x8e\x28\xbd\x2f\x8d\x63\x7f\xf9\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\x3d\x72\x08\x89\x2e\x8a\x92\xe8\x87\xfc\xcc\xcf\xe0\x4d\x8d\x0f\xe9\x94\xbc\x44\x36\x41\xa9\xfc\x76\xc4\x3d\xa0\x2f\x45\x3d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\xc3\xf6\x71\x90\x71\xe6\x81\x06\x99\xbe\x20\xe1\xb1\x85\x8b\x9e\xd0\x0d\x81\x66\xbf\x15\x43\x4c\xd5\xe4\x35\x07\xb9\xd6\x2b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xb8\x61\x12\x69\x3a\x4e\x7f\x3a\xc4\xea\xdf\xcc\x3f\x04\xd2\x4a\x7a\xfe\x51\xa8\x7f\x82\xd4\xa4\x35\xad\x27\xf3\x0a\xa2\xf9\
+    \x1a\x77\x34\x84\x00\xa8\x7f\x01\x80\xe2\x94\x28\x6a\x7a\x06\xc8\x57\xf5\x90\xe8\xed\x17\xee\xd7\xa6\xb4\xcc\x1c\xa5\xa9\x79\x29\
+    \x20\x7f\xeb\x0f\xff\xf2\xe4\x19\xbc\x96\x7d\xd5\x44\xab\xbd\xd2\xdc\xc6\x01\x31\x6f\x49\x95\x7f\x23\x19\xc9\xa0\x4f\x56\x15\x7d\
+    \x0d\xf4\xaa\xc4\xe6\x48\x32\x65\xaa\x3f\xc4\x62\x83\x5a\x90\x38\x0b\xc8\x40\x04\xfc\x46\xd1\xa8\x5e\x61\xba\xed\x31\x96\xba\x48\
+    \x2f\x2d\xf3\xdb\x6e\xd0\xe2\x7d\x31\x51\x20\x1d\xfe\x7f\x57\xfc\x2e\x5c\xcc\x69\xc2\x45\x85\x35\xdb\xdb\xb5\xd8\x8a\xea\xbb\x4f\
+    \x2f\x51\x81\x1c\x35\x68\xdb\xae\x39\x6f\x86\x83\x8c\x5c\x8b\x1b\xbf\x9b\x5b\xcb\x2c\xe9\x85\x42\xe2\x89\x18\x4b\x95\xf7\x4b\x7e\
+    \x11\x98\xec\x46\x6d\xc8\xc3\xf5\x5b\x04\x83\x69\xea\xc0\x49\x0e\x83\x59\x28\x89\x13\x25\xd4\xcf\x44\x61\xda\x1d\x22\x23\x02\x19\
+    \x19\x03\x68\x27\xeb\x07\x14\xa1\xee\x4f\xdd\x65\x3d\x86\x67\xdf\x19\x24\x02\xe2\x90\xdf\xdf\xc3\x9b\xf0\x05\xfd\xfa\x86\xc0\x43\
+    \x09\x78\xd7\x74\x01\x8c\x29\xf2\x81\x8d\xf3\x65\x31\x23\x71\xce\x40\x82\xa6\xc8\xc5\x2d\x2a\x96\x55\x16\xb7\x64\x36\x34\x90\x69\
+    \x23\x1e\xd2\xd9\x47\x67\xeb\x9a\xce\x11\x1a\xf9\x31\x7b\x23\x3f\xee\x03\x72\x14\xe3\xf6\xd7\xe3\x66\xa5\x81\x2c\x8f\xd1\xdc\xe5\
+    \x0f\xbd\x85\x02\xce\xba\x8a\xb9\x3e\xb0\x4d\x1d\xb5\x35\xd2\x5f\x4c\x3b\xe8\xac\xac\x4e\x8e\x04\x67\xb9\xd6\x06\x65\x34\x56\xc2\
+    \x20\xe9\xfa\xc3\x5d\xda\x46\x4e\x43\x6a\x4d\x2e\xae\x4c\xdd\x5b\x52\xc9\xb7\x12\xd5\xb4\xbf\xa4\x33\x34\x46\x3f\x84\x78\xd4\xbb\
+    \x15\xba\x9f\xc4\xb1\x75\x27\x8a\x12\xa5\xab\x39\xee\x01\x21\xb0\x6d\xd0\x9b\x92\x3b\xde\xdb\x69\xa2\x61\x82\x82\x14\x45\xba\x00\
+    \x0c\x3c\x9a\xec\x90\x48\xb1\x0a\x06\xd0\x62\x67\x5b\x63\x41\x7a\x1a\xe8\x70\x4f\x29\x18\x38\x24\x66\xbf\x57\x5e\x22\xa6\x54\xd3\
+    \x27\x95\xa4\x6e\xb4\xc7\x95\x95\xa9\xde\x21\x91\xc5\x24\xf0\xaa\xf9\x2f\x76\x41\x53\xb2\x3c\x35\x7e\xd5\x0b\xf3\xa9\x1c\xdd\xf0\
+    \x10\xab\x78\x93\xc4\x25\x3d\x86\x12\x76\xea\x30\xf2\x29\xeb\x86\xb9\x0c\x69\x98\x5f\xb6\xdd\xe4\xaa\x50\xe2\x48\x28\xca\xa8\x1a\
+    \x2d\x45\x09\xd8\x15\x9a\xf6\x2c\x44\x50\x3f\x64\xfc\x01\xe9\x53\xe9\x0c\x6d\xce\xd8\xba\x75\xc5\x8d\xf2\xaa\x09\x0a\x5f\xd3\x59\
+    \x15\x79\x46\x95\x7f\x4f\xeb\x94\xea\xe0\x9b\x9a\x6c\x27\x22\x04\xd1\x07\xea\x7b\x0e\x31\xb6\x75\x24\xa6\xc9\xee\x7f\x04\xe7\xb9\
+    \x30\x46\x4e\xfe\x02\xb6\x5b\x86\x2f\x9a\x0b\x59\xfe\xa6\xd6\x93\x4b\x39\x3e\x65\xcd\xc3\xd2\x9a\xe6\x7a\x75\x1a\x0d\x0f\x13\x6a\
+    \x09\x65\xb3\xa2\xd0\x56\x66\xd3\x8f\xfc\xda\xc4\xe5\xed\x02\x0c\x74\x2b\xa4\x1d\x76\x0e\x70\xf7\x4d\xcd\x8f\x8b\x13\x24\xc9\x01\
+    \x24\x53\x1c\x78\x46\x63\xd5\x76\x39\x92\x8f\x0a\x8f\x15\x20\x38\xa7\x79\xee\xbb\xd7\x0f\x65\x85\xda\xcc\x97\x16\x68\xeb\xec\x55\
+    \x22\x02\x07\x0d\xf8\xf8\x5b\x79\xbf\x7d\xeb\xf2\x4a\x9c\xc0\xf2\xcf\x30\x37\x0f\x26\xa9\x37\x2e\x01\x71\x8d\xae\x7c\xb6\x17\x1d\
+    \x08\x28\x2f\xc0\xba\xa2\xe9\xeb\x76\x16\x7b\x9a\xb2\x40\x5d\xb9\xc7\x8f\x1f\x37\xb1\x68\xbe\x64\x35\xd5\x38\x5a\x80\x6e\x74\x5a\
+    \x17\x8c\x3d\x6e\x47\xcd\x33\xe5\x57\x03\x11\xec\x8a\xd8\xdb\xca\x0d\xbf\x54\xaa\xdd\x13\x93\x73\x8d\x89\x83\x84\xc5\x77\x95\xe7\
+    \x12\xad\x90\x5f\x9d\x82\xf3\x36\x43\xb1\xfe\xfb\x90\xdc\xef\xff\xeb\x81\x8d\x1e\xc3\xf4\xfc\x3d\x68\x57\x20\x3e\xff\x5e\xd1\xea\
+    \x22\x96\x82\xc9\xe4\x16\x5b\x6e\xd1\xa2\x87\x0c\x14\xa1\xc8\xbb\x7b\x1c\x7d\x3b\x52\xf4\x2c\xe0\x22\x8b\x85\x48\x06\x66\xb4\xc4\
+    \x25\x19\x0d\x85\x37\x44\xdc\x11\xd1\x55\xde\x5e\x47\x96\x40\x30\x2a\x33\x3d\x47\xcf\x18\xdb\xf1\xff\x24\x1c\xe4\x2c\x88\xd4\xc9\
+    \x00\xdb\x47\xfa\xdc\x76\xbf\x4f\xd8\xdc\xfe\x26\x90\x8f\xe4\x8a\xb4\x2d\x39\x9d\xa9\x39\xac\x1f\x16\x7c\xa8\x60\x0c\xfb\xe7\xbc\
+    \x2b\xa5\xe8\x8a\xdc\xbe\x02\x57\x60\xf2\xc8\x65\x49\x35\xf0\x55\x7d\x14\xc6\xd4\x12\x5a\x83\x8f\x31\x2f\x2b\xa4\x39\xe2\xe9\x4c\
+    \x13\x76\xbb\xae\x85\x69\x9d\xcc\x29\x4f\x85\x0c\x85\x8b\x4b\x37\x0e\x51\xff\x31\x39\xbc\x3e\x0a\x1e\x52\xeb\x58\xd0\x8c\xa4\x1b\
+    \x13\x33\xfd\xa1\xe3\xd5\x8e\x07\x47\xf5\xc7\x36\x1d\x50\x75\x52\x69\x33\xea\x28\x3e\xee\x2a\xc3\x7b\x60\x42\xc6\x08\x3b\x6a\x42\
+    \x28\xbf\xc4\xdc\x95\x94\xe9\xad\xd6\x87\x74\x4b\x79\xf4\xfe\xb9\x79\xe2\x14\x3d\x74\x2d\x4e\x4d\x1d\x5b\xb6\x4b\xb7\x99\xa0\x2a\
+";
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BN254_T_12: &[u8] = b"\
+    \x20\xbb\x1e\x98\xf4\x0b\xfe\x80\xb8\xe2\xf2\xc8\x85\xea\x13\xa2\xea\x1f\x14\x6f\xf6\x12\x18\xc3\x10\x75\xad\x79\xdd\x8f\x4f\xfa\
+    \x1e\xd6\xab\xd0\x5c\x8d\x67\x8f\xa1\x4c\x0c\x77\xd9\x0f\x02\xfa\x1f\x8a\xf2\x49\x91\x5f\xa6\x51\x8f\x5f\x8d\x5e\x5c\x64\x9f\xb2\
+    \x29\x10\x7b\x18\x65\x8b\x47\xd5\x66\xf5\x06\x39\x75\xd6\xbb\xc5\x04\x38\x2b\x81\x77\x7e\x07\x96\xc7\xcb\x81\xf9\xb4\xe2\xcf\x46\
+    \x13\x4c\xee\xc3\xec\x06\x9d\xd7\x6f\xc9\x80\x4f\xf0\x29\xc2\xc2\x7c\x56\x46\x98\x6f\xf5\xdb\xeb\x17\x09\x1d\x9c\x47\x9a\xe9\x23\
+    \x0e\xe2\xe4\xf4\xa3\xc2\x3a\x1b\x71\x83\x4d\x1a\x95\xea\x40\x25\x04\xb8\xd6\x8f\xab\x6f\x74\x85\x5c\x88\x4d\xf8\x98\xc2\x86\xf9\
+    \x0a\x46\x9d\x3f\x3c\xb2\x50\x18\x1c\xc1\xe7\x0c\x82\x27\xda\xce\x34\x9b\x1c\x52\xfe\x8f\x73\x75\x74\x4c\x8b\xe5\xb8\x07\x71\xe5\
+    \x0c\xfa\x92\xab\x38\xd1\x16\xf1\xcd\xc2\x4e\xcc\x08\x3a\xd1\xcb\x17\xa2\x15\xde\x99\x68\x72\x6a\x81\x97\x0d\xde\x9c\xca\x70\xd6\
+    \x17\x3d\xf1\xa0\xdf\x85\xf4\x53\x36\x05\xf9\x57\x8b\x1f\x58\xcc\xad\xf1\xf8\x10\xe1\xfc\xca\x66\x80\x83\x82\xef\xa8\x4d\x68\x4a\
+    \x2c\xed\x3b\xf3\xcf\x64\x1c\x12\xa3\x11\xb1\x6b\x41\x07\x66\x33\x88\x87\x6a\x89\x4e\x70\xc2\x8c\x54\x98\x94\x6c\x7f\xd8\xdc\xd4\
+    \x09\x12\x07\x3a\x16\x42\x8c\x84\xbf\xbb\x61\x70\x10\x8a\xdf\x6d\x16\x81\x00\xac\x55\x87\xc2\x3c\x94\x05\xcf\x1f\x7c\xa8\xf1\x3b\
+    \x25\x0b\x31\x0c\xd1\x30\x63\xee\x49\xc7\x86\x80\xc1\x43\x48\x53\x96\x8f\x46\x4d\x2f\xc9\x91\x66\xc2\x3a\x2e\x33\x0d\xd7\x1d\x54\
+    \x08\xb5\x93\xb3\x98\x52\xf7\xad\x09\x5a\x03\xa3\xee\xe5\x46\xc3\x88\xe0\x28\x96\xd4\x60\x45\x4c\x6f\x18\x87\xc8\xd1\xc8\x2e\x37\
+";
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const RC_BN254_T_12: &[u8] = b"\
+    \x0f\x43\x62\x1f\x15\xc4\xaf\x9c\x4e\x52\x18\x0b\x3a\xb6\xfb\xc9\xcf\x57\x86\x3d\xc3\x4e\x61\x90\x23\x28\x41\xeb\x8b\x08\x56\x34\
+    \x16\x68\xba\xe5\x49\x07\xe5\x38\x78\x89\xe1\xd5\x5a\x4d\x1d\x3b\x13\x5c\x9a\x65\x2f\xa7\xa4\xd7\x0b\xb5\x14\x6f\x8b\x14\xed\x41\
+    \x0a\x87\xad\xff\xf4\x68\x09\xb3\xec\x14\x5f\xb4\x3a\xa8\x6f\x89\x3a\x10\x96\x73\xcd\x24\xd6\xcf\x2a\x49\xa5\x69\x00\x48\x76\x42\
+    \x1e\x9f\xde\x94\x0a\xab\x66\x67\x0c\x2b\x11\x81\xf6\xdf\x82\xc3\x82\xed\x1b\x5d\x74\x62\xfb\xc5\x1c\x4b\x5e\x40\x96\x28\xc8\x0d\
+    \x29\x36\x1f\xe9\xbc\x57\x57\x7f\xf4\xd8\x45\x74\xc9\x5e\x0e\xc1\x7d\x13\x57\x37\xa2\x7e\x1e\xac\x39\x10\x5d\x6e\x74\xe4\x01\xb1\
+    \x02\x59\xde\x79\xb5\x07\x00\x33\x7c\x1c\x9c\xb3\x1e\x11\x31\x66\x3e\xe8\x85\x5b\xd1\xb8\xa6\xef\x42\x10\x6d\x25\x57\x9f\x48\x8b\
+    \x0f\x6f\x59\x23\xc9\x46\x5c\xe3\x72\x59\x57\x78\x8d\x20\x81\x70\x00\x1f\x1a\xab\x4a\x0a\x30\x54\x85\xb8\x91\xa5\xdb\x32\x89\x79\
+    \x02\xaa\xa6\xd9\x34\xfd\x50\x1a\xe8\xec\x3c\x61\x24\x3f\x27\x89\x8b\x32\xec\x59\xa5\x91\xb5\x5a\x3b\x2c\x4f\xc9\xa8\x0b\xbb\xf1\
+    \x1f\x27\x56\x65\xd0\x96\x76\xb3\x7c\x23\x50\x95\xa0\x9a\x20\x57\x2c\xc9\xf7\xc5\x60\xef\x1b\xd7\x09\x60\x53\x1d\xfc\xe0\xf9\xc9\
+    \x0a\x37\x6d\x54\xe4\x74\x6f\x30\x35\x2f\xe6\x0f\xe7\x75\x49\xf6\x6c\xa6\xfe\x9b\x48\x95\x95\xc4\xb7\xf0\x3d\x6f\xde\x4d\xc6\x09\
+    \x0f\x9f\xb9\x9b\xa9\xed\x07\x6b\x06\xf6\xa8\x54\xaf\x79\x95\x63\x15\xb2\x0c\x7c\x84\x7a\xfb\xcb\x82\xf3\xcc\x4a\x63\x19\xac\xd1\
+    \x26\x47\xfd\xf7\x64\x20\x2d\xc2\x39\x74\xcb\x36\xf6\x78\xf8\x8a\xca\xbf\x40\xa3\xaf\x1e\xfe\xa8\x2a\x25\xd4\xad\xba\x28\x5a\x90\
+    \x16\x3d\x40\x24\xaa\x78\xa2\x9e\x1b\x29\xd8\x17\xe5\xa5\x4d\x62\xc3\x5a\x0c\xeb\xbd\xf2\x80\xd4\x6d\xa3\x80\x1f\x7c\xa3\xaa\x60\
+    \x18\xd1\x29\xe8\xe0\xa4\xe4\x79\x16\x4c\xdc\x89\x9a\x2d\x3e\xbf\x43\xd9\x74\xe4\xb0\x9d\x8d\xba\x6c\x32\x09\x46\x22\x3c\xf1\x82\
+    \x23\xec\x49\x0a\xc0\xfb\x5b\x14\xfb\x10\xe0\xfc\x78\xf0\xb9\xb6\xfe\x90\x7e\xb7\xd5\xb9\x8e\x67\x17\xa1\xdf\xee\xd2\x89\x7b\x47\
+    \x03\x8b\xb6\xde\xc1\xce\xd2\xb3\xf1\x61\x6c\x3a\x36\x90\xa3\x39\x05\xd9\xe9\xc8\x00\x16\x98\x48\xea\x41\x52\x5d\xf1\x03\x8a\x35\
+    \x0e\xfa\x40\xec\x34\x78\xa8\xda\x5b\x53\x80\x17\x46\x9d\x4e\x4c\xad\xba\xe6\x5f\x39\x12\xfa\xd7\xfd\x6b\xfe\x4f\x8a\xec\xe0\x5c\
+    \x07\xee\x20\xd2\x24\xc6\xf2\x99\x6a\xb8\x69\x5e\x8a\xb5\x88\x72\xb3\x1d\x1a\x53\xa5\xa8\xfa\xf0\xee\x71\x0c\xc8\x46\x52\xa0\x20\
+    \x12\x96\xf3\xde\x85\xf6\xa1\xed\xca\xc3\xeb\x49\x94\x23\x27\x02\x51\x04\x45\x3c\x66\xbc\x5e\x53\x50\x33\x37\xc4\xfa\x17\xfe\x4a\
+    \x0b\x9f\x0f\xa8\xbd\x9d\x54\x70\x76\xa3\xa7\xcd\x1d\xf3\x10\x1a\xc5\xdb\x06\xe6\xae\xc5\xcb\x7a\xb6\xd3\x9b\x47\x14\xcd\x49\x30\
+    \x03\x53\x77\x33\xee\x07\x3b\x87\xfd\x68\x37\x76\xed\xcd\x01\x4f\x14\xd7\x50\x7e\x6a\xd9\x05\xcf\xe9\x91\x2f\x73\xdc\xa5\x3e\x05\
+    \x23\x3f\x02\x72\xe0\xc4\xf9\x19\xfb\x97\x81\xfe\x66\x77\x29\x17\xce\x70\xd5\xd4\xf0\x7a\x0b\x45\x04\x2b\xc3\x08\xe1\x22\xa8\xa2\
+    \x30\x59\xb9\xba\x4b\xa5\xd9\x56\x1c\x3d\xb9\x28\xa6\xbc\x0d\x03\x18\xa5\x9b\x5f\x2d\xca\xfa\xcd\x6c\xa0\x8b\x59\xbb\x2e\xbd\xdf\
+    \x2d\x5a\x94\x19\xc3\x40\xcc\x7c\x51\x84\x6a\xbb\xe6\xcc\x73\xda\x80\x24\x6b\xa6\x71\xaf\x65\xd4\x2a\xcd\x80\xeb\x26\x28\x5f\x01\
+    \x28\xcb\xed\xcf\x15\x43\x42\xee\x2c\x30\x6d\xfb\x30\x90\xbd\x86\x16\xb1\x2d\xe6\x1a\xac\x4b\xa3\xa9\x0f\x5c\x84\x97\x10\x07\xf8\
+    \x21\x3b\xdf\x6a\x17\xaf\xa7\x69\xb5\xe9\x80\xc3\x3c\x27\x89\x19\x8b\xb4\xe2\xc6\xd2\xd4\x60\xc8\x08\x47\xe0\x83\x29\x9c\x13\x78\
+    \x18\x56\xc7\xcf\x97\x1f\x9c\xf1\xb9\x26\x8c\x77\xb4\xce\x1c\xc2\x5e\x46\xd5\x98\xe4\x06\x31\x50\xf2\x13\x99\xb1\xdf\x2c\x91\x60\
+    \x16\xa4\x78\x4c\x34\x3b\x05\x32\x7e\x07\x52\x42\xbc\xba\x3b\x3f\x0a\x1c\x57\x4b\x76\x1b\xec\xd3\xa5\x48\xbe\x7b\xf5\x6c\x28\xa3\
+    \x22\x70\x15\xba\xeb\x62\xf7\x1b\x7e\xb5\xfa\xf4\xd5\x3e\x02\xaa\x25\x31\x9f\x39\x30\xd6\x80\x5b\xe6\x21\x86\xc2\xc8\x87\xc5\xcd\
+    \x2e\xba\xbc\x5d\x65\x74\x7d\x52\x48\xa0\xbd\x41\x7e\xb1\xf0\x8e\x24\x0b\xf1\x6c\xa3\x95\x17\xbb\x78\xf0\xbd\xe1\x13\x75\x6c\x5e\
+    \x04\x29\x4c\x63\x31\x45\x29\x82\x25\xee\xa3\x5a\xe6\x9c\x38\xeb\x16\xf7\xe8\x01\x93\x29\x77\x40\x6d\xc8\x77\x44\xfe\x0d\xba\x17\
+    \x20\xa7\xfd\x4c\xb2\xf4\x74\x54\x9d\x58\xa9\xbd\x35\xfe\x43\x7d\x0e\xe4\x77\x9d\x49\xd6\x37\xc7\xe6\x2b\xae\x43\xe4\x16\xa7\x1e\
+    \x24\x07\xc2\x64\xf3\x9d\x4f\xea\x00\x54\xee\x11\xd4\x9f\xfa\x37\xbc\x48\x5e\x6b\x2a\x56\xab\xaf\x47\x4c\x67\xf5\x19\x27\x8c\xa4\
+    \x26\xd6\xa5\x90\xd6\x7f\xe5\x97\x88\x68\x68\xc5\xf7\xb1\x12\xf5\x62\xd0\x05\xaa\x01\x08\xd2\x90\x85\x6c\x4a\x73\xae\x8d\xc7\x6f\
+    \x05\x88\xe0\x14\x4f\xf3\xf7\xac\x60\xcd\x6d\xac\x45\x47\x0e\xc5\x14\xe8\xe0\xb2\x93\x64\x61\x8b\x19\x90\xbe\x43\x84\x4f\x9c\x90\
+    \x16\x55\xa2\x88\x17\xd0\x34\xb9\x81\xd6\x8b\x72\xec\x79\x17\xa6\xbf\x82\xf2\xa9\x97\xc9\xd2\x34\xea\x98\x8a\x7a\xdc\x88\x5c\xbf\
+    \x19\x4d\xc6\x3b\x0b\x75\x9b\xd5\xd2\xf9\xff\xf9\xc7\xd9\x3a\xa6\x59\xbd\xdb\x58\x1e\xf7\xfc\x0a\x37\x7f\x37\x21\x28\x4c\x43\x7a\
+    \x27\xf6\x6e\x66\xbf\x16\x5f\x89\xba\xda\x48\xe8\xa3\x4c\xf4\x87\xbb\x7d\x95\x74\xe6\xe3\x0a\xdb\x35\xa4\xee\x99\xa9\x76\xb2\x59\
+    \x08\xc5\x5d\x2b\x68\xcd\x12\x3f\xd8\x56\x6e\xbe\x8a\xbe\x31\xbd\x60\x29\xbb\x9d\xa9\xdc\x2a\x35\x74\xe9\x27\xaa\x17\xb5\x84\xdc\
+    \x0b\xdd\xc6\x0c\x6a\x8b\xc1\xee\x5d\xde\xf1\x05\x69\x37\xf8\xc7\xd0\x06\x2d\xed\x8e\xe5\xc8\x30\xd3\x23\x5c\x11\x1c\x4d\xd6\x31\
+    \x19\xbd\x51\x5f\x62\x46\x46\x11\x7b\xc4\xea\x69\x76\xc4\xb4\x72\x94\xa4\xe2\x04\x85\x10\x1c\x82\x48\x53\x1c\x1c\xd6\x80\x1f\x4f\
+    \x21\x6a\x8d\xf9\x97\x67\xd8\x4f\xe6\x75\x3a\xef\xa8\x85\xdb\xac\x16\x2f\x86\x3f\x52\x8c\xeb\x36\x11\x5f\x31\xaa\x33\x77\x01\x1c\
+    \x0d\x4f\x51\xa5\xe0\xcb\x09\x15\x76\xea\xa2\x17\x28\xd4\x7e\xa6\x63\x7f\x81\xf4\x86\xd7\xb8\xbf\x8a\x95\x98\xc2\xd3\x1e\xbd\x99\
+    \x1b\xa2\xdf\x75\xae\xab\xd0\xda\x93\xf9\x82\x57\xae\x2a\xee\x8d\x6e\x6e\xd8\x8e\x6f\xcf\x8f\xc8\xe3\x8e\x70\x1c\x5b\x0c\xf4\xa0\
+    \x2e\x3b\x3f\xf1\x73\x2f\xa2\xbb\xf4\xfe\x23\x94\xd3\xc4\x6a\xc1\xf6\xcb\xd0\x89\x59\x8d\xd7\x4e\x8e\x5f\x01\x02\xf3\x5f\x00\x32\
+    \x2b\x8f\xf6\x3f\x53\x1c\x9f\x40\x79\x66\xfc\xa9\x84\xe8\x47\x0c\x0b\x89\xe7\x27\xdc\x02\x6e\x14\xec\xd6\x28\x01\xc4\x0f\x57\x42\
+    \x26\xae\xac\xad\xf7\x85\xa1\x4f\xf4\x74\x35\xfa\x91\xe4\x08\x2d\x28\xf6\x1e\x6c\xa6\x0d\x16\xaa\x59\x03\xe3\xef\x62\x9b\x5e\x92\
+    \x0a\x70\x17\xda\xb6\xdb\xca\x20\x96\x1c\x8a\x03\xf9\x9f\xa2\xad\x05\x1e\x2e\xd9\xe1\x9a\xd1\x4e\x8e\x9b\x29\x22\xdd\x43\x79\x7e\
+    \x2f\xcd\x24\xc9\x63\x82\x76\x17\xcd\xce\x5a\x14\x01\x4e\xa5\xaf\x93\xe4\x61\x90\x24\x26\x97\xea\x18\x2f\xf7\x2b\x04\x79\xd0\x9e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x29\x84\x13\xdf\x06\xc6\x0b\x6f\x56\xe2\x2c\x4b\x23\x7c\x9a\x68\x2d\x01\xe0\x32\x30\xc8\x4d\x01\x49\xa6\xe7\x04\xc2\x2a\xd5\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xd8\x70\x25\xbd\x3f\x33\x52\xa2\xa0\xc2\xf4\x70\x3c\x9d\x10\xd8\x45\xd0\x0b\xe5\x55\x97\x46\x5b\x12\xd0\xfd\x0c\x80\x13\x5d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x77\x7b\x7d\xc1\x70\xf1\x99\x0a\x45\x37\x75\x6b\x08\x4a\x4f\xe6\xec\x94\x6b\x36\x2f\x73\x99\x5a\x86\xfd\x75\x3d\xc5\xd1\x7f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x05\xec\x70\xa7\x71\xdb\x4d\xbd\x3e\x92\x8f\x64\x5a\x52\x02\x91\x2c\x0e\xac\xab\xf5\xf3\xe2\x44\x9e\xa4\x67\x2f\xe6\xf5\x38\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x7d\x38\x3a\x0a\x40\xcd\x84\xd5\xbc\xce\x99\xb5\x23\x8d\xae\x37\xcf\xa0\x73\x11\xb4\x7f\x44\x14\x02\xc2\x4a\x70\xa2\xda\x70\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\xad\xce\x7a\x70\x95\x24\x6e\xc0\xac\xdd\x4f\x44\xce\xe5\x6e\x1d\xd3\x49\xc9\x93\x53\xb3\x5e\x7f\x05\x8a\x49\x0a\x57\x76\xc8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\xa7\x8a\x8c\x74\xbe\x29\x17\xb3\xf4\xd9\x25\x39\x1c\x74\x38\x65\x70\xbb\x20\x50\x4c\xa0\x76\x55\xd9\x61\x30\x68\xa6\xae\x38\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x29\x98\xc6\x10\x7b\x89\xa0\x79\xdb\x6a\x42\xa6\xcb\x11\x40\xf2\x29\x2b\x1d\x29\xcc\x81\x35\x71\xaa\x8b\x5b\x02\x81\xad\x99\xa6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x64\x17\xc7\x14\xdc\x35\x36\x2d\x50\x0b\xc5\xc3\xe6\x9d\x5d\x95\xf7\x69\x38\x01\xbd\x97\x6a\xf9\x4a\x53\x6e\x33\xae\xab\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\xb5\xd2\xfc\xf0\xbf\x60\x94\x26\x93\x65\x48\xd3\x06\x03\x51\xed\xde\xa4\x65\x3c\xa3\x1b\x1d\x0b\xed\x34\x79\xc2\xd8\xd2\xbb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\xa7\x72\x55\x2b\xde\x1a\xec\xa8\x9e\x85\x62\x94\x1b\xad\x0c\x00\x46\xda\x3e\x1a\xd4\x6b\x17\x83\xae\x34\xe0\x5c\x26\x27\xb7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x2e\x2f\x3b\xc4\xdc\xec\x42\x75\xd2\x6f\x99\xed\xb2\xf2\xbd\xb9\x67\x21\xc4\xbe\x27\x36\xbc\xa2\xe1\x9e\x05\xc8\xc6\xcb\xe8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\x69\x3e\x6e\x0a\x0e\xd4\xae\x48\x6d\x22\xa8\x4d\x5f\x36\xf7\xc7\xac\x6c\x4a\xb1\x61\x75\x0c\xce\x40\x8d\xc6\xd8\x5b\xa2\x90\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\xd7\x27\x8a\x59\x73\x51\xe1\x58\xc4\x2f\xc0\x5e\xf6\x16\xeb\x37\xc4\x74\x9b\x71\x35\x85\xd5\xe0\xe3\x7a\xda\x09\x19\x13\xc1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x8a\x7b\xaa\xd9\xfe\x0a\xdb\xc5\xe1\x5a\x05\xaf\xd9\xf9\xab\x81\xad\xcd\xe6\x63\x2d\xbd\x1c\x6e\x62\x5a\xd1\xa4\x4e\x7c\x59\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x44\x93\x34\xc2\xbd\x49\x16\xaf\x31\x26\xcb\x62\xf1\xf6\x5f\x8e\x59\x44\x27\x23\x20\x99\x56\x19\x0f\x1b\x1e\x12\x29\x5f\x06\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x2d\x5c\x05\xf0\x10\xdd\xd7\xc7\x86\x0f\xf0\x84\x2e\x0d\xd5\xf5\x43\xc6\x6c\x54\xa9\x9c\xc2\x35\x7a\xe8\xfb\xfc\x4f\x97\x97\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x58\xd8\x58\x93\xbc\x85\x6e\xf7\x46\x22\x4e\xc8\xf7\x75\x01\x9b\xbc\x7a\x2b\x12\x87\x34\xbb\xb2\xad\x78\x29\x98\x92\x9c\xd4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\xe6\x15\x56\x1f\x2f\xcc\xfe\xfc\x5e\x7b\xd8\xa9\x1d\x0e\xec\x06\x4f\x13\xef\xa3\x9f\x47\x52\xc3\x84\x14\xc9\x14\xaf\x7b\x58\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\xb0\x07\x9d\xcf\xf2\x5e\x7a\x4f\x14\xcb\xe0\xed\x72\x11\x58\x0b\xa9\xfa\xde\x52\x41\x3d\xfd\x97\xfa\xdc\x91\x24\x90\xb4\x9d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xad\x16\x6c\x38\x63\x43\x6f\xb3\x00\x5f\xe9\xd4\x7e\xbd\x6d\x81\x90\xb1\x2a\x6b\x2e\xd5\xf0\xeb\x93\x8d\xc5\xc9\xa5\x70\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x37\x3c\x8d\x89\xf9\x4b\x7e\xc8\x7c\xa7\xc5\xe4\x05\x24\x6b\xd5\x6b\x75\x43\x87\x92\x66\xbb\x16\x99\x3a\x41\x08\x35\xbc\x87\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x5c\xe6\x85\xb1\x04\x97\x76\x96\xdf\xc5\x02\x35\x80\x34\x5a\x3d\xc8\xc3\xab\x1c\x9b\xc4\x51\xe1\x16\x25\x61\xe0\xbe\x20\x53\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x29\x40\xa7\xfd\xcf\x10\x04\xbe\xeb\x5e\xaa\x03\x30\x14\xc9\x33\xfc\xda\x85\xff\x80\x1b\xcd\xd6\xd6\x55\x93\x94\x61\x25\xb9\x53\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x19\x4b\x50\xd0\x92\xec\xd9\xa8\xaf\xb7\xee\x46\x02\xff\x9a\xe4\xd3\x90\xd2\x8d\x65\xc9\x38\xbc\x3e\xe0\x8b\x28\x40\xa3\xfa\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\xae\xbb\x76\x9b\x00\xca\x8b\xb2\x56\x21\xa6\x28\x69\xae\x11\xf2\xc6\x91\x36\x55\x5a\x43\x69\xde\x5d\xe5\x11\x59\xd5\x21\xf8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\xf3\xf5\x15\x0c\xe1\x72\x5e\xe5\xd4\xc3\x1c\x96\x2e\x72\x8d\x34\x73\x5a\x95\xf3\xe4\x6d\xc3\x7f\x42\xea\x79\xb0\x67\xf4\xed\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\xf9\x4a\x7f\x69\x8e\x00\xd7\xe5\x34\xa5\x9e\xe6\xc5\x5b\x03\x91\xbb\x63\x42\xf1\x21\xd3\xae\x61\xab\xf3\x45\x11\x71\xb9\xb7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x96\xce\xa8\xf3\xd3\xbe\x30\x51\x78\xf1\x13\x1b\x3d\x00\x04\x21\x78\x46\xb0\x9e\xeb\x2a\x4e\xa2\xd5\xe3\xe2\xf8\x6a\xf2\xff\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\xfd\x09\x26\x3a\x4c\x00\x40\x58\x1a\x28\xcd\x75\x2d\x73\xab\xce\x7e\xb1\xb7\xe2\x8f\xea\x49\xfc\x4b\x2e\x25\xb8\x62\x90\x76\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x7a\xb8\x51\xfc\xd8\x34\x92\xa4\x36\x72\x5c\x15\xea\x60\xb9\xb4\x96\x78\x6a\x65\x65\x72\xac\xdc\x98\xa1\xa5\x48\x6c\xd8\x39\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x57\xe2\xca\xb9\x00\x0c\x8c\x2c\x72\xac\x09\x45\xce\x8e\xc6\xef\x06\x2d\xfe\xd2\x48\xac\x4e\x11\x84\x3f\xce\x99\xc4\x7d\x12\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\xd7\x95\xb7\x87\xd0\x4b\xa6\x38\xae\xbb\x72\x8c\x9d\xc0\xfe\x46\x93\xaf\xfa\x15\x93\x3a\xdd\xb4\x7e\xc9\x4f\xc8\x20\xce\xa7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\xa3\x5c\xec\x60\xbd\x5f\x5c\x96\xe3\x98\x89\xcc\xc5\x08\xe3\xbf\xf3\x43\x4c\x69\x93\x4c\x1d\x44\x27\x39\xd4\x6d\x20\xa4\x61\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\x8a\xb5\xed\x78\xa0\x9c\x50\xeb\xe7\x01\x1c\x5a\x14\x22\xc3\x31\xd2\x18\x05\xdd\x38\x2f\xf3\xe9\xff\xf3\x0a\xaf\x97\xea\x57\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x79\xcd\xff\x07\xac\xb7\xa6\x1b\x18\x5d\x12\x5f\xe9\x8c\xba\xf9\x6d\x63\xc8\xf6\xa4\xa6\x7a\x5f\xae\x4f\xa0\x82\x88\x4b\x74\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x1d\xff\xb2\x73\xa3\x6b\x55\x05\xf6\xb9\x7f\xce\x8b\x21\x5b\x13\x84\x5f\x51\xe3\x4c\xa5\x19\x02\x5c\x37\xda\xc7\x4e\x6b\x3c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x4e\x65\x84\xbf\xf8\xc2\xef\x60\x6c\xa3\x96\xf8\xc7\x1d\xe7\x5f\xf9\x43\x35\x1d\xbf\x3f\x84\x07\xbb\x06\xf9\x3e\x55\xe2\x26\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\x11\x89\x89\x81\x19\x68\x8c\xc6\xd8\xc0\x88\xe1\x30\x0e\x30\x73\x41\x07\xdc\xc6\xc0\x86\x6c\x42\xb4\x03\x16\xe6\x19\xd9\x62\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\xcd\x97\x93\x67\x20\x5c\x7d\x09\xbd\xaa\xba\xa8\x1e\xbb\x4e\x31\x99\x3e\xac\xff\x14\xdb\xbd\x00\x1e\xe8\x0f\x42\x28\x1d\x4d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xf7\xa3\x26\x85\x17\x82\x82\xbc\x52\x2d\x62\x1c\xfd\x3c\x08\x9f\xdd\x9c\x11\xe7\x6e\xde\x52\x98\x7c\x1e\x66\x90\xf2\xd7\xd9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xf8\xc4\x0a\x97\xe2\x8d\xc7\x62\x70\xad\xe0\xe6\x18\x60\xb1\x8a\xda\x2e\x01\x46\x6d\x08\xa7\xee\xe7\xdd\xf1\xcb\x77\xfe\xfa\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x85\xca\x1e\x86\x41\xb5\xcd\x58\x5c\x41\x27\x63\x74\xda\xf3\x25\xf1\x1d\xe3\x87\x21\xdf\x39\x73\xbe\x90\x80\x1f\xbe\xbe\x9b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\xa5\x4a\x7a\x63\xca\xe9\xfd\xc3\xa9\x96\x9a\xbe\xc2\xd9\xbb\xaf\x97\xd9\xae\x99\x89\x6f\x16\x10\x75\x1c\x7a\x12\x9b\x48\xf5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x3e\xe5\xf8\x15\x08\x92\x9b\x70\x1f\x6a\x24\x46\x31\xce\x4e\x8a\x25\xc0\x28\x32\xf9\xae\x85\x77\x0b\xbe\x9c\x7a\x04\x06\x30\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1f\x09\x07\x8f\x3d\xab\x46\xbb\x46\xc6\x59\xda\xdb\x53\xf9\x8c\x45\x86\xec\x71\x85\x09\xf2\x22\xca\x9f\x84\x94\x55\xec\xc1\x19\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x30\xf1\x38\x06\x86\x89\xd3\xc5\xbf\x33\x0c\x55\x1f\x5e\x55\x29\x11\xbb\x55\x67\x04\x86\x21\xc5\x40\xaa\x4a\xda\x7f\xbe\x66\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\x16\x8a\xf7\x8f\x1a\xdf\x4d\x69\xe1\x08\xf7\xb5\x09\xc4\x3d\xaf\xd1\x08\xc5\xd8\x7a\x24\x21\xfc\x7d\xea\x57\x4a\xac\x44\xb1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x19\xa4\x31\xd8\xd0\x3f\xbb\xa2\xda\x04\x49\x61\xc6\xd6\x92\x0e\x53\x40\xac\x32\x31\x4f\x02\x5b\x66\xdb\x1a\xcf\xe8\x2c\x98\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xa1\x7f\xc8\xd6\xa5\x73\x95\xa5\x49\x5e\xd1\xde\xd8\xa0\x6e\xa4\x56\x24\xcd\x1b\x85\xe3\xcf\x9c\x30\xc8\x41\xcc\x7a\x6c\x07\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x1b\x50\x3a\x4d\x99\x38\x9d\x80\x7a\x7c\xd8\xcb\x1e\x1c\x7b\x87\x88\x15\x12\xd0\x42\x51\xba\x0f\x9b\x26\x66\x85\x11\xc8\xb3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x27\xde\xf6\x4d\x35\xb0\xdc\x44\xa0\x0b\x12\xd4\x2f\xa9\x69\x22\x80\x82\xa9\x7e\x06\xed\x7c\x85\xfe\xc0\xdf\x35\x10\x6b\x6a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\xa3\x59\xb2\x5a\x62\xac\x7c\x80\xae\xff\x17\x6e\x3c\x2a\x24\xf3\x49\x78\x04\x2e\xbc\x13\x1e\x05\x79\x13\x4b\xf6\x21\xa5\xbc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x87\xba\x9d\xaf\x13\x65\xce\x1e\xd2\xf9\x59\x9f\xe9\x5e\x4e\x19\xb5\xa6\x74\x4c\x4c\xd8\x9b\xd4\xa9\xf7\x9a\xe4\x5f\x68\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xd1\xa8\x12\x3d\x86\x26\x70\x91\x9a\x26\xf5\x43\x70\xd9\x37\xff\x96\xc8\x7e\xb2\x50\xea\x9c\xb5\x42\xb9\x88\xbe\xcd\x5b\x1b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x3c\xd5\x79\x2b\x12\x40\x86\xb7\x8d\x81\xe7\x0b\xf8\x76\x5d\x13\x1f\x05\xc5\x80\xfd\x84\x50\x65\xc2\x34\x91\xaf\x26\x48\xa8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\x97\x41\x23\x4d\x38\xe4\xe4\x1b\x57\x76\x47\x91\xd6\xfe\xa6\xe5\x22\xfc\x17\x73\xce\xf3\xbe\xf7\xe9\x84\x84\x32\x7e\x04\xcd\
+    \x0d\x1c\x1f\x6b\x8e\x9c\x21\xbc\xb4\x44\x67\xc3\x03\xc9\xc3\x2e\xe5\x10\x3f\x2b\xed\x5a\x5f\x82\xfe\x4f\xce\x6a\x5e\x53\x21\xcb\
+    \x2b\x46\xe9\x45\x3f\xf8\x79\x61\x97\xfc\xf9\x6c\x5e\x6b\x89\xea\xa0\x31\x01\xfe\x1f\x9d\x02\x55\x73\x43\x3a\x21\x5c\x14\x43\x2d\
+    \x08\xfa\x33\xe2\xe1\xfd\x04\x9d\x1b\xd9\x2f\x5f\xe3\x4e\x20\x98\xa0\xf5\x6c\x99\x09\x7e\x3f\x82\x2f\xa3\x61\x6f\xbf\xa1\x8d\xe1\
+    \x06\xc6\xff\x67\x19\xdf\xd6\x21\x4d\xbe\x3e\x9d\x89\x4f\xc4\x0e\xdd\xc7\x10\xea\x85\xd2\x9b\x73\xc5\xc0\xf0\xbb\xe7\x68\x9d\x5a\
+    \x27\xa5\x64\x22\x13\xdf\x89\x9e\x80\xb1\x36\x5a\xfd\x1a\xb8\xfa\xcf\x86\xfe\xcb\x09\x42\x77\xd7\x45\x94\x38\x4b\x3f\xe0\x2e\xeb\
+    \x21\xf5\xc7\xaf\xcb\xbf\x04\xac\x23\x4a\xdb\xb2\xed\xad\xb9\x8a\xb1\xce\xda\x1f\xda\x0e\x33\x85\x8f\x2c\xf2\x26\xb1\xcb\x75\x10\
+    \x08\x17\x36\x28\xfb\x47\xce\x14\xa3\x9d\x3b\x1c\x5a\x86\x1e\x03\xbc\x76\xe4\xf6\x77\x01\x4f\xc8\xd0\x94\x2b\x74\xee\xa4\xb8\x15\
+    \x12\x57\x5f\x86\x47\xb4\x4d\x45\xe4\x15\xc0\xc2\x7e\xdb\x52\x61\x64\x0f\x50\xab\x9c\xea\x63\x69\x0b\xa6\x0d\xdb\xcb\x94\x46\xac\
+    \x24\x1f\x07\x1e\x84\x22\x62\xbd\x50\x2a\xad\xbd\xe4\xe0\xb3\xda\x3b\x78\xe6\x31\x5d\xed\x09\xd2\x62\xc9\x5f\x74\x7d\x1e\x69\xc3\
+    \x02\x59\x65\x06\x61\x34\x5b\xbb\x09\x8a\xaa\xaf\x58\x07\x1b\x5e\x05\x97\x31\x54\x2b\x79\xf0\xe8\x40\xe7\x82\xa1\xb0\xd7\xc1\xe8\
+    \x10\xe5\xea\x58\xbb\x15\x5a\xd7\x98\xc9\xfc\x61\x0e\xd9\x51\x3e\x72\xdf\x51\x99\xe0\x86\xf0\x32\x5b\x0e\x38\x31\x23\xe3\xe1\x4f\
+    \x2c\xb2\x8f\x0c\x40\xf2\x14\xed\x89\x86\x52\x55\x10\x02\x64\xcf\x11\x8e\x48\xe1\xb5\xc9\x55\x64\x76\x56\x0f\xe4\x01\x5a\x9f\xb7\
+    \x2b\x8c\x22\x45\x51\x8e\xa1\x87\x66\xd1\xc1\xf7\xc9\xcd\xe2\xdd\x35\x20\xa7\xeb\x5a\x62\x54\x96\xd5\x80\x84\xbc\xd6\x2f\x08\x53\
+    \x21\x0c\x52\xaa\xce\x33\x29\x94\x6c\x76\x9c\x65\xdc\xa9\x79\xb4\xfd\x94\xa3\xf0\x27\xf3\x2d\x2e\x29\x58\xc6\x46\xe6\xa0\x3c\x3a\
+    \x2a\xcd\x7e\x14\x55\xec\x1b\xa3\xc8\x4e\xbf\x2b\xbf\x2d\x86\xdd\xaf\x8a\x12\xf4\x1b\x3e\xd6\xf4\xe5\xff\x98\xbc\xa1\xff\x4d\x1b\
+    \x0b\x1a\x81\x4a\x47\xa8\x1e\xb8\x46\x4f\x6b\xaf\xe3\x1b\x8b\x05\x51\x66\xe9\xb8\x71\xca\xf5\x14\x6f\x99\x9d\xe1\x31\x69\x85\x09\
+    \x0c\x10\x58\x01\xd7\xa6\x4e\xda\x3f\x38\x2f\x2d\x22\x52\x41\x64\x3e\x2c\x2a\x90\x07\x30\x13\x08\x32\x2b\x97\xc7\xdd\xcc\x5d\x40\
+    \x26\xaa\x5f\x65\x76\x05\x6d\xaf\x7a\xe8\xae\x3c\x87\xf3\x86\x70\x0a\x4e\x26\xde\x68\xec\x1d\xad\xfb\x49\x26\xea\xdf\x98\xaf\xa9\
+    \x1a\x4f\x0c\x78\xd5\x71\x78\x8f\xb4\x94\xcc\x07\x97\xf0\x3a\x44\x91\x2c\x9b\x77\xc4\x92\x74\xf6\x6e\x91\xe7\x1f\xe2\x51\x09\xf2\
+    \x13\x4e\x5d\x62\x45\xf3\xa0\xd6\xa1\xec\x5a\x69\xa2\x29\x1d\x66\xad\xd1\xd3\x27\xa1\x6a\x5f\x4e\x86\x39\xb4\x92\xb8\xf7\x51\x55\
+    \x11\xe6\xff\x9b\xf1\xfc\xdb\x77\x0e\x96\xa6\x1a\x43\x57\xbc\xaf\xee\xa1\x3b\x9b\xdf\xdf\x09\xe7\x38\x38\x5a\x36\x25\x8d\x52\x24\
+    \x19\x8b\x03\x79\x5e\x8c\x98\xfc\x13\x23\x35\xed\x96\x4d\xde\xbc\xbf\x17\x41\xc2\x0c\xd9\xb1\x09\x63\xe4\xb1\xb6\xa5\x3e\x12\xa2\
+    \x27\xb4\xe6\x92\x2a\x47\xdd\xd1\x07\xc7\x52\xd8\x3f\xfd\x27\xee\xff\x76\xb6\x2e\x72\x32\xdf\xdf\xa9\xe9\xb9\x77\xbb\x8d\x2b\x84\
+    \x2e\xc9\x1c\x78\x80\x4c\xe2\xf0\xa1\x3c\xfb\xa2\xb1\x64\xdb\x38\x66\xbe\xb7\x72\x0f\x72\x52\xe9\x08\xeb\x00\x96\x6c\xae\x26\x68\
+    \x24\x88\x22\x9b\xbf\xf9\xd6\xb3\x6f\x83\xb3\x8d\x4e\x53\x72\x66\x7b\x52\xee\x88\xf7\x27\xe9\xa7\x15\xfe\xfb\x6d\xbc\xdf\x2d\x53\
+    \x05\xeb\xde\x6c\x2e\x83\xd8\x1f\xe0\x57\x80\xb1\x95\x06\xe1\x8e\x93\xee\x5e\x01\x53\x6d\x77\x46\x0d\xcd\xf7\xd1\xf3\x4e\xb1\xed\
+    \x1c\xd8\xa0\xcb\x5d\xc6\xe2\xb6\x7f\xc3\x2b\xe1\x9a\xef\x22\xce\x97\x7f\xf4\x84\xf2\x18\x8e\x60\x3b\xda\x24\xea\x8d\xc1\xda\x4d\
+    \x08\x51\x35\xe4\xa7\xa8\x9d\xcd\xfd\x03\xd7\x8e\x75\x3a\x1d\xa0\x18\x47\x55\x37\x06\xa5\x8c\x9f\x2c\x66\x8f\xe3\x18\x05\x0a\x49\
+    \x17\x02\xb8\x8e\x6d\x0a\x6c\x81\x03\x26\xe5\x6a\x58\x84\x7c\xc8\x06\x5e\xbb\x21\xf3\xa7\x44\x66\xcb\x19\x83\xc0\x0b\x0a\x8c\xf3\
+    \x22\x18\xbc\xb2\xb6\x8a\xa0\x37\x3f\xb7\x6c\x33\xca\x3f\x2c\x09\xf0\x1a\x0d\x3e\xff\xc0\xa0\x89\x7b\x01\x74\xf4\xf3\x07\x64\x00\
+    \x2e\xe1\xd3\xc9\x64\xdd\xdf\xff\x94\xda\xec\x6e\x0e\x02\xb6\x04\x90\x3b\xe9\x70\x47\x80\x0c\xc6\x82\x01\x42\x8c\xd6\x02\x41\x82\
+    \x01\x49\xd8\x75\x56\x57\xa3\xe8\x1f\x78\x33\x50\xbd\x16\x71\xeb\xa5\xc0\x66\x86\x6e\x11\x87\x81\xf3\xd7\x07\x45\x0e\x77\x3b\xda\
+    \x03\x38\x18\xac\xbe\xeb\xad\xa6\x83\xb7\x55\x44\x30\x46\xde\x5f\x65\xa5\x1b\xe2\xca\xac\x0b\xb8\xa1\xa1\x11\xda\xb2\xbf\x22\x83\
+    \x10\x24\x3f\x1c\xf0\x6a\x2e\xb5\xd5\x70\x13\x47\xb2\xe7\xad\x9c\x08\x8b\x3b\xf7\x3d\xd8\x79\x93\x37\x40\x7c\x24\x2c\xc8\x07\x0e\
+    \x24\xaa\x14\x3d\x47\x5c\x35\x66\x88\xee\x0b\xc6\xe3\x0e\x6e\x0b\x72\x58\x7b\x88\x52\x5f\xbb\xea\x1f\x1a\xc4\x3d\xad\x42\x56\x8f\
+    \x04\x71\x2c\xd6\x4d\xcf\x33\x58\xe3\x7b\x00\xbd\xda\xd2\xa3\xc6\xe7\x7d\xba\x77\xd0\xb1\x9b\x81\x87\xe7\x47\x1f\x1c\x0f\xab\xe4\
+    \x22\x12\x30\x70\xab\x73\xae\xf7\xfd\x56\xd8\xf2\x97\x59\x9f\xc7\x07\x8a\x74\x1c\xdb\x00\x14\xa7\x2e\xfe\x31\x81\x1c\x75\x63\x18\
+    \x24\xa7\xc2\x52\xfa\xb1\xd2\x8f\xe5\xcf\x37\x54\xdb\xcc\x02\x06\x6f\x20\x54\xde\x39\xa0\xf2\x76\x75\x79\xf6\x75\xe5\x2b\xd7\xfc\
+    \x09\xf3\xfd\x4c\x5e\x51\xdb\x15\x31\x14\x60\x97\x18\x5d\x64\x1c\xb1\xb4\x97\xc9\x6a\x51\xd7\xbd\x10\x31\x3c\xd9\xe0\xd7\xbc\xaf\
+    \x1a\x1a\x52\x58\x54\x39\xd7\xc7\x87\x51\xde\x23\xcb\xb2\x6d\xcd\x90\xfa\xf9\x30\x6d\xa8\xb7\x55\x52\x06\xb9\x33\x3b\x03\x61\x30\
+    \x04\x69\x58\xb4\xc6\x08\x46\x86\x77\xdb\xc3\xf0\x83\x3a\x8d\x5f\x44\x04\x17\xd5\x68\x0a\x2a\xd2\x3b\xb0\x33\x1b\x97\x09\xa6\x77\
+    \x21\x23\x1b\x60\xee\x1f\xce\x7c\x57\x22\x6a\x45\xd4\x36\x6f\xea\xc2\xbd\xde\xb6\x3b\x66\x7d\xc3\x47\x03\x1e\xfe\x3c\xdd\xf1\x5a\
+    \x1e\x8c\x25\xcc\x2b\x98\x49\x6b\x73\xa8\x94\x44\xa6\x45\x20\x2d\xa7\x91\xbb\x4b\xc7\x68\xb8\x28\x16\xf4\x02\xe7\xf6\xfc\x68\xec\
+    \x0d\x68\xbd\x59\x4a\xd7\x70\xfb\x7a\x82\xba\x79\x05\xfe\xa8\x5c\x15\x0b\x4b\x33\x5b\x50\x00\xfe\x05\xaa\x9a\x0a\xca\x01\xb1\xf7\
+    \x13\xc9\xce\xee\xe0\x8c\x13\x70\x64\xfa\x51\x4c\x15\x40\x12\x81\x30\x53\xca\x32\x9f\xa4\xc8\xda\x08\x05\xa4\x28\x42\x8a\x0f\xf7\
+    \x23\xfa\xd6\xa5\x46\xec\xbb\xbe\x75\xb2\x51\x84\x15\x4e\x6a\xf3\x9e\x7b\xcc\xa2\xb0\x08\x3b\x63\x2d\x70\xfc\x12\x41\xf6\xb5\xa1\
+    \x11\xea\x79\xfb\x35\xb1\xa8\x2c\x8b\xb6\xa9\xe1\xa1\x7c\xc0\x58\x97\x5d\x89\x53\x27\x9f\x36\x2f\xc4\xc6\x49\x44\xea\x6c\x0b\x3d\
+";
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BN254_T_16: &[u8] = b"\
+    \x26\x9a\xaf\x7c\x0e\x0a\xe1\xa7\x09\xc1\xb7\xcd\x13\x7c\x36\x6a\x3e\xf2\x1c\x0c\xa7\xd9\xfb\x2b\x33\xb5\xa1\xae\x23\x57\x68\xe4\
+    \x30\x54\x3e\xe0\x40\x32\x61\x4e\x31\x72\x29\xed\xfa\xf3\xb2\x7d\xa1\x0d\xd0\x79\x2f\x35\xec\xb2\xfb\x82\xa2\x0c\x30\xeb\x1d\xe3\
+    \x01\x74\x16\xb1\x31\x60\xb7\xd8\xd7\x3f\xfd\x44\xef\xc7\x5c\xe6\x42\xf1\xd0\x02\xe3\x32\xad\x4b\xd6\x84\x69\xb8\xb8\x3c\x5f\xc4\
+    \x09\xb1\x03\xf4\x38\xa4\x3f\x1a\xab\xb6\xbc\x5d\x34\x90\xd3\xc4\x43\xd7\x73\xb9\x66\xd9\x02\xd3\x6c\x81\x49\x06\x14\x93\x9e\xaf\
+    \x08\xf9\xe8\x1e\xa2\x1a\xa8\x82\xda\x55\xbd\xe4\x2c\x83\x0d\x26\x14\x62\xc4\x48\x94\x51\xab\x18\x15\x13\x61\x49\x83\xfc\xdb\x30\
+    \x02\x6d\x2c\xf7\x7c\xf4\x85\x77\x7f\xb7\x97\xf7\xc3\xbf\x17\xac\xaf\xcb\x36\x79\x54\x9a\xc9\x8a\xcb\x6e\x43\x0e\xb5\x3e\x4b\xe5\
+    \x06\x52\x44\x2b\xfa\x09\x59\x0b\x71\x0b\x32\x73\xf0\xd3\xc3\xde\x61\xde\xfe\x08\x35\x9a\xa8\x28\x9b\x63\xf3\x6e\xec\x1d\x7a\x7b\
+    \x0d\x6e\x46\xbf\x1e\x37\x25\xff\x88\x4f\x82\x60\x23\x21\xdb\x7d\x05\xc1\x52\x34\x9b\x4c\xd1\x11\x71\x95\xe5\xf7\x78\xf9\xc2\x7b\
+    \x28\x57\x54\xe6\x89\x29\x1a\x5f\x02\xe4\xa3\xc9\xb0\x73\x59\xd3\xfc\x33\xa6\x87\xa7\x55\xf8\x42\xcc\x45\xa0\x37\x77\x4d\x05\x42\
+    \x09\xa4\x88\x4b\x8c\xe2\xa5\xdc\x8e\xee\x7e\x18\x15\x26\xdd\x65\x56\x7e\x70\xaa\x4c\xb6\x2c\x3d\x12\x8e\x7d\x94\x34\x5a\x4d\xc4\
+    \x06\xaf\x44\xda\xc4\xca\x6c\xc9\x5e\x69\x2a\x20\x90\x76\x07\xde\xfa\x71\x16\x23\xca\x94\x93\x4b\xea\x9d\x70\xbd\x55\x5a\x59\x4d\
+    \x0f\x8b\x77\x38\xaf\xe6\xbd\x0d\x66\xcb\x58\x97\x0b\xf7\x48\x4b\xe2\xc6\x7a\x45\x19\xd1\x40\x6f\x07\x4a\xe1\x65\xab\x5d\x2a\xd5\
+    \x29\x4d\xbe\x90\xe6\x73\xac\xcd\xcc\x6d\x72\x11\xbb\x0a\xc3\xaa\xb9\x02\xa8\x84\x76\xef\x7f\x7a\xc6\xfe\x3b\xa7\xb1\x28\xc7\x1a\
+    \x05\xc3\xf9\xce\xca\xd5\x33\xb1\x4b\xac\xe3\xf9\xd7\xd7\x71\x3c\xcf\x40\xc9\x42\x9b\x4f\xce\x2c\xfa\x3a\xa4\xee\x3d\x4a\xe0\x39\
+    \x26\xcb\xff\x87\x2a\xc3\xdf\x2a\x37\x87\x87\x8f\x24\xee\x28\xb6\xad\x4f\x1d\xca\xb4\x11\x26\xb8\x0f\x40\x38\xf4\x05\x10\xd7\xe1\
+    \x1b\xa0\xb4\x93\xc9\x87\xb9\xc1\x42\x4e\xde\x92\x39\xba\x10\x0d\xc0\x05\xe7\x17\xaa\x71\xca\x6d\x6a\x60\x55\x61\xe3\x79\xbd\xce\
+";
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+pub(crate) const RC_BN254_T_16: &[u8] = b"\
+    \x11\xa8\xc5\x0a\xe2\xba\xf9\xf5\xe8\xb3\xc6\x72\xc7\x32\x6f\x00\x2c\xde\xc5\x57\x44\x8f\xdf\xa2\x63\xf0\xad\xfb\xaa\xce\xac\xff\
+    \x06\x2a\xf6\xc4\x37\x3d\xaa\xc1\x87\x54\xf0\x0e\xe8\x40\xe8\x92\x0e\x3f\x71\x5a\xa7\xd1\x42\x33\x94\x8e\x54\x59\x6a\xc3\x4e\x43\
+    \x06\x76\x68\x74\x27\x69\xc8\xdc\x00\x2f\x62\xad\x79\xb9\x36\x46\x4e\xe1\x8b\x54\x1f\x9d\x56\x0a\x1d\xf6\x62\xa1\xde\x73\x50\x99\
+    \x09\x25\x66\xab\xce\xb5\xed\xc4\xfd\xd6\x0f\x23\xc5\xc6\x05\xa7\xb8\x5a\x62\x82\xa1\xcf\x79\x20\xea\xf4\xf3\x45\x87\x72\x29\x04\
+    \x1d\xce\x67\x8a\xf0\x89\xb5\x69\xd6\xb9\x0e\xb8\x0d\x3d\x93\x7f\x98\xf9\x52\xe8\xf4\x92\x8a\x6f\x6d\x41\xc4\xdd\xee\xa8\xac\x8a\
+    \x0a\x0f\x0b\xd9\x83\xe5\x51\x75\xdb\x60\x27\xfc\x88\x47\x26\xd4\xc7\x9e\x1b\xf6\xe6\xb6\x5e\xb7\xa6\x05\x1d\x02\xca\xa2\xad\xc3\
+    \x2a\xb8\x87\xd5\xbe\xf4\xf2\xbb\x90\x47\x2c\x6d\xa5\x53\x15\x55\xab\x3e\x5b\x69\x2c\x21\xb5\x78\x55\xd4\xcd\x77\xc0\x8b\x73\xa1\
+    \x0e\x29\xf9\x01\x5a\x74\x43\xd2\xbe\x60\x33\x84\xab\xb2\x9d\xd7\xbf\x93\x0d\x1a\x28\x68\x6c\xfd\xbc\x34\x7e\x91\x63\x87\x0b\x73\
+    \x21\xb3\xa5\xf0\xe0\x68\x93\x4a\x74\x36\xd1\xd2\x8e\x8c\xf0\xbc\x61\xd7\x12\xe1\x58\xa8\xbb\x2c\x42\xa5\x71\x25\xd8\x72\x7a\xd2\
+    \x0e\x1f\x8e\xa9\x55\xcc\x9e\x2e\xfd\x16\xf2\x6f\xe4\x54\x22\xf1\x74\x9a\x9f\xd2\xfc\x10\xff\x4e\xd3\x43\x1b\xa7\x3e\xa1\xc1\x26\
+    \x21\x30\x51\x33\x65\xc0\x23\xad\x89\x97\xf5\x30\xb8\xf5\x07\x7c\x7b\xc5\x40\x8d\xc8\x17\x7b\x03\x79\xd2\x5e\xb4\x62\x66\xd0\x6a\
+    \x19\x96\xa1\x3e\x24\xa3\x07\x41\x9e\xf1\x7e\xaa\x21\x20\xd6\xa3\x51\x0e\x7d\x0d\xaf\xf7\x0d\x3b\x73\xe1\xc3\xda\x47\xd9\xe8\x1a\
+    \x2a\xe8\x28\xa9\xed\xb5\x91\xd6\x68\x10\x14\x6f\x3e\x0e\xfe\x95\x33\x6e\x91\x9f\xb8\x92\x4f\x65\x80\x7d\x2e\x20\xb7\x0f\xfe\x32\
+    \x2f\x61\x7f\xc3\xdd\xba\x10\xcf\xc4\x59\xbe\xf0\xad\xcb\x6d\xcf\x02\x4b\x50\x92\x4f\x5a\x97\x19\x70\x7e\xa9\xcf\xac\xee\xba\x86\
+    \x26\x03\x4a\xf9\xd2\x1f\xea\x59\xb3\x5e\x1e\xdd\x6b\xc6\xad\x24\xe3\x9b\xb2\x30\x90\x14\xf2\xdf\x43\x4b\x01\xd5\x23\x60\xad\x6a\
+    \x08\x61\xd9\xa9\xb5\x0e\xda\x74\xe9\x9b\xc7\x41\xba\xb3\xc8\xd7\xc3\x6e\x4d\xc3\x82\x8e\xf8\x8a\x88\x22\xad\x1d\x97\xcb\x2d\xc1\
+    \x09\xd3\x07\xc6\x25\x13\x80\xf1\x29\x59\x31\x8c\xb1\xb6\xb6\xbc\x8b\x5f\x0e\x87\xc8\xaa\xa5\xb9\xa9\xc0\x2a\x74\x7f\x82\x23\x0a\
+    \x2d\x72\x49\xc0\x15\x89\x36\x81\xc5\xb6\x40\x6a\x5f\x3e\xd9\x91\x31\x81\xfa\x18\xcf\x0c\x9c\xc5\x40\x4d\x00\x26\x12\xe5\xf6\xee\
+    \x30\x0c\x39\x4a\xb0\x16\x9e\x35\x79\xb6\x04\xd3\xe4\xc8\x34\x4f\xfa\x05\x16\x4a\x7f\xc6\x6d\x1d\x0f\xec\x5e\x1b\x0a\xba\x7c\x3c\
+    \x05\x32\x46\xa3\x05\x07\x74\x9b\x78\xda\xca\x7f\x5e\x1b\xb4\x0d\x8d\xd0\x95\xa2\x28\xd4\x75\x4f\x58\x7a\x52\x2a\xe3\xbe\xbc\x5f\
+    \x2b\xb8\xc8\x9e\x99\x59\xb6\xbc\x4a\xf0\x81\x41\x10\xa5\x8e\x9f\x2d\xe2\x78\xcb\x4c\xe3\xa3\xe8\x5b\x5d\xf6\x9f\x20\xbc\x6c\x85\
+    \x03\xd0\x87\x49\x00\x08\xdc\xe2\xb7\x85\x89\x8e\xa7\xd6\x3a\xa0\x73\xe5\xb0\x16\x45\xc5\x9c\x49\xce\x13\x62\x75\xcf\x59\x83\xb5\
+    \x1b\x18\x60\x48\x6b\x80\xb3\x34\x67\xe2\x50\xe5\xfe\xe7\x00\xe4\x2e\xa8\xb1\x01\x0a\xe9\x72\x0e\x0d\xba\x07\x4d\x0a\x71\x14\x83\
+    \x07\xc2\xdf\x19\xe3\x35\x02\xe0\xd6\x40\x72\x04\x24\x0d\x9a\xe9\xd5\x36\x71\xa8\xac\xb8\x27\x5c\x87\xa8\x78\xc8\xd3\x1a\xe6\xa9\
+    \x26\x81\x36\x0e\x63\x7f\x30\x7e\x9d\x3d\xd4\x6d\x8d\x9a\xc3\x2d\x1e\xaa\x80\xfb\x00\x8a\x16\x4c\x49\x5c\xdc\xba\x8f\x80\x57\x5b\
+    \x26\xe4\x0b\x31\xde\xa8\xfd\x07\x59\x1f\xe4\xcd\x50\x46\xb8\x99\x5a\xd2\xb3\x8c\xff\xf1\x2b\xb5\xd9\x55\x60\x49\x06\x2f\x8c\x60\
+    \x14\x2d\xb0\xb3\x20\x5d\x81\xb9\x1e\x28\x9e\x86\x40\x44\x7c\xe6\x1d\x0a\x56\x24\xe7\xa8\x02\x76\xb2\xaa\xfc\x6c\x02\x0b\xd4\x5b\
+    \x11\x48\x1a\xe8\xce\x1f\x44\x53\xb2\x9a\x23\xcd\xf0\x6b\xb4\xda\x13\x44\xfa\x08\x58\xcc\x36\x6e\xab\x6e\x28\x71\xf6\x82\x0b\xbf\
+    \x14\xaf\xa4\x14\xed\xfc\xf9\x85\xdc\x05\x05\x16\xd4\xe6\xe6\xf6\x1b\x32\xfd\xf2\xec\x6f\xae\xe7\xea\xe1\x03\xbe\x60\x2c\x2d\x9d\
+    \x07\xfc\xf3\xec\x2d\xf0\xdb\x69\x71\xc7\x19\x24\xe8\xad\xb1\xf9\x6f\x8d\x53\xfe\x7d\xe3\x67\x6a\x77\x04\xf6\x21\xac\x47\x0f\x5b\
+    \x21\xda\xa5\xe3\x6d\xf0\x01\x36\xf5\x44\x19\xd5\xe4\xc0\x9e\xc0\x9b\xba\x58\x90\x31\x73\xc5\x58\x22\xe5\x1d\x65\xb0\x77\x9e\x6f\
+    \x2e\x32\x4f\x62\x7a\xba\xdd\x10\xf0\x82\x06\x43\x0b\xef\xea\x7a\xe4\xb5\xed\x5e\x96\x5e\xc1\x38\x0a\xf6\xf8\x83\xee\xac\x7d\x3f\
+    \x0b\x5b\xb3\x84\xcf\xcc\xbd\x7b\x19\x1e\x65\x19\x01\xf6\x19\x3f\x94\x9a\x3c\x8a\x22\x6c\xec\x1f\xde\x2a\x43\xd8\x82\x9d\xbe\x86\
+    \x06\x9d\xdb\x00\x7f\xaa\xba\xf7\x33\x50\xdc\x4e\xad\xb9\x07\xbd\x2d\x0d\x4a\x21\x4f\x66\x54\xb3\x07\x56\xe8\xad\x18\xdf\x5f\x18\
+    \x20\x8b\x46\x5f\x89\xb4\x47\x78\x3e\xa3\xfb\x14\xcb\xb2\x35\x89\x1a\xe8\xc4\xe5\xa5\x03\x13\x65\xf4\x70\xba\xb6\xcf\x86\xdd\x3c\
+    \x13\x62\x97\xf9\xe8\x31\xfb\x96\x40\xb0\x24\xdb\x98\xaf\xd0\xa4\xeb\xa7\x14\xda\xa3\x19\x44\xc4\xe7\x61\x3d\xd7\x8f\xc7\xe1\x4c\
+    \x10\xa7\x85\x9a\x7d\xb6\x8f\xae\x99\xf5\x9a\x4a\x78\x4c\xf5\x18\xf4\xe7\xb9\xcf\x94\xad\x7a\xad\xfc\x8b\x08\x9f\x3b\x3e\xd9\xe8\
+    \x08\x62\xb4\xda\x27\xd4\x15\xd0\xee\x6e\xe4\x21\xb3\xdd\x98\xec\xb3\x18\x84\x89\x10\x3f\xc6\x02\x86\x84\xd1\x66\xa5\x21\x2e\xbc\
+    \x23\x3a\xf2\x96\x7f\x6f\x77\x40\xff\x8f\xeb\x10\x3f\xf3\x34\x16\xf7\xd2\xa2\xf0\x8a\xeb\x48\xe6\xa8\x43\x47\x78\x9f\xa4\x40\x4c\
+    \x2a\xe0\x69\xb5\x94\x8a\xb8\xf0\xab\x75\x96\x17\x73\x2f\xab\xf2\x8f\x73\x4d\xfd\x57\x32\xf7\xf2\x2d\x07\x97\x07\x89\x08\xd5\x92\
+    \x00\x58\x3f\x3b\x42\x88\x02\x63\xba\x00\x69\xd2\x78\xa8\xd6\xc6\x33\x91\x3f\x93\x05\x10\xea\x5e\x8b\xc4\xfd\xb6\xe0\xd6\x54\xb8\
+    \x12\xe2\xf2\x96\x32\xa6\xe4\x7b\x53\x6e\x42\x91\x25\x19\xb2\xf5\xf6\x08\x6f\xd9\x2b\xa2\x11\xaf\x77\x76\xbe\x12\xb3\x42\x73\x0a\
+    \x06\xdb\xb6\xc7\xb8\x44\x8c\x82\x0a\xe1\x28\x61\x2b\x7f\xc4\x69\x8a\x74\xcf\xa8\xbb\x05\x8a\x90\x74\x2d\x41\x88\xb3\x06\x3b\xf1\
+    \x18\x50\x93\x80\xd3\x53\x24\x3d\xec\xd5\x3f\x8b\x29\xe3\xe8\x30\xdc\xe8\x94\xb5\xed\x59\x0e\xee\xab\x18\xb1\xb9\x2f\x3a\x6d\xa7\
+    \x0c\x6f\xc9\x9e\x5d\x3a\x8b\xa8\x36\xd2\xa5\x16\x32\xa7\xef\xa4\xa2\x73\x2c\x5b\xe3\x2f\xf1\xbb\x89\x88\xe9\x73\x57\xcf\xe5\x61\
+    \x04\x21\x96\xce\xf4\x47\x99\x72\x03\xde\x5a\xa9\x5c\xd5\xa8\x15\x8d\xdf\xb1\x1a\x8a\xe8\x3c\x73\x3c\xb3\xce\x94\x2f\x1e\x39\x52\
+    \x2d\x74\x1b\x55\x8a\x9a\x39\xfc\x48\x44\x2b\x13\xf7\xa0\x93\x94\xd8\x78\xd4\x1b\x79\x63\x10\x51\x75\xb5\xea\xcc\x07\x62\xd3\x41\
+    \x28\x56\x13\x83\xe4\x9f\xc5\x5b\x46\x5f\x9a\x55\xc5\x68\x72\xa9\xda\x39\xcc\x1a\x86\x01\xc9\x84\xfc\x14\x2f\x51\x9e\x01\x10\x7a\
+    \x28\x3c\x11\xef\x39\x41\x49\xfa\xf2\xda\xbe\xf8\x93\x84\x3a\xd8\xaf\xc4\x2d\x83\x22\x00\x26\x48\xb1\x5c\x7a\x6b\x2f\x8e\x36\xda\
+    \x16\xe5\x50\x2b\x75\x77\x23\x10\x18\xc8\xb8\xa5\x9b\x3d\x9d\x60\x95\x2d\x4e\x16\x9f\xa3\x8a\x08\x0c\xe1\x86\x0c\xd7\x71\xea\x5d\
+    \x1d\xc3\x43\xeb\xb1\x99\x9b\xdd\x84\x9b\x51\x9b\x6c\x88\xc2\x5e\xd4\x0f\xa6\x73\xb4\x4a\x37\xae\x89\x5f\x1e\xe2\xef\xa9\x74\x58\
+    \x19\x2b\x8a\xfb\x63\xd8\xd9\x35\x7b\x5d\x74\x13\x6d\x34\x1c\x28\x11\xf6\x11\x33\x6a\xcf\xeb\x20\xa0\xe4\xa8\x3f\xc7\x26\xcb\x1e\
+    \x1a\x30\xb6\x09\x40\xaf\xd0\x87\x12\x65\xb3\x29\xec\x9a\xe8\x6a\x61\xdf\x68\x6c\xbb\x09\xb6\xb5\x7a\xdc\xd2\xee\xfb\xe2\x43\xb8\
+    \x20\xa0\x0f\x04\xfb\x23\x9f\x15\x1d\x60\x7d\x4a\x38\xfd\xa2\xef\x13\xfc\xfd\xf2\xcb\x72\x90\xc8\x6b\x19\xbd\xed\xae\x40\x48\xfc\
+    \x02\x93\xb4\x20\x83\xe8\xd2\xae\x73\x7e\x11\x2e\x01\x2d\xf4\x92\x7f\xa6\x1a\x56\x75\x3e\x4c\xd9\xad\xa1\x69\x0e\x5e\xd5\x29\xda\
+    \x2d\xb1\x03\xa8\x9e\x5c\xb3\xb4\x2a\xc0\x19\x13\xfe\x5c\x8d\xe3\xcf\xe9\x59\xea\x60\x33\xbf\xc3\xa1\x74\x51\x90\x65\x60\xf8\x07\
+    \x1c\xb8\x1d\x2d\xfa\x93\x8c\x93\x97\xa2\xda\x95\xa6\x81\x84\x98\x3c\xe0\xff\xf3\x21\x07\x14\x70\x25\x0b\x78\x1c\xdd\x80\xc7\x3c\
+    \x03\xf1\x35\x5c\x9f\x18\xf3\x7d\x78\x37\x81\x3b\x19\x94\x67\x87\x30\x1f\x68\xb7\xb3\x72\x0f\x02\xcd\x35\x8b\xc5\x0c\x9a\xd2\x16\
+    \x13\xaf\x73\xa1\x96\x17\xa9\x26\x25\xa1\xc3\x07\x43\xbc\x97\x75\xa4\xe8\xb0\xb9\xf3\xce\xf0\x88\xf3\x9b\xbc\xcf\x12\xca\xa3\xf5\
+    \x11\x49\x38\xf0\x9f\x61\x8d\x61\x76\xcf\xf8\x7e\xc5\xd1\x53\x2a\x26\x5a\xc4\xa8\x7e\x21\xd6\x8e\xe6\x1d\xae\x8d\x9a\x55\x49\x24\
+    \x0a\xcb\x45\x5d\x2f\x8d\xd6\x61\x52\x8e\x73\x2e\xa0\x7e\xf9\x3c\x9e\x03\x63\x0f\x51\x90\x3b\x86\xbc\x68\x35\xaf\xcc\x03\xa9\x8a\
+    \x15\x1a\xdb\x5d\xd2\xd8\xfa\x77\x43\x7a\xfe\x73\xae\xd3\x19\xd8\x4d\xd5\x46\x83\x9f\x9d\x7b\x68\x7e\x82\x5d\x9b\x23\xdc\x17\x44\
+    \x25\x54\x85\x23\xd0\x39\xac\x34\x6a\xf1\x9b\x64\xd5\x25\x49\xe2\x4d\xea\x02\xea\x0d\x54\x76\xa0\x39\x5c\x4c\x43\x8f\x61\x34\x64\
+    \x09\xcc\x75\xc2\x2a\x37\xff\xa2\x64\xdb\x25\x40\xae\x85\xd1\xef\x69\x65\x11\x14\x61\xea\xbc\x9f\xc6\x52\xa8\x79\xb4\xbd\xd1\x40\
+    \x0f\x11\x39\x93\x4f\x4f\xb0\xff\x2f\x4b\xd0\xb7\xd2\x85\x44\xa1\xc8\x93\x8d\xe9\x2d\xcf\x96\x5e\x1a\x17\x81\xc8\x6a\xa8\xe6\xc2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x6f\x9a\x7f\xe5\x2a\xd4\x5a\xe8\x85\x76\x83\xea\x06\x6c\xdb\xa3\xf7\xc3\xdc\x7d\xa7\x84\x11\xc8\x35\x83\x07\x0a\xc1\x2d\x47\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\xfc\xc8\x96\xbe\x2e\x9e\xdc\xfd\x06\xd0\xef\x23\xc5\x23\x95\x61\x48\xf3\xab\xcf\xfc\xb0\x80\x2e\x57\xc6\x5d\xfa\x7e\xb6\xd8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\xae\x74\x93\xc4\x50\xae\xc1\x81\x99\x9c\x3f\xb9\xc0\xb4\xd4\xa5\xa5\x95\x67\x2b\x2c\xab\x41\x81\xa5\xb5\x2e\xc2\xfc\xcf\x68\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x37\x6c\x58\xb8\x31\x38\xf9\x60\x98\x1b\xcf\x86\xf6\x80\xc7\x90\x81\xc9\x5f\x69\x14\x47\x7f\xb5\xd6\xcf\xc7\x49\x7c\x15\x26\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x14\x23\x35\xa5\x5e\x77\x46\x2a\xaa\xc6\xae\xbd\x3f\x92\xcc\xb1\x2e\x15\x20\x45\x46\xaf\x29\xee\xd4\xf5\xe7\x36\x00\xa4\xbf\x8c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x5d\xda\xe1\x6b\x04\xdc\x05\x1c\x9f\x41\x9c\x25\xb7\xf6\x40\x8a\x3d\x65\x3e\xbd\xa3\x49\xce\x93\x32\x59\x3b\x37\x1f\xdf\x33\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x89\xb4\x67\x83\x92\xa8\x46\x00\xcf\x0b\x6f\xb2\x5c\x9b\x35\xc0\x65\xb6\x95\x8a\xaa\x05\x6a\xe8\xb0\x99\xfa\x61\xe8\x7b\x75\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x57\xaf\xc3\x9e\xd2\xd8\xec\x9b\x9c\xf1\xc6\x85\xf7\xb7\x3c\x0a\xc1\xd0\x54\x04\x60\xef\x91\xee\x30\xe5\x6b\x84\x60\x7b\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\x78\xb8\x60\x30\x8b\x6b\x50\x84\x5c\x74\x4c\x96\xa2\x23\x7f\xc6\xea\x46\xa0\x5d\x48\x84\xdb\xe8\xa5\xe7\x97\x43\x2c\x26\x9a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\xcc\x94\xf4\x96\x32\x7f\x94\x6b\xd5\xbf\x50\x4a\xb8\x67\x14\xbc\x34\x5f\x83\x6f\xcb\x9b\x1f\xe3\x07\xc4\x0d\x62\xc3\xbe\x3b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\xd6\xde\x09\x35\x38\xa8\x6b\xaa\xcd\x50\xc2\xba\x65\xc2\x12\xbb\xfd\x46\xc8\x8a\xcf\x6a\xdc\x91\x1e\x67\x8b\xfc\x7c\x10\x14\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x58\x06\x7f\x01\x7e\xe1\x2a\xe2\x39\x44\xf9\xb7\x1c\x0e\x8f\x39\xd1\x65\xa2\x29\x95\xc8\xa1\x2a\x76\xbb\x34\x0c\x85\x3a\xeb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x14\xb3\xd1\x02\xf3\x3c\x05\xe6\xea\x23\xd0\x15\x9d\x2b\xf8\x79\xca\x8b\xca\x2e\xe5\x47\xe2\x5b\xde\x0d\x32\x89\x29\x55\x42\xfc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\xb8\x9c\x7f\xff\xc9\x8d\xc5\xba\x33\x74\xee\x0e\xc8\xc5\x86\x07\xe4\x50\x15\xb0\x8c\xb6\xd1\x3d\x28\x2d\x13\xfd\xcd\x9c\xbb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x7b\x6c\x14\x79\x57\xfe\x1e\x07\x42\x37\xcb\x27\x2f\x8b\x38\xfa\xdf\x54\x8a\xb3\x7c\x7c\xca\x24\x8c\x2b\x6e\x7f\x6e\xf1\x84\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\xe4\xd3\xe1\xb8\x86\xad\xcd\x0f\x5b\x0c\xf7\x3c\x1a\x9b\xf1\xd3\xdb\x30\x30\x56\x51\xdc\x85\x8b\x75\xc2\xc2\x2c\x1b\x4b\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x68\xfa\x9b\x0d\x5d\xf7\xdc\xe1\x62\x1b\xeb\xf6\x59\xa1\x82\x85\x20\x4c\x82\xef\xfc\x3a\x17\xa5\x2c\x8f\x86\x9a\x04\x38\x6f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x34\x37\x3a\x44\x27\xe6\xed\x68\x22\x63\x3c\x67\x6f\x76\x24\x4b\x73\x23\xdf\x66\x09\x4f\x7d\xac\x02\xfe\xbb\xab\xf9\x3c\xe6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x18\x16\xea\xe4\xa5\xa5\x96\x47\x76\x9d\xb3\x6b\xd3\x2d\x91\xe3\x6a\x4d\x28\x81\x18\x09\x21\xc6\xae\x1c\x77\x4d\xa8\xa8\x8c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\x8d\xc7\xcd\x6f\x52\x19\xb8\xf0\xa2\x02\xd5\xd9\x14\x2e\xb8\x01\x44\xfb\x49\x45\x77\x71\x75\xcc\x21\xa5\xde\x7f\x93\x73\x4d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x5c\x7c\x9c\x18\xbf\xc8\x4d\x82\x98\x30\x55\x90\xff\xbb\x6a\x6e\xc5\x13\x89\xeb\x26\x78\x52\x2b\xa5\x3d\xc7\xb6\xa9\xc9\x89\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\xa7\x05\xe5\xfb\x58\xd7\x54\xef\xb8\x03\xb9\xc2\x4d\xed\x86\xfd\xf4\x4f\x0b\x90\x27\x85\x8c\xb7\x46\x88\x89\xb9\xb7\x93\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\xb7\xb1\x6f\xa0\xd8\xca\xfd\xb1\x38\x56\xe6\x7e\x23\x25\x31\xb6\xb6\x4b\x18\x17\x17\xb5\x6e\x91\x8d\x89\x5d\x1f\x6d\xe7\x79\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\xee\x21\xa8\xe9\x9d\xe4\x9e\x0f\x23\x48\x43\x46\xd5\x1c\xa8\x4c\x76\x3f\xd3\xbc\x42\xed\x49\x30\x2c\xe5\x17\xef\xb7\xc3\xd1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x64\x17\x0c\xd5\x7c\x89\x62\x6c\xd9\xf1\xc2\x5e\xa6\x04\x10\x3c\xf5\xc7\xc6\xb6\x38\x4d\x63\xcc\xcc\x25\x39\xfd\x22\x7b\x10\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\xd9\x2e\xe6\xe4\xad\x5e\xd8\x67\x1a\x3a\x90\x2e\x47\x1b\x7b\x17\x42\x3a\x0a\x12\x3a\x06\x9f\x5c\xe7\x24\xd2\x19\x90\x0e\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\xe7\xeb\x25\xaa\x2f\x77\xc9\xf6\x4e\x6e\x99\x75\x69\x59\x3b\x1b\x1a\xad\xa9\xd9\x7b\x4f\xa8\xd5\xaa\x58\xea\xca\x28\xee\x3d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x24\x5f\x36\x70\xa6\xe3\xbe\x91\x04\xfc\x99\x9f\x97\x7e\x05\xda\xb4\x6a\x04\x34\xe6\x82\x27\x2a\xfd\xfe\x33\xb4\x31\x48\x94\x22\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x38\xe4\x4c\x82\xe5\x27\xc3\xf9\xb4\x06\x4e\x96\x91\xa0\x1b\x01\x42\x6c\x26\x4a\x5e\xab\x10\x26\x27\xca\x17\x98\xa9\x67\x31\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\x5b\xa9\x64\xc4\x3c\x4d\xd0\x3b\x90\xbe\x05\x2c\x36\x7b\x59\x88\xb0\xf6\x13\xd6\x7a\xfe\x23\xca\xec\xb7\x53\x5b\x02\xd8\xc2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x7a\x92\x63\x59\xec\xb9\x9d\xbf\x2f\xbb\x5b\xcf\xeb\x66\xb4\x43\xb7\x30\x74\xde\x47\xf7\x5d\x52\x85\xa6\x3d\x5e\x9b\xb8\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x0e\x98\x70\x35\xc7\xab\x80\x91\xaf\xda\xb9\x73\x7d\x67\xf7\xb2\x9d\x5a\x05\x5c\xfe\x3e\x80\xda\x76\xc5\xed\x40\x03\x6f\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x7d\x97\x27\xfa\x97\x87\xcb\x13\xd2\xc6\xc4\xf8\x61\xfe\x69\x48\xce\x20\x85\xe8\x17\xb1\x7f\x2d\x01\xc7\xac\x5d\x9a\x71\xe2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\xff\x9c\x76\x71\x54\x7d\x04\x03\xa7\xc6\xdd\x36\xa8\x18\xdb\x81\xd8\x4f\x73\xc6\x61\x8b\x28\xb9\xad\xc1\x17\xde\x6e\xa2\x86\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x1c\xbd\x6d\xbd\x13\x39\x31\x9b\xdb\x45\x49\x09\x0f\x7f\x90\x7c\x46\xf5\x20\xaa\xea\x6c\x3e\x66\x9b\x1b\x93\x3b\xc9\x78\xdf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x3f\xe1\x7b\xbf\x0d\x32\xf5\xee\xf2\x48\x96\x94\x61\x58\x4f\xf7\x71\x8c\x1a\x84\xe7\x9d\xaf\x88\xc8\x13\x86\x8f\x09\xa1\x0b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x76\x82\x55\xaa\x23\x80\x77\xf2\x15\x10\xe7\x08\x02\x7b\x2a\x93\x79\xb9\xbb\xbd\x34\xe8\x32\x22\x0e\xf0\xc7\x41\xd7\xbc\x76\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\xf3\xf2\x31\x31\x73\x92\x30\x95\x5f\xa7\x17\xed\xa7\x3f\x9d\xa0\xbf\x59\x6b\x1d\xff\x0c\x23\xed\x24\xcb\x2f\x95\x04\x41\x60\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x24\x10\x6b\x38\x83\xd2\x9d\x76\x32\x5e\x0e\xa8\x2d\x0e\x34\xc7\x42\xb9\x92\xfe\x78\xf9\x1e\x09\xc7\xe3\x08\xe8\xd3\x7d\xdc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x0f\xe2\xd4\x0c\x9e\x86\xc9\xd3\x5e\x14\x8a\x06\xea\x4a\x34\x10\xf2\x9e\x75\x19\xe3\x94\x25\x9e\x0f\x6a\xfb\xfb\x65\x19\xfc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\xae\x2a\x58\x37\xcf\x9c\x79\xcc\xab\xe4\xf7\xf6\x12\x52\xec\x8e\x6b\xbb\x27\x20\x05\x29\x7c\xca\x83\xcc\x40\xb4\x11\x0e\x89\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x68\x4f\xef\xda\x12\xb3\x2b\xa4\x87\x35\x64\xc3\x50\x2a\x67\xa2\x07\x61\x34\xf7\x1f\xf6\x6f\x4e\x2e\xe5\x31\x5b\x02\xa1\x9c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x04\x5f\x60\x63\xec\x9e\x2e\x6e\x70\x40\xc0\x94\x9a\x46\x23\x56\x93\x80\x5a\xf8\xe7\x5a\x29\x76\x0d\xd4\x94\x55\xe6\x1d\xda\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xff\x37\xa0\x31\x17\x30\xe3\xce\x35\xc3\xae\x54\x67\xf4\x74\x01\xad\xd0\x29\x62\xda\xa4\xce\xbc\xf2\xca\xe5\x30\x85\xeb\x10\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xa8\xf6\x8d\xad\x75\xdb\x50\x95\x47\xb9\x7c\x08\x69\x9e\xf0\x5d\xc6\xe7\xa8\x71\x55\x3d\xac\x62\xf3\xd9\xbe\xe8\x7e\x14\xdd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\x44\x81\x1d\xe6\x8c\x06\x4a\xf3\x69\x42\xe4\xae\x90\x59\x28\x4f\x0d\xfe\x86\x65\x0c\x72\xca\xcf\xba\x8c\x8a\x06\xe9\x20\xff\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xc1\x76\x3d\xf1\xa7\x20\x67\x05\xb6\x5d\x09\x42\xac\xec\x0c\x4c\xf6\x1e\x60\x78\x2f\xfa\xe5\x40\xb6\xc4\x1d\xd6\xa4\x3d\x50\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x75\x34\x04\x55\xa0\x54\x74\xd7\x48\x60\x80\x31\xd2\x1e\xa1\x50\x2e\x27\xc6\xfd\x34\x8f\x99\x92\xcf\x88\x81\xc2\xd8\x1e\x69\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\xf5\x01\xb2\x02\x8e\xb5\x49\x37\x20\x61\xad\x34\x23\xe3\xf0\xf7\x1b\xc4\x5b\x89\x87\x0b\xe3\x97\xc5\xc5\xe0\x1b\x19\x76\x0e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\xb6\x22\x75\xeb\x9f\xa3\x6c\x94\x13\x6e\xfb\x0b\x37\x67\xbd\xe4\x6f\xf1\xd3\x5b\x81\x17\x59\xaa\x2b\x54\xc9\x0d\xab\xbb\x54\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x19\xec\x5d\xc1\xa9\xb5\x38\x92\x3f\x0c\x33\x54\x60\x3b\xd0\xc7\xd7\xac\xd9\xb9\xce\x81\x7b\x70\xbb\x98\xac\xc4\x74\x4d\x58\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x49\xa0\xa9\x85\x67\x93\xb1\xfb\x73\x04\x91\x89\xf3\x4b\x2e\x0e\xd9\xe0\x8c\x48\x87\x53\x59\xaa\x89\x9d\xce\xe9\x1e\x1e\x82\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\x19\x1e\x3f\xf7\x93\x6b\xd9\x7b\x94\x8b\x09\x5a\x6b\xb2\xe4\x58\xb3\x3a\x4f\x2a\x73\x1b\x5a\x06\xc3\x47\xf1\xf6\xb4\x6d\x6d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\x3d\x69\x0c\x70\xb4\x93\x03\x41\xaf\xbb\xea\x18\x6b\x03\x94\xa3\xa7\xdd\x7d\x91\x1f\x81\x06\xdb\x6c\x96\x47\x63\x95\xf2\x5b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\xd3\x9a\x7a\x0b\x5f\x37\x20\x41\xd7\x17\xd4\x22\xdc\x3b\x86\x2a\x8a\x8b\x6e\xd1\xa1\x74\xef\xb2\x4b\x92\x3c\x95\x05\xc5\xb0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x3b\x47\x89\x2d\x40\x3e\x28\x7b\xbf\x80\xd3\x19\x86\x6f\x50\xad\xc5\xa5\xb5\x0f\x81\xf1\x70\x89\x86\x07\x8e\x64\x06\x5b\x26\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\x4b\x72\x41\x62\x94\x3f\x08\xad\x65\x75\x57\xa6\x38\xbf\x3e\x7b\xec\x8e\x57\xd5\xdb\x00\xb9\x76\x76\x82\xc8\x21\x31\x32\xd8\
+    \x20\x35\x61\x42\x27\xe3\x01\x89\x40\x56\xbf\x19\x70\x33\xea\x74\xe5\x68\xe2\x2a\x46\x44\xda\x42\x34\xfa\xd6\x01\x0f\x91\x95\xf1\
+    \x28\x67\xe2\x8d\x25\xa0\x56\x0e\x62\x0a\xf7\xe7\x34\xe3\x15\x55\x28\x40\x62\x85\x83\x4d\xb3\x00\xd3\xf8\x8a\x5c\x63\x15\x85\x45\
+    \x2d\x9a\xba\x98\x83\x9b\x67\xae\x81\x7c\x67\x3f\x35\x05\x9c\x57\x4d\x22\x95\x29\x65\x0b\x0b\x71\x8e\x0a\x89\xde\x46\xbc\x9b\x77\
+    \x26\x20\xdc\xb3\x5a\xbd\x4a\x2c\x1c\x34\x21\x9e\xed\x20\x5f\x8c\xd0\x95\xa9\x6c\x00\xcb\x66\xdb\xe1\x06\x12\x76\x15\x56\x28\x23\
+    \x18\x96\x93\xff\x51\xf3\x7b\x66\xcd\xb4\x14\x14\x74\x65\xba\x7a\xeb\xbe\xd3\x00\x68\x53\xa8\xb6\x69\x5b\x46\xd0\x88\xda\x9f\x9d\
+    \x0d\xcf\x0b\xb6\x7a\xa3\x09\x91\x4b\x0a\xee\x36\xc5\x26\x61\x72\x3c\x0b\x3a\xf0\xab\xef\x68\xd4\x38\x96\xba\xb6\x6a\x11\x1d\x80\
+    \x20\x7a\xba\xc6\x76\xca\xb8\x1b\xde\xe4\x21\x46\x85\x77\xde\x3d\x55\xdf\x73\x4b\xcf\xc5\xe7\xb0\x96\x6c\x7c\x05\xf6\x67\x6e\xd5\
+    \x07\x27\x51\x66\x9c\xc4\x0c\x66\xa1\xe5\x6c\xac\xd6\xa5\x7e\xbe\xf4\x04\xb8\x08\x9d\x8b\xcb\x82\x7b\xed\x33\x5b\x3a\x81\xf6\x8b\
+    \x2d\xd0\x61\x6e\x2f\xab\x65\x05\xdc\x6a\x0e\xf4\x47\x01\x39\xd3\x3d\xac\xe7\x0f\x71\x02\xa1\x79\xc6\xc5\xf4\x5e\x19\x7a\x3b\x31\
+    \x2b\x6d\x0c\xa5\x0c\x9b\x10\x22\x97\x74\xa0\x59\xbe\xe1\xfc\x4d\x09\x0c\x87\x9b\xdc\x49\xfd\x79\xa6\xe9\x7b\xe8\x5d\xe5\x42\x1c\
+    \x13\x15\x94\x77\x27\xa3\x68\xa3\x71\x3a\xc6\xb5\x96\x8c\xb5\x0a\xe3\x0e\xd2\xf2\x65\x46\x06\x2e\xb3\xbe\x93\x52\x84\xb2\xd7\xac\
+    \x2c\x50\x01\x8a\x60\x9e\x80\x5d\xd4\x54\x28\x01\x51\x80\xee\xf9\x05\xc8\x85\xd1\x0f\x4b\xdc\x57\xbf\x82\x2b\xcd\xbb\x7a\xf7\xbc\
+    \x1a\x51\xc1\x5b\xbe\xdb\xa9\x63\xfb\x10\x8a\x0b\x54\x70\x85\x1f\x0e\x98\x96\xf6\xeb\xb1\xb3\xb6\xe5\x1e\xd5\xc6\xc5\xe2\x6e\xd4\
+    \x22\x1a\x70\x96\x8c\xea\xdb\x2b\x1a\x39\x49\x21\x5d\xb0\x3d\x70\x45\x79\x82\xd2\xe5\xbd\xce\x6e\xdd\x22\x48\x37\xed\x95\x27\x57\
+    \x11\x53\x67\x53\xad\xfe\x66\x5c\x99\xcc\xb6\xa3\x8c\xbd\x9d\x06\xc3\xc7\xb7\x12\x2b\x51\xdc\x06\x36\x28\xa6\x69\xdd\xed\x0b\x36\
+    \x03\x18\x97\xf3\x07\x96\xe5\x0a\x12\xb2\x12\xbd\x14\x6e\xd5\x8f\xb5\x0b\x44\x3a\xe9\xa2\xdb\xfd\xb4\xf6\xb8\xfe\x5c\x61\x47\x9e\
+    \x1f\x56\xaf\x93\x2c\x20\x12\xa3\x8d\x77\x35\x2c\x6d\xdb\x68\xfa\x13\xc1\xc9\xc1\x4b\xa9\x6f\xe7\xe6\xd1\x70\xff\xed\x0d\x75\x9a\
+    \x1e\xcd\x95\xda\xe8\xcb\x50\x8b\xcf\x72\xe6\x58\x48\x98\xd2\x10\x88\x54\x74\x9c\x3c\xc6\x86\xe4\xb0\x2f\x8f\xe5\x7a\xed\x95\xdf\
+    \x02\xfa\xba\xa8\x28\xc2\x7c\x72\x1f\x68\xc3\x3d\x9d\x2b\x88\x12\x10\x70\x4a\x41\xd9\xc2\x8f\x0a\x4a\xf0\x2e\x67\xfd\x34\x5c\xaf\
+    \x1d\xa2\xfc\x3d\x07\x3c\x37\xc2\x92\x1a\xf1\x6e\x44\x33\x0e\xe7\x2c\xae\xc3\x66\x43\xd7\x5e\x76\x6c\x74\x37\xc5\x9b\x12\xd6\xe4\
+    \x1e\xe2\x0f\x40\xea\x03\xcb\x4d\x56\x20\xd4\x42\x2f\x73\x2f\x54\x7c\x19\x8e\x40\xfe\xf3\x83\x93\x70\x86\x8c\x44\xa0\xeb\x5d\x17\
+    \x1c\x79\xdb\x7d\x2a\x94\xac\x6c\xc1\x52\x85\xa8\xe5\xde\x39\xe4\xe9\x3b\x57\x4c\x4d\x72\x74\xe0\x8b\x5f\x27\xe9\x8c\x96\xa9\xcc\
+    \x03\x93\x23\x13\x3d\xe3\x51\x9b\xd4\x62\x23\xb4\x3f\x89\x05\x77\x23\x23\xe7\x11\x1b\x39\x8c\xab\x54\x2b\x4f\xbd\xbd\x4b\x4d\x8d\
+    \x02\xe2\x1c\x34\x67\x78\xfe\xd8\x57\x51\xf9\x5c\xca\x3d\xa1\xe6\x7f\x96\xfb\x5d\x4c\x4a\x40\x0e\xc8\x1e\x32\x4f\x99\xce\x88\xe3\
+    \x17\x41\x99\xbd\x6b\x9b\xab\xd9\x96\x1f\x90\x76\xe8\xe0\x9c\x47\x80\x8e\xa3\x37\xa2\xe8\x3b\x4b\x80\xd7\x68\x5e\x1f\x01\x91\x2b\
+    \x29\xa0\xcc\x2b\xd1\x5e\xd7\x6a\x35\x6d\x76\xfd\x2c\x33\xcc\x8d\xf8\x4e\x81\x06\x8a\xc7\x99\xe8\xa9\xec\xa0\xa8\xe2\x83\x6d\x14\
+    \x05\x13\x6a\x95\xe0\x80\xef\x56\xc2\x3c\x09\x9a\x7f\x85\x69\x66\x75\x84\x65\x5e\xb8\xd8\xce\xf8\xdc\x46\xad\x90\x94\x3f\x48\x4a\
+    \x28\x20\xe3\x35\x7a\xbf\x5a\xee\xdf\x9a\x45\x76\x03\xa6\x27\xe9\xbd\xd8\x07\xdf\x27\x34\xbd\x6e\x81\x05\x1b\x00\x9f\x13\x09\x3c\
+    \x20\x5e\x6b\x63\x1b\xad\x87\x31\x62\x9a\xc0\xaf\x44\x64\x57\x16\xf0\xf9\xdd\x54\x9d\x7b\xe0\xaa\x4e\xca\xa8\x2f\xda\x20\x27\x6c\
+    \x1e\xb9\xab\x4d\xd7\x42\x6e\x30\xf8\x09\xc9\x92\x9f\x84\xea\x59\xd6\x13\x6d\xaa\x5f\x70\x22\x84\x7a\xc8\xe0\x1f\x04\xee\x91\xe2\
+    \x2d\x89\xf5\x92\x53\xf5\xb9\xa2\x4e\x01\x88\xc1\xff\xe0\xfe\x6d\x15\x85\xc3\x0c\x01\x9b\xba\x8b\x4c\x13\xfb\x65\x44\x1d\x8b\x0c\
+    \x2b\xba\x2d\x4a\xfc\x39\x69\x25\xd0\x3d\x39\xea\x49\xfd\x7d\x23\x17\x4a\x3e\xe0\x23\xa3\x62\x5b\x7d\x93\x79\x36\xb7\x84\x96\xf3\
+    \x0e\x59\xa5\x8b\x60\x2d\x4c\xa9\xe2\xca\x2a\x10\x79\x6a\x23\x56\x18\x8a\x39\xea\xfa\x39\xe5\x52\x5f\x53\xf5\x60\xd3\x34\x9c\xbd\
+    \x1c\xc1\x83\x78\x05\x61\x72\x4f\xe8\xdb\x9d\x8f\x8c\x45\x6c\x32\xc8\xc2\x95\x56\xc1\x2c\x01\x57\xef\x8f\xbc\x9f\x00\xe7\x89\xa7\
+    \x05\x41\x20\x82\x1f\x04\xfd\x77\x8d\xde\x89\x68\xf5\x5b\xa1\xf8\x62\x1a\x17\xaa\xc4\xc0\xda\xe7\xfd\x3e\x19\xb5\xff\xe7\xed\x32\
+    \x1f\x02\x89\x3a\x85\x8c\x61\xd8\x60\xab\xd6\x38\x71\x0b\xc1\x3a\xa6\xae\x1e\xe2\xf3\x22\x75\x13\xc9\x23\x78\xc9\xd7\x4d\x04\xa9\
+    \x03\x71\xfd\x91\x8b\xcd\x2d\x93\xca\x93\x86\x4f\x1d\xfb\x8c\x85\x1c\x20\x74\xed\x5b\x06\xfd\x13\x39\xba\x1e\xed\xd2\x0a\x40\x78\
+    \x1f\x3f\xb6\x1a\xfc\x1c\xcc\x18\x1b\x5f\x33\x13\x9f\x59\xcd\xbe\xe7\x67\x78\x72\x6b\x9a\x73\xfb\xc1\x96\xe7\x45\xaf\xd3\xf1\x0a\
+    \x1f\x6a\xb6\x1f\xea\xa7\x16\xf4\x31\x1a\xdc\xa7\x96\xe4\x44\xe9\x63\x6a\xbe\xbb\x50\x1d\xd7\x22\x46\x92\xfd\xfa\x0d\x64\xc9\xfd\
+    \x0b\xbc\x08\xa1\x7c\x0f\x31\xe7\x3a\x00\x49\xd3\x46\x48\x97\x16\x75\x66\x88\x0b\xbc\x88\x4e\xab\x97\x4c\x63\xb3\x7e\x66\x35\x89\
+    \x2f\x6e\x6c\x68\xf9\x7c\xff\xb2\x03\x5d\x97\x30\x14\x67\x80\x81\xf8\xa8\xf0\xfe\xde\x19\xb7\xd5\x70\x2d\x6b\x5e\x36\x8f\x26\x5c\
+    \x10\xea\xe0\x66\x30\x59\xeb\xa3\xe2\xb8\x42\xc9\x07\x60\x76\xb5\x24\x1b\x49\x77\xde\xc5\x91\x2f\x14\x5d\x89\x1f\xe5\x8e\x9b\x93\
+    \x0f\x23\x4b\xec\x6c\xc1\x40\x51\xfb\xb4\xeb\xaa\xdc\x99\xaa\xca\x49\x7c\x1c\x2d\x5f\xef\x51\xe5\xed\xb6\xe8\x17\x4f\xc7\x37\xb5\
+    \x11\xd7\xa6\x7d\xa5\x23\x07\x03\xf0\x13\xee\x57\x46\x53\xd7\xe0\x2d\xcb\x7f\xdf\x07\xf3\x9f\x21\x41\x0f\xda\x7f\xfc\xb0\x9f\x9a\
+    \x0f\xee\x56\x2c\x54\x44\xf1\xe0\x94\xb4\xa6\xbd\x73\x2b\x72\x07\x71\xf9\xba\x98\xdb\x7d\xcf\x82\x31\xb8\xd1\x76\x46\x84\x34\x32\
+    \x0a\x07\x64\x67\xe3\xa9\x60\x3a\x77\x63\xd3\x20\x26\x16\x30\x2c\x71\x9e\x0c\xc0\x58\x70\xd0\xa8\x45\x88\x42\xe3\x0c\xe0\x47\xd3\
+    \x1c\x73\x3d\x34\x81\x52\x20\x31\x7f\xac\xf6\xf5\x4e\xa3\x20\x06\xe2\xd3\x98\x6a\xae\x8c\x72\xad\xd1\xb2\xe1\x19\x62\x34\xdd\xf9\
+    \x18\x6d\x4f\x68\x69\x94\xb1\xe7\x79\x0b\x2e\xcb\xa7\x70\xc3\x73\xee\x1d\x70\xfd\x63\xb7\x10\x73\xe6\x5e\xca\xd8\x8d\xd5\xa3\xa9\
+    \x12\xd1\xd2\x77\xd3\xcc\xb4\x99\x7d\x6c\xd5\x45\x98\x1b\x73\xbb\x48\xfa\xb1\x83\xbf\x9d\xe1\xdb\x16\xe0\xd1\xcb\xcf\x44\xc3\x13\
+    \x16\x16\x97\x40\xab\xc7\x33\xa5\x75\x32\x34\x25\x7d\x31\x89\xd5\x94\xd1\xee\x5b\x2b\xa7\xea\x94\x45\x70\x78\x42\xd6\x80\x5b\xdd\
+    \x25\x66\xd9\x9b\x2f\xc3\x1c\xb5\x83\xaa\x14\x6e\x9d\xd3\x9c\xcc\x09\x43\x6a\x45\xad\xe8\xa5\x7c\x9f\x4c\x2f\x4d\xaf\x96\x32\xb1\
+    \x14\x6f\x2b\xa6\xb2\x4e\x1f\x31\x96\x2c\xba\xda\xe6\xb3\x6f\xc4\xa8\x96\xcc\x6e\xb9\x9d\x63\xd9\x85\x9a\x2a\x4f\x91\xaa\xdc\xda\
+    \x2a\xd5\xae\xe9\xf6\x37\x9b\xb5\xe2\x75\xb6\xc2\xac\x94\x7f\xb0\xd5\x42\x16\xc8\x5a\x6c\x78\x6b\x2b\x5b\xa1\x33\xa1\xce\x17\xea\
+    \x2f\xcd\xf0\x8e\xf1\x10\xe1\x8c\x87\x7c\x4e\x7e\x28\xc4\xf9\x02\xbf\xac\xe3\x89\xa0\xb9\x55\xaf\x66\x90\x65\xd0\x76\x6d\x83\xa7\
+    \x2d\x9f\x57\xde\x99\xfa\xc2\x0c\x55\xf4\x02\xf0\x6d\x9c\x9d\x3a\x8f\x49\xd6\x2f\xb1\x06\xe4\xdd\x8c\xca\x84\xcb\xd6\x85\xc0\x6a\
+    \x1c\x03\x9f\xc1\x3e\x41\x61\x99\x8e\xd6\x0f\xc9\x09\xe1\x94\xeb\xce\xb1\xe7\xa4\xee\x75\x5d\x72\x01\xff\x96\xec\xf3\x63\x2e\x03\
+    \x17\xf2\xad\x6e\xca\xaf\x3e\x5d\xb0\x4b\x6a\xea\x8d\x17\xbc\xb5\x8c\xfe\x4a\x10\x24\xec\xdb\xa3\xef\x5d\x57\xdb\xfd\x02\x02\x02\
+    \x19\x6e\xc1\xe2\x7e\xab\x45\x8b\x37\x89\x61\x28\x4b\x6a\x98\xa6\x76\x6c\xd6\xb3\x79\xb8\x22\x86\x87\x15\xe8\x26\xe0\xa2\x14\x06\
+    \x10\x9a\xfa\x2d\x34\xc4\xfb\xac\x99\xbe\xcf\xa7\x0f\x20\xb2\x08\x7f\x71\x58\x41\x3a\x02\x60\x4b\x38\x66\x84\xf3\xf8\x48\xa8\x96\
+    \x14\x8b\xfc\xcb\xaa\xf2\xf7\xe9\x51\xef\x26\x6b\x8c\x11\xa8\xc1\x9b\xfe\x01\x37\x49\xc3\x8f\x5b\xcf\xe6\x08\xd3\x28\x2f\xb3\x7c\
+    \x0a\x25\xd1\x1a\x8d\x1e\xd5\xc8\x7e\x03\xb6\x84\xb7\xb6\xd3\x41\x40\x06\x3c\xb4\xf7\x7e\x31\x2e\xc5\xac\x12\xda\x98\x91\x1e\x40\
+    \x24\x79\xd8\x50\xbb\x1a\x9b\x91\x43\xf1\x26\xda\x89\x06\x3a\x8a\x3e\x5d\x0a\x9d\x59\x17\xe2\xbf\xa5\x92\x7d\x8e\xeb\x70\xc5\x26\
+    \x11\x51\x07\xe6\x29\x02\xf6\xfa\xcb\xd8\xa1\xca\xa2\xa2\xf6\x7a\x15\x30\x1e\x5c\x03\x4c\xb9\x9c\x76\x4f\x64\x02\xca\x33\x17\x81\
+";
+
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const MAT_DIAG_BLS12_381_T_2: &[u8] = b"\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bn254_t_3(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000002).into()),
-    ]
-}
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const MAT_DIAG_BLS12_381_T_3: &[u8] = b"\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bn254_t_4(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b).into()),
-    ]
-}
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const MAT_DIAG_BLS12_381_T_4: &[u8] = b"\
+    \x07\x56\x4a\xd6\x91\xbf\x01\xc8\x60\x1d\x68\x75\x7a\x56\x1d\x22\x4f\x00\xf3\x13\xad\xa6\x73\xab\x83\xe6\x25\x5f\xb4\xfd\x5b\x3d\
+    \x61\x84\xe3\xbe\x38\x54\x9f\x7c\x08\x50\xcd\x06\x9b\x32\xf6\xde\xcb\xfd\xe3\x12\xdd\x4b\x8c\x18\x34\x9b\x1b\x37\x76\xa6\xea\xa4\
+    \x41\x92\x89\x08\x81\x78\xad\x74\x2b\xe6\xf7\x84\x25\xc0\x15\x6b\x65\x46\xa1\x8f\xd3\x38\xf0\x16\x99\x37\xde\xa4\x6c\xfb\x64\xd2\
+    \x32\x44\xcd\xec\x17\x3b\x71\xa4\x65\x9e\x25\x29\xb4\x99\x36\x2d\xac\x10\xcb\x2f\xd1\x75\x62\x86\x0c\x8b\xb9\xd0\xfd\x45\xb7\x87\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bn254_t_2(e: &Env) -> Vec<Vec<U256>> {    
-    vec![e,
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x09c46e9ec68e9bd4fe1faaba294cba38a71aa177534cdd1b6c7dc0dbd0abd7a7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c0356530896eec42a97ed937f3135cfc5142b3ae405b8343c1d83ffa604cb81).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e28a1d935698ad1142e51182bb54cf4a00ea5aabd6268bd317ea977cc154a30).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x27af2d831a9d2748080965db30e298e40e5757c3e008db964cf9e2b12b91251f).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e6f11ce60fc8f513a6a3cfe16ae175a41291462f214cd0879aaf43545b74e03).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2a67384d3bbd5e438541819cb681f0be04462ed14c3613d8f719206268d142d3).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0b66fdf356093a611609f8e12fbfecf0b985e381f025188936408f5d5c9f45d0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x012ee3ec1e78d470830c61093c2ade370b26c83cc5cebeeddaa6852dbdb09e21).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0252ba5f6760bfbdfd88f67f8175e3fd6cd1c431b099b6bb2d108e7b445bb1b9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x179474cceca5ff676c6bec3cef54296354391a8935ff71d6ef5aeaad7ca932f1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c24261379a51bfa9228ff4a503fd4ed9c1f974a264969b37e1a2589bbed2b91).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1cc1d7b62692e63eac2f288bd0695b43c2f63f5001fc0fc553e66c0551801b05).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x255059301aada98bb2ed55f852979e9600784dbf17fbacd05d9eff5fd9c91b56).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x28437be3ac1cb2e479e1f5c0eccd32b3aea24234970a8193b11c29ce7e59efd9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x28216a442f2e1f711ca4fa6b53766eb118548da8fb4f78d4338762c37f5f2043).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c1f47cd17fa5adf1f39f4e7056dd03feee1efce03094581131f2377323482c9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x07abad02b7a5ebc48632bcc9356ceb7dd9dafca276638a63646b8566a621afc9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0230264601ffdf29275b33ffaab51dfe9429f90880a69cd137da0c4d15f96c3c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1bc973054e51d905a0f168656497ca40a864414557ee289e717e5d66899aa0a9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2e1c22f964435008206c3157e86341edd249aff5c2d8421f2a6b22288f0a67fc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1224f38df67c5378121c1d5f461bbc509e8ea1598e46c9f7a70452bc2bba86b8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x02e4e69d8ba59e519280b4bd9ed0068fd7bfe8cd9dfeda1969d2989186cde20e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1f1eccc34aaba0137f5df81fc04ff3ee4f19ee364e653f076d47e9735d98018e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1672ad3d709a353974266c3039a9a7311424448032cd1819eacb8a4d4284f582).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x283e3fdc2c6e420c56f44af5192b4ae9cda6961f284d24991d2ed602df8c8fc7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1c2a3d120c550ecfd0db0957170fa013683751f8fdff59d6614fbd69ff394bcc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x216f84877aac6172f7897a7323456efe143a9a43773ea6f296cb6b8177653fbd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c0d272becf2a75764ba7e8e3e28d12bceaa47ea61ca59a411a1f51552f94788).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x16e34299865c0e28484ee7a74c454e9f170a5480abe0508fcb4a6c3d89546f43).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x175ceba599e96f5b375a232a6fb9cc71772047765802290f48cd939755488fc5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c7594440dc48c16fead9e1758b028066aa410bfbc354f54d8c5ffbb44a1ee32).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a3c29bc39f21bb5c466db7d7eb6fd8f760e20013ccf912c92479882d919fd8d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ccfdd906f3426e5c0986ea049b253400855d349074f5a6695c8eeabcd22e68f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x14f6bc81d9f186f62bdb475ce6c9411866a7a8a3fd065b3ce0e699b67dd9e796).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0962b82789fb3d129702ca70b2f6c5aacc099810c9c495c888edeb7386b97052).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a880af7074d18b3bf20c79de25127bc13284ab01ef02575afef0c8f6a31a86d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x10cba18419a6a332cd5e77f0211c154b20af2924fc20ff3f4c3012bb7ae9311b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x057e62a9a8f89b3ebdc76ba63a9eaca8fa27b7319cae3406756a2849f302f10d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x287c971de91dc0abd44adf5384b4988cb961303bbf65cff5afa0413b44280cee).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x21df3388af1687bbb3bca9da0cca908f1e562bc46d4aba4e6f7f7960e306891d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1be5c887d25bce703e25cc974d0934cd789df8f70b498fd83eff8b560e1682b3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x268da36f76e568fb68117175cea2cd0dd2cb5d42fda5acea48d59c2706a0d5c1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e17ab091f6eae50c609beaf5510ececc5d8bb74135ebd05bd06460cc26a5ed6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x04d727e728ffa0a67aee535ab074a43091ef62d8cf83d270040f5caa1f62af40).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ddbd7bf9c29341581b549762bc022ed33702ac10f1bfd862b15417d7e39ca6e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2790eb3351621752768162e82989c6c234f5b0d1d3af9b588a29c49c8789654b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e457c601a63b73e4471950193d8a570395f3d9ab8b2fd0984b764206142f9e9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x21ae64301dca9625638d6ab2bbe7135ffa90ecd0c43ff91fc4c686fc46e091b0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0379f63c8ce3468d4da293166f494928854be9e3432e09555858534eed8d350b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x002d56420359d0266a744a080809e054ca0e4921a46686ac8c9f58a324c35049).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x123158e5965b5d9b1d68b3cd32e10bbeda8d62459e21f4090fc2c5af963515a6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0be29fc40847a941661d14bbf6cbe0420fbb2b6f52836d4e60c80eb49cad9ec1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1ac96991dec2bb0557716142015a453c36db9d859cad5f9a233802f24fdf4c1a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1596443f763dbcc25f4964fc61d23b3e5e12c9fa97f18a9251ca3355bcb0627e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x12e0bcd3654bdfa76b2861d4ec3aeae0f1857d9f17e715aed6d049eae3ba3212).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0fc92b4f1bbea82b9ea73d4af9af2a50ceabac7f37154b1904e6c76c7cf964ba).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1f9c0b1610446442d6f2e592a8013f40b14f7c7722236f4f9c7e965233872762).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ebd74244ae72675f8cde06157a782f4050d914da38b4c058d159f643dbbf4d3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2cb7f0ed39e16e9f69a9fafd4ab951c03b0671e97346ee397a839839dccfc6d1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a9d6e2ecff022cc5605443ee41bab20ce761d0514ce526690c72bca7352d9bf).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2a115439607f335a5ea83c3bc44a9331d0c13326a9a7ba3087da182d648ec72f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x23f9b6529b5d040d15b8fa7aee3e3410e738b56305cd44f29535c115c5a4c060).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x05872c16db0f72a2249ac6ba484bb9c3a3ce97c16d58b68b260eb939f0e6e8a7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1300bdee08bb7824ca20fb80118075f40219b6151d55b5c52b624a7cdeddf6a7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x19b9b63d2f108e17e63817863a8f6c288d7ad29916d98cb1072e4e7b7d52b376).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x015bee1357e3c015b5bda237668522f613d1c88726b5ec4224a20128481b4f7f).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2953736e94bb6b9f1b9707a4f1615e4efe1e1ce4bab218cbea92c785b128ffd1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0b069353ba091618862f806180c0385f851b98d372b45f544ce7266ed6608dfc).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x304f74d461ccc13115e4e0bcfb93817e55aeb7eb9306b64e4f588ac97d81f429).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x15bbf146ce9bca09e8a33f5e77dfe4f5aad2a164a4617a4cb8ee5415cde913fc).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ab4dfe0c2742cde44901031487964ed9b8f4b850405c10ca9ff23859572c8c6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e32db320a044e3197f45f7649a19675ef5eedfea546dea9251de39f9639779a).into()),
-        ],
-    ]
-}
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const RC_BLS12_381_T_2: &[u8] = b"\
+    \x62\x67\xf5\x55\x6c\x88\x25\x73\x24\xc1\xc8\xb0\x0d\x58\x71\xb2\xeb\xa1\x3c\xc3\x9d\x72\xaa\x10\xdd\xe6\xb6\x9b\xc4\x4c\x41\xc7\
+    \x30\x34\x77\x23\x51\x14\x38\xa0\x85\x11\x81\x66\xc6\x8b\xf0\xc4\xf4\xab\x5c\x10\xa2\xc5\x5a\xdb\x5c\xf8\x7c\xc9\xe0\x30\xf6\x0f\
+    \x10\xdb\x85\x69\x65\xe4\x00\x38\xeb\x64\x27\x30\x31\x81\xe7\xb7\x43\x9f\x1a\x05\x1a\xa4\x63\x0c\x26\xcf\x86\xd0\xa0\x45\x1a\x4b\
+    \x5a\x3d\x2d\xcd\x54\x1e\x4f\xaa\xae\x7e\xb1\x43\xee\xc8\x47\xa0\xf6\x52\xb6\xdc\x1b\x92\xe3\xf3\x9e\xc2\x3c\x80\x8b\x3a\x5d\x63\
+    \x3b\x07\xf0\xff\x7e\xdc\xf9\x3b\x1d\xd0\x48\x7b\xc9\xfa\xb1\xc6\x90\x5f\x9c\xee\xe3\x8d\xcc\xe8\x3e\xfe\xb3\xa3\x20\x39\x85\x26\
+    \x40\xc7\x3c\x52\x4b\x9f\xd0\xfa\xb6\x31\x28\x17\x5b\xef\xe0\x7b\x5c\x63\xcc\xdd\xe9\xca\x10\xe1\xa3\x72\x05\xc9\x60\x7f\xdf\x8a\
+    \x3a\x93\x38\x61\xcf\x23\x75\x23\x76\xd9\x4d\xbb\x24\xb0\xf3\xc6\x16\x30\x78\x79\x28\x87\x5c\x07\x67\x2b\x68\xab\xfb\x91\x91\xe0\
+    \x71\xcc\x16\x5e\x20\x85\x70\xb2\xd5\xef\x81\xdb\x84\xe3\xc5\xe7\x14\xea\x4e\xdf\xb3\x6f\xc7\xfb\x11\xef\x65\xa6\x4b\x2d\x97\x55\
+    \x6c\x0d\xc9\xeb\x33\x2b\x5d\x96\x8b\xec\x8a\xd6\x8f\xe2\x4c\xe3\x40\x87\xea\x54\x09\x3f\x15\x36\x18\x43\x44\x75\xbc\xe4\x02\xf8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\xf5\xba\xfd\x33\x5d\xae\x5c\x86\x96\x7b\x11\xd5\xdc\xef\xb9\x86\xa5\x4c\x9d\x60\xd3\x5e\xb0\x6d\xc7\xa3\xfd\x77\x9b\x39\x06\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6e\x12\x84\x79\x18\xf0\x30\xf2\x62\x6c\x15\x0a\xb6\x9e\x4b\xe0\xf1\x3d\x20\x2a\xe1\xf8\xbc\x87\xea\x74\x32\x3e\x93\x37\x2e\x3b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x55\x65\xd4\x0e\x21\xd0\x59\xa2\x6d\xb2\x41\xca\x12\x5d\x93\x16\x28\x3e\xad\xf1\x44\xb1\x31\x8e\x60\x4e\x25\x3e\xea\xe1\xfe\x9a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x60\x8e\x01\xb4\x2d\x3d\xca\x09\xfe\xd9\xb5\x4e\xad\xaa\xba\x3e\x4c\xe6\xae\xfe\x92\xb0\xdc\x95\x4a\x0f\xa4\x68\x3a\x96\x78\xf2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\xbb\xe4\x34\xb2\x4f\x94\xe2\xc4\x0e\xd1\xf4\xf9\xbd\x7d\x17\xe5\xbe\x96\xc3\xae\xc1\x55\x79\xb3\x5f\xd8\x0f\x0f\x80\xde\x9e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\x1b\xe8\x11\xa8\xe7\x32\x20\xca\xb0\x1c\xe9\x81\xd4\x75\x52\x2c\x3d\x7d\xd9\xe2\x71\x6c\x3a\x2c\xf4\xdd\xd5\x41\x54\x68\x90\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x59\x97\xa3\xaf\xfb\x18\xf9\x42\x86\x8b\x86\xf8\xee\x10\xa6\x89\x66\xe9\x0b\xac\x7b\xbd\x8c\x65\xed\xe7\xe6\xe5\xef\x1f\x63\x20\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4d\x92\xe8\x6d\x27\x00\x41\x06\x1e\xec\x80\x27\x80\x79\xfc\xa7\x71\x49\x9d\xea\x5c\xcd\xc9\x96\x82\xa9\x53\xbb\x3a\x03\x8b\x8e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\x6c\x8c\x5c\xe2\x32\xb9\x31\x4f\x69\x4f\xc6\xa9\x68\x44\x6e\xa9\xda\xf7\xa4\x07\x9c\xe1\xa7\x5f\xcc\x95\x07\x41\xd6\x80\xbb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x67\x7e\x31\xe7\x84\x6d\x91\x31\xbd\xc3\x50\xea\xf1\x1a\x8f\xf9\x18\xdd\x25\x8d\xdd\x80\x04\x44\x42\x4a\xfa\xb3\x4d\xfd\xfe\x3d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4e\x7d\x7f\x85\xae\xfc\x11\x0b\x23\x35\x25\xee\x3e\x53\x85\x1a\xee\x7d\x32\x41\xe2\xa1\x32\x58\x5e\x0e\x25\x00\x5e\xee\x0b\x0e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\xa8\xb4\x53\x94\x88\xb7\xdd\xdc\x48\xc3\xa2\x26\xdb\xda\x31\x3f\x90\x6e\x10\x6f\x84\x41\x96\xd5\x50\x13\xd3\x21\x24\x4f\x13\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x50\x91\x51\x7b\x6a\x85\x78\x31\x08\x99\x9f\x8e\x6b\xda\x3c\x79\x3b\xef\x3f\x2e\x95\x89\x64\x1d\x26\x0b\xdf\xde\x8b\xde\xf0\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\x27\x03\xe5\xb3\x0f\x54\xd7\xf4\x14\xe9\x01\x80\x2d\x54\xf8\xc1\x4c\xd6\x35\x54\x15\xdf\x6e\x0f\x06\x3d\x16\xbe\xf9\xc4\x3a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x56\xf6\x90\x96\x81\x11\x48\xeb\x38\xee\xc1\x43\xd3\x25\x65\xc0\x77\xb3\xd1\xa4\xa4\x35\x1f\x2b\x45\x8f\x43\xb1\x65\x9d\x44\x95\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\x2d\x94\xd3\x8d\x1d\xed\x42\x8a\xfd\x06\x20\x08\xc5\x70\x9b\x43\xa6\x78\xf6\xba\x51\x8e\xc5\x63\x83\xe8\xff\xba\x47\x35\x04\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x30\xc6\x07\xbb\xa7\x33\x37\x23\xa4\xa4\x45\x77\x81\x9b\x7d\xb8\x2a\x24\x57\x4f\x6d\x13\xee\xe4\xc8\x56\xc1\xca\x3d\xe9\xc7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\xac\x5f\x59\x25\x6c\x50\x04\xdc\x10\x43\xc5\x3b\x23\x80\x0a\x3f\xba\xb5\x3e\xb1\xa8\x3f\x55\x10\x56\xf2\x27\xb5\x14\xb9\xf6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x90\xb9\x25\x23\xc9\x73\xf1\xc9\x5b\x94\x93\x7a\xfb\xb5\x79\x6d\x89\x48\x1e\x7a\x56\x32\x8b\x44\xba\xb5\xba\x81\xae\x42\xf3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x63\xb5\x9d\x97\xbc\x26\x9d\x13\x96\x4f\xb3\xe8\x77\x1d\x0a\xcc\x74\x9b\xc8\x3e\xb2\xf0\x37\x24\x84\xe2\x66\x14\x2b\xb8\xc0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x52\xd0\x4e\x5f\x14\xa3\xa0\x5f\x7a\x01\x26\x2d\xf9\xe6\x8c\x77\xfd\xf7\xe2\xbf\xb5\x6c\x8b\x25\x2d\x21\x40\xef\xdf\x09\x14\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5a\xa9\xb3\xb8\x08\x81\x2b\x28\x48\x57\xe8\x62\x28\x43\xa8\x71\x7f\xa5\xcb\x49\xb2\x17\x01\x7f\x31\xd7\x9e\x8d\x0f\x96\x3f\xc0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\x3d\x18\xfd\xbe\xb1\xd7\x7e\xc1\x30\x45\x39\xb0\x0e\x61\x88\x78\x6d\xbb\xc4\x43\x52\x69\xb4\xc6\x28\x13\x67\xf4\x26\x56\xe3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x47\x43\xe8\x60\xdf\x26\x9a\x85\xdd\x76\xfb\x99\xdb\xe9\xd8\x40\xeb\x66\x9d\xc8\x59\x75\x4b\x3f\x74\x80\x5e\x57\xba\x28\x8b\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6c\x32\xca\xc3\x94\x68\x25\xf8\x0a\x43\x4c\x5a\xb3\x97\xfc\x1a\x1c\x6a\x9b\xdf\xaa\xb5\x31\x75\xd4\xcf\x3d\x29\xdd\xb6\xcb\xc6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x33\x3b\x0e\xea\x5d\xa7\xed\x1e\x39\x59\xd1\x62\x80\xa3\x61\xaa\x77\xdd\x24\xec\xbf\xb2\x8e\x1b\x25\x83\xac\x4e\x98\x94\x30\x5c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3b\x50\x3f\xc3\x33\xb7\x95\xcc\xc0\xc5\xbb\x3a\xe2\x6b\x07\x7d\xc3\x74\x2c\xb7\x45\xec\x88\x21\x64\x8c\x5c\xe7\xeb\xd9\xdf\x18\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4f\xa5\x85\x31\x88\xd9\xf7\x28\xa1\x75\x32\xd9\x4b\xee\x6f\xb2\x8f\xee\x51\x03\x80\xa5\xd5\x09\x27\xc6\xc5\xb1\xce\x28\x34\x44\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5d\x2e\xd8\xa6\x60\x3a\x90\x5b\xac\x49\x0e\xbf\xb9\xe6\xc1\x8f\x0b\xc9\xda\x1b\xbc\x21\x73\x29\x1b\x18\xde\x6b\x61\x86\x11\x8f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x83\x0a\x53\x58\x4c\x55\x56\x26\x48\x52\xf0\x75\xc7\x8f\x7f\x9e\xb0\x68\x01\x6a\xe8\x8a\xf9\xcd\xa9\x33\xd6\xae\x52\xec\xa7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\x50\xf4\xd6\x78\x0a\xd2\x9a\xe6\x0e\x55\xf1\x35\xb9\xac\x80\xcc\xc7\xc8\x1e\x3a\xdd\x37\xdb\x27\x6c\x26\xf1\xa2\xb1\xb8\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6e\x3e\x95\x95\xf5\x92\x20\x59\x9e\x23\xe8\x30\x72\x8d\x4a\x0c\x4d\x62\x51\x5e\xc1\xed\x10\xb7\x24\x46\xcf\x4d\xf5\xb4\xc3\x08\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xd3\x31\x45\x55\xd6\xfa\xf2\x3e\xe9\x0c\xdb\x88\x4f\x1c\x46\x97\xeb\xe9\x8e\x3a\x45\x0a\x62\x4c\x4d\x89\x62\x33\xb9\x3c\xd5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x58\x4a\x40\x8d\x0f\x37\x05\x43\xb8\x41\x3f\xee\x70\xa0\x60\xa3\x94\xe5\x61\xf5\x04\xd8\x67\x9f\x7b\xec\xe4\xbf\x22\x2e\x41\x08\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\x9c\xd5\x34\x37\xb9\xfc\xbf\x74\x79\xc0\x0f\xcc\x21\x29\x57\x59\x07\x4c\xe9\xbd\x1b\xb1\xfb\xd3\x46\x02\x37\xae\xf4\x75\x9e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x56\xa9\xb5\x67\xbd\x06\x46\xef\xfd\x06\x08\xd7\x4d\x53\x79\x91\x13\x60\x98\xd9\xa0\x6a\xf6\xcb\x3f\xf8\xf0\x10\xef\xb5\x75\x78\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\x5f\xae\x2b\x00\xd9\x68\xb9\x31\x44\x1b\x37\x4e\x27\xba\x4d\x03\xb3\x06\xbd\x60\x2d\x48\x73\x16\x77\x16\x9e\x75\xa6\x7e\x8c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\x1c\xc2\x8e\x39\x0e\x64\xaa\x1d\x60\xed\xb9\x9c\x0a\xed\xa7\xc8\xc3\x2b\xdb\x01\xba\x11\xab\xba\xd5\x02\x6b\x46\xec\xcb\x27\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x48\x20\x00\x06\x75\xdf\x7c\x27\x6b\xea\xc4\x08\xfe\x2e\x85\x1e\x73\x4a\x70\x08\xae\x09\xbb\xcb\x3c\x96\xc7\x00\x24\xf7\x1b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x2f\xe1\x01\xa2\xb5\x2b\x53\x8b\x90\x2c\x6b\x2d\xc9\x92\xcb\x26\x6f\x76\x36\xe0\x5b\x0c\x06\x83\x85\xb5\xfa\x19\xe9\x71\x42\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\x9b\x79\x0b\x78\xc0\xe7\x92\x7c\x6a\x17\x8e\xf2\xf0\x0b\x86\x87\xfc\x7b\xd4\xf2\x1a\x9e\x02\x57\x85\x51\x53\x50\x02\xbc\x95\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\xd0\x92\x6c\xf5\x6b\xba\xae\xc6\x49\x15\x13\xd0\x8a\x99\x83\xf9\x4a\x91\x08\x52\xa7\xb4\xea\x4b\xd4\x22\x2b\x93\xe1\x4c\x10\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x43\x16\xb3\x9d\xd7\xd6\x5b\x1b\xb5\x75\x19\x81\x04\xd4\x09\xb1\x69\x23\x6a\x7a\xde\x37\x1f\x7a\xb1\x76\xfc\xba\xe7\x5a\x5f\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x54\x02\x76\xd6\x10\x41\xb9\x1f\x6e\xa3\x06\x8e\xc2\x60\xa9\x33\x8b\x6e\x3d\xa1\x5d\x93\x4e\x64\x8c\x24\xf3\x5a\xee\x04\xe5\x35\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x37\xaf\x61\x29\x00\xb8\x39\x97\x7b\x14\x63\x24\xc8\x47\x72\xc5\x8a\x4c\xcc\x0f\x64\x94\xcc\x05\x45\x71\x82\x7e\x74\xbf\xd2\xd3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\xf0\x0c\x93\xd5\x9e\xd1\x4c\x99\x11\xe5\xcb\x37\x81\xd7\x72\x37\x1e\x83\x22\x8e\x42\x67\xbb\xce\x11\xd0\x65\xc1\x95\x53\x38\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\xb4\x87\x79\xb0\xcf\x7f\xf2\xc1\x0f\xd9\xb9\x1a\x6f\xf7\xb7\xa9\x9f\x93\x5e\x96\x1a\x5a\x94\xaa\x38\xf9\xd4\xf7\x1c\x8b\x4c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x54\x0b\xf5\xbb\xe0\x1f\x28\x56\x3b\xcb\xe1\x1a\x2c\xe3\x46\xd8\x23\x1a\x2c\xdd\x0f\xe0\x76\x41\xf9\xfa\x89\xe5\xc2\x19\x78\xe3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x2b\x6c\x84\x7a\x6d\x23\x91\x2c\xb1\x0e\xcb\xe5\x0b\x53\x49\x1f\x67\xf7\x1e\x9b\x87\xa4\xa3\x04\x46\xf2\x21\x80\x17\x87\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\xb3\x4a\xdb\xe7\x7b\x8f\x1e\x57\xa3\x70\xe4\xfd\x62\x60\x71\xee\xa7\x4b\x3f\x0b\x66\x64\x4a\x62\x9e\xfa\xa0\xe9\x64\x56\xc0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\x83\xe4\x3e\xf1\x18\xc9\x00\x46\xb1\xbd\xbe\xab\x8d\xd5\xcd\xca\xb6\x32\x80\x7c\x2c\xd0\xdc\x91\x47\xcb\xc5\xb7\x08\x4b\xe8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\xc6\xfa\x41\xb4\x1b\x67\x2d\x90\x05\x46\x87\x20\x91\x81\x30\xb6\x42\x56\x74\x62\xa3\xd5\x57\xa5\x95\xd4\xdc\x6c\x56\xf2\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\xf8\x1a\x15\x31\x99\xa7\x51\xa1\x11\xb8\xf5\x21\x2c\xfc\x5b\xf8\x2a\xac\xf0\x28\x7d\x03\xe1\x86\x4f\x8e\x57\x13\xfe\x4a\x17\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x17\x30\x75\x87\xa6\x75\xf4\xec\xd7\x3a\x54\xa7\xb2\x06\x16\x2d\x75\x1c\xab\xf3\xd9\xfd\x00\x7b\xcc\xa4\xde\x2c\x6f\x06\x49\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x47\xbe\x94\xc5\x15\x17\x8c\x79\x74\xa2\x45\x62\x4b\x64\x2b\xb1\xae\x6e\x2d\x4e\x16\x82\x08\x7e\x36\x2d\x7f\x98\xbc\x95\x3f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6e\x69\x0b\x95\x6e\x00\xb9\xe3\x39\xde\xc4\x9d\x67\x55\x86\xf6\x61\xf9\xb0\x81\xee\x3f\xa7\x69\x6d\x73\x97\x76\x58\xaa\x6f\xea\
+    \x66\x0b\x85\xbc\x22\xde\x06\xd4\x76\xc4\x7b\xf0\x84\xad\x43\x6f\x59\x87\x4f\x1d\x63\x0c\x0f\x5c\x91\xfb\xef\x51\xd5\xe7\x38\xc5\
+    \x32\xbf\x3d\x45\x1b\x69\xdd\xe0\x75\xfc\x37\x0e\xaa\x8c\x1b\x77\xb5\xc0\xbc\x2a\xab\x1c\x7b\x46\xda\x7e\xf9\xd1\x84\x0b\x04\x19\
+    \x73\x92\x4b\x40\xbe\xaa\x9c\x1c\xe4\x07\x4c\x21\x54\xd1\xaf\x4d\x65\x8c\x09\x39\x5a\x56\x8b\x99\xb2\xfb\xcc\x3b\x56\x85\xe8\x10\
+    \x17\xcb\xb3\xee\x0a\xdc\xb9\xd9\x77\xe9\x6e\x91\x52\xb3\x60\x42\x92\x52\x44\xfd\xd0\xaa\x18\x4c\x7a\x89\xa5\x8a\x2d\xc4\x00\x97\
+    \x29\xd7\x6a\x82\x1e\x32\x20\x77\x5c\x55\x2f\x6b\x59\x77\xab\x94\x95\x6e\x52\xb8\xda\xc3\x6e\xf8\x8a\xce\x05\x0d\x55\x37\x66\xa3\
+    \x62\xb1\xa6\xc0\x6a\xb2\x68\x81\xa1\xfe\x57\xec\xea\xc5\x6b\x5a\xec\x0b\x96\xda\x72\x11\x55\x7f\x4e\x27\xec\x24\x29\x6d\x7d\xb6\
+    \x0d\xfc\x47\x41\x51\xe5\xc6\x05\xa6\x93\xa5\x1a\xe8\x22\x7c\xc0\xa9\x9f\xdc\x45\x24\xfc\x28\x10\xc6\xed\xa9\x03\x5d\x04\x33\x4d\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bn254_t_3(e: &Env) -> Vec<Vec<U256>> {
-    vec![e,
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d066a255517b7fd8bddd3a93f7804ef7f8fcde48bb4c37a59a09a1a97052816).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x29daefb55f6f2dc6ac3f089cebcc6120b7c6fef31367b68eb7238547d32c1610).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1f2cb1624a78ee001ecbd88ad959d7012572d76f08ec5c4f9e8b7ad7b0b4e1d1).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0aad2e79f15735f2bd77c0ed3d14aa27b11f092a53bbc6e1db0672ded84f31e5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2252624f8617738cd6f661dd4094375f37028a98f1dece66091ccf1595b43f28).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a24913a928b38485a65a84a291da1ff91c20626524b2b87d49f4f2c9018d735).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x22fc468f1759b74d7bfc427b5f11ebb10a41515ddff497b14fd6dae1508fc47a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1059ca787f1f89ed9cd026e9c9ca107ae61956ff0b4121d5efd65515617f6e4d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x02be9473358461d8f61f3536d877de982123011f0bf6f155a45cbbfae8b981ce).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ec96c8e32962d462778a749c82ed623aba9b669ac5b8736a1ff3a441a5084a4).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x292f906e073677405442d9553c45fa3f5a47a7cdb8c99f9648fb2e4d814df57e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x274982444157b86726c11b9a0f5e39a5cc611160a394ea460c63f0b2ffe5657e).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a1d063e54b1e764b63e1855bff015b8cedd192f47308731499573f23597d4b5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x26abc66f3fdf8e68839d10956259063708235dccc1aa3793b91b002c5b257c37).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c7c64a9d887385381a578cfed5aed370754427aabca92a70b3c2b12ff4d7be8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1cf5998769e9fab79e17f0b6d08b2d1eba2ebac30dc386b0edd383831354b495).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f5e3a8566be31b7564ca60461e9e08b19828764a9669bc17aba0b97e66b0109).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x18df6a9d19ea90d895e60e4db0794a01f359a53a180b7d4b42bf3d7a531c976e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x04f7bf2c5c0538ac6e4b782c3c6e601ad0ea1d3a3b9d25ef4e324055fa3123dc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x29c76ce22255206e3c40058523748531e770c0584aa2328ce55d54628b89ebe6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x198d425a45b78e85c053659ab4347f5d65b1b8e9c6108dbe00e0e945dbc5ff15).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x25ee27ab6296cd5e6af3cc79c598a1daa7ff7f6878b3c49d49d3a9a90c3fdf74).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x138ea8e0af41a1e024561001c0b6eb1505845d7d0c55b1b2c0f88687a96d1381).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x306197fb3fab671ef6e7c2cba2eefd0e42851b5b9811f2ca4013370a01d95687).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a0c7d52dc32a4432b66f0b4894d4f1a21db7565e5b4250486419eaf00e8f620).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2b46b418de80915f3ff86a8e5c8bdfccebfbe5f55163cd6caa52997da2c54a9f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x12d3e0dc0085873701f8b777b9673af9613a1af5db48e05bfb46e312b5829f64).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x263390cf74dc3a8870f5002ed21d089ffb2bf768230f648dba338a5cb19b3a1f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0a14f33a5fe668a60ac884b4ca607ad0f8abb5af40f96f1d7d543db52b003dcd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x28ead9c586513eab1a5e86509d68b2da27be3a4f01171a1dd847df829bc683b9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1c6ab1c328c3c6430972031f1bdb2ac9888f0ea1abe71cffea16cda6e1a7416c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1fc7e71bc0b819792b2500239f7f8de04f6decd608cb98a932346015c5b42c94).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x03e107eb3a42b2ece380e0d860298f17c0c1e197c952650ee6dd85b93a0ddaa8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2d354a251f381a4669c0d52bf88b772c46452ca57c08697f454505f6941d78cd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x094af88ab05d94baf687ef14bc566d1c522551d61606eda3d14b4606826f794b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x19705b783bf3d2dc19bcaeabf02f8ca5e1ab5b6f2e3195a9d52b2d249d1396f7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x09bf4acc3a8bce3f1fcc33fee54fc5b28723b16b7d740a3e60cef6852271200e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1803f8200db6013c50f83c0c8fab62843413732f301f7058543a073f3f3b5e4e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f80afb5046244de30595b160b8d1f38bf6fb02d4454c0add41f7fef2faf3e5c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x126ee1f8504f15c3d77f0088c1cfc964abcfcf643f4a6fea7dc3f98219529d78).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x23c203d10cfcc60f69bfb3d919552ca10ffb4ee63175ddf8ef86f991d7d0a591).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2a2ae15d8b143709ec0d09705fa3a6303dec1ee4eec2cf747c5a339f7744fb94).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x07b60dee586ed6ef47e5c381ab6343ecc3d3b3006cb461bbb6b5d89081970b2b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x27316b559be3edfd885d95c494c1ae3d8a98a320baa7d152132cfe583c9311bd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d5c49ba157c32b8d8937cb2d3f84311ef834cc2a743ed662f5f9af0c0342e76).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2f8b124e78163b2f332774e0b850b5ec09c01bf6979938f67c24bd5940968488).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e6843a5457416b6dc5b7aa09a9ce21b1d4cba6554e51d84665f75260113b3d5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x11cdf00a35f650c55fca25c9929c8ad9a68daf9ac6a189ab1f5bc79f21641d4b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x21632de3d3bbc5e42ef36e588158d6d4608b2815c77355b7e82b5b9b7eb560bc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0de625758452efbd97b27025fbd245e0255ae48ef2a329e449d7b5c51c18498a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2ad253c053e75213e2febfd4d976cc01dd9e1e1c6f0fb6b09b09546ba0838098).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d6b169ed63872dc6ec7681ec39b3be93dd49cdd13c813b7d35702e38d60b077).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1660b740a143664bb9127c4941b67fed0be3ea70a24d5568c3a54e706cfef7fe).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0065a92d1de81f34114f4ca2deef76e0ceacdddb12cf879096a29f10376ccbfe).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1f11f065202535987367f823da7d672c353ebe2ccbc4869bcf30d50a5871040d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x26596f5c5dd5a5d1b437ce7b14a2c3dd3bd1d1a39b6759ba110852d17df0693e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x16f49bc727e45a2f7bf3056efcf8b6d38539c4163a5f1e706743db15af91860f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1abe1deb45b3e3119954175efb331bf4568feaf7ea8b3dc5e1a4e7438dd39e5f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e426ccab66984d1d8993a74ca548b779f5db92aaec5f102020d34aea15fba59).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e7c30c2e2e8957f4933bd1942053f1f0071684b902d534fa841924303f6a6c6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0812a017ca92cf0a1622708fc7edff1d6166ded6e3528ead4c76e1f31d3fc69d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x21a5ade3df2bc1b5bba949d1db96040068afe5026edd7a9c2e276b47cf010d54).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x01f3035463816c84ad711bf1a058c6c6bd101945f50e5afe72b1a5233f8749ce).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0b115572f038c0e2028c2aafc2d06a5e8bf2f9398dbd0fdf4dcaa82b0f0c1c8b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1c38ec0b99b62fd4f0ef255543f50d2e27fc24db42bc910a3460613b6ef59e2f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1c89c6d9666272e8425c3ff1f4ac737b2f5d314606a297d4b1d0b254d880c53e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x03326e643580356bf6d44008ae4c042a21ad4880097a5eb38b71e2311bb88f8f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x268076b0054fb73f67cee9ea0e51e3ad50f27a6434b5dceb5bdde2299910a4c9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1acd63c67fbc9ab1626ed93491bda32e5da18ea9d8e4f10178d04aa6f8747ad0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x19f8a5d670e8ab66c4e3144be58ef6901bf93375e2323ec3ca8c86cd2a28b5a5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1c0dc443519ad7a86efa40d2df10a011068193ea51f6c92ae1cfbb5f7b9b6893).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x14b39e7aa4068dbe50fe7190e421dc19fbeab33cb4f6a2c4180e4c3224987d3d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d449b71bd826ec58f28c63ea6c561b7b820fc519f01f021afb1e35e28b0795e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1ea2c9a89baaddbb60fa97fe60fe9d8e89de141689d1252276524dc0a9e987fc).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0478d66d43535a8cb57e9c1c3d6a2bd7591f9a46a0e9c058134d5cefdb3c7ff1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x19272db71eece6a6f608f3b2717f9cd2662e26ad86c400b21cde5e4a7b00bebe).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x14226537335cab33c749c746f09208abb2dd1bd66a87ef75039be846af134166).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x01fd6af15956294f9dfe38c0d976a088b21c21e4a1c2e823f912f44961f9a9ce).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x18e5abedd626ec307bca190b8b2cab1aaee2e62ed229ba5a5ad8518d4e5f2a57).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0fc1bbceba0590f5abbdffa6d3b35e3297c021a3a409926d0e2d54dc1c84fda6).into()),
-        ],
-    ]
-}
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const RC_BLS12_381_T_3: &[u8] = b"\
+    \x6f\x00\x7a\x55\x11\x56\xb3\xa4\x49\xe4\x49\x36\xb7\xc0\x93\x64\x4a\x0e\xd3\x3f\x33\xea\xcc\xc6\x28\xe9\x42\xe8\x36\xc1\xa8\x75\
+    \x36\x0d\x74\x70\x61\x1e\x47\x3d\x35\x3f\x62\x8f\x76\xd1\x10\xf3\x4e\x71\x16\x2f\x31\x00\x3b\x70\x57\x53\x8c\x25\x96\x42\x63\x03\
+    \x4b\x5f\xec\x3a\xa0\x73\xdf\x44\x01\x90\x91\xf0\x07\xa4\x4c\xa9\x96\x48\x49\x65\xf7\x03\x6d\xce\x3e\x9d\x09\x77\xed\xcd\xc0\xf6\
+    \x67\xcf\x18\x68\xaf\x63\x96\xc0\xb8\x4c\xce\x71\x5e\x53\x9f\x84\x9e\x06\xcd\x1c\x38\x3a\xc5\xb0\x61\x00\xc7\x6b\xcc\x97\x3a\x11\
+    \x55\x5d\xb4\xd1\xdc\xed\x81\x9f\x5d\x3d\xe7\x0f\xde\x83\xf1\xc7\xd3\xe8\xc9\x89\x68\xe5\x16\xa2\x3a\x77\x1a\x5c\x9c\x82\x57\xaa\
+    \x2b\xab\x94\xd7\xae\x22\x2d\x13\x5d\xc3\xc6\xc5\xfe\xbf\xaa\x31\x49\x08\xac\x2f\x12\xeb\xe0\x6f\xbd\xb7\x42\x13\xbf\x63\x18\x8b\
+    \x66\xf4\x4b\xe5\x29\x66\x82\xc4\xfa\x78\x82\x79\x9d\x6d\xd0\x49\xb6\xd7\xd2\xc9\x50\xcc\xf9\x8c\xf2\xe5\x0d\x6d\x1e\xbb\x77\xc2\
+    \x15\x0c\x93\xfe\xf6\x52\xfb\x1c\x2b\xf0\x3e\x1a\x29\xaa\x87\x1f\xef\x77\xe7\xd7\x36\x76\x6c\x5d\x09\x39\xd9\x27\x53\xcc\x5d\xc8\
+    \x32\x70\x66\x1e\x68\x92\x8b\x3a\x95\x5d\x55\xdb\x56\xdc\x57\xc1\x03\xcc\x0a\x60\x14\x1e\x89\x4e\x14\x25\x9d\xce\x53\x77\x82\xb2\
+    \x07\x3f\x11\x6f\x04\x12\x2e\x25\xa0\xb7\xaf\xe4\xe2\x05\x72\x99\xb4\x07\xc3\x70\xf2\xb5\xa1\xcc\xce\x9f\xb9\xff\xc3\x45\xaf\xb3\
+    \x40\x9f\xda\x22\x55\x8c\xfe\x4d\x3d\xd8\xdc\xe2\x4f\x69\xe7\x6f\x8c\x2a\xae\xb1\xdd\x0f\x09\xd6\x5e\x65\x4c\x71\xf3\x2a\xa2\x3f\
+    \x2a\x32\xec\x5c\x4e\xe5\xb1\x83\x7a\xff\xd0\x9c\x1f\x53\xf5\xfd\x55\xc9\xcd\x20\x61\xae\x93\xca\x8e\xba\xd7\x6f\xc7\x15\x54\xd8\
+    \x58\x48\xeb\xeb\x59\x23\xe9\x25\x55\xb7\x12\x4f\xff\xba\x5d\x6b\xd5\x71\xc6\xf9\x84\x19\x5e\xb9\xcf\xd3\xa3\xe8\xeb\x55\xb1\xd4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x03\x26\xee\x03\x9d\xf1\x9e\x65\x1e\x2c\xfc\x74\x06\x28\xca\x63\x4d\x24\xfc\x6e\x25\x59\xf2\x2d\x8c\xcb\xe2\x92\xef\xee\xad\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\xc6\x64\x2a\xc6\x33\xbc\x66\xdc\x10\x0f\xe7\xfc\xfa\x54\x91\x8a\xf8\x95\xbc\xe0\x12\xf1\x82\xa0\x68\xfc\x37\xc1\x82\xe2\x74\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\xdf\xd8\xb0\x14\x01\xc7\x0a\xd2\x7f\x57\x39\x69\x89\x12\x9d\x71\x0e\x1f\xb6\xab\x97\x6a\x45\x9c\xa1\x86\x82\xe2\x6d\x7f\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\x1b\x9b\xa6\x98\x3b\xcf\x9f\x05\xfe\x47\x94\xad\xb4\x4a\x30\x87\x9b\xf8\x28\x96\x62\xe1\xf5\x7d\x90\xf6\x72\x41\x4e\x8a\x4a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x2a\x14\xc6\x2f\x9a\x89\xb8\x14\xb9\xd6\xa9\xc8\x4d\xd6\x78\xf4\xf6\xfb\x3f\x90\x54\xd3\x73\xc8\x32\xd8\x24\x26\x1a\x35\xea\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x19\x3e\x0f\x76\xde\x58\x6b\x2a\xf6\xf7\x9e\x31\x27\xfe\xea\xac\x0a\x1f\xc7\x1e\x2c\xf0\xc0\xf7\x98\x24\x66\x7b\x5b\x6b\xec\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x46\xef\xd8\xa9\xa2\x62\xd6\xd8\xfd\xc9\xca\x5c\x04\xb0\x98\x2f\x24\xdd\xcc\x6e\x98\x63\x88\x5a\x6a\x73\x2a\x39\x06\xa0\x7b\x95\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x50\x97\x17\xe0\xc2\x00\xe3\xc9\x2d\x8d\xca\x29\x73\xb3\xdb\x45\xf0\x78\x82\x94\x35\x1a\xd0\x7a\xe7\x5c\xbb\x78\x06\x93\xa7\x98\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x72\x99\xb2\x84\x64\xa8\xc9\x4f\xb9\xd4\xdf\x61\x38\x0f\x39\xc0\xdc\xa9\xc2\xc0\x14\x11\x87\x89\xe2\x27\x25\x28\x20\xf0\x1b\xfc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\x4c\xa3\xcc\x4a\x85\xd7\x3b\x81\x69\x6e\xf1\x10\x4e\x67\x4f\x4f\xef\xf8\x29\x84\x99\x0f\xf8\x5d\x0b\xf5\x8d\xc8\xa4\xaa\x94\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xba\xf2\xb3\x71\xda\xc6\xa8\x1d\x04\x53\x41\x6d\x3e\x23\x5c\xb8\xd9\xe2\xd4\xf3\x14\xf4\x6f\x61\x98\x78\x5f\x0c\xd6\xb9\xaf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\x5b\x27\x77\x69\x2c\x20\x5b\x0e\x6c\x49\xd0\x61\xb6\xb5\xf4\x29\x3c\x4a\xb0\x38\xfd\xbb\xdc\x34\x3e\x07\x61\x0f\x3f\xed\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x56\xae\x7c\x7a\x52\x93\xbd\xc2\x3e\x85\xe1\x69\x8c\x81\xc7\x7f\x8a\xd8\x8c\x4b\x33\xa5\x78\x04\x37\xad\x04\x7c\x6e\xdb\x59\xba\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\x9b\xdb\xba\x3d\xd3\x4b\xff\xaa\x30\x53\x5b\xdd\x74\x9a\x7e\x06\xa9\xad\xb0\xc1\xe6\xf9\x62\xf6\x0e\x97\x1b\x8d\x73\xb0\x4f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\xe1\x18\x86\xb1\x80\x11\xca\x8b\xd5\xba\xe3\x69\x69\x29\x9f\xde\x40\xfb\xe2\x6d\x04\x7b\x05\x03\x5a\x13\x66\x1f\x22\x41\x8b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\x07\xde\x17\x80\xb8\xa7\x0d\x0d\x5b\x4a\x3f\x18\x41\xdc\xd8\x2a\xb9\x39\x5c\x44\x9b\xe9\x47\xbc\x99\x88\x84\xba\x96\xa7\x21\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x69\xf1\x85\x4d\x20\xca\x0c\xbb\xdb\x63\xdb\xd5\x2d\xad\x16\x25\x04\x40\xa9\x9d\x6b\x8a\xf3\x82\x5e\x4c\x2b\xb7\x49\x25\xca\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5d\xc9\x87\x31\x8e\x6e\x59\xc1\xaf\xb8\x7b\x65\x5d\xd5\x8c\xc1\xd2\x2e\x51\x3a\x05\x83\x8c\xd4\x58\x5d\x04\xb1\x35\xb9\x57\xca\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x48\xb7\x25\x75\x85\x71\xc9\xdf\x6c\x01\xdc\x63\x9a\x85\xf0\x72\x97\x69\x6b\x1b\xb6\x78\x63\x3a\x29\xdc\x91\xde\x95\xef\x53\xf6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5e\x56\x5e\x08\xc0\x82\x10\x99\x25\x6b\x56\x49\x0e\xae\xe1\xd5\x73\xaf\xd1\x0b\xb6\xd1\x7d\x13\xca\x4e\x5c\x61\x1b\x2a\x37\x18\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2e\xb1\xb2\x54\x17\xfe\x17\x67\x0d\x13\x5d\xc6\x39\xfb\x09\xa4\x6c\xe5\x11\x35\x07\xf9\x6d\xe9\x81\x6c\x05\x94\x22\xdc\x70\x5e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\x5c\xd0\xa0\x64\x3c\xfb\x98\x8c\x24\xcb\x44\xc3\xfa\xb4\x8a\xff\x36\xc6\x61\xd2\x6c\xc4\x2d\xb8\xb1\xbd\xf4\x95\x3b\xd8\x2c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\xca\x29\x3f\x7b\x2c\x46\x2d\x06\x6d\x73\x78\xb9\x99\x86\x8b\xbb\x57\xdd\xf1\x4e\x0f\x95\x8a\xde\x80\x16\x12\x31\x1d\x04\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x41\x47\x40\x0d\x8e\x1a\xac\xcf\x31\x1a\x6b\x5b\x76\x20\x11\xab\x3e\x45\x32\x6e\x4d\x4b\x9d\xe2\x69\x92\x81\x6b\x99\xc5\x28\xac\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6b\x0d\xb7\xdc\xcc\x4b\xa1\xb2\x68\xf6\xbd\xcc\x4d\x37\x28\x48\xd4\xa7\x29\x76\xc2\x68\xea\x30\x51\x9a\x2f\x73\xe6\xdb\x4d\x55\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\xbf\x1b\x93\xc4\xc7\xe0\x1a\x2a\x83\x0a\xa1\x62\x41\x2c\xd9\x0f\x16\x0b\xf9\xf7\x1e\x96\x7f\xf5\x20\x9d\x14\xb2\x48\x20\xca\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4b\x43\x1c\xd9\xef\xed\xbc\x94\xcf\x1e\xca\x6f\x9e\x9c\x18\x39\xd0\xe6\x6a\x8b\xff\xa8\xc8\x46\x4c\xac\x81\xa3\x9d\x3c\xf8\xf1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x35\xb4\x1a\x7a\xc4\xf3\xc5\x71\xa2\x4f\x84\x56\x36\x9c\x85\xdf\xe0\x3c\x03\x54\xbd\x8c\xfd\x38\x05\xc8\x6f\x2e\x7d\xc2\x93\xc5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3b\x14\x80\x08\x05\x23\xc4\x39\x43\x59\x27\x99\x48\x49\xbe\xa9\x64\xe1\x4d\x3b\xeb\x2d\xdd\xde\x72\xac\x15\x6a\xf4\x35\xd0\x9e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xc6\x81\x00\x31\xdc\x1b\x0d\x49\x50\x85\x6d\xc9\x07\xd5\x75\x08\xe2\x86\x44\x2a\x2d\x3e\xb2\x27\x16\x18\xd8\x74\xb1\x4c\x6d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6f\x41\x41\xc8\x40\x1c\x5a\x39\x5b\xa6\x79\x0e\xfd\x71\xc7\x0c\x04\xaf\xea\x06\xc3\xc9\x28\x26\xbc\xab\xdd\x5c\xb5\x47\x7d\x51\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xbd\xbb\xed\xa1\xbd\xe8\xc1\x05\x96\x18\xe2\xaf\xd2\xef\x99\x9e\x51\x7a\xa9\x3b\x78\x34\x1d\x91\xf3\x18\xc0\x9f\x0c\xb5\x66\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x39\x2a\x4a\x87\x58\xe0\x6e\xe8\xb9\x5f\x33\xc2\x5d\xde\x8a\xc0\x2a\x5e\xd0\xa2\x7b\x61\x92\x6c\xc6\x31\x34\x87\x07\x3f\x7f\x7b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x2a\x55\x87\x8a\x08\x44\x2b\x9a\xa6\x11\x1f\x4d\xe0\x09\x48\x5e\x6a\x6f\xd1\x5d\xb8\x93\x65\xe7\xbb\xce\xf0\x2e\xb5\x86\x6c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x63\x1e\xc1\xd6\xd2\x8d\xd9\xe8\x24\xee\x89\xa3\x07\x30\xae\xf7\xab\x46\x3a\xcf\xc9\xd1\x84\xb3\x55\xaa\x05\xfd\x69\x38\xea\xb5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4e\xb6\xfd\xa1\x0f\xd0\xfb\xde\x02\xc7\x44\x9b\xfb\xdd\xc3\x5b\xcd\x82\x25\xe7\xe5\xc3\x83\x3a\x08\x18\xa1\x00\x40\x9d\xc6\xf2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x5b\x30\x8b\x0c\xf0\x2c\xdf\xef\xa1\x3c\x4e\x60\xe2\x62\x39\xa6\xeb\xba\x01\x16\x94\xdd\x12\x9b\x92\x5b\x3c\x5b\x21\xe0\xe2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x54\x9f\xc6\xaf\x2f\x3b\x72\xdd\x5d\x29\x3d\x72\xe2\xe5\xf2\x44\xdf\xf4\x2f\x18\xb4\x6c\x56\xef\x38\xc5\x7c\x31\x16\x73\xac\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x42\x33\x26\x77\xff\x35\x9c\x5e\x8d\xb8\x36\xd9\xf5\xfb\x54\x82\x2e\x39\xbd\x5e\x22\x34\x0b\xb9\xba\x97\x5b\xa1\xa9\x2b\xe3\x82\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\xd7\xd2\xc0\xb4\x49\xe5\x17\x9b\xc5\xcc\xc3\xb4\x4c\x60\x75\xd9\x84\x9b\x56\x10\x46\x5f\x09\xea\x72\x5d\xdc\x97\x72\x3a\x94\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x64\xc2\x0f\xb9\x0d\x7a\x00\x38\x31\x75\x7c\xc4\xc6\x22\x6f\x6e\x49\x85\xfc\x9e\xcb\x41\x6b\x9f\x68\x4c\xa0\x35\x1d\x96\x79\x04\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x59\xcf\xf4\x0d\xe8\x3b\x52\xb4\x1b\xc4\x43\xd7\x97\x95\x10\xd7\x71\xc9\x40\xb9\x75\x8c\xa8\x20\xfe\x73\xb5\xc8\xd5\x58\x09\x34\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x53\xdb\x27\x31\x73\x0c\x39\xb0\x4e\xdd\x87\x5f\xe3\xb7\xc8\x82\x80\x82\x85\xcd\xbc\x62\x1d\x7a\xf4\xf8\x0d\xd5\x3e\xbb\x71\xb0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\x10\xbb\x7a\x82\xaf\xce\x39\xfa\x69\xc3\xa2\xad\x52\xf7\x6d\x76\x39\x82\x65\x34\x42\x03\x11\x9b\x71\x26\xd9\xb4\x68\x60\xdf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x56\x1b\x60\x12\xd6\x66\xbf\xe1\x79\xc4\xdd\x7f\x84\xcd\xd1\x53\x15\x96\xd3\xaa\xc7\xc5\x70\x0c\xeb\x31\x9f\x91\x04\x6a\x63\xc9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x1e\x75\x05\xeb\xd9\x1d\x2f\xc7\x9c\x2d\xf7\xdc\x98\xa3\xbe\xd1\xb3\x69\x68\xba\x04\x05\xc0\x90\xd2\x7f\x6a\x00\xb7\xdf\xc8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x31\x3f\xaf\x0d\x3f\x61\x87\x53\x7a\x74\x97\xa3\xb4\x3f\x46\x79\x7f\xd6\xe3\xf1\x8e\xb1\xca\xff\x45\x77\x56\xb8\x19\xbb\x20\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3a\x5c\xbb\x6d\xe4\x50\xb4\x81\xfa\x3c\xa6\x1c\x0e\xd1\x5b\xc5\x5c\xad\x11\xeb\xf0\xf7\xce\xb8\xf0\xbc\x3e\x73\x2e\xcb\x26\xf6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x68\x1d\x93\x41\x1b\xf8\xce\x63\xf6\x71\x6a\xef\xbd\x0e\x24\x50\x64\x54\xc0\x34\x8e\xe3\x8f\xab\xeb\x26\x47\x02\x71\x4c\xcf\x94\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x51\x78\xe9\x40\xf5\x00\x04\x31\x26\x46\xb4\x36\x72\x7f\x0e\x80\xa7\xb8\xf2\xe9\xee\x1f\xdc\x67\x7c\x48\x31\xa7\x67\x27\x77\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3d\xab\x54\xbc\x9b\xef\x68\x8d\xd9\x20\x86\xe2\x53\xb4\x39\xd6\x51\xba\xa6\xe2\x0f\x89\x2b\x62\x86\x55\x27\xcb\xca\x91\x59\x82\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4b\x3c\xe7\x53\x11\x21\x8f\x9a\xe9\x05\xf8\x4e\xaa\x5b\x2b\x38\x18\x44\x8b\xbf\x39\x72\xe1\xaa\xd6\x9d\xe3\x21\x00\x90\x15\xd0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\xdb\xfb\x42\xb9\x79\x88\x4d\xe2\x80\xd3\x16\x70\x12\x3f\x74\x4c\x24\xb3\x3b\x41\x0f\xef\xd4\x36\x80\x45\xac\xf2\xb7\x1a\xe3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\x8d\x6b\x46\x08\xaa\xe8\x10\xc6\xf0\x39\xea\x19\x73\xa6\x3e\xb8\xd2\xde\x72\xe3\xd2\xc9\xec\xa7\xfc\x32\xd2\x2f\x18\xb9\xd3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4c\x5c\x25\x45\x89\xa9\x2a\x36\x08\x4a\x57\xd3\xb1\xd9\x64\x27\x8a\xcc\x7e\x4f\xe8\xf6\x9f\x29\x55\x95\x4f\x27\xa7\x9c\xeb\xef\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6c\xba\xc5\xe1\x70\x09\x84\xeb\xc3\x2d\xa1\x5b\x4b\xb9\x68\x3f\xaa\xba\xb5\x5f\x67\xcc\xc4\xf7\x1d\x95\x60\xb3\x47\x5a\x77\xeb\
+    \x46\x03\xc4\x03\xbb\xfa\x9a\x17\x73\x8a\x5c\x62\x78\xea\xab\x1c\x37\xec\x30\xb0\x73\x7a\xa2\x40\x9f\xc4\x89\x80\x69\xeb\x98\x3c\
+    \x68\x94\xe7\xe2\x2b\x2c\x1d\x5c\x70\xa7\x12\xa6\x34\x5a\xe6\xb1\x92\xa9\xc8\x33\xa9\x23\x4c\x31\xc5\x6a\xac\xd1\x6b\xc2\xf1\x00\
+    \x5b\xe2\xcb\xbc\x44\x05\x3a\xd0\x8a\xfa\x4d\x1e\xab\xc7\xf3\xd2\x31\xee\xa7\x99\xb9\x3f\x22\x6e\x90\x5b\x7d\x4d\x65\xc5\x8e\xbb\
+    \x58\xe5\x5f\x28\x7b\x45\x3a\x98\x08\x62\x4a\x8c\x2a\x35\x3d\x52\x8d\xa0\xf7\xe7\x13\xa5\xc6\xd0\xd7\x71\x1e\x47\x06\x3f\xa6\x11\
+    \x36\x6e\xbf\xaf\xa3\xad\x38\x1c\x0e\xe2\x58\xc9\xb8\xfd\xfc\xcd\xb8\x68\xa7\xd7\xe1\xf1\xf6\x9a\x2b\x5d\xfc\xc5\x57\x25\x55\xdf\
+    \x45\x76\x6a\xb7\x28\x96\x8c\x64\x2f\x90\xd9\x7c\xcf\x55\x04\xdd\xc1\x05\x18\xa8\x19\xeb\xbc\xc4\xd0\x9c\x3f\x5d\x78\x4d\x67\xce\
+    \x39\x67\x8f\x65\x51\x2f\x1e\xe4\x04\xdb\x30\x24\xf4\x1d\x3f\x56\x7e\xf6\x6d\x89\xd0\x44\xd0\x22\xe6\xbc\x22\x9e\x95\xbc\x76\xb1\
+    \x46\x3a\xed\x1d\x2f\x1f\x95\x5e\x30\x78\xbe\x5b\xf7\xbf\xc4\x6f\xc0\xeb\x8c\x51\x55\x19\x06\xa8\x86\x8f\x18\xff\xae\x30\xcf\x4f\
+    \x21\x66\x8f\x01\x6a\x80\x63\xc0\xd5\x8b\x77\x50\xa3\xbc\x2f\xe1\xcf\x82\xc2\x5f\x99\xdc\x01\xa4\xe5\x34\xc8\x8f\xe5\x3d\x85\xfe\
+    \x39\xd0\x09\x94\xa8\xa5\x04\x6a\x1b\xc7\x49\x36\x3e\x98\xa7\x68\xe3\x4d\xea\x56\x43\x9f\xe1\x95\x4b\xef\x42\x9b\xc5\x33\x16\x08\
+    \x4d\x7f\x5d\xcd\x78\xec\xe9\xa9\x33\x98\x4d\xe3\x2c\x0b\x48\xfa\xc2\xbb\xa9\x1f\x26\x19\x96\xb8\xe9\xd1\x02\x17\x73\xbd\x07\xcc\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bn254_t_4(e: &Env) -> Vec<Vec<U256>> {
-    vec![e,
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x19b849f69450b06848da1d39bd5e4a4302bb86744edc26238b0878e269ed23e5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x265ddfe127dd51bd7239347b758f0a1320eb2cc7450acc1dad47f80c8dcf34d6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x199750ec472f1809e0f66a545e1e51624108ac845015c2aa3dfc36bab497d8aa).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x157ff3fe65ac7208110f06a5f74302b14d743ea25067f0ffd032f787c7f1cdf8).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2e49c43c4569dd9c5fd35ac45fca33f10b15c590692f8beefe18f4896ac94902).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e35fb89981890520d4aef2b6d6506c3cb2f0b6973c24fa82731345ffa2d1f1e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x251ad47cb15c4f1105f109ae5e944f1ba9d9e7806d667ffec6fe723002e0b996).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x13da07dc64d428369873e97160234641f8beb56fdd05e5f3563fa39d9c22df4e).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c009b84e650e6d23dc00c7dccef7483a553939689d350cd46e7b89055fd4738).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x011f16b1c63a854f01992e3956f42d8b04eb650c6d535eb0203dec74befdca06).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ed69e5e383a688f209d9a561daa79612f3f78d0467ad45485df07093f367549).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x04dba94a7b0ce9e221acad41472b6bbe3aec507f5eb3d33f463672264c9f789b).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0a3f2637d840f3a16eb094271c9d237b6036757d4bb50bf7ce732ff1d4fa28e8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x259a666f129eea198f8a1c502fdb38fa39b1f075569564b6e54a485d1182323f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x28bf7459c9b2f4c6d8e7d06a4ee3a47f7745d4271038e5157a32fdf7ede0d6a1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0a1ca941f057037526ea200f489be8d4c37c85bbcce6a2aeec91bd6941432447).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c6f8f958be0e93053d7fd4fc54512855535ed1539f051dcb43a26fd926361cf).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x123106a93cd17578d426e8128ac9d90aa9e8a00708e296e084dd57e69caaf811).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x26e1ba52ad9285d97dd3ab52f8e840085e8fa83ff1e8f1877b074867cd2dee75).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1cb55cad7bd133de18a64c5c47b9c97cbe4d8b7bf9e095864471537e6a4ae2c5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1dcd73e46acd8f8e0e2c7ce04bde7f6d2a53043d5060a41c7143f08e6e9055d0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x011003e32f6d9c66f5852f05474a4def0cda294a0eb4e9b9b12b9bb4512e5574).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2b1e809ac1d10ab29ad5f20d03a57dfebadfe5903f58bafed7c508dd2287ae8c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2539de1785b735999fb4dac35ee17ed0ef995d05ab2fc5faeaa69ae87bcec0a5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c246c5a2ef8ee0126497f222b3e0a0ef4e1c3d41c86d46e43982cb11d77951d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x192089c4974f68e95408148f7c0632edbb09e6a6ad1a1c2f3f0305f5d03b527b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1eae0ad8ab68b2f06a0ee36eeb0d0c058529097d91096b756d8fdc2fb5a60d85).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x179190e5d0e22179e46f8282872abc88db6e2fdc0dee99e69768bd98c5d06bfb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x29bb9e2c9076732576e9a81c7ac4b83214528f7db00f31bf6cafe794a9b3cd1c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x225d394e42207599403efd0c2464a90d52652645882aac35b10e590e6e691e08).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x064760623c25c8cf753d238055b444532be13557451c087de09efd454b23fd59).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x10ba3a0e01df92e87f301c4b716d8a394d67f4bf42a75c10922910a78f6b5b87).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e070bf53f8451b24f9c6e96b0c2a801cb511bc0c242eb9d361b77693f21471c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1b94cd61b051b04dd39755ff93821a73ccd6cb11d2491d8aa7f921014de252fb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d7cb39bafb8c744e148787a2e70230f9d4e917d5713bb050487b5aa7d74070b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2ec93189bd1ab4f69117d0fe980c80ff8785c2961829f701bb74ac1f303b17db).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2db366bfdd36d277a692bb825b86275beac404a19ae07a9082ea46bd83517926).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x062100eb485db06269655cf186a68532985275428450359adc99cec6960711b8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0761d33c66614aaa570e7f1e8244ca1120243f92fa59e4f900c567bf41f5a59b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x20fc411a114d13992c2705aa034e3f315d78608a0f7de4ccf7a72e494855ad0d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x25b5c004a4bdfcb5add9ec4e9ab219ba102c67e8b3effb5fc3a30f317250bc5a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x23b1822d278ed632a494e58f6df6f5ed038b186d8474155ad87e7dff62b37f4b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x22734b4c5c3f9493606c4ba9012499bf0f14d13bfcfcccaa16102a29cc2f69e0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x26c0c8fe09eb30b7e27a74dc33492347e5bdff409aa3610254413d3fad795ce5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x070dd0ccb6bd7bbae88eac03fa1fbb26196be3083a809829bbd626df348ccad9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x12b6595bdb329b6fb043ba78bb28c3bec2c0a6de46d8c5ad6067c4ebfd4250da).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x248d97d7f76283d63bec30e7a5876c11c06fca9b275c671c5e33d95bb7e8d729).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a306d439d463b0816fc6fd64cc939318b45eb759ddde4aa106d15d9bd9baaaa).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x28a8f8372e3c38daced7c00421cb4621f4f1b54ddc27821b0d62d3d6ec7c56cf).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0094975717f9a8a8bb35152f24d43294071ce320c829f388bc852183e1e2ce7e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x04d5ee4c3aa78f7d80fde60d716480d3593f74d4f653ae83f4103246db2e8d65).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2a6cf5e9aa03d4336349ad6fb8ed2269c7bef54b8822cc76d08495c12efde187).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2304d31eaab960ba9274da43e19ddeb7f792180808fd6e43baae48d7efcba3f3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x03fd9ac865a4b2a6d5e7009785817249bff08a7e0726fcb4e1c11d39d199f0b0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x00b7258ded52bbda2248404d55ee5044798afc3a209193073f7954d4d63b0b64).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x159f81ada0771799ec38fca2d4bf65ebb13d3a74f3298db36272c5ca65e92d9a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1ef90e67437fbc8550237a75bc28e3bb9000130ea25f0c5471e144cf4264431f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e65f838515e5ff0196b49aa41a2d2568df739bc176b08ec95a79ed82932e30d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2b1b045def3a166cec6ce768d079ba74b18c844e570e1f826575c1068c94c33f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0832e5753ceb0ff6402543b1109229c165dc2d73bef715e3f1c6e07c168bb173).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x02f614e9cedfb3dc6b762ae0a37d41bab1b841c2e8b6451bc5a8e3c390b6ad16).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0e2427d38bd46a60dd640b8e362cad967370ebb777bedff40f6a0be27e7ed705).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0493630b7c670b6deb7c84d414e7ce79049f0ec098c3c7c50768bbe29214a53a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x22ead100e8e482674decdab17066c5a26bb1515355d5461a3dc06cc85327cea9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x25b3e56e655b42cdaae2626ed2554d48583f1ae35626d04de5084e0b6d2a6f16).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1e32752ada8836ef5837a6cde8ff13dbb599c336349e4c584b4fdc0a0cf6f9d0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2fa2a871c15a387cc50f68f6f3c3455b23c00995f05078f672a9864074d412e5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2f569b8a9a4424c9278e1db7311e889f54ccbf10661bab7fcd18e7c7a7d83505).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x044cb455110a8fdd531ade530234c518a7df93f7332ffd2144165374b246b43d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x227808de93906d5d420246157f2e42b191fe8c90adfe118178ddc723a5319025).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x02fcca2934e046bc623adead873579865d03781ae090ad4a8579d2e7a6800355).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ef915f0ac120b876abccceb344a1d36bad3f3c5ab91a8ddcbec2e060d8befac).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1797130f4b7a3e1777eb757bc6f287f6ab0fb85f6be63b09f3b16ef2b1405d38).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0a76225dc04170ae3306c85abab59e608c7f497c20156d4d36c668555decc6e5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1fffb9ec1992d66ba1e77a7b93209af6f8fa76d48acb664796174b5326a31a5c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x25721c4fc15a3f2853b57c338fa538d85f8fbba6c6b9c6090611889b797b9c5f).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c817fd42d5f7a41215e3d07ba197216adb4c3790705da95eb63b982bfcaf75a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x13abe3f5239915d39f7e13c2c24970b6df8cf86ce00a22002bc15866e52b5a96).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2106feea546224ea12ef7f39987a46c85c1bc3dc29bdbd7a92cd60acb4d391ce).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x21ca859468a746b6aaa79474a37dab49f1ca5a28c748bc7157e1b3345bb0f959).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x05ccd6255c1e6f0c5cf1f0df934194c62911d14d0321662a8f1a48999e34185b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f0e34a64b70a626e464d846674c4c8816c4fb267fe44fe6ea28678cb09490a4).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0558531a4e25470c6157794ca36d0e9647dbfcfe350d64838f5b1a8a2de0d4bf).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x09d3dca9173ed2faceea125157683d18924cadad3f655a60b72f5864961f1455).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0328cbd54e8c0913493f866ed03d218bf23f92d68aaec48617d4c722e5bd4335).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2bf07216e2aff0a223a487b1a7094e07e79e7bcc9798c648ee3347dd5329d34b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1daf345a58006b736499c583cb76c316d6f78ed6a6dffc82111e11a63fe412df).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x176563472456aaa746b694c60e1823611ef39039b2edc7ff391e6f2293d2c404).into()),
-        ],
-    ]
-}
+#[cfg(feature = "poseidon2-bls12-381")]
+pub(crate) const RC_BLS12_381_T_4: &[u8] = b"\
+    \x1a\x3b\xdc\xbf\xc1\x1d\xab\xfb\x6e\xd0\xdd\x5f\x5a\x9b\x38\x19\x14\x88\xbc\xe9\xee\xcd\x81\x1c\x10\xf9\x37\x8b\x32\xdb\x8c\x61\
+    \x52\xb7\x33\xe8\x57\x91\x2f\xdd\x22\x48\xdc\x96\x38\xdd\x79\xb1\xce\x18\xb2\x85\xb2\x77\x92\x23\x8b\x44\xc2\xb2\x3c\x0f\x5d\x5f\
+    \x47\xd6\xdf\x02\xd7\x3e\x6c\x78\xce\xd5\x50\xec\x8d\xf1\xa4\x59\xac\x41\xf3\x18\xd8\xb9\x04\xa3\x76\x52\xb5\x81\xb2\xb7\x66\xb8\
+    \x11\xec\x28\x47\x26\xdd\xdc\xf3\xcb\xb2\xb8\x18\x62\xc9\xcd\x95\xe9\xde\x81\xce\x03\x17\x30\x2e\x1e\xf4\x32\xd5\x9b\x91\x33\x88\
+    \x19\xe2\x1d\x74\x99\x05\x90\x4f\x3e\x10\xcd\x57\xf7\x81\x7c\x25\x64\xc0\x6e\xc1\xb1\xe2\x29\xde\xf2\x12\x9e\x79\xa7\xa7\x77\x38\
+    \x6e\xee\xcf\x4c\x83\xe1\xec\x16\x42\x17\xf3\xc0\x09\x56\xd8\x3c\x3b\xd8\x45\xbe\xe2\xd8\x6b\x26\x3c\xf3\xbf\x89\xa3\x45\xe5\xe1\
+    \x10\xd5\x80\x8a\xb4\x72\x95\xf7\xa9\x50\xdc\x72\xf9\x68\x39\x8b\x9c\x67\x42\x6a\x21\x78\x11\xb9\xbd\x7e\x97\xf2\xa2\x61\x99\x2f\
+    \x25\xd3\x34\xfb\xad\xcb\xfd\x26\x44\x9c\x4c\xe1\x47\x2f\x96\x1f\x7d\x40\x1a\x3d\xdf\x40\xa7\x0a\xf5\xc1\x0a\xc9\x17\x6d\x15\x1b\
+    \x3e\xf8\xa4\x6e\xc0\x7e\xe5\x51\xec\xba\x60\xb1\x60\x1c\xbf\x6e\x36\x64\x41\x83\x31\xa2\x54\x72\x9b\x7c\x6a\x5c\x3d\x13\xa6\xc6\
+    \x30\xd3\x73\xe2\x98\xc0\x9d\x2a\x6e\xfe\x66\x1d\x70\x8f\xcf\xa6\x16\x3d\xc6\x1a\xe1\xeb\x10\x3d\x98\xcf\x88\xc6\x3e\x48\x21\x25\
+    \x18\x76\x98\x39\x94\x9b\x2d\xda\x9e\xf3\xf1\x11\xac\xd8\x6e\x10\x25\x0f\xfa\xd4\xc4\xc4\x26\x3e\xa9\x70\xe4\xf4\x72\x6c\xfa\xd7\
+    \x2c\x94\x12\x86\xd1\x53\x4f\xf3\xbe\x3a\x66\xf9\xdd\x59\x83\x3e\x65\xb1\xfa\x67\xdb\x23\x51\x1f\x7b\x4b\x2a\xe3\xb3\xd6\x6b\xc9\
+    \x08\xb6\xd9\xc9\x0b\x41\x39\xea\x70\x49\x7a\xc8\xa2\x2b\x30\xfb\x9b\x76\xb8\xce\xab\xf7\x04\x49\xd2\x82\xb5\x7d\x98\xc6\x0d\xdd\
+    \x3a\x8a\x2d\xd9\x17\x22\x2a\x71\xca\x67\x8b\x5c\x0a\x80\x3a\x43\xc6\x2d\xe2\xf2\xc9\xfe\x37\x93\x1b\x5b\x2a\x01\x7f\xe6\x46\x38\
+    \x2d\xc1\x73\x08\xab\xd0\xe7\x31\xb3\xcf\xfa\xfd\x29\x6c\xc3\xe6\xe2\x40\x3d\xc5\x63\xba\xa1\xc7\x97\xa6\x8d\xa9\x75\x4d\xd2\x07\
+    \x1d\x04\xe5\x42\xf5\x44\x31\xc2\x3f\x9d\x18\x12\x39\x2a\x0c\x87\x20\x31\x44\xe3\x43\xc4\x59\xd3\xee\x64\x0b\x04\xe2\x03\xbe\x6c\
+    \x50\x07\x60\xe2\xef\x6b\xf4\x63\xfd\xec\xbf\x7b\x47\xf4\xad\xaa\x82\x14\xc7\x97\xe5\x93\x59\x43\x9d\x63\x16\x9e\x1c\xdb\x9d\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\xf3\x6f\xaa\xc1\xc1\x2d\x75\x6d\xcc\x3b\x54\x91\xf2\xb2\x25\x65\x40\x9a\x4e\x95\x2b\x8f\x8e\x72\x6e\xe1\x26\xc0\xe1\xc0\xe6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\xe7\x2d\xb5\x24\xa4\x82\x43\x84\x9f\xea\x06\x8e\x58\xd0\xc0\x78\xb0\xc7\x38\xcd\x48\x2a\x2e\x21\x60\x60\x26\x57\x45\x6c\xcb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4a\x74\x67\x7f\xff\x94\x82\x1e\x97\xb2\x0b\x86\x06\x3c\x36\xd3\x5c\x6a\x29\x08\xc0\xa1\x9a\x9f\x13\x0e\xdd\xe9\xc8\xce\xb6\xb5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\xd2\x7f\xd5\x08\x0b\x44\xa4\x36\xe1\x30\x66\xce\x1c\x80\xdd\xef\x2a\xf0\x86\xc2\xa4\x80\x6b\xd9\x9f\xa0\x2d\xe9\x72\x6d\xed\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\xa2\x6c\x92\xfc\x51\xdd\x28\x5a\x61\xc2\xc1\x37\x9f\x67\x31\x37\x7a\xeb\x29\xc6\x26\x96\xaa\x6a\x9c\x10\x9c\x29\x91\xf2\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\xb9\x00\xa5\xe9\xb2\x68\x5b\xe9\xf8\xf0\x24\x98\x12\x3a\xc7\x1b\xd7\xbf\xa4\x0b\x68\x53\xab\x52\x7a\x8d\x25\x12\xb9\xae\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6f\xf7\x55\xd2\x37\x74\x24\x98\xe0\xb7\x88\x29\xcd\x83\x58\xbd\xb8\x31\xe9\x6f\xef\x08\xeb\x19\x2b\x7e\x33\x41\x23\x08\x0a\xfa\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x95\x44\x7e\x5b\x94\xa6\x4c\x8b\x36\x81\xf5\x70\x68\xe1\x9e\xe3\x69\x64\x40\xef\x78\x5e\x35\x12\x38\x86\x31\x1f\x48\xcd\x1e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2b\x0e\xab\xc0\x8e\xef\x3a\xba\xe3\x30\x4c\xbb\x2e\xfc\x67\xe7\xb4\x37\xbd\x96\xb6\xf7\xd1\x75\xda\xed\x6d\xa5\xa2\x14\x4e\x8b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3a\x34\x5e\xe8\xe7\x15\xf3\x22\x6e\x52\x83\x86\x52\x21\x92\x02\xef\xb4\xe6\xd4\xf0\x57\xf9\x04\xd6\x9d\x3f\xbd\x78\x1e\x39\xf5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\xeb\x2a\x07\xb5\xda\x81\xb5\x7b\x7a\xa4\xfa\xb1\x9e\x69\x1f\x9a\x68\x4b\xae\xf4\xcf\xb5\xaf\xb6\xf4\x6d\x23\xcb\xf1\xa9\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x30\x51\x66\x3e\xf7\xde\x76\x74\x50\x6f\x2c\x87\x34\x09\x72\x53\x63\x84\x95\x64\x81\x5b\x8b\xd2\x91\xf0\x0b\x9c\x60\xb4\xda\xf0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x04\x1a\x63\x2a\x7b\xc1\x67\xf6\x7c\xa9\xc3\x82\x50\x21\xa0\x5b\x13\xf8\x1f\x81\x4e\xe1\x01\xd3\x77\x16\xe1\x2e\xfe\xc3\x12\x1f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\x5a\xe0\x06\xb7\x0b\xfe\xbc\x1a\x48\x5e\xed\x2c\x07\x86\x54\xdc\x0f\x10\x51\x4e\x8c\x73\x9c\xa2\xa7\x01\x9f\x42\x44\x4c\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0f\x3d\xba\x73\x0b\x64\xdd\xa2\xbc\x2e\xaa\x50\xed\xe0\xfc\xaa\x86\xa3\x7c\x74\xc3\xa1\x7b\xe9\x7a\x9d\x96\x9f\xca\x21\xfb\x37\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6d\x6c\x02\x00\x4f\x2a\x4c\xbb\x96\x5f\x9e\x73\x79\x90\x41\xfa\xfb\x49\xf6\x1a\xee\x66\x23\x2f\x45\xae\x09\x97\x2a\xf0\x8e\x86\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x60\x39\x1f\x43\x6a\x32\xfd\xb0\xee\xba\x85\x78\x31\x35\x79\xc0\x4c\x48\xc1\x26\xf4\xeb\x6a\x0c\x49\xe2\x49\xff\x1c\x1f\xbf\x4a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\x0a\x30\x71\x0a\x42\x53\x9d\x44\x40\xce\x9a\x2c\xc7\xd9\xac\x10\x24\x09\xa1\x88\x96\x0d\x0f\xbd\x24\x9f\xc4\x35\x2b\xf3\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x35\xcb\x6c\xe5\x53\x52\x59\x93\x32\x47\x1a\xd8\xbe\x46\xdd\xc0\xcf\x59\x8e\x0e\x36\xb8\xa8\xfa\x5f\xae\x6f\x26\x27\x7b\x31\x8e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x48\xa8\x11\x6b\x25\x4b\x17\x10\x52\x25\x1f\x35\x3c\x93\xca\x38\x16\x73\x4e\x4e\x0b\xae\xea\x10\xb4\x26\x9b\xba\xb9\xa2\xbe\x55\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\x62\x58\x21\x1a\x4b\xb2\x03\x51\x21\xc7\xca\x12\x9e\xae\x71\x9d\x4d\xdf\xef\xa9\x99\x21\xa8\xbe\xfb\x7a\x16\x0e\x0f\x84\x5f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5b\xf6\x98\x54\x73\xbc\x55\x00\xf1\x40\x84\x4f\x5b\x66\xce\xa8\x58\x15\x77\x2c\xce\x03\x59\x6e\xda\x60\xe6\xc7\xdc\xb4\xeb\xbd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5a\xf4\x6d\x3d\xd0\x22\x61\xd8\x93\xc4\x18\x58\x21\x88\xd3\x28\xa8\xa8\x4f\xf8\xf6\xac\x6c\x19\x97\xd3\xaa\xad\xf6\x42\x20\xd9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5e\x3c\x84\x92\x56\x09\x01\x6d\xa5\x8d\xf8\x2c\x1f\x2b\x51\x59\x0e\x3b\x91\xe5\x50\x2d\xca\x2e\xba\x13\xd8\x35\x49\x16\xdd\xbe\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x41\x0e\x6f\xb4\x39\x03\x79\xa8\xb7\x26\xcf\xb2\x2a\x9e\xaa\xb7\x23\x2b\xc4\xb6\x0e\xad\xdb\x62\x9a\x4a\xac\xfc\x7d\xcd\xbf\xcf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xa5\xde\xbb\x9d\xe1\xc4\xbb\xfa\x02\x04\x54\x3d\xd9\x75\x72\x41\x61\x1c\xd2\x8b\x5e\xac\x2d\x7f\x37\xba\xa2\x93\xfa\x26\x18\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4a\x15\x54\x02\x31\x54\xa9\x9b\x45\x72\x57\x2c\xcd\x5d\x89\xfa\x87\x45\xc1\x17\xa2\xa8\x5e\x57\x86\xef\x6e\xf3\x94\x10\xd6\x11\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xa7\x5f\xb2\x28\x81\xb2\x38\xb6\xc9\x6d\xa6\xb9\x08\xf9\x22\xda\x8b\x54\xf9\x09\x23\x70\x51\x77\x5c\x64\xfe\xc8\xd5\x92\x0c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x54\x6e\xa8\xad\x3d\xff\xe1\x4c\x4c\x96\xe4\xd6\xd8\xc4\x3c\x64\x42\x12\xfb\x66\x34\x86\x16\x5b\x34\xcb\x26\xdd\xd7\x17\xf3\x41\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6b\x16\x8e\xe8\x7f\x2c\x53\x2e\xfd\xb3\x78\x11\x6d\xfe\x46\x2f\x63\x2a\x18\xec\xcf\x1e\x9b\xcf\xc7\x40\xd3\x5e\x53\x5b\x27\x35\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4c\xf9\x17\xa7\x80\xb8\x50\x29\x2f\x44\x50\x2b\x80\x86\x5d\x36\x64\xd7\x52\x13\xf8\x9e\x8a\xe5\x86\x1d\xbc\xb5\x2a\xa9\x5e\x76\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x02\xe8\xdf\xe9\x04\x6d\x8b\x95\x15\x32\x46\x6b\xdf\x1b\x62\x57\x1b\x85\xe5\x51\xb3\x4c\x8b\xbf\xb1\xcc\xd3\x21\x62\x71\xb7\x3d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4a\x34\xd2\xab\xd0\x1f\x09\xf7\x43\xcc\x56\xa3\xf8\x7f\xeb\xd6\x0a\xe5\xd8\x8e\x22\x49\x18\xd8\x40\x65\x6d\xa9\x73\xd9\x25\x2e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\x3f\xad\x26\xc6\x31\x8d\x47\x1f\xa3\x76\xfb\xaf\x02\xd3\x41\xe4\x1e\x68\x7d\xb6\xf3\xda\x88\x26\x6d\xe2\x1c\x71\x57\xe9\x40\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x1c\x9d\xf7\x17\x6e\xad\xb9\xaf\x3a\x14\xf0\xf5\x3a\x9b\x87\x19\x6e\xf6\x0b\xbb\x89\xb3\x63\x01\x42\xff\x8d\x9d\x69\x13\x7b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\x53\x68\x2a\x81\x20\x10\x6b\x42\x24\x1c\x86\xde\xee\x87\x92\x63\xd8\x1f\xe8\xe3\x42\x3e\x01\xf5\x67\x31\x6d\x95\x24\x64\x7e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5e\xd9\x72\x5a\xf6\xa9\x10\x90\x02\x7a\xcf\xc1\x21\x00\x73\x3a\x9d\xea\x89\x77\x10\x48\xaa\x2d\xfe\x40\xda\xea\x95\x46\x64\x5b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x7b\x7a\x87\xf3\x1e\xbd\x24\x64\xbe\x9f\x21\x1d\x41\x71\x9b\x45\x39\x39\xd5\x3f\xf7\xbe\x07\xa2\xcc\x21\x74\x1d\x48\xb4\x67\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x11\x81\x59\xeb\x07\x35\x3f\xef\x60\xe9\x73\x30\x77\xcc\x82\xf9\x2a\x6e\x2b\xd3\x61\xc8\x8d\x81\x22\xe9\xa2\x92\x21\x77\x57\xe0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5b\x46\x26\xe7\x87\xd4\xa4\xb0\x0b\xa4\x69\x3f\xd7\x90\x0e\xca\xa1\x96\x24\xbe\x9f\x2f\xee\x6b\x9c\x3b\x53\x8d\x4f\x89\x6b\x8a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\xca\xd2\x2a\xe0\x6b\xb0\xe8\xd0\x43\xf7\x92\x84\x77\x21\x9e\x4d\x38\xac\x54\x4c\xe8\x1c\x4c\x68\x52\x67\x08\x9e\x4c\x70\x03\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x11\x14\x95\x94\x75\x8e\x78\x9b\x44\x0c\xe5\x34\xb5\x2b\x6c\x50\x8a\xe1\x88\x16\x69\xd1\xfa\x4a\x7e\x55\x1e\xf8\x4e\x4d\xc4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x42\x8b\x85\x32\xef\xa1\x94\xa7\xf5\xcb\xf4\xc2\x96\x78\x63\x46\xdd\xfc\xfe\x8f\x2b\xab\x26\xf2\x53\x21\xf5\xd9\x84\xdf\xc3\x07\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xfd\x03\x5c\xc1\x29\xe9\x1e\xac\x66\xd8\x41\xe2\x86\x49\x89\xd1\xff\x48\x53\x66\x21\x66\x14\x35\x35\x60\x3b\xf9\x46\x06\x21\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\x16\x75\x65\x11\x44\x75\x41\x2c\x76\xf0\xdf\x19\x61\xea\xca\x9d\xeb\x17\x62\x2c\x9f\xb6\xf5\xac\x8a\x89\xa2\x9f\x42\xdf\x69\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\xca\xc3\xff\x02\x12\x54\x19\xac\x84\xff\x80\x29\x55\x89\xd1\x12\x4a\x1e\x65\xa9\x4e\x8d\x9b\x63\xa4\x11\x31\x7c\x7f\x57\x28\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x72\x13\xc2\xf1\x7a\xec\x53\x6d\x0a\x51\x96\xe8\x77\xd1\x62\x6a\x63\xd7\xdb\x5b\x4e\x3b\xd7\x73\x27\xeb\x36\x17\xb9\x32\xae\xe7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\x86\xee\x2e\x45\xfb\xcd\x59\xfa\x43\xed\xee\x66\x30\xdd\xa5\xcb\x3b\x89\x4e\xb4\x70\x1e\xff\x5f\xd5\x39\x67\xab\xad\x26\x66\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x94\x18\x50\x09\x1b\xd1\x68\x75\xf4\x08\xf1\x83\xfb\x93\xd2\x50\xc5\x38\xe5\xee\x1e\x89\x79\xe9\x60\xb5\xb4\x99\xb2\xf0\x70\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x03\x1f\x75\xd5\x3b\xdd\x5a\xc6\x03\xc2\x52\x92\x51\x48\xa7\x4f\xa5\xf3\xbf\x5d\x9f\xfa\xbe\x64\xc6\xe9\x32\xbe\x90\x42\x04\xec\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x55\x29\x6a\x48\xdd\x39\x9c\x65\x79\x8c\xdb\xda\x11\xe8\x9f\x26\xf9\x98\xf2\xd3\x3c\xf3\x28\xe8\x7a\x1c\x36\x4e\x05\xbe\x49\x2b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x19\x11\x75\xec\x33\x7e\x64\xa4\x87\x85\xa0\x83\x55\x91\x76\x16\x8b\xce\xa3\xdb\x28\x22\x63\x0c\x48\xf1\xd4\x97\xd0\x3e\x1e\x35\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x33\x20\x0b\x51\x8e\x6b\x1a\x1e\x96\xba\x21\x4c\xad\x18\x92\x9e\x7f\x5b\x4e\x33\x36\xe5\x22\xc5\xfa\x1f\xeb\xf6\x9c\x08\x18\xc6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\x98\x9e\x24\xf9\x7d\xc0\x6c\x07\x5b\xb5\x34\x04\xe3\xea\xaa\xe3\x2a\x1b\x6d\x89\xd9\xe0\x9a\xcd\xee\xe6\x57\x12\xaa\x42\x16\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\xe4\xb7\x71\x63\xf1\x57\x2b\xba\xa3\x25\xf4\x8b\xde\x7a\x42\x9b\x6b\x0f\x80\x3a\xde\x45\xa0\xe7\x2c\xd2\xbd\xbd\x84\x68\x0c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\xf2\x70\xd4\x08\xf4\x92\x40\x2b\x72\x71\x43\x2b\x6c\x44\x3a\xbb\xd1\xf0\xe2\x20\x88\x8d\x73\xf4\x74\x98\xd5\x90\x44\xd7\xcc\
+    \x3d\x5b\x53\x10\xe7\xb6\xbe\x63\xbe\x8f\x12\x59\x4e\xcd\x68\x10\x8f\x4b\x3f\x62\x66\x68\x3b\x13\x17\x8d\x0c\x80\xd8\x53\x0c\x2f\
+    \x5f\x69\xd7\x48\xd0\x6e\x22\x91\x60\x88\xbd\x21\xfb\x47\x23\x34\xdd\xbe\x52\x23\xff\x49\x4b\x6c\x0d\xcd\xf6\x2d\xb6\x9d\x43\xac\
+    \x6c\x1f\xd7\xdb\xb3\x29\x8d\xa8\x6d\xfe\x5d\x04\x35\xb2\x6e\x0a\x91\xfe\x6a\xd6\xaa\x25\xb9\x40\x7d\x5d\xa5\x44\xe6\xb9\xcd\x27\
+    \x37\x17\x2d\xd4\x9b\xf1\x4e\xbc\xa5\x2e\x1c\x31\xb6\x0f\x31\xde\x82\x13\x64\x4e\x33\x0b\xcf\x2c\xc5\x96\x84\x60\x6e\x62\x53\x7d\
+    \x50\x9c\xf8\x29\xb6\x8e\x58\x86\x1c\x3d\x6b\xce\x60\x46\xac\x1b\x6e\x9c\x06\x53\x78\xfe\x95\x56\x34\x8c\x0c\x18\x14\x80\x8e\x20\
+    \x52\x9d\x5d\xd1\x32\xf5\xcb\x4b\x71\x99\x4a\xe6\xb3\x30\xb7\x94\x4f\x0f\xd8\xba\x31\x95\x82\xd2\x41\x4b\x9b\x10\x76\x8b\x74\x48\
+    \x5d\x25\x13\x91\xab\xee\x22\x88\x33\xdb\xd2\x4b\xe9\x9d\x16\x3b\xa9\xf8\xc9\x2a\x2c\xf7\x3a\x4e\x1f\x8a\x87\xe9\xc4\xca\x97\xba\
+    \x1a\xbe\x1d\x77\x22\x6f\x2e\xff\x6f\x1a\x6b\xfc\x6c\x8d\x9e\x00\xdd\xe1\xda\x9d\x67\x85\x8e\x69\x84\xe5\x3b\xc2\xac\xeb\xa5\x35\
+    \x4c\xa7\x4b\xa7\x2b\xae\xa6\xd8\x4f\xa8\xf2\xa0\x5e\x38\x7a\x1c\x41\xa5\xa1\xb2\xf1\xa4\x79\xfb\x11\xbb\xf9\xd5\xe0\x9b\xd0\x1a\
+    \x23\x2b\xfa\x6a\x31\x20\x22\x48\x14\x96\x73\x15\xeb\xf3\xd8\xc8\x8b\x2e\xb0\xea\x8c\x20\xc7\x9c\xe8\x54\xf9\x0a\xec\x7a\x9b\x91\
+    \x10\x9f\x5b\xd7\x22\xc8\xcf\x28\xab\x6a\x38\x52\x97\x0b\xcb\x66\x5f\x39\x8a\x8d\xc9\xd3\x31\x95\x8d\xc2\x91\xd4\x9a\x1b\xa9\xb4\
+    \x4a\x18\x1a\x5d\xe6\xc7\x9a\x05\xc0\x25\x33\x35\x0a\xb5\x1d\xee\xe2\x4b\x8f\x5b\x91\x54\xc5\xcf\x09\xba\x5e\x25\x86\x2a\xe1\x76\
+    \x6e\x06\x3b\x99\xc1\x60\x28\xdf\x94\xb1\xeb\x71\x91\x64\xae\x8c\x83\x36\x89\x47\x91\x51\x6c\xa2\xc3\x7c\x5a\x1a\x39\x4b\xb0\xd7\
+    \x6b\xbd\x2d\x3a\x64\x3d\x30\x1d\x25\xa8\xcf\x15\x65\x42\x16\x5a\xbe\xe3\x03\x90\x68\xfe\xcf\x50\x1e\x9d\x39\xf3\x13\xca\x2a\x87\
+    \x04\x69\x0f\xe1\xbe\x7c\x7b\x8c\x10\xc8\x1e\x63\xf5\xe5\x08\xfe\x93\x85\x3c\x61\xf0\x43\x5f\x81\xea\xbc\x99\x97\xfa\x3b\x99\xf3\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bls12_381_t_2(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000002).into()),
-    ]
-}
+// Wider parameter sets (t = 8, 12, 16), generated with the same reference sage script.
+// For these widths the script computes rounds_f = 8 and rounds_p = 57.
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BLS12_381_T_8: &[u8] = b"\
+    \x11\x18\xb6\x10\xc2\x54\x4e\xfa\x26\xb7\x0d\x9d\x60\xca\x6c\xa3\x62\xaf\xcf\xff\x12\x43\x6c\xf3\xb0\xf8\xa3\xec\x58\x95\xd9\xea\
+    \x5b\xa2\x88\xc5\x19\x7e\x71\x74\x5a\x8f\xde\x16\xac\xa5\x75\xe3\x79\xdc\xc1\x9f\x21\x04\x2d\x8b\x93\x75\xe4\x78\xf8\x09\x32\x5b\
+    \x07\x9a\x98\x7d\x87\xd7\xc8\x0d\x5f\x4a\x3b\x40\x18\x51\x7c\x50\xf5\x06\x7e\xcb\x51\x6f\x6b\xd1\x4d\x79\xea\xba\xa8\x34\x9e\x62\
+    \x4c\x64\x97\xb0\xb9\x9e\x1f\x1a\xf4\xec\x03\x22\xdc\x38\x86\x9b\x2d\xfb\x79\xdb\x3a\xb5\xfa\x68\x93\x6c\xc8\xb6\x02\x5a\xad\x1f\
+    \x48\x3b\x5c\x50\x71\xe9\x0c\x98\xbd\x35\x35\x56\x45\x3f\x04\x11\x34\x42\xf2\x9a\x1c\x4c\x23\x6b\x4c\xa3\x18\x90\x13\x6b\xee\x4d\
+    \x3e\xf7\x6c\x8b\xae\x0a\xa7\x55\xdd\xe5\x94\xd8\xec\x22\xb1\x57\xf9\x13\x32\x3e\x5b\x29\xbb\xd0\x65\x2e\x4b\x74\x97\x3a\xc8\xf9\
+    \x09\x17\x67\xb2\x80\xc5\x9a\x58\xa3\x9f\x29\x3b\xfc\x22\xae\x94\x4c\xb9\x21\xc2\xef\xa2\x40\x26\x2b\x5b\x66\x31\x27\x24\xf2\x0b\
+    \x45\xef\x82\xa5\x68\x41\x37\xe5\xfc\x96\x13\xe0\x58\x1c\xb6\x5b\x5a\xd3\xd4\x34\x70\xea\xcf\x0f\x06\x0e\x17\x11\xc4\xc5\x76\x23\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bls12_381_t_3(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000001).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000002).into()),
-    ]
-}
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const RC_BLS12_381_T_8: &[u8] = b"\
+    \x53\xad\xa4\x2e\xbd\xbe\x37\x50\xf1\x85\xd8\xae\xd9\x27\xc2\xa3\x39\xb1\x02\x3d\xfd\xe1\x96\xb5\x8a\xea\x36\x2b\x14\x58\x20\x8b\
+    \x52\xa4\xbd\x5e\xae\xe5\x8d\x29\xbf\xe5\x74\xca\xfd\x97\xf9\x87\xb1\xda\x70\x99\x87\xe3\xd0\x09\xae\x97\x1b\x0c\x62\x7e\xda\xd6\
+    \x3b\x7a\xbf\xef\xc7\x3e\x31\xa0\x12\xce\xbf\xd0\x42\x0d\xf3\x27\xdd\x6c\xf8\xbb\x1a\x94\xad\xed\x25\xe4\x0c\xb1\xd9\x7a\xe6\x23\
+    \x18\xf6\x08\x74\x44\x78\x7b\x86\x3f\x51\xd5\xf9\x27\x22\x67\x4e\x34\x40\xa0\x76\xd3\x5b\x38\x27\xae\x2a\x34\xe2\x34\x9f\xe6\x13\
+    \x1f\x6c\x10\x01\x6b\x4c\xa6\x94\xf4\x10\x14\x51\x7b\x6f\xae\x41\x34\x91\x2b\xdd\x35\xb6\x6c\x1c\x61\xa4\x00\x23\xf3\x62\xf7\xe3\
+    \x37\xf5\xdd\x62\x3b\x97\x25\x6a\xa9\x2b\xa9\x83\x8b\x52\xe6\xb9\x51\x48\x17\x79\xf8\x41\xcf\x5b\x15\x8b\x52\xb2\x64\xe1\x95\x42\
+    \x3b\xcb\x78\x48\x6b\x25\xea\x78\x99\x3b\xdc\xc3\xac\x52\x4d\xaa\x00\xcc\xb2\x9b\xac\xe5\x9b\x43\x14\x7a\x6c\xd3\x4e\x90\xea\x9d\
+    \x42\xf7\xf2\x05\x78\x93\xb0\x11\x8b\xa7\x2d\xee\x03\x90\x74\x55\x95\x01\x84\x99\xc0\x70\x80\x26\x35\x25\xfb\xee\x17\x9f\x45\x3f\
+    \x1e\xbf\xf9\xe9\x8d\x89\x13\xca\xea\x56\xfc\xfb\xde\x1f\xa0\xe2\x8e\x7c\x88\xa6\xfd\x26\xed\x1c\xaa\xcb\x21\x67\xac\x01\xd4\x6b\
+    \x50\xbc\x0b\x19\xec\x65\xae\x49\x19\x03\x80\xda\xc9\x06\x85\x1e\xff\xc5\xde\xd5\x98\xdf\xc3\xa6\xdf\xf9\xd1\x93\xe2\x8c\xaa\x92\
+    \x17\x35\x42\xf5\xc5\xf7\x05\xe8\xc0\x7e\xeb\xce\x46\x5e\xc0\x40\x75\x91\x1d\xa4\x4f\xfe\xba\x61\x35\xda\x9b\x99\x08\x66\x16\x51\
+    \x10\x4f\x0e\x44\x95\xd7\x8b\x09\x88\x79\x6d\x50\x99\xb0\x81\x4c\x06\x83\x57\x68\x1e\x1f\x68\xdf\x2e\x75\x89\xea\xb1\x07\xbc\x18\
+    \x3a\xa8\x20\x15\xf9\xde\x71\x49\x7b\x0d\x3a\xb9\x9e\x35\x3f\x15\xfd\x90\x29\x72\x26\xb3\xb0\xa7\x21\xef\x46\x37\xc3\x26\x6d\xa6\
+    \x21\x8a\x06\x65\x57\x03\x54\x47\xf5\xf6\x5c\x9c\xa1\xf6\x59\x1b\x2e\x89\x83\x62\x99\xbe\xbb\x64\x34\xa4\xdc\x79\x5d\xf2\x57\x5e\
+    \x46\x29\xcc\xf1\xaf\x64\x82\x48\x71\xea\x33\xc7\x0b\x35\x4c\x6d\xa3\x6f\x8f\xd5\x2c\x3e\x23\x77\xc7\xbf\x9a\xe7\xf3\xe9\xc3\x0c\
+    \x4e\xb2\x71\x81\x49\x31\x85\x48\xc4\xf7\x05\x54\xea\x54\xd9\xe8\x6c\x4f\xe4\xb5\xfe\x84\xdc\x40\x93\xb1\x36\x90\x60\xa3\x9d\x98\
+    \x4e\xf4\x17\xe3\xae\xed\xed\xf5\x0e\xbc\x47\xa5\xa8\xe2\xb5\x71\xf3\xac\xba\xe7\x51\xb3\xc2\x63\xee\x26\xc7\xcc\x25\x54\x5b\x5b\
+    \x56\x62\x91\x3a\xb2\xc5\x9d\xc3\x41\x3a\x93\x4f\x86\x80\x5c\x74\x42\xb2\x58\xad\xeb\x72\x8c\x3e\xdb\x61\xa5\xc7\x62\x94\x5b\x84\
+    \x12\xe9\xdf\x9b\x98\x79\x5b\xd6\x6d\xe9\x10\x37\xd4\x3a\x84\x80\xcd\xe4\x36\xb3\x0a\x5e\xd7\x7b\xe7\x1f\x9d\x66\x74\xd7\xd4\x45\
+    \x49\x27\xf3\x0e\x0d\x98\x7d\x23\x60\x5c\x7c\xb4\xd9\x03\xa3\x82\x5b\xdc\x6a\xc7\xd0\x8c\xec\xec\x16\x07\x62\x1c\xbe\xa2\x6b\x47\
+    \x09\x38\x8b\x6f\x74\x1e\xea\xfb\xfc\x7c\xc4\x47\x97\x78\xc2\xea\x00\x13\xe9\x8b\x33\xb7\x67\xd1\x17\xc0\xbd\x78\x0b\xc6\xd7\xeb\
+    \x65\x39\x15\xb0\x47\x69\xba\x57\xe5\x8e\x29\xa1\x63\xda\x61\x05\xc1\x88\x97\xfe\x0c\x43\x75\x4f\xcd\xf4\x27\x5d\x0a\x89\xfc\xc2\
+    \x32\xae\xae\xf7\xbc\xb8\xb9\x7f\x92\x70\x22\x11\x3b\xaf\xfa\xc5\x51\x5a\xb9\xec\x7f\x6e\x56\xc9\x49\x24\xa7\x87\xca\x3b\x10\x43\
+    \x14\xd3\x9f\xe7\x4d\x6c\x0c\xe5\x7b\xa4\x0d\xe0\xda\xca\xe6\x28\x42\x8f\x0b\x30\x21\xac\x71\xe4\x78\x56\xeb\xa4\xba\x10\x68\xb5\
+    \x65\xda\xca\xec\x23\x29\x36\xd4\x7c\x34\x41\xc9\x60\x73\x6c\xfa\x3f\xdb\xe1\x3e\x25\x99\x26\x3e\xbd\xd5\x45\x3c\xf7\x16\xdb\xbd\
+    \x30\x83\x8e\x97\xb6\x6d\xf6\xa3\x9c\xdc\xda\x90\x3a\x9c\x7f\x28\x34\xcb\xa1\xf1\x1c\x5a\x18\xa1\xa2\x5a\xe1\xa8\x51\xcd\x7c\xc5\
+    \x2b\xa2\x19\x20\xed\x44\x41\xcc\xa3\x7a\x95\xbe\xff\x2c\x1d\xba\xbc\x66\xc0\x89\xfa\xb4\x27\x87\xfe\xb9\xc7\xd6\x3f\x56\x3d\xb4\
+    \x48\xe8\x27\x27\xbd\x39\x46\xdc\x06\x77\x19\xd5\x38\x7e\x7d\x8d\x8b\x96\xd1\x50\x70\x8c\x60\x07\xfa\xde\x6a\xe7\xd8\xbb\x97\xf7\
+    \x72\xbe\xb7\x59\x2e\x1a\x8b\x5f\xfb\x67\xf9\x12\x7f\x7e\xea\xd4\x2a\xe5\xc9\x56\x56\x29\xb4\x4a\xec\x7e\xe8\x06\x46\x64\xb5\x81\
+    \x01\x52\xc4\xc7\xe8\xf0\x9d\x7d\x28\x80\x77\x30\x4f\x9e\x6f\x23\xd7\xbe\x63\x74\x6f\x73\xa6\xb4\x86\xd6\x6d\xeb\xd6\x8b\xe8\x7b\
+    \x07\x65\x81\x50\xe9\xfa\x7b\xaf\xea\x32\xdb\x94\x23\x7a\x98\x58\x57\xf1\x1e\x71\x79\xd0\xd1\x66\x5f\x55\x00\xee\xe9\x9a\x6b\xbd\
+    \x06\x25\xe0\x7f\x82\xa4\x51\x84\x2d\x18\x23\x6a\xed\xa5\xea\x88\x48\x40\x5c\x54\xeb\x2b\xc2\xe9\xc7\x21\x71\x5d\x89\xe6\x0e\x00\
+    \x6e\x8e\x2f\xef\x14\x24\xc9\x78\xdb\x61\xab\x53\x07\xc5\x7f\x5a\xe9\x77\xcc\x0f\x99\x29\x78\xa0\x79\x89\x8b\x81\x8d\xf7\x1c\x38\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3b\xf1\xdc\xb8\xed\xb1\x77\x8b\x29\x58\x61\xf3\xf4\x46\x53\x97\x43\x69\xaa\xdb\x6d\xc7\xa6\x57\xec\xf1\xe9\xb9\xf9\x97\x5d\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x4b\x5e\x52\x60\xd5\xa3\x74\xa5\x2d\x20\x5d\xc4\xcf\x2f\x6d\xdd\x2e\x26\x8c\x19\xd4\xd8\xc2\x3a\x9a\x83\xdd\xd9\x5f\x3b\x56\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x8b\xa5\x0e\xfb\xf0\x84\x21\xf9\xd8\x44\x39\xe7\x19\x8c\xf6\xd6\xd7\x44\xc3\x22\x49\x6c\xbe\xe7\xb0\xfa\xd5\x15\x48\x3c\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\xa9\x86\x1d\x98\xe4\xf0\xc7\xf4\x8a\x1c\x0f\x78\xb8\xc2\x54\xdb\x8f\x90\x23\xda\x33\xc0\xeb\xd8\x0e\x6a\x2f\xac\x37\xa9\xab\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6b\x4a\x81\xed\xff\x21\x01\xa7\x84\x94\x0a\x5d\x94\xd4\x99\x7e\x1a\x70\x64\xe7\xd1\x1c\xb3\x0c\x8d\x44\xfc\x17\xfc\xe5\xc7\xde\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x64\x6b\x6f\xe0\xfc\x67\xd8\xeb\x95\x7f\xc7\xf5\xc7\x1b\xb2\xeb\x49\xde\xb4\xd8\x00\xf8\x69\xf4\xa8\x51\xd4\x84\x52\x83\x7f\x80\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x72\xd1\x47\x0e\x48\x3e\xc6\xa6\xdb\xa9\x51\x59\x03\xf9\x30\xb8\x91\x38\x78\xc4\xd6\x1f\x75\x26\x4c\xb7\x8b\xd6\xeb\x38\x97\xd5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0e\xb8\x4e\x09\x7a\x3c\x15\x4e\x3e\xdc\xad\x69\x14\x67\xaa\x28\x33\xcd\x6e\x2c\x03\x4f\xc6\x7c\x4a\xad\xde\xa3\xe6\x12\xc0\xe4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x72\x67\x22\xe2\x8b\x68\x34\xcf\x52\xa4\x76\xe2\xc8\x5a\x77\x96\xdb\x36\x65\x4e\x16\x3d\x98\xa9\x0c\xa5\xc9\xc5\xec\x5c\xf4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x60\x3a\x8c\xe4\x1c\xd8\x98\x92\xde\x62\xf8\x0c\x2a\x69\xbf\x7c\x52\x5c\xe2\xc9\xd7\x3e\x17\x9a\x40\x33\x55\x73\xda\xfb\x53\x44\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6d\x29\xa4\x35\x3c\xb4\xce\x51\x42\x1f\x99\xe9\x8c\xf3\xcf\xea\x5d\xf6\xcb\x84\xfd\xa5\x97\x2d\xe3\x50\x79\x26\xe1\xce\x85\x17\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\x4b\xcf\x8b\x84\x40\xdd\xfc\xd9\x69\x60\xad\x3a\x94\x2f\x08\xee\x53\x05\x94\xa2\x2b\xa6\x89\x02\xac\x5c\xdb\x09\xdc\xb0\x04\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\xf8\x98\x06\x7b\xf9\x72\x91\x64\x2f\xc3\xf2\x77\xc7\x2a\xff\x18\x15\xdb\xda\xfa\xa1\x53\xdd\x3f\x4d\xac\x4c\xfe\xf6\x5c\x4b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xf7\xa5\xa0\xc3\x23\x14\x9d\x5e\xce\x7d\x78\x7c\x5f\xff\xc6\x4e\x35\x39\xee\x2a\x9d\x06\x36\xbd\xee\x1f\xd4\xfc\x74\x31\x95\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\x2b\xdc\x65\xbc\x46\xba\xe7\x28\xb1\x4a\x2c\xd1\x24\xf9\xd8\x9f\xca\x1d\x09\x4a\x13\x93\x8d\x53\x68\xbc\xbc\xfb\x61\xaf\x24\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\xe0\x44\x38\x48\x41\x78\x08\x6f\x10\x02\x5d\x4d\xd0\x75\x12\x9d\xb6\xee\x05\x43\xde\x3c\xd6\x26\x0f\x00\x5c\xbd\x1c\xfd\x12\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x28\x40\x70\xbc\x32\x1a\x19\xaa\xc8\xf7\x22\x93\xef\x5e\xe4\x04\x7a\x25\x90\x55\x71\xaa\x0f\xc0\xc9\x2a\x99\xfb\x9a\xae\x50\x2d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x43\x2b\x6f\x99\x88\x22\xa8\xbc\x18\x63\xd1\x89\x66\xf0\x25\x7e\x4c\xa0\x28\x60\xd4\x8b\x5d\xa9\x4b\x54\xc4\x4e\x50\x14\x8e\x9e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x50\xa9\x7d\xaf\x4a\x6a\xb7\xe7\xed\x38\xd5\x62\xe4\x5e\x55\x7c\x65\x37\x09\x5d\x5e\x82\xd8\xf8\x77\x0c\x56\x06\xcc\x37\x23\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x90\xb0\xa0\x48\x60\xc1\x75\x4b\xfa\xea\x7c\x66\x56\x75\x47\x19\x01\x9a\xfd\xa4\xdb\x1c\x2b\xdc\xc0\x2e\x70\x77\xcb\x8f\xe4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1c\xa5\x24\x2b\x5b\x82\x2c\xe4\xe5\x47\xef\x10\x44\xc2\x5f\xa8\xe4\xe8\xab\x34\x74\x0e\xe8\x09\x34\xbb\x78\x3d\xfc\xd8\xff\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\xef\x42\x4f\xbf\xa8\xf5\x7f\x53\x0b\x74\x72\x04\x9e\x39\x9b\x52\xd2\x43\xce\x63\x1f\x46\xab\x53\xe8\x38\x96\xcc\xcd\x17\x53\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x31\x3b\x67\x24\xe1\xef\x1a\x2f\x83\x13\xfa\xf9\x95\xb2\x86\xca\x6c\x4e\x29\x35\xc9\x38\xed\xa4\x23\x79\x9a\xd1\xca\x4f\x07\x4a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x71\xd6\xf9\x06\xd7\x67\x00\x70\xed\x88\x30\x74\x56\x82\x41\x80\xc8\x8d\xf4\xd6\xc5\xc4\x30\xec\xde\x2d\x40\x14\x68\x31\x7d\xde\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5d\xa2\x76\xba\x58\x9d\x6a\xe2\x4a\xc8\x95\xbf\x49\x5c\x02\x1a\x5d\xb4\x68\x41\xaa\x15\x59\x2c\xa7\x92\x41\x8c\x61\x03\x92\xdf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x55\x22\xc5\xdf\x7a\xad\x8e\x48\xc2\x8f\xf2\x40\x97\x28\xec\xbf\xf4\x93\xcd\xe8\xba\x22\x62\x78\x34\xb4\x72\xbd\x5f\x7e\x61\x59\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x39\xfd\x68\xc7\x44\x78\x23\x20\x74\x23\x84\xc6\x31\xda\x42\x65\x7f\x76\xad\x1f\xbb\x4b\x31\xcd\x39\xf9\xb9\x98\x71\xa6\x58\xa9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x07\x3f\x7f\x19\xa9\x9e\x12\x72\x02\x01\x01\x43\xd7\x3e\xb9\xc6\x07\xb8\xa4\x72\xd0\x8e\xef\x50\x28\xc0\x14\x29\x6a\xef\x92\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x9b\x17\x32\x45\xa8\x81\x29\xf9\x45\x61\x6b\x77\x53\x80\x4a\x79\xa9\x23\xa9\x48\x60\x99\xcb\xc6\x8e\xea\xb1\xa1\x8a\x3e\xcd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0b\xc4\x70\x54\x1a\x2d\x0d\x36\x13\x2b\x8c\x06\x2b\x98\x8a\x7a\x7f\xc6\x17\xa1\x94\x76\x3d\x1d\xf0\xd1\x85\x87\x0d\x43\x52\x14\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x14\xce\xf2\x8c\xef\xad\xe5\x4a\xb0\x92\x45\x61\x5d\xcd\x48\x12\xf5\x83\x8c\xb7\xe5\x3c\xc3\x99\x4d\xb3\x65\x97\xa8\x39\xf5\x98\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x67\x3b\x64\x89\xcf\x57\xee\xe2\x64\x9f\xad\x4e\xd7\x91\x7c\x77\xc0\x1a\x51\x00\x91\xdb\x44\xcb\x06\x31\xdd\xee\x29\x70\xfd\xa0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1a\xdf\x80\xdd\x64\x38\x87\xe7\x45\xce\xb8\xf8\xa7\x48\x2e\xba\x2f\xa9\xe3\xb2\xa9\xd7\x8c\x04\xe3\x4f\xaf\x05\xed\xac\x21\xfd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x34\x21\xbe\xbf\xd0\x0f\x6d\x99\x84\xf8\xae\x87\x13\x58\x3c\xf9\x04\xbc\x1c\xc9\x31\xd9\xf2\x20\x7e\x46\xf3\x16\xbe\xe5\x57\x5b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x33\x77\xeb\xbc\x99\x86\x9b\x11\x30\x41\x5a\x8b\x6e\xf6\x82\x8a\x09\x1b\xbc\x96\x2b\x77\x06\x79\xba\x25\x33\xb8\x59\xb2\x69\xbb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x53\x1c\x31\x89\xcd\x80\x61\xe2\x5c\x7b\x28\x80\xa7\x5b\x5c\xf5\x4a\x38\xea\xff\xbe\x16\xa6\xbb\x8c\x03\x07\x21\x77\xed\xb1\x7a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1d\xe2\x3e\x2b\x6a\x76\x56\x4e\x45\x6e\xc0\xee\x31\x4d\xd1\x13\x7c\xb7\x21\x45\x8c\x6a\xea\x42\xe6\x8d\xb3\x4f\x7b\xee\x49\x87\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3c\x63\xfe\xbc\xc1\x03\x74\x70\xdb\x1f\xef\xde\x11\xfe\xaf\xa0\x9f\x6b\x93\xd1\xbd\x7f\x4a\xb6\x94\x21\x20\x4c\x7b\x90\x97\x42\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\xf9\xa7\x84\x5f\xf4\xc1\x1b\x37\xea\xfa\xc2\x4e\x73\x2e\x27\x9f\x87\x15\x13\xb7\xe5\x05\x96\x4a\x44\x96\x2f\x26\x10\x79\xd1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x13\x1e\x54\x3c\x8c\xc3\xd6\x6c\x79\xe2\xd3\x4b\x33\x9e\xdb\x4e\x8d\xe1\x04\xf1\x62\x22\x10\x8c\xed\xf5\xed\xe0\xbc\x2a\x25\x9c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\x03\xaa\xcb\xa7\xd5\x7f\xbd\x9f\x8d\x5e\xba\x89\xa6\x5e\x69\xc6\xdc\x17\xd0\x0e\x06\x89\xff\x78\xa8\x53\xeb\xf2\x04\x52\x60\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\xc6\x80\x9b\xc5\x42\x90\x47\x7c\x60\xba\x33\x44\xb6\x8e\x73\x07\x9d\x2a\xcf\x14\x2f\x6f\xe1\x64\x7b\xf7\x7f\x7a\x9e\xcc\x0b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x88\x40\x1e\xe3\x84\xc7\x07\x4e\x4c\xf2\x62\xce\x0f\xad\x61\x7f\xac\x21\xc3\x17\x9b\x13\xbf\xb9\xb9\xae\x28\xb7\x6b\x2e\xd9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x08\x0a\x89\x05\xec\x71\x91\xf9\xe1\xfd\xac\xa7\x49\x10\x0b\x1e\xaa\x03\x94\x8e\x2b\xa1\x09\xf9\xb2\x35\x25\x85\x74\xcb\xc1\x03\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x05\x15\xbc\x8e\x5f\x1b\x4b\x0e\x07\x4e\x27\xf7\x3c\x02\xb1\xfa\x4a\x4b\xab\x66\x23\xea\xb0\x36\x81\x18\x6a\x26\xa9\x6d\xd7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\xd1\x70\xc9\x86\x0f\x7b\x31\x89\x02\xd6\xe3\xe7\x89\x2c\xd1\x1d\x1d\x4a\x77\xf6\xf9\xd1\xbb\xc0\x00\xf7\x10\x68\x6b\xe1\xef\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\xb7\xfc\x35\xaa\x93\xf8\x0a\x4e\xd6\x78\x7d\x9b\x12\x1f\x34\xc2\x91\x45\x15\x94\x0f\x6d\x8c\xcb\xf4\x4a\x1d\xc3\xd5\xe6\x6f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\x20\xc2\x63\xad\xbf\x43\xe7\x04\x33\x81\x68\x17\xa1\xa3\x65\x26\xeb\xa5\xe2\x6e\x81\xa5\x96\xab\xc5\x3c\xbf\xd4\xe1\x0c\x9d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\x63\xf1\xb0\x33\xc0\x9d\xb0\xa3\x16\xee\x60\xeb\xd7\x70\x79\x5e\xa8\x91\x04\x83\x24\x81\x56\x91\x8a\xe1\xf5\x0a\x20\x3d\xe5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\xae\xa5\x3c\x82\xe8\x01\x14\x59\x1f\xea\xf4\x7f\xd7\x65\x39\xec\xca\xeb\xeb\x78\x98\xf3\x86\x18\x26\xc0\x64\xb6\x94\x42\xaa\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x44\x2d\x65\x61\x79\x45\xbc\x65\xcb\x2c\x68\x7f\xcc\xac\x2e\x73\x1a\x78\xcf\x92\x5d\x84\x8e\x9a\x26\xa5\x89\x6c\x7c\xf7\xd6\xc2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x40\x45\x12\x7d\x05\xc3\xcb\x34\xd0\x05\xbc\xc5\x9a\xf6\xe7\xbf\x51\x4c\x50\xe8\x28\x76\x38\xf2\xaf\x91\x30\x9b\x1b\xb5\x66\xb9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x21\x24\x8b\x25\x1f\x26\x13\xad\x1e\x00\x37\xdb\x0f\x66\x2e\x48\x0b\xa3\x76\xa5\x92\x11\xd5\x3c\x0d\x40\x05\xb1\x0e\xd0\x7a\x72\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\x0b\x8e\x02\x0b\x30\x2c\x1e\x75\xde\x56\x0c\x26\x6e\x89\x75\x84\x90\xa2\x85\x2c\x73\x76\xe7\x69\x99\xcb\xc2\xec\xd4\xdf\xea\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4c\xe8\x2f\x49\x9d\xab\xeb\xd5\xf5\x86\x4d\x7b\x05\x45\x26\x4a\x33\x2c\x7b\xec\x6b\xcb\x85\x05\xe3\x8c\xc6\xcc\x87\x94\x02\x08\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x32\x3b\x2e\x2f\x2c\xcd\xb4\xfa\x2b\x7e\x0a\xa8\xff\xe8\x0f\xec\x5e\xbb\x27\x48\x37\x1b\x1e\xe7\x36\x7f\x94\x03\xa3\x01\x7a\x45\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4e\xe2\xad\xae\xfe\xb0\x4d\xd7\xd8\xd5\x67\xbb\xc1\x10\x22\xfb\x1e\xd7\xbe\x60\xdd\xdb\x7c\x10\x90\xbc\x2c\x2e\xaa\x20\xb3\xc2\
+    \x49\xdf\x28\x01\x84\xea\x12\xaa\x38\xe1\xb7\x83\x50\x16\x03\x7f\xbe\x22\x14\xdb\xff\xfb\xed\x63\xb3\xaf\xb3\x58\x7f\xc4\xa2\xf8\
+    \x07\x42\xc9\xcd\x49\x4e\x1f\x34\x9b\x5c\xdd\xa1\x35\x44\x07\xd1\x55\x28\xcd\xc5\xb1\x49\xd5\x21\x78\xbd\x74\x9d\x11\xa6\xa6\x2e\
+    \x4c\x30\x88\x16\x0d\x36\x2a\x2d\x49\xba\xee\x41\xa9\x20\xd1\x1d\x4a\x4f\xb1\x23\x0b\x51\x92\x3e\x3e\x8b\xc2\x93\x1b\x3b\x1c\x14\
+    \x41\x46\x88\x42\x71\xf8\xfd\xdf\x47\x7d\x77\x4c\x4e\xa7\xe5\x90\xa9\x04\x85\x89\x58\xcf\xfb\xf2\xff\x75\x97\x27\xc6\xf4\x30\x7f\
+    \x35\x85\x2b\xcb\x36\x41\x46\x11\xbd\x94\x16\x00\x3e\x36\xe2\xfd\xa4\x66\x39\x64\x7b\x00\x9f\x20\xa1\x57\x1f\xb6\x22\x55\xc0\x6a\
+    \x71\x38\x0a\x2e\x9a\xf8\x6d\x10\xd3\xc8\x53\x20\x6f\x27\xaf\xfe\xd5\xc8\xbb\xf0\x42\x09\x26\x5e\xc5\x3d\xc1\x6c\x30\x97\xcd\x6e\
+    \x31\x68\x25\xbc\x6a\x3d\x33\xbc\x45\x74\xa2\xbc\x08\x0b\x26\x7e\x9f\x90\x32\xcd\xba\x13\xb3\x92\xf3\xce\xf5\x65\x1b\x7b\xd7\x3b\
+    \x03\x9e\xe2\x57\xa7\xab\x11\xb8\x3c\xa2\xf8\x4b\x4c\x3d\x2a\x43\xb9\x97\x95\x93\xa3\xba\xf7\xff\xf3\x1a\x15\x82\x46\x3c\x9c\xd4\
+    \x3a\x7d\x9e\x36\x31\x87\x73\x61\x74\x8e\xad\xed\x82\x8a\x12\x26\xa8\xfa\xd1\xd9\xa2\x6b\x90\x4b\xb7\x67\x2c\x5a\x10\x98\xb0\x10\
+    \x08\xb2\xdd\xe1\xaa\x61\x2b\x89\xc9\x63\x4b\x19\x84\x82\x3f\x33\x15\x15\xc1\x0c\xbe\x93\xda\x28\xd5\x79\xb4\xad\x02\x95\x00\x83\
+    \x28\x6d\xbe\x81\x66\xb7\x5b\x79\xb9\x38\x2f\xe5\xe0\x3d\x6a\x8e\x89\xe3\xe1\x4a\x0d\x86\x82\x22\x53\x4c\x18\x40\xda\x65\x42\x62\
+    \x5c\xd2\xd7\x52\xb9\x80\x03\x3b\x42\x8a\x3c\x84\x28\xe7\xc0\x4c\x13\xb0\x16\x65\x9a\x9d\xb1\x68\x0f\xf0\xa8\x25\x0b\xe4\x34\x5c\
+    \x66\x41\x75\xd2\x30\x39\xd5\x5c\xa0\x72\x1a\xaf\xa9\x19\xaa\x2d\x9e\x58\xf7\xa6\x58\x50\x56\xe7\x2f\x78\x61\xcb\x5c\x68\xdf\x51\
+    \x01\x74\x22\xf2\x85\xfe\x18\x5e\x84\x09\xbf\x4e\x80\x80\x13\x69\x49\x82\xa6\xb8\x28\xe2\x5b\x9d\x02\x6f\xa9\x40\x99\xd8\xc7\xf7\
+    \x41\x26\xfe\xdd\x4c\xe3\xed\xd7\x08\xf5\x50\x4a\x7f\xb8\x0d\x45\x68\x97\x4d\x8e\xfd\x25\x22\xfa\x77\x7a\x20\x5b\x59\x93\x9d\x76\
+    \x36\x2d\x38\x61\x57\xa9\x88\xe3\x27\x18\x0a\xea\x82\xfe\xd7\x31\x87\xfc\x9b\xb4\x31\x7c\x80\x65\x07\x4a\x1c\xef\xe3\xd2\x91\x20\
+    \x5f\x9b\x6f\x0a\x37\xe1\xf1\x05\x79\xbb\x8f\xc1\x8c\x0e\x2f\x1a\x9a\xcc\xbb\x70\x9f\x55\x5d\x93\x1d\x81\x93\x9b\xaf\x21\xa8\x43\
+    \x45\x1b\x11\xc7\x3a\x54\xb5\x52\xb6\x77\x2e\x68\x6b\x9f\x37\x2c\x3a\x88\x05\x7e\x38\x62\x53\x8f\xe4\xfc\x93\xf3\xbc\xa4\x52\x20\
+    \x6d\xc7\xc3\x44\x51\x0f\x5d\xbc\xa0\xbc\x1c\x8a\x43\xfa\xae\x55\x62\xcd\xce\xf2\x19\x5d\x3a\x98\xcd\x1c\xc0\xb8\x09\x37\xdd\xef\
+    \x1a\xcf\x4f\xa5\x71\xf2\xdf\xa5\x59\xea\xa2\xed\x75\x61\x70\x22\xb7\x92\x6b\x7e\x38\x96\x9c\x3c\x40\x4c\xb6\xb4\x0a\x55\xc8\xd4\
+    \x60\x16\x92\xf0\x67\x1f\x9a\x0e\x5b\x64\x5b\x81\xf6\x65\x9f\xcb\xbe\xc9\x57\x00\xc6\xc4\x9c\xc2\x65\x51\xd0\x06\xe2\xe2\xab\xc2\
+    \x2c\x41\x8c\xde\x54\x94\x76\x90\x05\x05\x86\xe9\xfb\x64\x8d\x97\x9a\x04\x11\x7c\x6a\x98\x59\x51\xac\x99\xd3\xa0\x56\x09\x1e\x03\
+    \x10\x99\xeb\xe3\x6b\x8a\x48\x3f\xf9\x16\x0c\x7e\x49\xcf\x27\xd1\x27\x5f\x35\x63\x8f\x60\xd1\x5c\x25\x1d\x00\x4b\xdf\x0b\xe4\x3c\
+    \x70\x5a\xc0\x7e\xf6\xe6\xf3\x0e\x85\x87\xdd\xf3\x09\x71\x81\xf1\xd6\xa8\x21\x97\x83\x43\x81\x8a\xd6\x6d\x0b\x22\x03\xe4\x49\x90\
+    \x37\x6f\x4d\x39\x94\x72\x84\x4d\x98\x23\xbc\x00\xf9\xd9\xec\x72\x51\x27\x3d\xc1\xf0\x85\xc6\xaa\xd9\x69\x98\x6f\x79\x1c\xed\x02\
+    \x60\xdf\xac\x12\xac\x58\x84\xc1\x3e\x13\x67\x74\x13\x28\x52\x79\xd5\xf7\xb5\xdd\x06\x02\xae\x87\xf0\x28\x97\x90\x05\xea\x5c\x68\
+    \x41\x47\x8c\x30\x7f\xae\xb9\xfa\x78\xca\x4f\xd7\x77\x9f\x00\x68\x8c\x7b\x7f\x77\x09\x2f\x28\xfc\xcd\x5a\x02\x63\x72\x7c\xe5\xc4\
+    \x3d\x76\x40\xdc\x7a\xd9\x5c\x1a\x5f\xe3\xbf\xf9\xb6\xdd\x9f\x66\xd2\x7b\xf6\xbc\x99\xd1\x7e\xe1\x67\xfe\xeb\x09\xc4\xb7\x7d\x9e\
+    \x42\x7c\x36\x0b\x99\xfe\x9d\x86\x95\x28\xcf\x4e\x8a\xbb\xa9\x51\xde\xa3\x2b\x5b\x9b\x6f\xea\x4e\x3f\x39\xd9\xe7\x15\xcd\x57\x3d\
+    \x4e\x0f\xe9\x31\x5c\xc9\x43\x4a\x08\xa8\x7a\xef\x6d\x65\xdf\x04\x3f\x89\x61\x65\x02\x26\xf1\xcb\xe2\x6b\xf5\x5d\xd7\xac\x2b\x76\
+    \x69\xa0\x3b\xbf\xab\xd2\xa0\x63\x59\xad\x8c\x74\x8b\xc9\x9e\xc3\x6c\xc1\xe2\x72\x69\x40\x91\x53\x17\xf1\x5a\x81\x3b\xe4\x13\xa0\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_mat_diag_bls12_381_t_4(e: &Env) -> Vec<U256> {
-    vec![
-        e,
-        U256::from_be_bytes(e, &bytesn!(e, 0x07564ad691bf01c8601d68757a561d224f00f313ada673ab83e6255fb4fd5b3d).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x6184e3be38549f7c0850cd069b32f6decbfde312dd4b8c18349b1b3776a6eaa4).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x419289088178ad742be6f78425c0156b6546a18fd338f0169937dea46cfb64d2).into()),
-        U256::from_be_bytes(e, &bytesn!(e, 0x3244cdec173b71a4659e2529b499362dac10cb2fd17562860c8bb9d0fd45b787).into()),
-    ]
-}
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BLS12_381_T_12: &[u8] = b"\
+    \x30\x5e\x73\x82\x40\x6e\x54\x58\x6d\xa5\xc4\x4b\xb9\xbb\x11\x7e\x64\x9c\x62\x53\x6a\x3c\xf5\x7f\xd9\xe8\x39\xf7\x1d\xa5\xad\xf1\
+    \x59\xa8\x0e\xf6\x12\x9c\xc2\x20\xca\x83\xd0\x53\x29\xd7\xad\x7b\xa8\xac\x88\xa9\xf5\x49\x88\xc1\xce\x96\x62\x5e\x6a\x85\x0d\xee\
+    \x2f\x5c\xa1\xb3\xc7\xbd\x7d\x1b\xa7\xb6\xce\xc3\x46\x27\xc7\xf1\xf2\x2d\xc1\x0e\xfa\x2b\xd7\x89\x4c\xd7\x17\x4d\x7c\x0e\x40\x46\
+    \x1e\xf9\x83\x04\x22\x7e\xdd\xad\x62\xf3\xcd\x33\xf3\x3b\x49\xcb\x15\x5a\xcf\x64\x41\x39\xb0\x20\x04\x0f\x8c\xfd\x01\xf4\x96\x56\
+    \x5a\xde\x26\x28\xa4\xa1\x06\x83\x30\x1c\xaa\x3e\xe0\x06\xb1\x4a\xeb\x3b\x9a\x44\x8a\x57\xcd\x9e\x80\x12\x3f\xe7\xbf\x68\xa6\x86\
+    \x3b\xf4\x45\xc3\x49\xae\xc1\x20\xa7\x1f\x13\xd7\xaa\x6c\x7e\xfb\xf9\xc2\xbc\x39\xc5\x1b\x28\xb9\xb8\x49\x07\x49\x34\xf9\xfa\xc3\
+    \x69\x0a\x0f\xb6\xb0\xe6\x01\xa3\xd1\xff\xe9\x87\x0f\x3e\x33\xe0\xda\x28\x83\x23\x36\x44\x74\x35\xc4\x41\xaf\x8e\x9f\x62\x90\x2d\
+    \x1b\xf4\xe2\xf7\xc3\xe5\x95\x63\x6f\x72\x0b\xe9\x92\xa6\xde\x8c\x43\x2f\xbb\xd6\xc9\xbd\x6c\x43\xcc\xe4\x45\xdb\xe7\x3b\x86\xad\
+    \x22\x3a\x9a\xb1\x18\x40\xea\x2f\xdf\xef\xfd\x61\xdd\xe6\xc4\x5c\xd4\x96\x0e\xe6\xfe\x50\xcf\xf8\x91\x7d\xef\x0c\x7a\x92\x5c\x77\
+    \x19\x2f\x70\x67\xb0\x63\x78\xf4\x2a\x19\x04\x32\x46\xc2\xc4\xb2\x80\x20\xd5\xfb\xed\xb7\x41\xc4\x80\xa4\x6e\xd1\x52\xb7\x03\xcc\
+    \x39\x19\x51\x3c\xcc\x2b\x22\xbf\xb8\xea\x86\x51\x74\xfd\x16\xbe\x9d\x86\x69\xf4\x5c\x50\xb5\x07\x57\x18\xe4\x67\x8d\x58\x4e\xfc\
+    \x15\x27\x1d\x23\x01\x67\x8b\x1f\x09\x6a\xac\x18\x93\x04\x12\x87\xd9\xaa\xa3\x17\x3c\xaf\xc9\x71\x7b\xbf\xbf\x16\xd1\xe0\x63\xab\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bls12_381_t_2(e: &Env) -> Vec<Vec<U256>> {
-    vec![e,
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6267f5556c88257324c1c8b00d5871b2eba13cc39d72aa10dde6b69bc44c41c7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x30347723511438a085118166c68bf0c4f4ab5c10a2c55adb5cf87cc9e030f60f).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x10db856965e40038eb6427303181e7b7439f1a051aa4630c26cf86d0a0451a4b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x5a3d2dcd541e4faaae7eb143eec847a0f652b6dc1b92e3f39ec23c808b3a5d63).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3b07f0ff7edcf93b1dd0487bc9fab1c6905f9ceee38dcce83efeb3a320398526).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x40c73c524b9fd0fab63128175befe07b5c63ccdde9ca10e1a37205c9607fdf8a).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3a933861cf23752376d94dbb24b0f3c61630787928875c07672b68abfb9191e0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x71cc165e208570b2d5ef81db84e3c5e714ea4edfb36fc7fb11ef65a64b2d9755).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6c0dc9eb332b5d968bec8ad68fe24ce34087ea54093f153618434475bce402f8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0af5bafd335dae5c86967b11d5dcefb986a54c9d60d35eb06dc7a3fd779b3906).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6e12847918f030f2626c150ab69e4be0f13d202ae1f8bc87ea74323e93372e3b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5565d40e21d059a26db241ca125d9316283eadf144b1318e604e253eeae1fe9a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x608e01b42d3dca09fed9b54eadaaba3e4ce6aefe92b0dc954a0fa4683a9678f2).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x16bbe434b24f94e2c40ed1f4f9bd7d17e5be96c3aec15579b35fd80f0f80de9e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0d1be811a8e73220cab01ce981d475522c3d7dd9e2716c3a2cf4ddd541546890).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5997a3affb18f942868b86f8ee10a68966e90bac7bbd8c65ede7e6e5ef1f6320).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4d92e86d270041061eec80278079fca771499dea5ccdc99682a953bb3a038b8e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x616c8c5ce232b9314f694fc6a968446ea9daf7a4079ce1a75fcc950741d680bb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x677e31e7846d9131bdc350eaf11a8ff918dd258ddd800444424afab34dfdfe3d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4e7d7f85aefc110b233525ee3e53851aee7d3241e2a132585e0e25005eee0b0e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x06a8b4539488b7dddc48c3a226dbda313f906e106f844196d55013d321244f13).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5091517b6a85783108999f8e6bda3c793bef3f2e9589641d260bdfde8bdef00d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0d2703e5b30f54d7f414e901802d54f8c14cd6355415df6e0f063d16bef9c43a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x56f69096811148eb38eec143d32565c077b3d1a4a4351f2b458f43b1659d4495).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x622d94d38d1ded428afd062008c5709b43a678f6ba518ec56383e8ffba473504).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2730c607bba7333723a4a44577819b7db82a24574f6d13eee4c856c1ca3de9c7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x01ac5f59256c5004dc1043c53b23800a3fbab53eb1a83f551056f227b514b9f6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0790b92523c973f1c95b94937afbb5796d89481e7a56328b44bab5ba81ae42f3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d63b59d97bc269d13964fb3e8771d0acc749bc83eb2f0372484e266142bb8c0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a52d04e5f14a3a05f7a01262df9e68c77fdf7e2bfb56c8b252d2140efdf0914).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5aa9b3b808812b284857e8622843a8717fa5cb49b217017f31d79e8d0f963fc0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6a3d18fdbeb1d77ec1304539b00e6188786dbbc4435269b4c6281367f42656e3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4743e860df269a85dd76fb99dbe9d840eb669dc859754b3f74805e57ba288b00).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6c32cac3946825f80a434c5ab397fc1a1c6a9bdfaab53175d4cf3d29ddb6cbc6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x333b0eea5da7ed1e3959d16280a361aa77dd24ecbfb28e1b2583ac4e9894305c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3b503fc333b795ccc0c5bb3ae26b077dc3742cb745ec8821648c5ce7ebd9df18).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4fa5853188d9f728a17532d94bee6fb28fee510380a5d50927c6c5b1ce283444).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5d2ed8a6603a905bac490ebfb9e6c18f0bc9da1bbc2173291b18de6b6186118f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2d830a53584c5556264852f075c78f7f9eb068016ae88af9cda933d6ae52eca7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0250f4d6780ad29ae60e55f135b9ac80ccc7c81e3add37db276c26f1a2b1b86e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6e3e9595f59220599e23e830728d4a0c4d62515ec1ed10b72446cf4df5b4c308).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2cd3314555d6faf23ee90cdb884f1c4697ebe98e3a450a624c4d896233b93cd5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x584a408d0f370543b8413fee70a060a394e561f504d8679f7bece4bf222e4108).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x499cd53437b9fcbf7479c00fcc21295759074ce9bd1bb1fbd3460237aef4759e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x56a9b567bd0646effd0608d74d537991136098d9a06af6cb3ff8f010efb57578).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6a5fae2b00d968b931441b374e27ba4d03b306bd602d48731677169e75a67e8c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2e1cc28e390e64aa1d60edb99c0aeda7c8c32bdb01ba11abbad5026b46eccb27).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2d4820000675df7c276beac408fe2e851e734a7008ae09bbcb3c96c70024f71b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0c2fe101a2b52b538b902c6b2dc992cb266f7636e05b0c068385b5fa19e97142).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x209b790b78c0e7927c6a178ef2f00b8687fc7bd4f21a9e02578551535002bc95).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2dd0926cf56bbaaec6491513d08a9983f94a910852a7b4ea4bd4222b93e14c10).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4316b39dd7d65b1bb575198104d409b169236a7ade371f7ab176fcbae75a5f0d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x540276d61041b91f6ea3068ec260a9338b6e3da15d934e648c24f35aee04e535).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x37af612900b839977b146324c84772c58a4ccc0f6494cc054571827e74bfd2d3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2af00c93d59ed14c9911e5cb3781d772371e83228e4267bbce11d065c1955338).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x62b48779b0cf7ff2c10fd9b91a6ff7b7a99f935e961a5a94aa38f9d4f71c8b4c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x540bf5bbe01f28563bcbe11a2ce346d8231a2cdd0fe07641f9fa89e5c21978e3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x232b6c847a6d23912cb10ecbe50b53491f67f71e9b87a4a30446f2218017874b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0ab34adbe77b8f1e57a370e4fd626071eea74b3f0b66644a629efaa0e96456c0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a83e43ef118c90046b1bdbeab8dd5cdcab632807c2cd0dc9147cbc5b7084be8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1ec6fa41b41b672d9005468720918130b642567462a3d557a595d4dc6c56f2f9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x01f81a153199a751a111b8f5212cfc5bf82aacf0287d03e1864f8e5713fe4a17).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2617307587a675f4ecd73a54a7b206162d751cabf3d9fd007bcca4de2c6f0649).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1647be94c515178c7974a245624b642bb1ae6e2d4e1682087e362d7f98bc953f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6e690b956e00b9e339dec49d675586f661f9b081ee3fa7696d73977658aa6fea).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x660b85bc22de06d476c47bf084ad436f59874f1d630c0f5c91fbef51d5e738c5).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x32bf3d451b69dde075fc370eaa8c1b77b5c0bc2aab1c7b46da7ef9d1840b0419).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x73924b40beaa9c1ce4074c2154d1af4d658c09395a568b99b2fbcc3b5685e810).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x17cbb3ee0adcb9d977e96e9152b36042925244fdd0aa184c7a89a58a2dc40097).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x29d76a821e3220775c552f6b5977ab94956e52b8dac36ef88ace050d553766a3).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x62b1a6c06ab26881a1fe57eceac56b5aec0b96da7211557f4e27ec24296d7db6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0dfc474151e5c605a693a51ae8227cc0a99fdc4524fc2810c6eda9035d04334d).into()),
-        ],
-    ]
-}
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const RC_BLS12_381_T_12: &[u8] = b"\
+    \x45\x76\x7f\x84\xe1\x4f\x4d\x6e\xca\x40\x5f\x2c\x31\x7c\x88\xd2\xfb\x08\xc1\x64\x0e\xf0\x0e\x92\x71\x18\xf1\x89\xaf\x91\x54\x2a\
+    \x42\x2f\x2c\x8c\x3b\x90\x3b\x22\x66\xf4\x57\x11\xa6\x72\x5f\x47\x17\xda\x72\x5b\xbd\xbe\x0f\x6b\xb0\xc8\x87\x25\x95\xb1\x4d\xa7\
+    \x12\x18\x21\x4e\xe5\xcd\xe3\xe9\x1b\xa2\x22\xec\xb7\xf0\x83\x29\x77\xaf\x0d\x68\x56\x77\x7e\x6b\xd1\x0e\x54\x9c\xcc\xdf\xa7\x0c\
+    \x1b\x63\xf1\xb1\x03\x3e\x1c\x5a\x11\x68\x79\xc1\x29\x35\x5b\x15\x00\x97\x7d\x93\xad\xc9\xf8\xdf\x44\x22\x38\x2b\xbd\xd4\x2d\x45\
+    \x50\x81\xd1\x01\x99\x53\x0a\xa5\xe4\xf0\x48\x1b\x65\x19\x5e\x70\x0e\x53\x68\xb5\x2e\x22\x0b\xd7\xcc\xc4\xc4\x5d\x36\x7c\x2b\x09\
+    \x36\x37\x03\x95\xcf\x71\xf3\xa9\xbb\x5f\x7c\xa0\xcc\x37\x75\xa2\x11\xd3\x97\x63\xe3\x85\x12\x5d\x8d\xd6\x63\xb1\x86\xd2\x75\x56\
+    \x31\x00\xcb\x8e\x92\x58\xf4\xd4\x6f\xa1\xa9\x4e\x10\xaa\xaa\x01\x6a\x11\x18\x16\x76\xa5\xbc\x97\x34\xcf\xd1\xe4\x1d\x11\x02\xe4\
+    \x24\x5a\x3b\x23\x58\x52\x37\x41\x4d\x22\x3b\x10\x33\x5e\xf9\xc0\xa6\xdc\x0a\x54\x99\x8e\x96\x09\xa1\xd7\x81\xed\x2e\x27\x99\x4f\
+    \x08\xf7\x7c\x34\x2a\xe6\x4d\xfe\xff\xfc\xcc\x9e\xa5\xff\x5c\xab\x29\x66\x33\xca\xef\x5a\x6b\x37\xfc\xda\xb9\x91\x34\x74\xa4\x52\
+    \x4e\x7e\xd5\xa7\x0b\xbe\xb4\x8f\xc2\xa8\xee\xa4\x12\xc6\xdd\xa5\x60\x0d\xa8\x55\x69\x27\x3b\x63\x24\x76\xa0\xf7\x68\x4a\x91\xd7\
+    \x6c\x17\xb4\x92\x95\xb4\x8b\x5b\x13\x91\xed\x3b\x9c\x47\x05\xd4\x39\x83\x08\x43\xbf\xe6\x4a\xf9\x1c\x59\xbc\x8f\x41\xf8\x9b\x41\
+    \x37\x11\xd2\x5e\xf0\x20\x4f\x74\x6e\xa9\xf3\xd6\x4d\x02\x3f\x01\xfd\x28\x86\x16\xd4\x66\x53\xb6\x14\x03\x2b\x83\x3c\x03\x01\x43\
+    \x2c\x27\xb9\x88\x0d\x14\xda\x09\x31\x18\xb2\x55\x1a\xe9\x41\x15\x1a\xb9\xd0\xe1\xcf\x30\xa7\x67\x76\x39\xd8\x31\x03\x4e\x86\x3d\
+    \x62\x3e\xef\x66\x03\x97\x7b\x86\xca\x6f\x25\xfd\xbe\x5a\x14\x1e\x76\xe1\x72\xc7\x74\x0d\x67\x83\x6c\x5e\xff\xa8\x06\xae\x21\x24\
+    \x29\x83\x22\x37\xf9\xc6\xc2\xcb\xf3\xa1\x3b\x42\xf6\xb5\x36\x3a\xcb\x19\x92\xdc\x1f\x8c\x46\xff\xd4\x46\x2f\xcb\xa3\x76\x21\x6c\
+    \x4d\x38\x1e\x1f\x0a\x12\x69\x55\x7f\x9c\x37\x3a\x60\x46\xf4\x20\xb7\x3e\x55\xc7\xf9\x22\x98\xa3\xa3\x13\xde\x7b\x5b\x2f\xfe\x3f\
+    \x50\x39\xf5\xe7\x17\xac\x29\x39\x32\x78\xc5\x73\xa3\x0c\x0f\x8c\x9c\xdf\xf8\xeb\x76\xee\xc0\xd6\xc5\x8b\x53\x68\x9a\x0a\x22\x3e\
+    \x4f\x4b\x47\xfb\x24\x67\xf5\x14\x28\xfa\x12\xdb\xf7\xfe\x97\xa8\x1d\x67\xdc\x08\x84\xfc\x88\x66\xcb\x5e\x00\xcd\x0e\xc3\x23\x1a\
+    \x6d\x55\x7d\x9c\x96\x47\x97\x64\x75\x6c\x4f\x61\x88\x10\x33\x81\x21\xec\x99\x3b\xc1\x23\xf5\x43\xcc\x6b\xe9\x19\x1c\x6b\x08\xcf\
+    \x4c\x7a\xc8\xb3\x67\x27\x35\xb9\xbc\x59\x8d\xdf\x26\xa6\xff\x9d\xdc\x6d\xb6\xd6\x00\x5c\x0d\x3a\xb0\x5d\x11\xeb\xda\xda\xc7\x64\
+    \x23\x8d\x31\x1e\x0c\x35\x32\xc4\xff\x6d\xa2\xfb\xd7\x5c\xe5\xf5\xfd\x4e\xbd\x36\x27\xfc\x67\x5a\xa7\x33\x78\xeb\xcf\xf6\x5e\x2c\
+    \x14\xe6\x7b\x8e\xd7\xab\x5f\xcd\x4b\xf5\x23\xc6\xec\xc2\x1c\xd6\x62\x1e\x42\xc1\xc5\xed\x3e\xb3\xb6\x0f\x53\xe5\x56\x1e\x09\x00\
+    \x30\x75\xfe\xd1\x23\x86\xc9\x22\xca\x6b\xa5\x54\x63\x92\xee\xd7\x20\x5e\x5d\xbb\x2e\x00\x86\xd4\x21\x85\x89\x4d\xde\x36\xff\xa0\
+    \x2a\xd6\x38\x18\xe8\x65\x10\x6c\x2e\x15\x90\x3c\xf6\x4d\x12\x8c\x10\x09\xf8\x28\xeb\xc0\x74\xc0\xcc\x98\xb1\x86\x92\x4a\xa7\x79\
+    \x1b\x1b\x51\x2c\x55\xa1\x64\xf5\x6f\xd5\xdb\xea\x6f\x8d\x98\x26\xcf\xd2\x6a\x31\x2f\x11\xd1\xd8\x5a\xca\x03\x6d\xef\x1e\x5e\x64\
+    \x6a\xf8\xe7\xc9\x33\x11\x1e\x9a\xa2\xd0\x9f\x63\x45\xd3\xf8\xed\xb5\xb8\x3f\x6c\xa1\xf0\x18\x7b\xa0\xec\x41\x7b\xb0\xa8\xc8\x4b\
+    \x43\x8b\x33\xbd\x49\x12\x0a\x91\xa9\x82\xb7\x44\xa4\x29\x0d\x79\x5c\x62\x29\x0a\x19\x84\x25\x41\x3d\x42\xd2\x93\x35\x9d\x9e\x5d\
+    \x33\xe1\xe0\x52\x09\xe5\xc9\x4d\xb9\xf8\xe3\x51\x5f\x33\x21\x19\x23\xa7\x3f\xb4\xda\x83\xc5\x9b\xdc\x8b\x7b\xc1\x0f\x70\xc4\x0d\
+    \x01\xdc\x2d\xac\x51\xc0\x5d\x19\x06\x55\x9d\x10\x91\x4e\xd2\x8f\xbe\x99\x62\xe9\xab\x3e\xb0\x0c\xd8\xef\x7d\xd3\xec\x0d\x1c\x2f\
+    \x6e\xea\x27\x41\x3c\x4d\xec\x37\x8c\xff\xae\xb9\x39\x38\xef\xae\x4e\x2c\x66\xb5\x79\xda\x51\x96\x2e\xe2\x3f\x6b\x39\xa3\x55\xea\
+    \x04\x3b\x81\xb4\xfc\x5a\xec\xfe\x01\xf7\x1e\x2a\x4c\x59\x06\x93\xa4\xdd\x86\x73\x95\xc5\x8b\x86\x26\x60\xc0\x2c\x2b\xaf\xf3\x08\
+    \x3c\x96\xc0\x76\x7b\xc9\xcd\x2a\xc1\x9a\x5a\x0e\x7c\xb5\xf6\x20\x41\x0a\xe5\x23\x2f\x29\x99\xb1\xa5\xdd\xd4\x6c\x66\xa2\xb4\x2f\
+    \x23\xc6\xf3\x02\x2a\x3b\x26\x1e\x88\xfc\x0f\x96\x87\xb5\x19\xf8\xc5\xb0\x11\x78\x23\x8d\xc9\x89\xfc\xd9\x67\x24\x50\xe2\x97\x9e\
+    \x19\x78\x71\x03\x94\x6b\x9f\xc1\x23\x92\x59\xfe\x16\x2f\xfd\x56\x4d\xb0\x92\x20\x05\x8c\x64\xde\x21\x36\x2a\xf8\x38\xb8\xfa\xb0\
+    \x70\xde\x62\xd0\x21\x83\x81\x18\x72\x85\x8c\x14\xaf\x82\x54\x8c\x39\x8f\xa9\x37\x1c\x1b\xd7\x7f\xac\xa6\xd3\x59\x48\x29\x38\xec\
+    \x44\x6d\xc7\xbd\x14\x6c\x2e\xab\xd2\x0f\x4a\xf2\xe8\x2e\x40\x0f\xd9\x3e\xa0\x59\x74\x94\x6b\x7f\x7f\x51\x8c\x57\xa6\x5c\x48\xd8\
+    \x0a\x2b\xfb\x50\xed\xe5\x0e\x8d\x44\x00\x35\x6f\xb7\x49\x37\xae\x84\x6e\xb8\x5f\x17\xd6\x85\x7a\xe4\x80\x52\xa3\x15\x90\x58\x22\
+    \x04\x80\xec\x8f\xc2\xa5\xb2\x12\x61\x87\x4d\x20\x02\xc3\x4e\x2c\x82\x0d\x19\xa1\xd7\xfa\xf3\x17\x46\x3e\xc6\x39\x00\xfd\xc1\x2f\
+    \x38\xb3\x69\x98\xe4\x71\xf2\xaf\xf1\x5a\x71\xd0\x99\x40\x25\x45\xa4\x6d\x15\xb1\x4a\xe6\x96\xae\x14\x09\x26\xe8\xe8\x5a\x60\xb8\
+    \x6c\x9b\x0a\xe1\x91\xd5\xd4\x33\x43\x9f\x75\xd6\xd7\xa8\xb9\x2f\x95\x04\x2e\xf3\x9c\xd9\xd1\x86\xa3\x0f\x67\x34\xbd\xc8\xd8\x7d\
+    \x03\x6e\x1f\xae\x8d\x9e\x8d\x4c\x60\xb3\x4b\x2b\x5a\xc6\xc0\x3a\x08\x1a\x5e\xb0\x72\xe5\x31\x74\x7e\x11\x4d\x1b\xa2\xd4\x4f\x36\
+    \x37\x18\x9c\x3c\xf6\xbb\xab\x3e\xea\xb5\x4a\xe3\x63\x28\x40\x59\x9d\x86\x9a\x94\xe8\x5e\x0a\x84\x0b\x11\x1f\x40\x3d\x8f\x64\x81\
+    \x1e\xbb\xe1\xd3\xc1\x21\x6c\xea\xf5\x4e\x45\x4e\xde\x64\x88\x2d\xa1\x53\xc5\x97\x45\x7b\xaf\x5c\xa6\x30\x36\xaf\x57\xec\x40\x79\
+    \x4e\xed\xdd\xa0\xf2\xb4\x87\xcf\x13\x42\xce\xa9\x66\x4a\xf5\x26\x3b\x69\xbc\x57\x7b\xc8\xbd\xbc\x21\x8b\xef\x29\x98\xba\x6e\x80\
+    \x0a\x43\x13\x02\xd7\x44\x47\xf4\xc7\x2d\x66\xfd\x09\xb0\x6b\x88\x80\x01\x66\x5f\xa5\x84\x42\x5e\x47\xf7\xec\x69\xc9\x92\x63\xe7\
+    \x40\x56\x8e\x41\xd5\x46\x60\x34\x57\xd8\x7c\xbb\x25\xe8\x85\x43\x7f\xde\xff\x41\x4e\x40\x4d\xc6\x36\xc6\x97\x10\x68\xe1\xa3\xee\
+    \x0d\x50\x20\x76\x71\xd2\xab\x58\xd8\xd4\xbc\xc3\xd3\x04\x19\x0d\x73\x0d\x5f\x1b\x17\x71\x3d\x49\xda\x76\xd1\xe9\xe9\x27\x2c\xb5\
+    \x57\x07\x7c\x7d\xec\x61\x31\x61\x67\xb4\x15\x3c\x46\x97\xf4\x43\x8f\x51\x7b\xc7\x55\x1b\x83\x51\x96\xe5\xbe\x92\xc3\x9d\xd0\xf3\
+    \x2f\x5b\x33\xdf\x47\x37\x31\x3e\xfd\x2a\x44\xcc\x92\x73\xab\xc2\x98\xc3\x6f\xae\x49\xa0\x38\xe0\x07\xaa\x3a\x19\xc9\x97\xc5\xf2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x73\x7f\x3b\x25\xee\x11\xab\xa1\xe7\x0d\xa0\x38\x7d\xcd\x50\x57\x74\xc0\xc2\xbf\x65\x89\x16\x91\x9b\x8f\xf9\x57\xc4\x62\x58\x32\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x54\x84\x3f\x80\x77\xeb\x33\x73\x99\x1c\x26\xb2\x4a\x9d\x51\xb3\x68\x70\xd7\xdf\x06\xbb\xd2\xbc\xaa\xa7\x6e\xab\xd8\x95\x05\xf1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\x46\x84\xf1\x09\x22\x66\x29\xe3\xd0\xfb\x29\xb2\x42\x3f\x19\xe1\xf6\xcf\x27\xec\x4b\x25\xd9\xba\x91\x6b\xac\x16\xb4\x17\xaf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3a\xb1\x34\x00\x7d\x21\x3c\x86\xb3\x5a\xbe\x64\xea\x65\x7f\x3f\x1b\xd5\x7d\x65\x5d\x23\xa3\xf8\x9f\x01\xf2\x4f\x33\xf8\xde\x83\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\xa0\x9c\x74\xf9\xec\x1c\x25\x40\x72\x5e\x5d\x9d\xbe\x1d\x2e\xe6\x77\xea\xb9\x39\xe9\x40\xb8\xf1\x2d\x17\xd8\xfc\xa4\x68\xec\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x3d\x49\x16\xe0\x3b\x83\x38\x74\x22\x81\xab\x5d\x56\xa0\x85\xbb\x5b\x5c\x87\x40\xe7\xb1\x3a\xda\xff\xd1\x02\x77\xd2\x16\xcb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x69\x32\xbf\xb4\xc7\x74\xe8\xa8\x35\xed\x5d\xa9\x34\xd2\x45\x01\x92\x0a\x6a\xe0\x86\xce\xe0\xf1\x0b\xa6\xde\xab\x67\x18\x32\xfc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6c\x56\x75\xec\x64\xf2\x63\x9f\xec\x41\x0b\x4f\xe5\xdc\xab\xc4\x19\xe5\x3c\x4d\x44\xa8\x40\x83\xe8\xf8\x61\x46\x00\x7a\xe7\xe2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x92\xb5\xec\x28\xde\x07\xe1\x0a\x9b\xe9\x66\x01\x65\x49\xa0\xf4\x89\x99\x36\x93\x17\x59\xa3\x24\xff\x2f\xd7\x89\xbc\x3f\x5f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\x32\xc2\xb9\xdd\xa1\x45\x00\x1b\xb2\xc4\x5f\x32\x09\x67\x0c\x30\xbc\x4c\x04\x6f\x4e\xfd\x00\x1d\xa1\x4d\x69\x91\x2b\x0d\x4c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x39\x8f\xe3\x78\x57\xee\x3a\xf7\x16\x67\x7e\x66\x42\xc3\x17\xf3\x98\x50\x38\xfd\x9a\x4c\x44\x10\x56\x65\x25\x91\x30\xe6\x0c\xf2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x22\x1c\x1d\x40\x24\x61\xe1\x0b\x61\xd0\xa0\x2a\x7d\x39\x67\x49\xa3\x01\xb1\x95\xf6\xce\x5c\xf8\x35\xcd\x03\x1d\x34\xd4\x62\x51\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2c\xe6\x58\xad\x24\x9c\x00\x2e\xd2\x04\x1f\x1d\x58\xfb\xc1\x96\x9b\xc4\xec\xf9\xa3\x58\x97\x60\x23\x61\xda\x35\xbb\xea\x50\xa2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x35\x62\xd1\x72\xfc\x8e\xed\x8a\x43\xe5\x2e\x5c\x43\xe0\xc4\xe1\xed\x57\x2a\x61\xf3\x51\xe7\xb1\xf1\x3a\x77\x97\x35\xdc\x45\x25\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x6e\x9f\x27\x28\x9c\xea\x10\x06\xd1\x9e\xbe\x9d\x2d\x41\x74\xd8\x11\xf0\xfc\x26\xf6\x2d\x85\x1e\x93\x1d\x13\xdb\x1e\xca\xa9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3b\x0d\xbc\xea\xd0\xbe\x7d\x25\x08\x0a\xae\x14\xce\xe9\x11\x0d\x36\x87\x58\xc6\xca\x44\x13\x1a\x8e\x89\xbd\xf8\x49\x4d\xac\x52\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x58\x5d\xef\x4e\xf4\x5e\x7c\xf1\x66\x4a\x9e\xf4\x30\x64\x04\x75\x42\x4f\xd4\xf9\xf4\x14\xcb\xcd\xb9\x20\x34\xf6\xac\x8b\xd0\xdc\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x43\x4f\xb0\xf4\x8b\x1b\xa6\xb5\x60\xd5\x4d\xb5\x59\xd0\x00\xe2\xc8\xa9\x80\xf2\x23\xc1\xcd\xac\x3e\xb3\xa1\x5c\x44\x16\x41\x43\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x37\xd4\x8b\x17\x48\xce\xb6\x79\xc5\xa9\xd1\xc0\x50\x1b\x4b\xaa\x79\xc4\x1b\x7f\x68\x83\xb8\x44\x48\x62\x6d\x32\x91\xb7\xa8\xb0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\x18\xd3\x4a\x6d\x2d\x43\x3c\x58\xd0\xf7\xee\xf4\x78\x05\xf6\x6b\xd5\x4c\x35\x7a\x0f\xf8\xc8\x72\x1a\x55\xc0\x55\x3c\x27\xc8\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x21\x51\x0f\x03\xd8\xd7\x68\x53\xde\xe4\x5b\x91\xc5\xe6\xe0\xdb\xa9\x6d\xb0\x83\x95\xa1\xca\x57\xe9\x34\x4c\xdb\xdd\x34\xe2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x76\x4d\xd1\xae\x5a\xa6\x8d\x55\xfb\xc8\xf2\x86\xaa\xdb\xf9\x7c\xd9\x14\x98\x21\xaf\x58\x58\x4e\x33\xe2\x5e\x0a\x17\xd0\x74\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x4c\xe9\x03\x85\xc8\xd1\x74\xdd\xf7\x05\xdb\x75\xd1\x4a\x76\x20\xfd\x8e\xd0\x96\x4b\x28\xb3\xca\x7f\x93\xcd\xa3\x66\x62\x6a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x69\x6f\xd1\x24\xbd\x16\xec\xda\x15\xfb\xb6\x47\xe6\x00\x80\x9e\x35\xd0\x72\x45\x14\x74\xc7\x34\x71\x54\x70\x4d\x52\x3e\x60\x1e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x31\xfc\xbb\x71\x63\xf4\x9b\xc5\xd3\x88\x01\xce\xdf\x84\x2c\x3b\x89\x3a\x56\x8f\xa5\x25\x90\x66\x26\xdf\x97\x6b\xa0\xf4\x91\xd7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x06\x72\x4b\x34\x73\xfc\x3d\xe5\xa0\x70\x00\x50\x0a\x8c\x23\xa6\x2e\x75\x67\xc2\xa6\x21\x83\x97\x24\xa5\x2d\xd9\x63\x4a\x59\xe9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x41\x35\x53\x37\x05\x23\x1f\xbc\xec\x58\x31\xac\xb5\x51\x23\x4f\xdf\x11\x68\xb6\xf7\x8b\x28\xc6\xa2\xc7\x40\x9a\xca\x59\xe8\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1b\x28\xfe\x3a\xfd\xc2\x63\x8d\x88\x79\x23\x07\x5c\xc0\x1e\x40\xd2\x5a\xcc\x1f\xac\x8f\xd9\x41\xfd\x18\x6a\x26\x68\xf2\x8f\x72\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x51\xbe\x2c\x6b\xa1\xf0\x6a\x04\xd6\xa0\x34\xe2\x47\x40\x27\x5c\xd6\x23\x7a\x5c\x04\xc8\xe0\xea\x97\x30\xf5\x0b\xfa\x85\xeb\xc0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x12\xf1\xee\xbb\xbe\x36\x07\xea\x27\x44\x4a\x03\x75\xd3\x75\x9d\xb0\x04\xb7\x9f\x26\xd2\x4e\xda\x1e\xd0\x50\x67\xb9\x46\xd0\xa7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x37\xda\x10\x3f\x0f\xe7\xf9\x9d\xb6\xc9\x6f\x91\xf6\x7c\x2a\xaf\x8e\x1a\x58\x08\xbd\xcf\x8a\x39\x28\x26\x61\xb5\x21\x44\x53\x6e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4d\xf7\xe9\x75\xd2\xc9\x24\x20\x0e\xf7\x57\xcb\xe0\x66\x9f\xff\x21\x92\x00\x46\x7c\xa4\xa9\x5e\xb4\xc6\x33\xa7\x38\x56\x73\x7d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\x7c\xb0\x54\x01\xa4\xaa\xa9\xd3\xfc\xd1\x5e\x17\xf6\x34\x42\x8d\x72\x46\x18\xdc\xe0\x50\x9f\x54\x36\xcb\xd6\xef\xf5\x1a\x3c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x14\x0a\x9c\x6f\x22\x58\x74\x65\x3b\xb3\xcd\x71\x7b\x04\x1d\x66\xe9\x50\xd4\x84\xad\x29\x47\x71\xbd\x91\xeb\xc1\x66\x62\x1d\x70\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\xdb\x31\x06\x01\x15\x29\x00\xf3\x11\xb6\x9b\xa8\xee\x0f\xe6\x5f\xd4\x31\x07\x7a\x8d\xbf\xf8\xb9\x85\xf9\x3b\x6b\x68\xa1\x02\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\xc6\x1f\x3d\xe3\x63\xa8\x42\x8e\x43\xd8\xb4\x39\xb9\x51\x55\xab\x49\x39\x97\xb4\x96\xa8\x07\xb1\xdf\x42\xa6\x2f\x73\xfa\x81\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\xdf\x6c\x15\xd4\xf8\x9c\x48\xb9\x99\x78\x2b\x84\x69\x31\x7b\x6c\xb6\x8c\x23\x03\x0e\x83\xa4\xd1\xbb\xb6\xbd\x9d\xed\x18\x5b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x16\x48\x1f\x5d\x49\xc0\x71\xe6\x5c\xe4\x69\x22\x7c\x9f\xdd\x8f\x19\x80\xae\xd0\xa7\x57\x35\x04\x0e\x23\x3d\x14\xfc\xa7\x67\x73\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x64\x91\xac\xda\x87\x3d\x37\x92\xd5\x53\xf6\x1b\xb4\xca\xb7\x44\xed\x3b\xd3\xf8\x83\xf2\x36\x13\x48\x79\x7c\xb8\xaf\x89\xd9\xe1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4f\x40\xed\x6d\xc8\x75\x37\x12\xf8\x07\xf1\x47\x02\xef\x2e\x5c\x11\xa7\xd4\x70\x95\x23\xe5\x24\x29\x33\x68\xbe\x10\xb0\x0b\x27\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x33\x8b\xc0\xbf\x77\x83\xf1\x0d\x12\x74\xb6\x0e\xb6\x8f\xcb\x07\x6e\x9e\x7b\x2f\xab\x41\x07\xcb\x63\xfa\xb9\xd0\x24\x6f\x54\xcf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x83\x91\x95\x92\xfe\x92\x53\x88\x5d\x95\x81\xa0\x17\x7e\x5c\xa1\xd5\xce\x38\x8f\xa3\x53\x55\x18\xc2\x75\xd1\x43\xfa\x18\x04\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4d\xca\x31\xf9\x89\xd7\xc4\x82\xce\x03\x2c\xde\x51\x1a\x30\x9b\x03\x32\x27\xda\xd5\x16\xcd\xd1\x78\x5e\xb0\x8d\x28\xe6\xf4\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x62\x16\x9b\x0f\xf7\xec\x2c\x67\x41\xfd\xaa\x6f\xfc\x2d\xea\x2a\x24\x4e\xde\xfe\x08\x92\xcc\x54\x55\xcf\x50\xb6\x7d\xfe\x4e\x0d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\x85\xc5\x8a\x36\x4a\x5b\x75\x8d\xda\x04\x7e\x2b\xd1\x64\x7b\x4a\xb4\xad\xd7\xeb\x1a\xf9\x1b\xa5\xf7\x84\xb2\x98\x99\x9b\xfb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\x9f\xb9\x79\x27\x20\x62\x97\x45\xc7\x09\xaf\x1d\x3b\xaf\xad\x5f\xfe\xdc\xe9\x0f\x1e\x24\x61\x41\x32\xb4\xfc\x0f\x2f\x3d\xbb\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\x16\xe2\x5f\x84\x1d\x80\x3d\x14\x7e\xa9\xe4\x0e\x2f\x01\xf9\x6b\x8e\x83\x15\x85\x20\x48\x7f\xfc\xa9\x0f\xdf\xc8\x65\x1e\x86\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x01\xad\x32\xa1\x7f\xa8\xff\x30\xe4\x6c\x63\xf8\x2e\x7f\x98\xcb\x71\xf9\x98\xf5\x20\x0e\x07\x45\x93\x28\x6f\x07\x8a\xac\xd0\x01\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x51\x26\x91\xc1\x67\xaf\x39\x0f\x75\x5b\x85\xd3\xde\x76\x8c\x20\xa9\x32\x8f\x24\x18\xf5\xf7\xae\x15\xa9\x8b\x59\xb1\xc1\x8f\xe0\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x20\x97\x08\x90\x34\x7d\x95\x74\x52\x49\xc5\xa6\xb2\xf7\x48\xea\x7a\x11\x1f\x4d\x72\x9f\x87\x0e\x75\x06\xe7\xdf\xbd\xbc\x3f\xe1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6e\xd8\xf1\x7e\xc4\xc9\x8e\xff\xe9\x9f\xa2\x24\x47\x8c\x40\xc7\x2e\x8f\x88\xfa\x2c\xe1\xbb\x61\xe8\x5e\xd5\xbe\xa9\xba\x54\x32\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x05\x58\x56\xc5\x19\x56\xb5\xc4\x06\x87\x30\x88\x05\x17\x0a\x40\x12\x17\xee\x74\x47\xe5\xc7\x5b\x32\x13\x13\x7c\x6a\xca\x82\x82\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5c\xff\xc1\xde\xb5\x13\x40\xfa\xe1\x61\xcd\xae\x99\xfa\x0c\x52\x8e\x40\x37\x63\xcc\x1a\x99\x35\x10\x26\x6a\xd0\xad\x50\x58\xc7\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x68\x1a\x83\x0c\x69\xc7\x18\xdc\x5c\x1b\x61\x6b\x28\x22\xfd\x5f\xbd\x50\x88\x44\x71\xb5\xfc\x46\x36\x00\x2c\xab\xd3\xce\x09\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x49\xf7\x42\x8a\xcc\x8c\x8a\x30\x07\xd7\x96\xdf\xb0\x4c\xc0\x4c\xbe\x52\xa5\x05\x31\xc9\xcc\xf4\xdb\x79\x8d\xfc\x09\x14\x22\x23\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x18\x2b\x56\xea\xf1\x6e\xdb\xd2\x50\xcb\x89\x0b\xb1\x44\xff\xba\x8c\x76\xad\x85\xa5\x63\x08\x68\x2c\xd1\xd4\xcb\x6e\x26\x7f\x35\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x51\xd9\x77\x10\x9b\x60\xfc\xc8\xaa\x95\xea\xc9\x35\x74\x06\x1f\xb7\x2b\x09\x6a\x97\x38\x0e\xe2\xb4\x36\xa0\x0c\xe1\x71\x95\x7a\
+    \x64\x34\xeb\x45\xbf\xda\x02\xb9\xef\x82\xee\x84\x8a\x04\xd7\x06\xe7\xd6\xa3\x66\x85\x1c\x71\xaa\x31\x2f\x02\xca\x3b\x8d\xfb\x2d\
+    \x2b\xef\xf6\x4d\xf7\x94\x23\x87\xa5\x99\xb6\x12\xec\x3b\xb4\xd8\x21\xd5\xae\xa5\x24\x85\x10\xe4\x87\x28\xda\xdb\xd6\x59\xee\x9c\
+    \x23\x37\xc0\x00\xd8\x97\xc5\xdb\x9e\xf9\x98\x3c\x61\xa9\xbe\xc4\xaa\x48\xd4\x9a\xad\x30\x3a\x02\xae\x3c\x1d\x6d\x78\x3f\x5c\x2b\
+    \x3b\x1a\x07\x6e\x2f\xb0\xcd\x37\x8e\x13\x45\x20\x70\x5b\x00\x7a\x6a\x02\x66\xad\xc4\xf2\x3b\x9c\x96\xc5\x20\x84\x52\x5e\xb4\x6d\
+    \x41\xea\x69\xfa\x8a\xc4\xaf\x45\xb4\x75\xdd\x5b\x26\x35\xe1\xd1\x9d\x2c\xd1\x2e\x3a\x08\xdd\xfd\xf6\x83\xf2\x14\x0a\xe5\xbf\x93\
+    \x03\xf5\x8b\xa3\x33\x7b\xa9\xee\xc0\x64\x70\x3f\xb2\x91\xf4\x10\x8d\xd7\xdd\xa3\xc1\x6a\x31\x7e\x85\xb9\x12\x60\xd2\xcb\xc2\x2c\
+    \x69\x46\x72\x0d\x4d\xb5\xd8\x79\x3b\x83\x33\x1d\xc5\x7a\xc6\xc9\x62\xd5\xbc\x9d\x73\x10\xac\xf8\xf3\xa0\xea\x7e\xbd\xa9\xd4\x96\
+    \x5d\xec\xe6\x81\x9a\xe4\x80\x39\xe2\xd7\x4b\x89\xf9\xd2\x03\x1e\x7e\xa1\x9d\x28\xb5\x7e\xf2\xee\xeb\x24\x58\x29\x56\xd4\xbc\xa8\
+    \x1e\x2e\x99\xd3\xab\x67\xd9\x5f\x96\x3f\x16\xdf\xd9\x40\xfb\x16\x53\x74\x1a\x8a\x07\xd1\x09\xff\x2b\xe3\x42\xfd\x20\xc9\xa0\x39\
+    \x08\xd1\xf2\x28\x68\xdb\x2e\xa4\x5e\x9f\x01\x9e\xaa\x75\x04\x9b\xb2\x33\x15\x3b\x41\x79\x92\x9a\x74\xd2\x14\x4d\x4c\xa0\x7c\x38\
+    \x50\xc2\xb5\x11\x4d\xdb\x8e\x4a\xd0\x5c\x01\xd3\xb3\xb7\x8f\x89\x36\x1e\x2b\xdf\xea\x3c\x7a\xd2\xcf\xf1\x82\xc5\x30\x7a\x1c\x6a\
+    \x34\x26\xda\x26\xe5\xed\x5e\xb3\xe9\x04\xe8\x0f\x28\x1d\x4d\xf2\x1b\x4a\x4d\x57\xbe\x73\xea\xff\x60\xfc\xf1\x5b\xe7\xa8\x13\x44\
+    \x72\x3d\x02\x0a\x4b\x58\x04\xbd\xc5\x80\xff\x39\xa4\x27\x62\x33\x9b\x14\x90\x65\x04\xde\x80\xc5\xe5\xe9\x37\x3e\x55\xcc\x76\x35\
+    \x1b\x85\x7b\xbb\x34\x17\xdb\x25\x81\x98\xe7\x51\x7b\xfc\xaf\xa2\xab\x90\x65\x12\x03\x22\x1a\xda\xba\x99\x3e\x54\x31\x75\xea\xa3\
+    \x00\x87\xd9\x32\xc5\xa7\x31\x53\x1d\x2a\x05\xe5\xe2\x23\xcc\x63\xaa\x21\xcd\x67\x9b\xd4\xd0\xd8\x51\x61\xe2\x4c\xba\x03\xe0\x63\
+    \x22\xeb\x42\x32\xfd\xc1\xda\xe3\x68\x31\x9d\xf2\x76\xa6\x00\x32\x3e\x4f\x33\xd7\xfa\xce\x57\x88\x27\x56\x16\x9c\xd6\x5b\x8e\x13\
+    \x16\x74\xcf\x9c\x13\x21\xa0\x3f\x28\xde\x75\xad\xe9\x50\x49\xa3\x62\x10\xaf\xb4\x53\xca\x06\xdc\xa5\x66\xb8\x56\x03\x1a\x17\x4f\
+    \x3d\xd4\x59\xa8\xf5\xe7\xb9\x0c\x25\xd3\xaa\x61\x28\xa4\x9c\xd8\xa4\xc3\x4e\xcf\x88\x9e\x5c\x6b\x64\x0e\xa1\x01\x97\xfd\x71\x6d\
+    \x2b\xd2\x3f\xb2\x73\x62\x2e\xef\x6b\x63\xe9\xff\xd2\x87\x93\x86\xf8\xe3\x03\x09\x72\x84\xcc\x65\x8b\xb3\x5e\xe8\x89\xd7\x11\x35\
+    \x30\x59\xcd\x45\x00\x80\x2d\xc4\x88\xdf\x2a\x7f\xbb\x26\x19\xca\xe4\xa4\x7a\x6e\xa9\x19\x03\x96\x54\xd5\xf0\xc1\xfb\xdb\x95\x4f\
+    \x23\x59\x02\xab\x11\x26\x50\x59\xe0\x12\xe6\x10\x6f\xbc\x29\xdb\x16\xdf\xc6\xc2\xd4\xfa\xf6\x64\x33\x50\x95\xae\xcd\x9c\x02\x5a\
+    \x04\x60\x66\x80\xaf\x2c\x83\xcc\x3e\xcf\x4c\x5b\x80\xd1\x65\x17\x55\xaf\x7d\x54\x5f\x82\xb2\x15\x2d\x18\x3e\xf8\x7c\x22\xe5\xbf\
+    \x28\x0a\x7f\xcf\x52\x9a\xf7\xe9\xf9\x2f\xff\xd2\x7f\x05\x8b\x84\x1e\x71\x5a\x17\x4c\xff\xf4\xe9\x78\x04\x75\xff\x45\xef\xa2\x40\
+    \x0a\x3f\xc2\x67\x42\x97\x8b\x41\x0e\x73\xbd\x7b\xe0\x7c\x46\x92\x1f\x33\x71\x9a\x3f\x1c\xea\xa8\xef\xfd\xe8\xd5\x76\x49\x78\xc4\
+    \x1d\xd9\xee\x29\xda\x96\x83\x73\xa0\x59\xeb\xfe\x37\x2e\x21\x03\x89\xdc\x4e\x88\x76\xde\x9f\xaf\x0a\x0e\x8e\xda\xff\x9e\xd9\x53\
+    \x04\x49\xa1\xe7\x43\x5c\x94\xc7\xe3\x0f\x80\x6b\x3b\x4b\xd8\x61\xd3\x54\x38\xc5\x71\x68\xfc\x13\xc9\x5f\x81\x5a\xde\xa7\xb7\x4e\
+    \x0e\xef\xce\x9a\xec\xf0\xb7\x9b\xe9\xff\xc4\x73\xd4\xc5\xc7\x0a\x33\x3d\xb7\x1e\xec\x91\x42\x59\x5f\xa1\xc9\xb0\x0e\x7b\xdc\x01\
+    \x36\xc3\x1d\x80\x67\x0f\x55\xbd\x95\x0e\xdd\x2b\x59\x3d\xa9\x02\x54\xdd\x74\x85\x89\x80\xbb\x36\xac\x09\x0c\xa4\x62\x09\x49\xc1\
+    \x22\xab\x60\x1e\x85\x81\xc6\x3f\xe0\xeb\x36\x2c\x70\x7c\x6a\xcb\x97\xbb\x50\x13\xa2\x2f\xa4\xa9\x59\x87\xe2\x18\x4e\x3e\xa5\xb1\
+    \x6f\xea\x7d\x4d\x76\xfd\x60\xa1\x20\xc6\x20\x86\x5c\xd2\xe2\x10\xf4\xac\xfb\x4e\xbd\x86\xe6\x35\x7e\x88\x3c\xeb\x0d\xd4\xd8\xd4\
+    \x69\x86\xad\x56\xe1\xf8\x00\x22\xe3\x10\x4b\xa3\x78\x96\x58\xcc\x22\xb4\x28\x31\xb6\xb9\xba\x0c\xe1\xa6\x89\x82\xab\xd3\x9e\x21\
+    \x5a\x6d\xa1\x3d\x75\xfd\x19\xcd\xea\x2b\x41\x61\x96\xc3\xa3\xfd\x13\x11\xfb\x38\x63\xb5\x43\xc0\xbb\x19\xd4\x14\x62\xdd\x83\xac\
+    \x1e\x36\x99\x9b\xff\x99\x93\xa2\x9e\x5f\xc8\xdb\xa7\xb4\xe5\x36\x21\xcf\xcf\xc2\xe4\x27\x1a\x87\x9f\x38\x28\x8c\x5f\x16\xb3\x47\
+    \x1e\x93\x5b\xad\x14\x15\xe3\xfb\x21\x33\x8d\x4c\xb5\x88\xf4\xf8\xeb\x2e\x4b\x77\x22\x7c\xc5\xd1\xf2\xc5\xec\xec\x73\x18\x1b\x55\
+    \x3c\x99\x4f\xac\x6a\x25\xfb\x9f\xbc\x4c\x83\x7d\xbf\x76\x4d\x55\x8c\x4a\xba\xb4\x55\x2e\xfe\x89\xc4\xbc\x66\xc0\x78\xca\xe4\xd9\
+    \x33\x34\x48\xe8\x2f\xf3\xc8\x54\xd2\xfb\x04\x4f\xb2\x66\xd2\xcd\xd1\x9f\x2d\x56\xec\xfb\xf7\x29\xa1\x08\x19\x05\xf5\xfe\xf9\x0a\
+    \x1e\x24\xa4\x05\x08\xfe\x4b\x80\x0d\x58\xb8\xb6\xfe\x72\x95\xcb\x5b\x39\x12\xa4\x93\x22\x47\x72\xff\xd7\xd6\x87\x88\xa5\x9d\xf5\
+    \x35\x6b\x95\x57\x3b\x0c\xce\xd8\x53\xdf\xea\xa7\x0b\x7e\xf0\xb2\xcb\x59\x71\xeb\xfc\x7d\xfa\x6f\x7e\x01\x15\x96\x6b\xa5\xb3\x57\
+    \x64\xa9\xfc\xaa\x5a\xd3\x4b\x67\x24\x93\x1a\x95\xda\x6e\xdc\xb8\x0e\x49\xf6\x31\x43\x52\xe6\x44\x42\xbd\x27\x7a\x42\x4d\x66\xd2\
+    \x18\x10\x63\x84\x79\x13\xc5\xa5\x80\xe6\x0c\x97\x36\x7d\x68\x0f\xfa\x32\xe5\xfd\x99\x37\xa9\x06\xd4\xe6\xd3\x7e\x5c\x80\xfe\xa9\
+    \x03\x8f\xd4\x24\x1e\x7b\xa4\xfc\xcd\x67\x9b\x96\x84\xb5\xe4\x96\x96\x96\x0d\xcf\xc3\x5b\xd9\xb0\x73\x04\x55\x2b\x49\x21\x27\x3a\
+    \x4d\xcd\x15\x56\xac\xb3\x7b\xdd\x85\xd3\x03\x70\xb3\x5a\x90\xd6\x27\x5d\xda\xeb\x9d\x24\xa3\x8c\x8e\x9f\x9c\x23\xa8\x98\x22\x79\
+    \x57\x5e\x96\x69\xef\x7c\x1f\x69\x49\x31\x3f\x59\xb0\xf4\x46\x72\xcd\xaa\xb8\xc5\x4e\x1d\x02\x2e\x50\x02\xe3\xb5\x62\x6e\x7d\x89\
+    \x26\x02\x11\x92\x62\xff\xe4\x4c\x84\x1d\xfe\xa5\x96\x6b\x47\x16\x44\x65\x07\xe4\xbb\xc0\x89\x40\x21\x72\xae\xf2\x91\x64\x74\xde\
+    \x73\x98\xc3\x7d\x75\x8f\xf0\x6f\x5f\x9d\x19\x04\xec\xb2\x4e\x88\x13\xe6\x85\xae\xac\x85\xfe\x8b\x28\xd7\x5d\x30\x8d\xfd\x35\xd0\
+    \x12\x0b\x8a\xce\x0c\xa3\xf5\x0d\x81\xd7\xf4\x30\x96\x47\xb0\x65\x15\xa7\x3e\x2b\xa9\x12\x34\x8d\x60\x3b\x73\x11\xaa\x1a\xae\xe3\
+    \x0a\xb3\xf8\x8b\xd4\xa3\x50\xdf\x34\xba\xb9\x0c\x96\x4d\x27\xc5\xf0\xe1\xb9\x5b\x6a\xd5\x07\x42\xc5\x5b\x3a\x57\x10\x83\x31\xff\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bls12_381_t_3(e: &Env) -> Vec<Vec<U256>> {
-    vec![e,
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6f007a551156b3a449e44936b7c093644a0ed33f33eaccc628e942e836c1a875).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x360d7470611e473d353f628f76d110f34e71162f31003b7057538c2596426303).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x4b5fec3aa073df44019091f007a44ca996484965f7036dce3e9d0977edcdc0f6).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x67cf1868af6396c0b84cce715e539f849e06cd1c383ac5b06100c76bcc973a11).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x555db4d1dced819f5d3de70fde83f1c7d3e8c98968e516a23a771a5c9c8257aa).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2bab94d7ae222d135dc3c6c5febfaa314908ac2f12ebe06fbdb74213bf63188b).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x66f44be5296682c4fa7882799d6dd049b6d7d2c950ccf98cf2e50d6d1ebb77c2).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x150c93fef652fb1c2bf03e1a29aa871fef77e7d736766c5d0939d92753cc5dc8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x3270661e68928b3a955d55db56dc57c103cc0a60141e894e14259dce537782b2).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x073f116f04122e25a0b7afe4e2057299b407c370f2b5a1ccce9fb9ffc345afb3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x409fda22558cfe4d3dd8dce24f69e76f8c2aaeb1dd0f09d65e654c71f32aa23f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2a32ec5c4ee5b1837affd09c1f53f5fd55c9cd2061ae93ca8ebad76fc71554d8).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5848ebeb5923e92555b7124fffba5d6bd571c6f984195eb9cfd3a3e8eb55b1d4).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x270326ee039df19e651e2cfc740628ca634d24fc6e2559f22d8ccbe292efeead).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x27c6642ac633bc66dc100fe7fcfa54918af895bce012f182a068fc37c182e274).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1bdfd8b01401c70ad27f57396989129d710e1fb6ab976a459ca18682e26d7ff9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x491b9ba6983bcf9f05fe4794adb44a30879bf8289662e1f57d90f672414e8a4a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x162a14c62f9a89b814b9d6a9c84dd678f4f6fb3f9054d373c832d824261a35ea).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2d193e0f76de586b2af6f79e3127feeaac0a1fc71e2cf0c0f79824667b5b6bec).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x46efd8a9a262d6d8fdc9ca5c04b0982f24ddcc6e9863885a6a732a3906a07b95).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x509717e0c200e3c92d8dca2973b3db45f0788294351ad07ae75cbb780693a798).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x7299b28464a8c94fb9d4df61380f39c0dca9c2c014118789e227252820f01bfc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x044ca3cc4a85d73b81696ef1104e674f4feff82984990ff85d0bf58dc8a4aa94).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1cbaf2b371dac6a81d0453416d3e235cb8d9e2d4f314f46f6198785f0cd6b9af).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d5b2777692c205b0e6c49d061b6b5f4293c4ab038fdbbdc343e07610f3fede5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x56ae7c7a5293bdc23e85e1698c81c77f8ad88c4b33a5780437ad047c6edb59ba).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2e9bdbba3dd34bffaa30535bdd749a7e06a9adb0c1e6f962f60e971b8d73b04f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2de11886b18011ca8bd5bae36969299fde40fbe26d047b05035a13661f22418b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2e07de1780b8a70d0d5b4a3f1841dcd82ab9395c449be947bc998884ba96a721).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f69f1854d20ca0cbbdb63dbd52dad16250440a99d6b8af3825e4c2bb74925ca).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5dc987318e6e59c1afb87b655dd58cc1d22e513a05838cd4585d04b135b957ca).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x48b725758571c9df6c01dc639a85f07297696b1bb678633a29dc91de95ef53f6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5e565e08c0821099256b56490eaee1d573afd10bb6d17d13ca4e5c611b2a3718).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2eb1b25417fe17670d135dc639fb09a46ce5113507f96de9816c059422dc705e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x115cd0a0643cfb988c24cb44c3fab48aff36c661d26cc42db8b1bdf4953bd82c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x26ca293f7b2c462d066d7378b999868bbb57ddf14e0f958ade801612311d04cd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4147400d8e1aaccf311a6b5b762011ab3e45326e4d4b9de26992816b99c528ac).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6b0db7dccc4ba1b268f6bdcc4d372848d4a72976c268ea30519a2f73e6db4d55).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x17bf1b93c4c7e01a2a830aa162412cd90f160bf9f71e967ff5209d14b24820ca).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4b431cd9efedbc94cf1eca6f9e9c1839d0e66a8bffa8c8464cac81a39d3cf8f1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x35b41a7ac4f3c571a24f8456369c85dfe03c0354bd8cfd3805c86f2e7dc293c5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3b1480080523c439435927994849bea964e14d3beb2dddde72ac156af435d09e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2cc6810031dc1b0d4950856dc907d57508e286442a2d3eb2271618d874b14c6d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6f4141c8401c5a395ba6790efd71c70c04afea06c3c92826bcabdd5cb5477d51).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x25bdbbeda1bde8c1059618e2afd2ef999e517aa93b78341d91f318c09f0cb566).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x392a4a8758e06ee8b95f33c25dde8ac02a5ed0a27b61926cc6313487073f7f7b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x272a55878a08442b9aa6111f4de009485e6a6fd15db89365e7bbcef02eb5866c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x631ec1d6d28dd9e824ee89a30730aef7ab463acfc9d184b355aa05fd6938eab5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4eb6fda10fd0fbde02c7449bfbddc35bcd8225e7e5c3833a0818a100409dc6f2).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2d5b308b0cf02cdfefa13c4e60e26239a6ebba011694dd129b925b3c5b21e0e2).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x16549fc6af2f3b72dd5d293d72e2e5f244dff42f18b46c56ef38c57c311673ac).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x42332677ff359c5e8db836d9f5fb54822e39bd5e22340bb9ba975ba1a92be382).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x49d7d2c0b449e5179bc5ccc3b44c6075d9849b5610465f09ea725ddc97723a94).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x64c20fb90d7a003831757cc4c6226f6e4985fc9ecb416b9f684ca0351d967904).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x59cff40de83b52b41bc443d7979510d771c940b9758ca820fe73b5c8d5580934).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x53db2731730c39b04edd875fe3b7c882808285cdbc621d7af4f80dd53ebb71b0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1b10bb7a82afce39fa69c3a2ad52f76d76398265344203119b7126d9b46860df).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x561b6012d666bfe179c4dd7f84cdd1531596d3aac7c5700ceb319f91046a63c9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f1e7505ebd91d2fc79c2df7dc98a3bed1b36968ba0405c090d27f6a00b7dfc8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2f313faf0d3f6187537a7497a3b43f46797fd6e3f18eb1caff457756b819bb20).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3a5cbb6de450b481fa3ca61c0ed15bc55cad11ebf0f7ceb8f0bc3e732ecb26f6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x681d93411bf8ce63f6716aefbd0e24506454c0348ee38fabeb264702714ccf94).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5178e940f50004312646b436727f0e80a7b8f2e9ee1fdc677c4831a7672777fb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3dab54bc9bef688dd92086e253b439d651baa6e20f892b62865527cbca915982).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4b3ce75311218f9ae905f84eaa5b2b3818448bbf3972e1aad69de321009015d0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x06dbfb42b979884de280d31670123f744c24b33b410fefd4368045acf2b71ae3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x068d6b4608aae810c6f039ea1973a63eb8d2de72e3d2c9eca7fc32d22f18b9d3).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4c5c254589a92a36084a57d3b1d964278acc7e4fe8f69f2955954f27a79cebef).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6cbac5e1700984ebc32da15b4bb9683faabab55f67ccc4f71d9560b3475a77eb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x4603c403bbfa9a17738a5c6278eaab1c37ec30b0737aa2409fc4898069eb983c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x6894e7e22b2c1d5c70a712a6345ae6b192a9c833a9234c31c56aacd16bc2f100).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5be2cbbc44053ad08afa4d1eabc7f3d231eea799b93f226e905b7d4d65c58ebb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x58e55f287b453a9808624a8c2a353d528da0f7e713a5c6d0d7711e47063fa611).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x366ebfafa3ad381c0ee258c9b8fdfccdb868a7d7e1f1f69a2b5dfcc5572555df).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x45766ab728968c642f90d97ccf5504ddc10518a819ebbcc4d09c3f5d784d67ce).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x39678f65512f1ee404db3024f41d3f567ef66d89d044d022e6bc229e95bc76b1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x463aed1d2f1f955e3078be5bf7bfc46fc0eb8c51551906a8868f18ffae30cf4f).into()),
-        ],
-        vec![e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x21668f016a8063c0d58b7750a3bc2fe1cf82c25f99dc01a4e534c88fe53d85fe).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x39d00994a8a5046a1bc749363e98a768e34dea56439fe1954bef429bc5331608).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x4d7f5dcd78ece9a933984de32c0b48fac2bba91f261996b8e9d1021773bd07cc).into()),
-        ],
-    ]
-}
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const MAT_DIAG_BLS12_381_T_16: &[u8] = b"\
+    \x6e\xb2\x14\xdb\xad\xa3\x28\xc5\x5f\x98\x17\xc4\xf1\x17\x9e\xe7\x98\x00\x62\xf2\xa1\xbc\x66\x8f\x05\x7a\x6b\xf9\xec\x30\x52\xfb\
+    \x45\x15\x68\xd9\x0f\x01\x59\x85\x98\xcb\x78\xef\x91\x31\x71\xb0\x88\xe9\x3b\x6b\xb2\x53\xe3\x2c\xbc\xdf\x2a\x8d\xad\xe1\xcf\x82\
+    \x73\xe8\x12\x68\x53\x76\xb1\xaa\xf8\x10\x86\x6a\xea\xc2\x0b\x6e\xcb\xe3\x43\xae\xb2\x7f\xb6\xa8\x8b\xa9\x3a\xa3\xc4\xa2\xcf\xe8\
+    \x49\x76\xed\x12\xb7\xe0\x8d\x36\x2f\x5d\xfc\xbb\xed\x01\x2a\x54\x40\x24\x78\x87\x49\xda\x2d\xae\x40\xce\x0a\x4c\xb3\xcb\x0e\x19\
+    \x28\xc7\x26\x62\x7f\xf9\x6f\x8a\xb3\xa9\x1c\x08\x5c\xc6\x24\xef\x34\x99\x0d\x1f\xdc\x68\x2c\xfd\xeb\xce\xbc\xc2\x5b\xbd\x74\xb1\
+    \x10\x0d\x31\x21\xa6\xa2\x11\x7d\xf4\x8e\x0c\xd6\xdd\xf1\xa5\xa5\x29\x1d\xfe\x77\x68\x4e\xec\xbe\x3b\xc1\xa7\xbc\x82\x58\xd1\x87\
+    \x50\x4f\x68\x4b\x4d\x18\xa4\xab\xc5\x13\xb8\x34\x8a\x40\xe0\x99\x18\x0d\xfd\x12\x19\x5a\x22\xb3\xf8\xa5\x50\xd8\xb6\x97\xf1\x1a\
+    \x11\xee\x76\xa5\x69\x03\x0c\x45\xf3\x18\xcf\x56\x26\x2f\x69\xbc\x11\x80\x02\x6b\xaf\xde\x10\x66\x98\xb0\x85\x15\xac\x3b\x80\xaf\
+    \x73\x9f\x1f\x72\xdc\xf8\x08\x88\x89\x00\x14\x8a\xa7\x82\x30\xc8\x1e\xfa\x79\x41\x61\x26\x02\xf2\xd9\xce\x02\x78\x3c\x1a\x4b\x7c\
+    \x66\x7b\xd7\xd9\xf7\xd2\xe3\xc3\x91\x9d\x61\x77\xf2\xeb\x82\x73\xc5\xae\xbd\x28\x97\x9a\xc8\x61\x6b\x11\xf8\xac\xe3\x2c\x3b\x0b\
+    \x6d\x26\xe4\x53\xae\x25\xab\x85\x7d\xc1\x9f\xc3\x04\xeb\x9f\xf5\xe6\x95\xa0\x00\x10\xe5\x18\x96\xa5\x76\x0f\xc5\x93\x76\x0e\x76\
+    \x30\xf6\xab\x53\xb9\xd6\x00\xff\xd6\xa4\x0d\xab\x89\x09\x25\x37\xc1\xc9\x60\x47\x3c\x78\x0c\x1b\xca\x81\xbf\xb4\x61\x8f\xd4\x0a\
+    \x17\x4a\x3e\xde\x13\xa3\x10\x97\x23\x41\x7f\x75\x27\xb9\x3e\x37\x6b\xb3\xb3\xa6\x48\xc5\x48\x02\x03\xd3\xee\x6d\x44\x04\x46\x4c\
+    \x71\xb9\x1c\x63\x70\x41\xd7\xff\xf1\x8b\x9f\x29\x8d\xe0\x9f\xa6\x59\x45\xf0\x85\x66\x21\x0f\x7a\x86\x61\x5a\x42\x19\x17\x97\xf9\
+    \x24\x5a\xd8\x7b\xe5\x84\x5d\xe3\x33\xcf\x6d\xed\xc1\xe3\x5d\xe2\xda\x38\x3d\x73\x9f\xbf\x18\x44\xb1\x8e\x7d\xd5\xdc\x88\x69\xd0\
+    \x02\x6d\x3a\xe4\x9f\x72\x11\x80\xa3\x15\xa7\xa0\xb8\x4f\x80\x09\xe5\x68\x3d\xd0\x81\x18\xac\x77\x32\xb8\xed\x05\x3b\xf6\xf8\x8a\
+";
 
-#[rustfmt::skip]
-pub(crate) fn get_rc_bls12_381_t_4(e: &Env) -> Vec<Vec<U256>> {
-    vec![
-        e, 
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1a3bdcbfc11dabfb6ed0dd5f5a9b38191488bce9eecd811c10f9378b32db8c61).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x52b733e857912fdd2248dc9638dd79b1ce18b285b27792238b44c2b23c0f5d5f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x47d6df02d73e6c78ced550ec8df1a459ac41f318d8b904a37652b581b2b766b8).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x11ec284726dddcf3cbb2b81862c9cd95e9de81ce0317302e1ef432d59b913388).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x19e21d749905904f3e10cd57f7817c2564c06ec1b1e229def2129e79a7a77738).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x6eeecf4c83e1ec164217f3c00956d83c3bd845bee2d86b263cf3bf89a345e5e1).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x10d5808ab47295f7a950dc72f968398b9c67426a217811b9bd7e97f2a261992f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x25d334fbadcbfd26449c4ce1472f961f7d401a3ddf40a70af5c10ac9176d151b).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3ef8a46ec07ee551ecba60b1601cbf6e3664418331a254729b7c6a5c3d13a6c6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x30d373e298c09d2a6efe661d708fcfa6163dc61ae1eb103d98cf88c63e482125).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x18769839949b2dda9ef3f111acd86e10250ffad4c4c4263ea970e4f4726cfad7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c941286d1534ff3be3a66f9dd59833e65b1fa67db23511f7b4b2ae3b3d66bc9).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x08b6d9c90b4139ea70497ac8a22b30fb9b76b8ceabf70449d282b57d98c60ddd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x3a8a2dd917222a71ca678b5c0a803a43c62de2f2c9fe37931b5b2a017fe64638).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x2dc17308abd0e731b3cffafd296cc3e6e2403dc563baa1c797a68da9754dd207).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x1d04e542f54431c23f9d1812392a0c87203144e343c459d3ee640b04e203be6c).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x500760e2ef6bf463fdecbf7b47f4adaa8214c797e59359439d63169e1cdb9dfb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x61f36faac1c12d756dcc3b5491f2b22565409a4e952b8f8e726ee126c0e1c0e6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x49e72db524a48243849fea068e58d0c078b0c738cd482a2e2160602657456ccb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4a74677fff94821e97b20b86063c36d35c6a2908c0a19a9f130edde9c8ceb6b5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x62d27fd5080b44a436e13066ce1c80ddef2af086c2a4806bd99fa02de9726ded).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x15a26c92fc51dd285a61c2c1379f6731377aeb29c62696aa6a9c109c2991f200).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1db900a5e9b2685be9f8f02498123ac71bd7bfa40b6853ab527a8d2512b9aefb).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6ff755d237742498e0b78829cd8358bdb831e96fef08eb192b7e334123080afa).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1595447e5b94a64c8b3681f57068e19ee3696440ef785e35123886311f48cd1e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2b0eabc08eef3abae3304cbb2efc67e7b437bd96b6f7d175daed6da5a2144e8b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3a345ee8e715f3226e52838652219202efb4e6d4f057f904d69d3fbd781e39f5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x49eb2a07b5da81b57b7aa4fab19e691f9a684baef4cfb5afb6f46d23cbf1a9cd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x3051663ef7de7674506f2c873409725363849564815b8bd291f00b9c60b4daf0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x041a632a7bc167f67ca9c3825021a05b13f81f814ee101d37716e12efec3121f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6a5ae006b70bfebc1a485eed2c078654dc0f10514e8c739ca2a7019f42444ce5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0f3dba730b64dda2bc2eaa50ede0fcaa86a37c74c3a17be97a9d969fca21fb37).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6d6c02004f2a4cbb965f9e73799041fafb49f61aee66232f45ae09972af08e86).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x60391f436a32fdb0eeba8578313579c04c48c126f4eb6a0c49e249ff1c1fbf4a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x570a30710a42539d4440ce9a2cc7d9ac102409a188960d0fbd249fc4352bf3e5).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x35cb6ce55352599332471ad8be46ddc0cf598e0e36b8a8fa5fae6f26277b318e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x48a8116b254b171052251f353c93ca3816734e4e0baeea10b4269bbab9a2be55).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x126258211a4bb2035121c7ca129eae719d4ddfefa99921a8befb7a160e0f845f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5bf6985473bc5500f140844f5b66cea85815772cce03596eda60e6c7dcb4ebbd).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5af46d3dd02261d893c418582188d328a8a84ff8f6ac6c1997d3aaadf64220d9).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5e3c84925609016da58df82c1f2b51590e3b91e5502dca2eba13d8354916ddbe).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x410e6fb4390379a8b726cfb22a9eaab7232bc4b60eaddb629a4aacfc7dcdbfcf).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2ca5debb9de1c4bbfa0204543dd9757241611cd28b5eac2d7f37baa293fa2618).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4a1554023154a99b4572572ccd5d89fa8745c117a2a85e5786ef6ef39410d611).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2ca75fb22881b238b6c96da6b908f922da8b54f909237051775c64fec8d5920c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x546ea8ad3dffe14c4c96e4d6d8c43c644212fb663486165b34cb26ddd717f341).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6b168ee87f2c532efdb378116dfe462f632a18eccf1e9bcfc740d35e535b2735).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4cf917a780b850292f44502b80865d3664d75213f89e8ae5861dbcb52aa95e76).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x02e8dfe9046d8b951532466bdf1b62571b85e551b34c8bbfb1ccd3216271b73d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4a34d2abd01f09f743cc56a3f87febd60ae5d88e224918d840656da973d9252e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0a3fad26c6318d471fa376fbaf02d341e41e687db6f3da88266de21c7157e940).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x131c9df7176eadb9af3a14f0f53a9b87196ef60bbb89b3630142ff8d9d69137b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5753682a8120106b42241c86deee879263d81fe8e3423e01f567316d9524647e).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5ed9725af6a91090027acfc12100733a9dea89771048aa2dfe40daea9546645b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c7b7a87f31ebd2464be9f211d41719b453939d53ff7be07a2cc21741d48b467).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x118159eb07353fef60e9733077cc82f92a6e2bd361c88d8122e9a292217757e0).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x5b4626e787d4a4b00ba4693fd7900ecaa19624be9f2fee6b9c3b538d4f896b8a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x18cad22ae06bb0e8d043f7928477219e4d38ac544ce81c4c685267089e4c7003).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0511149594758e789b440ce534b52b6c508ae1881669d1fa4a7e551ef84e4dc4).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x428b8532efa194a7f5cbf4c296786346ddfcfe8f2bab26f25321f5d984dfc307).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x00fd035cc129e91eac66d841e2864989d1ff4853662166143535603bf9460621).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2c167565114475412c76f0df1961eaca9deb17622c9fb6f5ac8a89a29f42df69).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x10cac3ff02125419ac84ff80295589d1124a1e65a94e8d9b63a411317c7f5728).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x7213c2f17aec536d0a5196e877d1626a63d7db5b4e3bd77327eb3617b932aee7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1b86ee2e45fbcd59fa43edee6630dda5cb3b894eb4701eff5fd53967abad2666).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0b941850091bd16875f408f183fb93d250c538e5ee1e8979e960b5b499b2f070).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x031f75d53bdd5ac603c252925148a74fa5f3bf5d9ffabe64c6e932be904204ec).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x55296a48dd399c65798cdbda11e89f26f998f2d33cf328e87a1c364e05be492b).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x191175ec337e64a48785a083559176168bcea3db2822630c48f1d497d03e1e35).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x33200b518e6b1a1e96ba214cad18929e7f5b4e3336e522c5fa1febf69c0818c6).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x2f989e24f97dc06c075bb53404e3eaaae32a1b6d89d9e09acdeee65712aa4216).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x0de4b77163f1572bbaa325f48bde7a429b6b0f803ade45a0e72cd2bdbd84680c).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x0000000000000000000000000000000000000000000000000000000000000000).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x6af270d408f492402b7271432b6c443abbd1f0e220888d73f47498d59044d7cc).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x3d5b5310e7b6be63be8f12594ecd68108f4b3f6266683b13178d0c80d8530c2f).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x5f69d748d06e22916088bd21fb472334ddbe5223ff494b6c0dcdf62db69d43ac).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x6c1fd7dbb3298da86dfe5d0435b26e0a91fe6ad6aa25b9407d5da544e6b9cd27).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x37172dd49bf14ebca52e1c31b60f31de8213644e330bcf2cc59684606e62537d).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x509cf829b68e58861c3d6bce6046ac1b6e9c065378fe9556348c0c1814808e20).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x529d5dd132f5cb4b71994ae6b330b7944f0fd8ba319582d2414b9b10768b7448).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x5d251391abee228833dbd24be99d163ba9f8c92a2cf73a4e1f8a87e9c4ca97ba).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x1abe1d77226f2eff6f1a6bfc6c8d9e00dde1da9d67858e6984e53bc2aceba535).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x4ca74ba72baea6d84fa8f2a05e387a1c41a5a1b2f1a479fb11bbf9d5e09bd01a).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x232bfa6a3120224814967315ebf3d8c88b2eb0ea8c20c79ce854f90aec7a9b91).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x109f5bd722c8cf28ab6a3852970bcb665f398a8dc9d331958dc291d49a1ba9b4).into()),
-        ],
-        vec![
-            e,
-            U256::from_be_bytes(e, &bytesn!(e, 0x4a181a5de6c79a05c02533350ab51deee24b8f5b9154c5cf09ba5e25862ae176).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x6e063b99c16028df94b1eb719164ae8c8336894791516ca2c37c5a1a394bb0d7).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x6bbd2d3a643d301d25a8cf156542165abee3039068fecf501e9d39f313ca2a87).into()),
-            U256::from_be_bytes(e, &bytesn!(e, 0x04690fe1be7c7b8c10c81e63f5e508fe93853c61f0435f81eabc9997fa3b99f3).into()),
-        ],
-    ]
-}
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+pub(crate) const RC_BLS12_381_T_16: &[u8] = b"\
+    \x57\xd4\x01\x72\xc1\x55\x4f\x7e\x27\x9e\xca\x87\x8e\xd0\xb3\xe2\xcb\xfd\xca\x17\xa2\x89\xe6\xbb\xdb\x94\xb4\x71\x87\x46\x96\xd5\
+    \x5e\xfe\x23\x11\xbc\x5d\x1b\xc7\xae\xda\x55\x82\xb1\x3f\xd1\xa9\xaa\x42\x6c\x55\xaa\x6f\x71\xe6\xb7\x78\x3f\xa9\x65\x30\xbb\x52\
+    \x04\x34\xd2\x08\xf3\x80\x04\x91\xb1\xd9\x8f\x90\x18\x73\x86\xe9\x63\x5e\x2b\x7f\x2b\xcc\x66\x91\x5b\x36\xde\xc8\x77\xa4\xbd\xf1\
+    \x62\x3b\x6f\x89\x57\x37\xe1\x4e\xd3\xe4\x08\x68\xd0\x23\x45\x3d\x53\x8d\x9f\x12\x71\xde\x9a\x3f\x76\xd7\xa6\xdf\xff\xce\x3c\x68\
+    \x57\xd4\x39\x2a\x7a\x91\xb5\x08\xfc\x3c\x87\x9d\xea\x81\x66\xd3\x14\x43\x0d\x68\x8e\x26\xcd\xda\x9e\x80\xdf\x39\x07\x6a\x0f\x37\
+    \x69\xf3\x08\x2d\xe3\x3f\x0b\xf4\xd0\x9d\x0a\x62\x6d\xfd\xe5\xab\xb4\xb1\xd4\x9e\xc3\x65\x51\x9a\x6c\xf4\x20\x06\x11\xb6\x74\x02\
+    \x04\x1e\x58\xe2\xbe\x56\xdc\x6c\x69\x8a\xa2\x31\xb1\xb1\x37\x3d\xc0\x0f\x03\x66\x11\xa9\x1a\x5d\x22\x73\xb5\xa6\x89\x02\x67\x79\
+    \x0a\x30\xe9\xfa\x87\x12\xe4\x9c\x56\x5f\x7f\x0e\xaf\x5c\xfd\x1a\xbb\xf1\x83\x49\x0a\x94\xdc\xf6\xa3\xc8\xe2\x37\xae\x44\x82\x57\
+    \x4e\xae\x2c\xe7\x35\x37\xdd\xc2\xf6\x79\x0d\x9a\xd1\x85\x56\x7d\x12\x22\x75\x20\xa2\x23\x0c\x5a\xb8\x23\xfb\x80\x4b\x37\xb9\x02\
+    \x46\xbb\x4f\xe4\xed\x51\x0a\xc9\xa1\x92\x5e\x0b\xf2\x42\xac\xcd\x26\xc7\x46\x8b\x79\x84\x2a\xfb\xcc\x63\x85\xa9\xa9\x9d\xae\x66\
+    \x17\x0f\xcc\x6f\x3d\x61\xb1\xcc\xf0\x58\xeb\x01\x71\x7d\x7e\xd2\xdf\x46\xfd\xd1\xd8\xa5\x4c\x82\xa8\x1a\x53\x9c\x99\x81\x16\x30\
+    \x04\x4f\x78\xe0\x57\x67\xaf\x26\x7b\x3c\xd7\x9c\xbc\x3a\x94\x77\x28\x26\x7e\x5e\x42\xe1\x68\xcc\x26\x5b\xe0\xb3\x74\xbf\x16\x92\
+    \x39\x82\xeb\x47\x4e\x98\x5b\xbc\x1d\x30\x85\x9a\x13\x4c\xa1\x20\xd4\xa1\xaa\x59\x06\xc4\xb7\x4b\xc8\x65\xc5\x52\xc6\x34\x38\xf3\
+    \x35\xc8\x36\x7a\x7d\x77\x33\x12\xb9\xd4\x58\x19\x1d\x39\x37\x6b\x17\xf0\xe8\xb9\xd7\x24\x5a\xc1\x88\x14\xd9\x3b\x5c\x1e\x7e\x28\
+    \x31\x58\x7e\x9d\xba\x0c\x47\x98\xf5\xba\xb3\xd5\x8b\x94\x60\xeb\xb6\x9c\xdd\xf0\x8a\x8f\xca\x5c\x6f\xed\x1b\xf9\xd2\x6d\xda\xa7\
+    \x6f\xc6\x62\x2f\x51\x19\x14\xf5\x25\x44\x6a\xb7\x54\x53\x50\x1c\xf9\x3f\xf9\x7f\x2b\x33\x98\x6f\xbb\x7d\xc6\xf8\x29\x10\x74\xc6\
+    \x12\x04\xef\x2f\x3e\x36\xa3\x9e\x53\xcf\x96\x98\x7e\x4b\xd1\xd4\x27\xd1\x26\xa0\x12\x40\xb4\xff\x08\x33\x83\x29\x72\x11\x6e\x0f\
+    \x6a\x95\x3e\x1b\xc9\xd1\x22\xba\x6f\x79\x9b\x07\xc2\x2d\xdd\x4f\x21\x98\x58\x59\x82\xbf\x7b\x12\x73\xb0\xee\xfe\x57\x42\xb7\xa6\
+    \x33\x7e\xd6\x09\x25\xb4\x46\x36\x60\xe6\xdb\x1c\x39\x3f\xb3\x78\xc5\x2c\x83\x67\xd0\x70\x31\x27\x23\xa9\x45\x34\x2a\x19\xf4\xd3\
+    \x5c\x06\x75\xb7\xe2\x17\x62\x40\x3e\x5b\xbe\x81\xde\x5e\x0b\x47\xf0\xf6\xd8\x42\x18\x7c\x02\x75\x2b\xc2\xe3\xfd\xe9\x92\x4f\xf8\
+    \x4d\x75\x8a\x45\xaf\xed\x23\x40\x01\x72\x6e\x74\x81\x72\x44\x14\x83\xa3\x13\x55\xa8\xdc\x84\xfa\x51\xf6\x47\x3d\x7d\xb2\x84\x77\
+    \x0a\x91\x03\xfb\x87\x25\xc9\x39\x32\x74\xae\xd2\x6b\x07\xce\x2e\x04\xe9\x4a\xb9\xc9\x54\x92\xf1\xa0\xf7\xe4\xdb\x94\xa1\x90\x46\
+    \x49\xc5\x14\x63\x6e\xb5\x5a\x1d\xea\x83\xc2\xf3\x44\x25\xf7\xc1\xe4\x00\xb5\x52\xba\xa7\x3a\x9b\x93\x9e\x16\xe1\xd9\x10\xb0\x2b\
+    \x69\xb8\x1d\x6b\xfc\x09\x98\xc7\x7e\xa2\xad\x8b\x55\x8c\x50\xa7\xa6\x43\xf7\xc1\x26\xbe\x93\x51\x17\xe4\x24\x83\x5b\x25\xad\xba\
+    \x5e\x9d\xd1\x35\xec\x76\xb4\x66\x6a\x21\xb0\xa4\x32\x6f\x92\x7e\xc3\x2e\x1c\x32\xd0\x9b\x46\x0b\x76\x42\x74\x1a\x3d\xe8\xa3\x88\
+    \x18\x39\x8c\xdd\xaf\x57\x08\x7c\x99\x71\xfb\x09\x0d\xd9\x56\xc8\x50\x9f\x84\x58\xa4\xfb\xcd\x12\x01\xe7\xcb\x0f\x51\x4a\x4a\xcc\
+    \x06\x48\x65\x78\x66\x04\xa9\x9e\x50\xa5\x69\xa6\xcf\x15\x45\x00\x24\xcf\x11\xd3\xd6\xa2\xf7\x21\x62\x0e\xb4\xd5\xd3\x8c\xcc\x7a\
+    \x24\xbc\xb5\xa7\x32\xfa\xef\x11\x2a\x11\x83\x55\xaa\xd3\x90\x4a\x28\x6c\xf9\x76\x43\x5b\xd1\x76\xed\x88\xb6\x3d\x51\x7c\x68\xc9\
+    \x4a\x36\x75\x5a\xc6\xd4\x47\x79\x64\xee\x6b\x31\x80\x9c\xa9\xd6\x00\xd3\x1c\xef\x47\xc6\xbd\x4e\x32\x12\x09\xea\xf5\xd1\xc7\xcd\
+    \x2c\x8b\xfd\xc0\x5b\xe9\x38\x5f\xf3\x9c\x92\xe0\x8a\xd9\x4e\xbb\x12\x17\x39\x1d\x19\x52\x22\xb6\xa5\x3a\xff\xa8\x9a\x2e\x8d\x1f\
+    \x3a\xa2\xe5\x03\x61\x04\x8f\xe0\xdb\x52\xad\x95\xfb\x6c\x33\x17\x22\xae\x4d\xe6\xe4\x7f\x87\xc1\xa6\x45\xbc\xc9\x63\x2f\x3c\xf4\
+    \x43\x41\xa1\x0e\xd8\xce\x01\x86\xcb\x88\x32\x48\x54\xe6\x69\x59\xb1\x05\x4f\xc1\x5e\x58\x23\x16\x9d\x10\xc1\x98\xce\x5e\x49\x15\
+    \x3f\xbb\x1b\x4b\xd2\xaf\x44\x33\x5c\x5b\x7c\x7c\x0c\x71\xb5\xc9\x13\x85\x9b\x7c\xe9\x39\xd5\x9f\x85\x82\xb9\x3d\x97\xf3\xa0\x47\
+    \x27\x9c\xad\x5c\x85\x75\xfb\xbb\x12\xf0\x91\x0b\xae\x1d\xd2\x60\xa9\x31\x16\x46\x4e\xab\x39\x2c\x87\x5b\x5a\x8e\x4e\xa6\x01\x97\
+    \x0f\xc9\xd8\x6a\xaf\xf5\x89\x30\x6c\x00\xd9\x4a\xdc\x36\x54\xb3\xd6\xd2\x6d\x42\xdc\xea\x2f\xcb\x39\x91\xcb\x78\x2e\x0f\xbc\x8e\
+    \x2a\xee\x70\x29\x7d\xa8\x34\x27\x5e\xc6\xde\x38\x80\x3a\xa1\xa0\xb6\x1e\x1a\x26\x6f\x51\xed\x39\x8f\x74\x2d\x59\x78\x5b\x59\xd8\
+    \x09\xce\x42\xba\x68\xad\x05\xec\x12\x45\xb2\x27\xb4\x5a\x23\x7e\x82\xde\xb7\xb0\x64\x6d\xf6\x67\xfa\x2a\xbf\x6a\x19\xc1\xc1\x8f\
+    \x2b\x67\xd3\x6e\x99\x3d\xa5\x67\xce\x83\x6b\x54\x10\xdf\xc3\x2f\xb7\xde\x06\x03\x54\xa6\x88\xaf\xa6\x8f\x1b\x47\x01\x25\x0b\x2b\
+    \x3b\xcc\x09\xf0\x52\x4d\x95\xfb\x5b\x2f\x09\x75\x84\x7c\x3d\xb0\x70\xb3\x56\x7a\x08\xc2\xbf\x8c\xca\x17\x13\x06\x89\x3d\x15\x97\
+    \x65\xbf\xc4\x64\x5f\x60\x04\x24\x2b\xe1\x81\x48\x50\x11\x54\xab\x48\x90\x27\xc7\x17\xd2\x32\x86\x75\xf7\x7f\xff\x15\xa9\x50\x9f\
+    \x68\x60\x0d\x97\x8f\xe3\x57\xa0\xcd\xf2\x6d\xb5\x5b\x57\xdd\x56\xb8\x0e\x0c\x4a\x8b\xb4\xba\x17\xf9\x43\xf9\x88\x49\xa8\xfe\xdd\
+    \x16\x5c\x1a\xf6\xd7\x12\xc4\xee\x7f\x43\xde\xc9\xa9\x20\x46\x2e\x67\x54\xbe\xfc\x32\x82\xf9\x73\xe0\xdd\x33\xdb\x45\x82\xf4\xf3\
+    \x4e\x86\x28\x5f\x18\xdf\xcc\xf4\x96\x01\xc3\x77\x09\x9d\x50\xf6\x14\xaf\x2c\x36\xc8\x46\x62\x8c\xf6\x9c\xeb\x2f\xdc\x7c\x16\xa1\
+    \x22\x89\x38\x05\xc6\x79\x42\xeb\x4b\x8d\x99\x02\x0a\x34\xb9\x52\xf8\x15\xdc\xf0\xe6\xe1\xd9\x5a\x28\x2b\x45\xaf\x1e\xc3\x07\xcc\
+    \x41\x9b\x32\xe4\x1c\x38\x92\x25\xc3\xcc\x0f\x14\x31\x2a\x96\x2b\xb5\x31\x3f\x4d\x8f\x09\x20\x42\x1a\x91\x09\x74\xb0\x94\x48\x71\
+    \x6a\x48\x09\xa5\xe0\x2c\x40\x5d\xeb\x15\x4f\x9e\xd2\xaa\x4d\xb4\x21\xe5\xab\x9e\x02\xf6\xec\xb7\x8b\x64\x1c\xe0\xa3\x47\x35\x4f\
+    \x5c\x4a\x4a\xa0\x50\xb9\xbe\x2b\x11\x65\xc0\x8d\xaf\x88\x29\xce\x0a\x91\x3b\x0c\x8f\xf6\xb2\x39\xf2\xa6\x2b\x29\x63\xff\x3e\x51\
+    \x15\x49\x69\x40\x58\x6c\x95\xcd\x14\x76\x15\x1c\xe1\x84\x3b\xa2\x63\x33\x62\x4a\x1f\x08\x9b\x5d\x3d\xcb\xa9\xe0\x83\xaa\x8a\x3d\
+    \x2d\x9d\xa7\x20\x9b\x22\xcb\x91\x66\x99\x69\xea\x20\x47\xa0\xb0\x1b\x90\x50\x16\xeb\x32\xd4\xd0\xe6\x63\x2b\x98\xf3\xfd\x76\x1a\
+    \x2d\xf7\xf9\x5f\xc6\x91\x42\x97\x87\x65\xa4\xc5\x6e\x4c\xf1\x92\xbf\xbb\xf9\xdb\x57\x56\x04\x4e\x34\x60\xba\x4d\x40\x48\xf3\xa9\
+    \x59\x5a\x5b\x81\xa2\xce\x31\x93\x7c\x63\x10\xe1\x83\x4f\xd9\x78\x62\x17\x94\x6c\x52\x34\x0b\xa3\x12\x89\x82\x9e\xbe\x8b\x24\xc4\
+    \x5d\x00\x81\x39\x98\xbe\xea\xd6\x56\xf7\xe9\x3e\x59\x53\x45\x96\x4c\xe9\x6e\xa7\xa2\xd0\x8b\x0f\xf0\x1d\x3d\xa7\x6a\x9b\xef\x32\
+    \x5a\x3f\xfc\x14\xbd\x1c\x50\x5f\xd1\x35\xec\xf9\xae\x59\xfe\x15\x6d\x02\xe8\x21\x4f\x9f\xf1\x7b\xfe\x79\x06\x1c\x44\xa4\x1d\x0d\
+    \x38\x7e\xdc\xaa\x09\x76\x37\x87\x38\x1e\x41\xca\x07\xe7\x7a\x59\x61\x6c\x54\x08\xd0\xec\x5b\x07\xdf\x40\x61\x05\x85\x17\xdc\x04\
+    \x2b\xa2\x8e\x2f\x09\x6a\x8d\x6a\xd5\xae\x49\x45\xef\xd3\x31\x52\x3b\x1e\x8f\x96\x02\x8b\x19\x58\xb5\x1a\xdf\x86\x5d\xf6\xd0\x72\
+    \x5a\xba\x45\xb0\x8b\x28\x89\xa2\x1d\x76\x07\xcf\x8d\xaf\x3b\x39\x81\xbf\x1b\x16\x29\xba\x84\x13\x36\x7a\x67\x74\x44\x82\x08\x41\
+    \x3a\x83\x0a\x5a\x11\x27\x7a\x5e\xc1\x32\xf7\xa2\xf8\xbf\x1a\x95\x3d\x50\xef\xa1\x4f\xeb\xcc\xda\xff\x9f\xc4\x5b\xa0\xc1\x63\x77\
+    \x51\xe2\x13\xcb\x67\x04\x3f\xb5\x19\x51\x8c\x4f\x02\xee\x20\x72\x9b\x9c\x05\x7f\x47\x7c\xed\x92\x76\xca\x5d\x46\xb4\x98\x5a\x84\
+    \x25\x53\x0c\x7a\xc6\xe3\x09\xe3\x54\x67\x31\x72\xac\x20\x07\x14\x45\xcc\x19\x3a\xc7\xd2\x5f\x7f\x8d\x29\x61\xb2\x1c\xaa\x94\x93\
+    \x6c\xda\x98\x0f\xa5\x42\x50\x8f\x3d\xf0\xc2\x26\x17\x7a\xab\x10\x89\x85\xd2\xa2\x7e\x9e\xa2\x0c\xc9\x88\x84\xf0\x47\x76\x93\x0e\
+    \x56\x80\xcf\x6b\x89\xe1\x98\x19\x79\x6d\x3c\xdd\x3d\x64\x82\xab\x06\x3d\xdb\x5c\xad\x09\x3e\xd4\x31\xb6\x94\xd4\x2f\x18\xf5\x28\
+    \x60\x85\xc5\xd0\x20\x1c\xe7\x90\xea\xbc\xb5\x89\x04\xff\x77\xfe\xdd\x74\xd0\xf7\xae\x75\x9c\xa8\x46\xe3\x80\x33\x43\x8a\x94\x49\
+    \x24\x1c\x35\xa4\xa6\x57\xd1\x27\xb8\xd2\xc0\x8d\x5a\x30\xd0\x2b\x56\x3f\xf4\xac\x98\x9d\xe3\x58\x61\xce\xd9\x5e\xad\xba\x08\xb2\
+    \x05\x2e\x51\xf6\x44\x26\x6a\x0d\x25\x6b\x0f\xcd\x56\x8b\xa5\x58\x6f\x5e\x20\x14\xa7\xf0\xe0\x94\xb6\x2b\xea\xca\xe3\xfa\xc3\x7d\
+    \x36\x76\x60\x25\x49\x96\xa3\xb7\x27\xe7\x87\x51\x3c\xd0\x9a\x0e\x14\xff\x10\x3a\x0b\x26\xea\xc1\xef\x63\x96\xb2\x1d\x7a\x37\xf5\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x35\xea\xae\xd4\x48\x9d\xad\xc7\xb1\x63\x55\x9f\x1c\xc0\x8d\x2c\x44\x2a\xb2\xd3\x2c\x61\x61\xd2\x6c\x4e\x97\x31\x0c\x36\xcf\xb2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4d\x10\x0b\x09\xd3\x2d\x42\xe9\x0d\xad\xcc\x1c\xd3\xc6\x6f\xd3\xbd\x4d\x2b\x37\xc2\x68\x73\xae\x64\xf0\x20\x70\x75\xfc\x81\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x31\xaa\x22\x0f\x0a\xcf\x10\x48\x7b\x78\x15\xf0\xfe\xa9\x0b\x94\x63\x5a\x32\x5f\x0d\x14\x98\x48\x0d\xf3\x24\x0d\x31\x3d\xb2\xa4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x54\xc2\x48\x6c\xfd\x6b\x6a\x69\x0d\x4b\xed\x8d\x07\xcf\x38\xaf\xd6\x58\xd0\x3a\x57\x66\x95\x72\x24\xe7\x84\x92\x37\x1b\xef\xab\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x44\x48\x27\x55\xb0\x1b\xb6\xe6\x71\x72\xc0\xf9\x5f\x6e\xd8\x38\x09\x5a\x83\x5d\xf7\x29\x8c\x0f\xe0\x55\x5a\xf0\x93\x93\xf2\xbf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\xe6\x63\x9d\x9f\x20\xab\x5e\xf2\x1a\x29\xf0\x18\x30\x25\x41\xb0\x7c\x64\xaf\xcd\xef\xbb\x20\x8a\x1b\x06\x65\x41\x83\xf0\x85\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x65\xdc\x20\x66\x19\x5d\x62\x45\x92\xdf\x7d\xf0\x8c\x97\xc8\xa6\xc5\x90\x05\xee\x4e\x49\x51\x67\xc5\x5b\x7f\x45\x3e\xc7\x26\x27\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3a\xce\x2c\x1d\x11\x5e\xdd\x73\x46\xd8\x38\xdc\xfc\x51\xa8\x08\xb2\x08\x6d\xec\xae\xe4\x89\x57\x98\x83\x75\xc9\x20\x36\x1b\x52\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x16\xe2\x4e\x8b\xe8\xbe\x17\xf1\x22\x4f\x67\x26\xde\xdd\x08\xe1\x89\xdc\x9f\xaf\x2e\x7a\xd3\xef\x19\xff\x36\x26\xca\x6a\xf9\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2d\x1e\xc4\xfe\x72\xad\x64\xa6\xd0\x71\x58\xa7\x91\x20\xec\x23\xa2\x1b\xbb\x6c\x53\x5f\xfb\xc4\xa9\xf0\xa9\x45\xd6\xd2\xe5\x58\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x67\xde\x6f\xac\x02\x4f\x9c\xb1\xcf\xed\xb1\xb2\x0f\xfc\x19\x0d\xc3\xa9\xdd\x72\x9e\x89\x97\x8c\x6d\x56\x2a\xaa\xc7\x86\x80\x72\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4a\x2d\xa4\x3e\xdf\x63\x57\x1a\x46\x61\x93\xd9\x93\x51\x71\x35\x58\x65\x35\xb2\x1c\xf8\xb6\x38\xba\x42\xbf\x3b\x72\xec\x26\x67\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\xb3\xa4\xed\x0f\xb9\xdf\xfd\x64\xa3\xdc\x14\x82\x6c\xa8\x4d\x2e\x51\x12\x00\xe8\x01\x8a\x0f\xed\xf8\x3c\x30\x44\xdd\x73\x57\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x73\x25\xbe\x1e\x98\x02\xfc\xde\x49\x74\xf0\x9a\x99\x1b\x66\x9d\x04\xa4\x6b\x8d\xb1\x41\xb0\x49\xb8\x9e\xc0\xd1\xd6\xcd\xc9\xd6\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x60\x6a\x3a\xe3\xb9\x26\xa7\x6a\xa7\x41\xec\xed\xdb\x6d\x3f\x43\x35\xa2\x6b\x73\x29\xbf\x76\x25\x1e\x72\x8c\x02\xf4\x06\x28\x4e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x2e\x29\x5b\xed\x74\x46\x2c\xe6\x48\x28\x28\x37\xca\x9e\xd5\xd7\xbc\x7a\xb7\x76\x9f\x66\xe6\xbc\x75\x90\xd8\x9b\x24\xc7\x17\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0c\x4a\x4c\xa7\x82\x1b\x85\x31\x80\x23\xed\x28\xd3\x4b\x23\xe6\x4e\xec\xce\x7e\xe9\xab\x34\xae\xb5\x5e\x40\x68\xc0\x8b\xeb\x46\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4a\x1e\x4b\xb2\x12\xe5\x29\xd9\xc6\x71\x37\xa5\xc7\x4a\x02\x63\xc7\xd0\x67\x2d\x1b\xbd\x92\x7c\x65\x94\x67\x87\xf1\x7f\x1d\xac\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x64\xea\x66\x89\x9f\x00\x16\xc6\x9b\xaa\x5e\xc6\x13\x2c\xbd\x98\x91\x71\xa1\x33\x56\x46\xd6\x6e\x1b\x1b\x49\xf8\xf5\x3f\xd9\x53\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2f\xd4\xa4\xb9\xfe\x88\x9b\x6e\x2c\xb1\xf9\xa9\xf1\xd6\xdd\xed\x6e\x22\x92\xf9\xc1\xe3\xd0\xc3\xa0\x22\x4a\xef\x7e\x32\x21\x1d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x26\x3d\x3b\x6b\x88\x8f\xa2\xb3\x44\xf7\x23\x99\xf6\x45\xdf\xfa\x6d\x0e\x3c\xdd\x54\xfd\x16\x16\x23\xb3\xa1\x76\xa9\xb1\x20\x2a\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x71\xba\x0c\x45\x1d\x76\x09\xdb\x50\x6e\xf4\x6d\xb1\x73\x7d\xd1\x30\xcd\x68\xa3\xca\x8a\xcd\x99\x95\x79\x5c\x73\x63\xa8\x9c\x6d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x07\xf2\x41\xfd\x5c\xa3\x6b\x7a\xdc\xe9\x68\xbd\x44\xa3\xd2\xdd\x47\x7d\xa0\x43\xcc\x20\x73\x20\xf7\x39\x89\x9d\x4d\xde\x31\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\x08\x44\x7e\x8d\x30\xf4\xfe\xc2\xdf\x9c\x79\x23\x1d\xde\xc7\x6e\x28\x97\x2d\xa9\x19\xac\x3c\xef\x30\x60\x1d\x03\x8d\x9d\x92\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x48\xf5\xc5\x5d\x7a\x48\x11\xcb\x74\x08\xcc\x7f\xca\xe4\x5d\x0b\xe3\x09\x08\x59\x64\x9c\x63\xf0\x7a\xdb\x80\x2f\x01\xcc\xda\x40\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5e\xae\xef\x40\xdc\x75\xe5\x51\x81\x3a\x85\x9d\x7f\x2f\x04\x92\x31\x1a\xae\x8c\x5f\x56\xa5\xd5\x4c\xa8\x83\xe5\x3c\xe2\xcf\x5e\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x73\x9e\x1b\x7b\xb6\x37\xc2\x4c\x93\x6c\x6a\x84\xc8\x5d\xbf\x9a\xee\x1b\x8a\x32\x5b\xe0\xef\x14\xae\xdd\xf4\x53\x68\x93\xa6\x06\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x69\xdb\x78\xc9\x0e\xf0\x95\x3f\xf1\x1a\x14\xd8\x62\x23\x20\xf1\x7e\xdc\x33\x5b\x0d\xb6\xa2\xf7\x10\xf9\x51\x72\xad\xfb\xa1\x02\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\x23\x6e\x39\xe9\x1b\x51\x02\x00\xc3\x52\x89\xa4\xee\x98\xf6\xe8\x48\xba\xe0\x0d\xdc\x89\xfc\xb4\x0d\x7a\xc0\xaf\x89\x43\xf3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x58\xfd\x5a\x2e\x64\x2c\x92\x37\x0b\x85\xd7\xd5\xc8\x60\x82\xff\x04\x97\xbe\x59\xca\x47\x21\x84\x03\x1d\x73\xfe\x62\xe2\xf1\x30\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x09\xab\xe9\xf4\x92\x47\xca\x79\xca\x6c\xb4\x73\xde\x50\x6f\x4c\x7f\x20\x92\x99\xcb\xd5\x1c\x1a\xf1\x5e\x9e\xee\xbd\xf1\xd0\x1f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4f\xab\x1a\x76\x3b\xaa\x06\xc2\x19\x94\x78\xa1\xf3\x91\xaa\x92\x8d\x32\x8e\x67\x2e\x2b\x43\xe2\xe8\xd8\xb8\x6e\xb5\x94\xfb\xfe\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xf5\xb1\xb2\x01\x6c\x75\xf2\xdb\x2b\x71\x22\xd3\x3e\x01\x20\x1d\xfd\x63\xe5\x58\x2a\xf8\x0a\x66\x85\x7a\xc6\xb1\xaf\x65\x34\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x23\x1c\x47\xe7\xfc\xc5\x72\xa2\x7d\x9e\xdd\x03\x72\xfa\xe3\x91\x26\x53\x5f\x03\x21\x19\xf3\x4f\x04\xbf\xd3\xa1\x05\x73\x9b\x2c\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x61\x65\x90\x04\x3d\x49\x3d\xa5\x61\xd5\x55\x3e\x59\x25\xde\xf4\xcf\x56\xbf\x0d\x1b\x6d\xd3\xc6\x04\x5d\xaf\x54\x34\x3f\xa2\xf4\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x27\x8e\x57\x93\x55\x55\x13\xea\x73\x88\x06\xfe\xc1\x2e\x5d\x37\x5d\x98\x00\x81\xb8\x4d\x9b\x6c\x69\x70\xcd\xed\xa1\x82\x36\x40\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x5c\x8c\x4e\xbc\xc6\x56\x7f\xd1\x60\xd4\xa4\x2e\x0b\x2a\x27\x18\xd6\xf5\xff\xac\xad\xca\x27\x7a\xd6\x97\x7f\x6e\x18\x68\x75\x62\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x57\xa0\x2e\xf1\xc4\x90\xae\x7d\xc4\x02\x79\xfa\x86\x04\x89\xd0\x0a\xb4\x58\x2c\x42\xf6\x6a\x91\x65\x7f\x5e\x9e\xc5\xe3\x55\xe3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x25\xe9\x1c\x64\xa0\x85\xec\x2c\x95\x58\x78\xb4\x4e\x1f\xf6\x7f\x5c\x25\xed\xb0\x53\x5d\x55\x7b\xfc\x64\x51\x41\xfd\xf0\xdb\xc2\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x2a\xe5\x89\x00\x93\x9a\xfa\x80\x1e\x8c\x27\xfe\xe7\x49\x45\xad\xa3\x7f\x85\x36\xaf\xf7\x8a\xe5\xfd\x23\x4f\xd5\x9c\x40\xa0\x56\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x17\xa3\x3b\xa3\x6e\x24\x6e\x00\x2e\x15\x25\xd6\x34\x9a\x3e\x1d\xc0\x94\xec\xde\xf6\x81\xe4\x6f\xcf\x10\x70\xc1\xf0\x06\x42\x15\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x69\x1b\x0a\xa1\xfc\x8b\x10\x92\xaf\x98\x5d\x27\x74\x46\x7c\x1a\xac\xa1\x01\x2b\xb7\xe9\x77\x8a\x18\x3c\x93\xfc\x70\x2e\xc8\x0f\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0d\x14\x27\x34\x18\x6f\x86\x35\x01\x6d\x94\x13\xe1\x93\xcb\x3c\x54\xa8\x70\xab\x99\x12\x41\x19\x44\xa5\x11\x4d\xb2\x5a\x75\xdd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x3e\xf7\x63\xba\x3b\x88\x64\x35\x33\x2a\xda\x38\x35\xee\xa6\x47\xa5\x65\x13\x08\x29\xb0\x47\xbe\xe1\xba\xe1\x90\xa8\x95\x9d\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4d\x85\x1d\xfd\x4e\x9d\xcc\x8f\x50\x22\x0b\x48\xf0\x4a\x1b\xb7\x30\xe5\xc7\x45\x42\xf4\xe3\xed\x2a\x1a\x2f\xbf\x05\x35\x60\xbd\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x0a\xf4\xe7\xb0\x9f\xd2\x1d\xa5\xd3\x42\x33\x81\x57\x94\xb3\x9b\xf3\x09\xeb\x29\x0b\x63\x47\x24\x65\x2b\xa5\x10\x8e\xf2\x5e\xea\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x1e\x7e\x3a\x37\x67\x0a\xf8\x6d\x9f\xab\xdf\xe5\x09\xa8\xd4\x55\x57\x83\xd8\x4c\x64\x51\x19\x71\x39\x69\xe1\x3b\xdc\xd1\x8f\x5b\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x4f\x34\xa6\xa0\x71\x75\x1e\xa8\x6b\x01\xf6\x68\x16\x7c\xd5\x75\xc1\x95\xd7\x0d\x97\x99\x26\x23\x8b\xd4\x1d\x13\x00\x21\x67\xea\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x3e\xec\x4e\xbc\xcb\x30\x85\x5b\x4f\x04\x30\xba\x14\xbd\xd5\x44\x05\x57\x5e\x60\x1d\x4b\xf9\xdb\xd7\x0e\x48\x44\x0b\x0c\xc5\x91\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x15\x79\xd7\x55\xe4\xdd\x66\xf5\xd1\x51\x9d\x49\xd9\xfb\x7d\x2d\x6c\xe5\x14\x3e\x6b\x76\x10\x3c\x1a\xb8\xdb\x90\xe3\xa9\xce\xd3\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x59\xef\xf4\x40\x50\x5c\x43\x4f\xb6\xaf\xd8\x84\xc9\x79\x7b\xad\x82\x3b\xc1\x66\xf3\xe3\xa2\x6b\x0b\xa7\x72\x26\xf0\x95\x0c\xda\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x41\xca\x66\xa8\xf9\x14\xc0\x4a\x76\x05\xeb\x47\x39\xfb\x2d\xe4\x31\x6d\x1c\x8f\x27\x2f\xb0\x37\xa2\xcc\xe4\xd5\xc1\x8c\xaf\xe1\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6a\xe9\x4c\x06\x52\xa4\x18\xc1\x69\xbe\x4d\x49\xe4\xae\x64\xb6\x9d\x37\xb0\x33\x53\x8d\x69\x66\xf6\xa6\x07\x51\x82\x3b\xa4\xcf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x48\xd3\xa8\x40\xe3\x60\x61\x41\x4f\x84\x62\x03\x47\x65\xe7\x81\xe6\x7b\x73\x08\xbc\xc3\x83\x2a\xe4\x5c\x22\xfb\xfb\x64\xc2\x35\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x10\x85\xa3\x99\xd2\x63\x05\x29\x7b\xef\x8f\xbd\x21\xd7\x06\xa0\xb1\x2a\x06\xab\x05\xe9\x7d\xb1\x3f\x7a\x00\x5a\xd9\x6a\xe6\xdf\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x6e\x18\x47\xf9\x4b\xf7\xaa\xb7\x05\xfe\xef\x4e\x6c\xf7\x76\x4a\x37\x6a\x40\x12\x74\x87\x2b\x87\xb7\xaa\xcd\xec\x4f\xb4\x2f\xec\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x41\x0d\x49\x9d\x94\xf2\x79\x11\x76\x48\x77\x37\x57\xa1\x96\x6f\x72\xbe\xb5\xb6\x5d\x13\xdd\x58\x7b\xb1\xc9\xdf\xc7\xca\x85\x24\
+    \x27\x3f\xd0\xa9\xac\xb6\xd1\x6a\xd1\x4d\x05\x3c\x91\x97\xa9\xe1\xe6\x21\x5b\x30\xc0\x4a\xe5\x8d\xe9\xdb\x37\xff\x98\x22\xc5\xfc\
+    \x18\xbb\xcb\x9b\x4f\xf0\x1c\x61\xb4\xfa\xf8\x5c\xc1\x4f\xec\xc9\x5c\x82\x75\x6d\x57\xc1\x8d\xc6\xf8\xb5\x14\x28\x81\x3f\x75\x28\
+    \x03\x3a\xa7\x4d\xe5\x05\x67\x2c\x60\xd4\xcf\x31\x9f\x0d\x9f\x5c\x06\xaa\xd6\x3f\xc8\x88\x05\x49\x00\xee\x6a\x29\x30\x75\x09\xe6\
+    \x06\x4a\x80\xba\x22\x42\x3a\xc9\x42\x45\x56\xbd\x8a\x5c\xab\x17\xe6\x42\x60\x6c\x33\xb8\x25\x5d\xa2\x4f\x9e\xfd\x05\xe8\x93\x81\
+    \x1e\x3d\xe9\xae\x4d\x0b\x9f\xfd\x1d\x83\xb9\x0d\xb3\x5f\xa4\x58\x63\x78\xd4\x77\x79\x6e\xb8\x7a\x33\xa1\x95\xb0\x6a\x8a\x44\x28\
+    \x5b\xe2\x21\xdb\xc1\xed\x54\xa8\xc8\x80\xc9\x1f\x9b\x58\x08\xb1\x15\x66\x06\x51\xa3\xdc\x7e\x7d\xd7\xbb\x17\x0f\xea\xba\x43\x30\
+    \x61\x82\x60\x57\xf3\xd4\x3e\x4d\xe4\x40\x0d\xd6\xe7\xcc\x14\xf6\xe6\xcd\x48\xf6\xe4\xfe\x09\x31\xdc\x0c\x18\x33\x33\x8e\xc6\x50\
+    \x23\x97\xce\xf4\x25\x8b\xa4\x1a\x1b\x6d\x66\x02\xf5\x63\x19\xac\xdb\xba\x30\x19\xef\xbf\x22\x9d\x95\xc2\xe3\xc6\xb6\xba\xc8\x4c\
+    \x18\x10\xf9\xd5\x81\xd4\xd8\x62\x6e\x95\xdb\x0c\x54\x79\x49\xfc\x2d\x7b\x27\x2a\x73\x77\x58\x39\x6e\x1e\xab\xc7\xdc\xeb\xc1\x45\
+    \x73\x4f\x6b\x26\x21\x41\x08\xf4\x90\x0c\x5c\xe1\x64\x1b\xd6\x10\x06\x06\x8a\x17\x72\x6b\x58\x5b\x07\x41\xcb\x41\xf9\xdc\x84\x3c\
+    \x4b\x0c\x6b\xd4\x86\x98\x99\x76\x08\xea\x3c\xb8\xed\xfe\x73\x9c\x07\x7a\x42\xc7\xfd\x78\x5d\x23\x8c\x76\xca\x47\x11\xc1\x36\x99\
+    \x5d\x96\x09\x32\x7f\x10\x95\x08\xf9\x2c\xed\x4a\x03\x4f\x9e\x87\x02\x44\x02\x80\x5b\x3d\xb2\xbb\x12\xa1\xd1\x4f\x32\xdb\xb3\x94\
+    \x0a\x4b\xcb\xdb\xfa\xa5\xec\x8a\xa6\xa1\x00\xe6\x72\x22\xbb\x4a\x81\x72\x65\xc1\x7d\xac\x63\xae\x16\xc3\xc5\x66\x96\x70\x76\xcb\
+    \x30\x78\x6e\xa4\x28\xba\xf8\xac\xc9\x9d\xc3\x8b\x30\x95\x0d\x67\xda\x4f\xda\xba\xd0\x42\x6e\x6b\xf9\x46\x1d\xde\xdf\x26\x24\x5a\
+    \x22\xf1\xb3\xfd\xc7\x47\x59\xb9\x9f\x40\x2c\x28\x10\xa4\xc9\xa2\xd6\x6b\x5a\xd6\x92\xcb\x4f\x96\xec\xb5\x64\xa4\xeb\xbd\x61\xab\
+    \x65\xaa\x20\x73\xdb\xf5\xd2\x2f\x37\xc1\xe6\x78\x4e\xb3\x01\x9f\xe8\x8f\x0d\xd5\x6b\x6c\x4f\x49\x04\x85\x2b\xff\xd6\x15\xc3\x9c\
+    \x56\x91\xc0\x9b\x14\xd9\xfb\xaa\x5b\x5e\x82\x14\x33\x4f\x52\x54\x17\x60\x5c\x00\x89\xb9\x91\xd7\xbf\xf2\xe9\x9c\xa4\x0d\xf2\xee\
+    \x27\x8f\x20\x0d\x82\x74\xc5\x29\x2f\x6e\x38\x8f\x13\x87\x33\x7f\xb1\x7f\x4b\x07\x43\xcb\x5a\x7d\x11\x3c\xe9\x31\xab\xdc\x1e\xc9\
+    \x50\x8e\xad\xa3\xcd\xb2\xde\xdc\x40\x03\xb2\xe0\xf5\x99\x53\xf7\x5d\xfc\xf3\x3d\x87\x92\xff\xb0\x8a\x60\x40\xa3\x5b\xad\x48\x01\
+    \x6a\xb2\x42\x42\x3d\xd4\xa5\x1a\x6b\x6a\xa1\xeb\x33\xe8\xc1\x37\x16\x2b\x6c\x6d\xc0\x47\x29\xc5\xca\x23\x7f\x92\x78\x77\xfb\xea\
+    \x59\x82\xd2\x6f\xb6\x65\x49\x38\x0e\x03\x43\x89\xe7\xfe\x8c\x4f\x17\xcc\x33\x5a\x27\x12\xea\x1c\xf9\x43\x03\x4f\xf5\x16\x73\x60\
+    \x4d\x99\xcb\x2d\xac\x21\x8e\x5a\x4c\x31\x01\x34\xd1\x2f\x42\x7c\x4a\x05\x7c\xff\x88\x1e\xd5\x8f\x54\x9c\x68\xc0\x58\x00\x9b\xa2\
+    \x3c\xf9\xdc\xd4\xaa\xb0\xe7\xa5\x05\x81\xca\xc7\x19\xee\x0e\xad\x29\xb9\x1b\x80\x03\xbe\xa3\x99\xcb\x7b\x72\xbc\x4a\x11\x4d\xb4\
+    \x33\x73\x1c\xdf\x3f\x36\x8f\x99\x5a\xde\xd9\xa7\xd8\x8e\x7c\xa6\x19\xe0\xf7\xd9\x31\x04\xc7\x61\x95\xc0\x5e\x1e\x8a\x06\x95\x6d\
+    \x5d\xfe\xab\x3f\xff\x98\x64\x63\x04\xbd\x12\x2b\x17\xc4\x31\x95\xdc\x20\x36\x2c\x37\xe6\x01\xdd\x37\xd0\xd7\x1a\x40\xd5\x38\x50\
+    \x3f\x7b\x8d\xbb\x1c\x8a\x4a\xaf\xe7\x2b\x98\x3c\x0c\x39\x62\x58\xa7\x7a\xbc\x47\xb5\x02\xbb\x40\x9c\x37\x35\xff\x0f\xe5\xf1\xf0\
+    \x02\x74\xed\x2b\xcf\xad\x4c\xdb\xf8\xa1\x7c\xfc\xfe\xb5\xb6\xf0\x51\x2d\xb5\xe0\x24\x58\xf3\x21\xe8\x28\x33\x4a\x69\x3f\x58\x02\
+    \x4c\x15\x0c\xa5\x91\xc4\x40\x51\x45\x60\x0a\xf9\x7c\x7a\xe1\xb5\x3c\x2c\x16\xc7\x19\x8e\x19\x44\x1c\x69\x63\x08\xd8\x01\xe9\xba\
+    \x5d\x2a\x64\xd7\xcc\x80\x57\x3d\xc9\x4d\xab\x3c\x8d\xe6\x42\xf0\xdd\xcb\x29\x62\x1a\xe0\xa9\x3a\x3c\x97\x70\x45\xb8\xf7\xa4\x86\
+    \x2f\xf5\x13\x23\xf4\xc6\x11\xee\x34\xb4\xe0\x09\x2c\xaa\xaa\x56\xd3\x2c\xfd\xd1\x6b\xbe\x63\x67\xc1\xe1\xb5\xf3\xbf\x36\x4d\x19\
+    \x3d\x22\xb6\xfd\xf4\x8a\xe5\xf7\x9e\x6c\x7a\xfe\x00\xb7\x5c\x61\x32\xf7\xc8\xd8\xf3\xa9\x96\x09\x97\x31\x6e\x83\x67\x3f\x7c\xbc\
+    \x45\x01\x1f\x43\xf6\xbc\x86\xdd\x7e\x89\xb5\x69\xc0\x37\x03\xab\x8c\x33\x86\x63\x7b\x5d\x7c\xb8\x14\x4c\x22\x22\x72\xe4\x2a\x20\
+    \x1c\x7d\x0a\x3a\xf4\x8c\xd4\x99\x46\xaa\x36\xae\x9f\xfd\xa4\x9b\xfd\x00\x39\x79\x6c\xe9\x6a\x66\xe5\xce\x9c\xb1\xc1\x79\x25\xf6\
+    \x54\xd3\xa2\xeb\xfa\xa8\x32\xf2\x46\xa2\x3b\x40\xd4\xbe\x0c\x61\xd4\x46\xb5\x31\x56\x39\xcd\x87\x39\x2b\x10\x96\xbe\x04\x64\x39\
+    \x25\x9d\xa7\xa6\x2c\x03\xf8\x64\x91\x77\x67\x33\xad\x5d\xaf\x62\x7a\xbc\xf6\x8a\x53\x70\x17\x9c\x37\x66\x28\x66\x75\xbc\xb0\xa7\
+    \x28\x8b\xc6\x2b\xc2\x5e\x35\x40\x4e\x5b\xd2\x60\x20\x51\xfa\xc9\x77\x3b\x28\x9d\x63\x24\x55\x6d\x20\x54\xf9\x77\x0c\xe7\x61\xb1\
+    \x42\xdc\xf8\xa8\x03\x41\xab\xde\xce\x20\x36\xb6\x56\x0b\xa6\xd3\x1a\x40\xa9\xdb\x76\x21\xb6\xaa\x9a\x29\x85\x50\x4e\x02\x5c\x9b\
+    \x0f\x28\xb6\x0a\xaa\x3b\x4a\xe6\x4c\x84\xde\x6c\x59\x0b\x1a\x67\x9f\x30\xfb\x96\xc5\x6e\xb7\x86\x60\xc6\x6a\xda\xa8\xdb\x44\x01\
+    \x20\x3e\xc6\xdd\x06\xf8\xc1\xde\xe0\xf0\x29\x9d\x34\xf3\xca\x1b\xdd\xf1\x0c\x8f\x2a\x11\xa2\x76\xa2\x37\xad\x34\xf8\xcc\x51\x99\
+    \x40\x08\xcc\x83\x9a\x67\x6e\x8e\x45\x41\xda\x71\xac\x77\x50\xe4\xf9\x4f\x19\x37\xab\xf1\x7c\xc4\xd6\x66\x62\xd8\xd8\x03\x78\xa0\
+    \x22\xfa\x9f\x90\xa9\xba\x37\x0e\x75\x69\x29\xde\x01\x25\x7b\x28\xbc\x4b\x5f\x84\xf3\x78\xc1\x60\xb0\xc5\x1c\x0b\xf3\xf8\x21\x34\
+    \x3b\x67\xf1\x84\xa7\xc8\xc1\xee\xf6\x9f\xa3\xd2\x48\x0c\x7a\x66\x99\x83\x4f\x1a\x73\x5d\xe3\x66\x4c\xc5\x66\x62\x4f\xf1\xd0\x64\
+    \x41\x8f\xee\x8a\x0e\x20\x86\xc6\xe2\xbb\xe1\x18\x9c\x67\xde\x45\x3b\x6a\x2f\x06\xb3\x7c\xde\x96\x1f\x37\xc8\x39\xce\x24\xf9\xae\
+    \x64\x58\x91\x83\x76\xf9\x63\x6c\x8e\xb2\x18\xa8\x34\x2a\x29\xe7\x21\xcb\x25\x9a\x51\x6b\xa5\x0b\xfc\x03\x41\x9a\xe7\xdb\x4e\x65\
+    \x71\xec\xfc\xfc\x7b\xbd\x2c\xeb\xd6\x9e\xf8\xc6\x24\x0f\xad\x37\x92\x60\xdd\x55\x12\x8f\x17\x0e\x1c\x92\x6f\xd0\x99\xd6\x85\x29\
+    \x57\x54\x91\x8b\x4e\xa7\x75\x58\x38\xd7\xf0\x1c\x7e\x00\x59\x75\xa6\x30\x4c\x09\x9e\x5b\xf9\xab\x84\x05\xc8\x99\x30\x01\xc3\x46\
+    \x4c\x94\xc5\x74\xfa\xe4\x89\xa3\x7a\xf0\xbc\x9e\xcc\x70\x6a\xb9\x57\x76\x46\xd8\xa8\x84\x5c\xba\x14\x30\x89\x9b\x71\x79\xe6\x5a\
+    \x69\x25\x4f\xe4\x7c\x23\xee\x7e\xed\x6c\x06\xea\xa1\xe6\x4a\x56\x62\x4d\xb6\x67\x98\xf7\xaf\x18\x16\x13\x3c\xa2\xe3\xc0\x3b\xa6\
+    \x4c\x32\x51\xcd\x73\xff\x36\x47\x6d\x2b\xea\x94\x90\x9d\x39\x11\x67\x0b\x94\xf2\xf0\x1b\x1a\x88\x4b\xfd\xbe\xba\xae\xa0\xa6\x1e\
+    \x70\x01\x59\x75\xfe\x85\x57\xc8\x78\xd8\xe0\xf8\x7c\x21\xac\xe7\x3d\x5a\x09\xd2\xd3\xca\xb4\x6a\x1c\x28\x15\x96\xa7\x38\x0f\xd1\
+    \x23\x6e\xee\xd8\xc5\xf7\xb4\x45\x13\xac\x2d\xff\xe5\x38\xab\xab\x69\x0f\x0e\x8e\x90\x5f\x0a\x0d\x3e\xaf\x6b\x94\x67\x9a\x3c\xde\
+    \x4a\x39\x58\xfd\x9b\xbb\x4f\x16\xc0\xb0\x8f\xd6\xd7\xaf\x0e\x27\xf9\xaf\xfc\xb9\x49\x24\x57\x90\x1d\xa6\x59\xc5\x5f\xb9\xf0\xd6\
+    \x39\xf8\x3d\x83\x11\x88\xcd\x2a\x55\xe2\x53\x54\x84\x26\x3e\x1f\x4d\xde\xba\x96\x59\x8d\x7a\x8f\x03\x1e\x33\x2f\x04\x40\x32\x21\
+    \x44\xa0\x4b\x2e\xc4\x8b\x3a\x65\x83\xd1\x5f\x94\xfd\x16\x03\x35\x4c\x92\xca\x53\x46\x19\x78\x9c\x51\xa3\x03\x94\x45\xf3\x61\xc4\
+    \x68\x8b\x25\x3e\x39\xd7\x31\x12\x0f\x1d\xae\x41\x60\xcf\x9f\x42\xe0\x7d\x3b\xf8\x3d\x82\x09\x5b\x20\xd4\x31\x58\x14\x39\x7b\xb1\
+    \x2e\x51\xcc\x88\x78\x21\x55\xb1\xdf\xe5\xf3\xed\xc9\xfe\xb1\xf4\x6a\x40\x79\xcf\xfc\x88\xa1\xdb\x14\xbf\xcb\xef\xcc\x63\x7e\xfe\
+    \x24\xe3\xc7\x52\x53\xb2\x92\x65\xad\x38\x98\xe0\x98\x23\x0d\x85\x73\xb8\x50\x11\xe8\xdd\xcf\x82\x2d\x9f\xd0\xe8\xfe\xff\xee\x60\
+    \x29\x4e\x5e\x43\x72\xc3\x25\xa7\x4a\x5e\xee\xf7\x3f\xd1\x73\x72\xa1\x14\x7e\x31\xce\xf6\x80\x21\x4b\xfb\x1c\xe5\xd9\x1d\x1c\x32\
+    \x05\xc4\x3a\x54\x37\x07\x34\x81\x89\xa7\x29\xcd\x8d\x0c\x76\x42\x8d\x86\xfa\xd7\xcc\xd1\x3d\xf8\x54\x62\xe5\xf5\xa4\x90\xd1\x7a\
+    \x58\x84\x32\xcf\xa0\xf6\xa9\xd2\x44\x90\xf4\x6c\x23\x6e\xad\x92\x5c\x7b\x08\x83\xc9\xff\x26\xd3\x53\xc6\x9a\x25\xaa\xf4\x92\x82\
+    \x2f\xd0\x11\xce\x66\x7a\x1b\x07\x87\xdb\x8c\xf1\x3a\xdc\x8e\x36\xc3\xfd\x2f\x0a\x3b\x24\xce\x80\x52\x15\x6a\xb5\xae\x93\x95\xae\
+    \x4e\x1c\x56\x56\x5e\xcc\xab\x91\xdf\x8d\xd8\x67\x89\xad\xb6\x39\x27\xe3\xb4\x51\x09\x9c\xd2\x20\x47\x51\x61\xbb\x2c\x15\x80\x32\
+    \x11\x99\x0d\xb0\x2c\xa0\x6f\xe4\xd9\xee\xfd\xd7\x42\x13\xaf\x81\x42\x94\x8e\x64\xa0\x4a\xf0\x53\xd4\xc4\xf5\x59\x82\x34\xa9\xa7\
+";
@@ -1,16 +1,38 @@
-use crate::{
-    poseidon2::params::{
-        get_mat_diag_bls12_381_t_2, get_mat_diag_bls12_381_t_3, get_mat_diag_bls12_381_t_4,
-        get_mat_diag_bn254_t_2, get_mat_diag_bn254_t_3, get_mat_diag_bn254_t_4,
-        get_rc_bls12_381_t_2, get_rc_bls12_381_t_3, get_rc_bls12_381_t_4, get_rc_bn254_t_2,
-        get_rc_bn254_t_3, get_rc_bn254_t_4, SBOX_D,
-    },
-    Field,
+#[cfg(any(feature = "poseidon2-bn254", feature = "poseidon2-bls12-381"))]
+use crate::constants::{u256_matrix, u256_vec};
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+use crate::poseidon2::params::{
+    MAT_DIAG_BLS12_381_T_12, MAT_DIAG_BLS12_381_T_16, MAT_DIAG_BLS12_381_T_8, RC_BLS12_381_T_12,
+    RC_BLS12_381_T_16, RC_BLS12_381_T_8,
+};
+#[cfg(feature = "poseidon2-bls12-381")]
+use crate::poseidon2::params::{
+    MAT_DIAG_BLS12_381_T_2, MAT_DIAG_BLS12_381_T_3, MAT_DIAG_BLS12_381_T_4, RC_BLS12_381_T_2,
+    RC_BLS12_381_T_3, RC_BLS12_381_T_4,
 };
-use soroban_sdk::{
-    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
-    vec, Env, Vec, U256,
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+use crate::poseidon2::params::{
+    MAT_DIAG_BN254_T_12, MAT_DIAG_BN254_T_16, MAT_DIAG_BN254_T_8, RC_BN254_T_12, RC_BN254_T_16,
+    RC_BN254_T_8,
 };
+#[cfg(feature = "poseidon2-bn254")]
+use crate::poseidon2::params::{
+    MAT_DIAG_BN254_T_2, MAT_DIAG_BN254_T_3, MAT_DIAG_BN254_T_4, RC_BN254_T_2, RC_BN254_T_3,
+    RC_BN254_T_4,
+};
+use crate::{
+    bytes::pack_bytes,
+    field_element::FieldElement,
+    poseidon2::params::SBOX_D,
+    registry::ParamsRegistryClient,
+    safe::{sealed, SafePermutation},
+    Field, PoseidonError,
+};
+#[cfg(feature = "poseidon2-bls12-381")]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
+#[cfg(feature = "poseidon2-bn254")]
+use soroban_sdk::crypto::bn254::Bn254Fr;
+use soroban_sdk::{contracttype, vec, Address, Bytes, Env, IntoVal, Val, Vec, U256};
 
 const CAPACITY: u32 = 1;
 
@@ -22,19 +44,37 @@ pub trait Poseidon2Config<const T: u32, F: Field> {
     fn get_rc(e: &Env) -> Vec<Vec<U256>>;
 }
 
-// Internal struct storing the Poseidon2 constants, in the future we can make
-// this a #[contracttype], which can be stored as contract data (to reduce the
-// actual contract size)
-pub(crate) struct Poseidon2Params {
+/// The constants of a Poseidon2 parameter set.
+///
+/// This is a `#[contracttype]`, so a parameter set can be passed to a contract
+/// and kept in contract storage instead of being embedded in the contract's
+/// WASM. See [`Poseidon2Sponge::from_params`] and
+/// [`Poseidon2Sponge::from_storage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Poseidon2Params {
     pub rounds_f: u32,
     pub rounds_p: u32,
+    /// The internal matrix diagonal minus one (`T` elements).
     pub m_diag: Vec<U256>,
+    /// Round constants, one row of `T` elements per round.
     pub rc: Vec<Vec<U256>>,
 }
 
+impl Poseidon2Params {
+    /// Returns whether the matrix and round constant dimensions match the
+    /// state size `t` and the round counts.
+    pub fn is_valid_for(&self, t: u32) -> bool {
+        self.m_diag.len() == t
+            && self.rc.len() == self.rounds_f + self.rounds_p
+            && self.rc.iter().all(|row| row.len() == t)
+    }
+}
+
 /// A Poseidon2 sponge configured for a specific state size `T` and field `F`.
 ///
-/// This is a single-absorb, single-squeeze sponge. The primary benefit of
+/// This sponge absorbs any number of inputs (`RATE` elements per
+/// permutation) and squeezes a single output. The primary benefit of
 /// creating a sponge instance is to reuse the pre-computed parameters (MDS
 /// matrix diagonal and round constants) across multiple independent hash
 /// computations, avoiding repeated parameter initialization.
@@ -43,6 +83,16 @@ pub(crate) struct Poseidon2Params {
 /// internal state and computes a fresh hash. The sponge does not accumulate
 /// state between calls.
 ///
+/// # Streaming
+///
+/// Inputs can also be fed incrementally. Because the IV commits to the
+/// message length (`message_len << 64`), the length must be declared up front
+/// with [`start`](Self::start). Then absorb elements with
+/// [`absorb_one`](Self::absorb_one) / [`absorb_many`](Self::absorb_many) and
+/// obtain the digest with [`finalize`](Self::finalize). Exactly `message_len`
+/// elements must be absorbed, so streaming produces the same digest as
+/// [`compute_hash`](Self::compute_hash) on the concatenated inputs.
+///
 /// # Example
 /// ```ignore
 /// // Create sponge once (initializes parameters)
@@ -51,79 +101,173 @@ pub(crate) struct Poseidon2Params {
 /// // Compute multiple independent hashes, reusing the same parameters
 /// let hash1 = sponge.compute_hash(&inputs1);
 /// let hash2 = sponge.compute_hash(&inputs2);
+///
+/// // Stream the inputs instead of building a `Vec` up front
+/// sponge.start(2);
+/// sponge.absorb_one(a);
+/// sponge.absorb_one(b);
+/// let hash3 = sponge.finalize(); // == compute_hash(&vec![&env, a, b])
 /// ```
 pub struct Poseidon2Sponge<const T: u32, F: Field> {
     env: Env,
     state: Vec<U256>,
     params: Poseidon2Params,
+    // Built once, so hashing does not rebuild the modulus or the initial state
+    modulus: U256,
+    zero_state: Vec<U256>,
+    // Number of elements absorbed into the current (not yet permuted) chunk
+    cache_size: u32,
+    // Number of elements absorbed since the last `start`
+    absorbed: u32,
+    // Message length committed to by the IV
+    message_len: u32,
+    squeezed: bool,
     _phantom: core::marker::PhantomData<F>,
 }
 
 // BN254 implementations
+#[cfg(feature = "poseidon2-bn254")]
 impl Poseidon2Config<2, Bn254Fr> for Poseidon2Sponge<2, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bn254_t_2(e)
+        u256_vec(e, MAT_DIAG_BN254_T_2)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_2(e)
+        u256_matrix(e, RC_BN254_T_2, 2)
     }
 }
 
+#[cfg(feature = "poseidon2-bn254")]
 impl Poseidon2Config<3, Bn254Fr> for Poseidon2Sponge<3, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bn254_t_3(e)
+        u256_vec(e, MAT_DIAG_BN254_T_3)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_3(e)
+        u256_matrix(e, RC_BN254_T_3, 3)
     }
 }
 
+#[cfg(feature = "poseidon2-bn254")]
 impl Poseidon2Config<4, Bn254Fr> for Poseidon2Sponge<4, Bn254Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bn254_t_4(e)
+        u256_vec(e, MAT_DIAG_BN254_T_4)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_4, 4)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+impl Poseidon2Config<8, Bn254Fr> for Poseidon2Sponge<8, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BN254_T_8)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_8, 8)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+impl Poseidon2Config<12, Bn254Fr> for Poseidon2Sponge<12, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BN254_T_12)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bn254_t_4(e)
+        u256_matrix(e, RC_BN254_T_12, 12)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bn254", feature = "poseidon2-wide"))]
+impl Poseidon2Config<16, Bn254Fr> for Poseidon2Sponge<16, Bn254Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BN254_T_16)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BN254_T_16, 16)
     }
 }
 
 // BLS12-381 implementations
+#[cfg(feature = "poseidon2-bls12-381")]
 impl Poseidon2Config<2, Bls12381Fr> for Poseidon2Sponge<2, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bls12_381_t_2(e)
+        u256_vec(e, MAT_DIAG_BLS12_381_T_2)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_2(e)
+        u256_matrix(e, RC_BLS12_381_T_2, 2)
     }
 }
 
+#[cfg(feature = "poseidon2-bls12-381")]
 impl Poseidon2Config<3, Bls12381Fr> for Poseidon2Sponge<3, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bls12_381_t_3(e)
+        u256_vec(e, MAT_DIAG_BLS12_381_T_3)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_3(e)
+        u256_matrix(e, RC_BLS12_381_T_3, 3)
     }
 }
 
+#[cfg(feature = "poseidon2-bls12-381")]
 impl Poseidon2Config<4, Bls12381Fr> for Poseidon2Sponge<4, Bls12381Fr> {
     const ROUNDS_F: u32 = 8;
     const ROUNDS_P: u32 = 56;
     fn get_m_diag(e: &Env) -> Vec<U256> {
-        get_mat_diag_bls12_381_t_4(e)
+        u256_vec(e, MAT_DIAG_BLS12_381_T_4)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BLS12_381_T_4, 4)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+impl Poseidon2Config<8, Bls12381Fr> for Poseidon2Sponge<8, Bls12381Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BLS12_381_T_8)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BLS12_381_T_8, 8)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+impl Poseidon2Config<12, Bls12381Fr> for Poseidon2Sponge<12, Bls12381Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BLS12_381_T_12)
+    }
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        u256_matrix(e, RC_BLS12_381_T_12, 12)
+    }
+}
+
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+impl Poseidon2Config<16, Bls12381Fr> for Poseidon2Sponge<16, Bls12381Fr> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 57;
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        u256_vec(e, MAT_DIAG_BLS12_381_T_16)
     }
     fn get_rc(e: &Env) -> Vec<Vec<U256>> {
-        get_rc_bls12_381_t_4(e)
+        u256_matrix(e, RC_BLS12_381_T_16, 16)
     }
 }
 
@@ -131,17 +275,8 @@ impl<const T: u32, F: Field> Poseidon2Sponge<T, F>
 where
     Self: Poseidon2Config<T, F>,
 {
-    fn reset_state(&mut self, iv: U256) {
-        // State layout: [rate elements...][capacity element]
-        // Rate elements are at positions 0..RATE, capacity (IV) is at position T-1 (last)
-        self.state = vec![&self.env];
-        for _ in 0..Self::RATE {
-            self.state.push_back(U256::from_u32(&self.env, 0));
-        }
-        // IV goes at the last position (capacity element)
-        self.state.push_back(iv);
-    }
-
+    /// Creates a sponge with the built-in parameter set for `T` and `F`,
+    /// which requires the feature that compiles it.
     pub fn new(env: &Env) -> Self {
         let params = Poseidon2Params {
             rounds_f: <Self as Poseidon2Config<T, F>>::ROUNDS_F,
@@ -149,14 +284,137 @@ where
             m_diag: <Self as Poseidon2Config<T, F>>::get_m_diag(env),
             rc: <Self as Poseidon2Config<T, F>>::get_rc(env),
         };
+        Self::with_params(env, params)
+    }
+}
+
+// The parameter-driven constructors and the hashing paths only need `T` and
+// `F`, so they are available without any built-in parameter feature.
+impl<const T: u32, F: Field> Poseidon2Sponge<T, F> {
+    /// The number of inputs absorbed per permutation, `T - 1`.
+    pub const RATE: u32 = T - CAPACITY;
+
+    fn reset_state(&mut self, iv: U256) {
+        // State layout: [rate elements...][capacity element]
+        // Rate elements are at positions 0..RATE, capacity (IV) is at position T-1 (last)
+        self.state = self.zero_state.clone();
+        // IV goes at the last position (capacity element)
+        self.state.set(T - 1, iv);
+        self.cache_size = 0;
+        self.absorbed = 0;
+        self.squeezed = false;
+    }
+
+    /// Creates a sponge from an explicit parameter set, e.g. one passed to the
+    /// contract as an argument.
+    ///
+    /// This does not need a built-in parameter feature, so a contract that
+    /// only constructs sponges this way (or with
+    /// [`from_storage`](Self::from_storage) / [`from_registry`](Self::from_registry))
+    /// can depend on this crate with `default-features = false` and does not
+    /// compile the built-in constants at all.
+    ///
+    /// Only the dimensions of `params` are checked, not their security; see
+    /// [`store_params`](Self::store_params).
+    ///
+    /// # Panics
+    /// - if `params` does not match the state size `T` (`m_diag` must hold `T`
+    ///   elements, and `rc` must hold `rounds_f + rounds_p` rows of `T`
+    ///   elements).
+    pub fn from_params(env: &Env, params: Poseidon2Params) -> Self {
+        assert!(
+            params.is_valid_for(T),
+            "Poseidon2: parameters do not match the state size T"
+        );
+        Self::with_params(env, params)
+    }
+
+    /// Creates a sponge from an explicit parameter set, like
+    /// [`from_params`](Self::from_params), but returns an error instead of
+    /// panicking.
+    ///
+    /// # Errors
+    /// - [`PoseidonError::UnsupportedConfig`] if `params` does not match the
+    ///   state size `T`.
+    pub fn try_from_params(env: &Env, params: Poseidon2Params) -> Result<Self, PoseidonError> {
+        if !params.is_valid_for(T) {
+            return Err(PoseidonError::UnsupportedConfig);
+        }
+        Ok(Self::with_params(env, params))
+    }
+
+    /// Creates a sponge from a parameter set previously written to persistent
+    /// contract storage under `key` with [`store_params`](Self::store_params).
+    ///
+    /// Persistent storage is used rather than instance storage, so the
+    /// constants are only loaded by the invocations that hash. Extending the
+    /// entry's TTL is left to the caller.
+    ///
+    /// # Panics
+    /// - if no parameter set is stored under `key`.
+    /// - same conditions as [`from_params`](Self::from_params).
+    pub fn from_storage<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Self {
+        let params: Poseidon2Params = env
+            .storage()
+            .persistent()
+            .get(key)
+            .expect("Poseidon2: parameters not found in storage");
+        Self::from_params(env, params)
+    }
+
+    /// Creates a sponge from the parameter set for `F` and `T` installed in the
+    /// shared parameter registry contract at `registry` (see
+    /// [`ParamsRegistryInterface`](crate::ParamsRegistryInterface)), fetched
+    /// with a cross-contract call.
+    ///
+    /// # Panics
+    /// - if the registry has no parameter set for `F` and `T`.
+    /// - same conditions as [`from_params`](Self::from_params).
+    pub fn from_registry(env: &Env, registry: &Address) -> Self {
+        let params = ParamsRegistryClient::new(env, registry)
+            .poseidon2_params(&F::symbol(), &T)
+            .expect("Poseidon2: parameters not found in registry");
+        Self::from_params(env, params)
+    }
+
+    /// Writes this sponge's parameter set to persistent contract storage under
+    /// `key`, to be loaded later with [`from_storage`](Self::from_storage).
+    ///
+    /// The entry is write-once, so whoever stores it first fixes the hash
+    /// for the contract's lifetime. [`from_params`](Self::from_params) only
+    /// checks the dimensions of the set, not that it is secure (e.g. it
+    /// accepts `rounds_p = 0`), so parameters supplied by a caller must be
+    /// compared against a pinned digest of the reference set, such as the
+    /// SHA-256 of its XDR encoding, before they are stored.
+    ///
+    /// # Panics
+    /// - if a parameter set is already stored under `key`.
+    pub fn store_params<K: IntoVal<Env, Val>>(&self, key: &K) {
+        let storage = self.env.storage().persistent();
+        assert!(!storage.has(key), "Poseidon2: parameters already stored");
+        storage.set(key, &self.params);
+    }
+
+    /// Returns this sponge's parameter set.
+    pub fn params(&self) -> &Poseidon2Params {
+        &self.params
+    }
+
+    fn with_params(env: &Env, params: Poseidon2Params) -> Self {
         let mut inner = Self {
             env: env.clone(),
             state: vec![env],
             params,
+            modulus: F::modulus(env),
+            zero_state: Vec::from_iter(env, (0..T).map(|_| U256::from_u32(env, 0))),
+            cache_size: 0,
+            absorbed: 0,
+            message_len: 0,
+            squeezed: false,
             _phantom: core::marker::PhantomData,
         };
         // Initialize with default IV of 0
-        inner.reset_state(U256::from_u32(env, 0));
+        inner.start(0);
         inner
     }
 
@@ -173,28 +431,105 @@ where
         );
     }
 
-    pub(crate) fn absorb(&mut self, inputs: &Vec<U256>) {
-        // <= is safe here because IV = input_len << 64 provides domain
-        // separation for different-length inputs. This differs from Poseidon V1
-        // (which uses IV=0 and therefore requires == RATE).
+    /// Resets the sponge to begin absorbing a message of `message_len`
+    /// elements.
+    ///
+    /// The capacity element is initialized to `message_len << 64`, matching
+    /// noir's Poseidon2 IV. This provides domain separation between messages
+    /// of different lengths, which is why any length (including zero and
+    /// lengths that are not a multiple of `RATE`) is safe.
+    pub fn start(&mut self, message_len: u32) {
+        // The initial value for the capacity element: input.len() * 2^64 for Poseidon2
+        let iv = U256::from_u128(&self.env, (message_len as u128) << 64);
+        self.reset_state(iv);
+        self.message_len = message_len;
+    }
+
+    fn absorb_checked(&mut self, input: U256, canonical: bool) {
+        // Inputs are absorbed in chunks of RATE elements, matching noir's
+        // cache-and-permute sponge: each element is added into the rate
+        // portion of the state, and the permutation is applied only once a
+        // chunk is full *and* another element needs to be absorbed. The final
+        // (possibly partial) chunk is permuted by `squeeze`. `canonical` skips
+        // the modulus check for inputs already known to be field elements.
+        assert!(!self.squeezed, "Poseidon2: cannot absorb after squeeze");
         assert!(
-            inputs.len() <= Self::RATE,
-            "Poseidon2: inputs.len() must not exceed rate (T - 1)"
+            self.absorbed < self.message_len,
+            "Poseidon2: absorbed more inputs than the declared message length"
         );
-        let modulus = F::modulus(&self.env);
-        for i in 0..inputs.len() {
-            let v = inputs.get_unchecked(i);
-            assert!(v < modulus, "input exceeds field modulus");
-            self.state.set(i, v);
+        assert!(
+            canonical || input < self.modulus,
+            "input exceeds field modulus"
+        );
+        if self.cache_size == Self::RATE {
+            self.perform_duplex();
+            self.cache_size = 0;
         }
+        let s = self
+            .state
+            .get_unchecked(self.cache_size)
+            .add(&input)
+            .rem_euclid(&self.modulus);
+        self.state.set(self.cache_size, s);
+        self.cache_size += 1;
+        self.absorbed += 1;
     }
 
-    pub(crate) fn squeeze(&mut self) -> U256 {
+    /// Absorbs a single field element into the sponge.
+    ///
+    /// # Panics
+    /// - if the declared `message_len` (see [`start`](Self::start)) has
+    ///   already been absorbed.
+    /// - if the sponge has already been squeezed.
+    /// - if `input` is greater than or equal to the field modulus.
+    pub fn absorb_one(&mut self, input: U256) {
+        self.absorb_checked(input, false);
+    }
+
+    /// Absorbs each element of `inputs` in order, equivalent to calling
+    /// [`absorb_one`](Self::absorb_one) on each of them.
+    ///
+    /// # Panics
+    /// Same conditions as [`absorb_one`](Self::absorb_one).
+    pub fn absorb_many(&mut self, inputs: &Vec<U256>) {
+        for v in inputs.iter() {
+            self.absorb_checked(v, false);
+        }
+    }
+
+    /// Applies the permutation to the last (possibly partial) chunk and
+    /// returns the output at position 0, matching noir's `squeeze`.
+    ///
+    /// The sponge is left in squeezing mode; call
+    /// [`finalize`](Self::finalize) instead to also reset it for the next
+    /// message.
+    ///
+    /// # Panics
+    /// - if fewer than the declared `message_len` elements have been absorbed.
+    /// - if the sponge has already been squeezed.
+    pub fn squeeze(&mut self) -> U256 {
+        assert!(!self.squeezed, "Poseidon2: sponge already squeezed");
+        assert!(
+            self.absorbed == self.message_len,
+            "Poseidon2: absorbed fewer inputs than the declared message length"
+        );
         self.perform_duplex();
+        self.squeezed = true;
         // Output is at position 0
         self.state.get_unchecked(0)
     }
 
+    /// Squeezes the output and resets the sponge with an empty message
+    /// length. Call [`start`](Self::start) before absorbing the next message.
+    ///
+    /// # Panics
+    /// Same conditions as [`squeeze`](Self::squeeze).
+    pub fn finalize(&mut self) -> U256 {
+        let output = self.squeeze();
+        self.start(0);
+        output
+    }
+
     /// Computes a fresh Poseidon2 hash of the inputs.
     ///
     /// This method resets the sponge state and computes a new hash from
@@ -207,16 +542,202 @@ where
     /// [noir's Poseidon2
     /// implementation](https://github.com/noir-lang/noir/blob/master/noir_stdlib/src/hash/poseidon2.nr).
     ///
+    /// Inputs of any length are supported. They are absorbed `RATE` elements
+    /// at a time, with one permutation per chunk, matching noir's
+    /// `Poseidon2::hash` for a fixed-length message (`message_size == N`).
+    ///
     /// # Panics
-    /// - if `inputs.len() > RATE` (i.e., `T - 1`). For larger inputs,
-    ///   multi-round absorption would be needed (not yet implemented).
     /// - if any input value is greater than or equal to the field modulus.
     ///   All inputs must be valid field elements (i.e., less than the modulus).
     pub fn compute_hash(&mut self, inputs: &Vec<U256>) -> U256 {
-        // The initial value for the capacity element: input.len() * 2^64 for Poseidon2
-        let iv = U256::from_u128(&self.env, (inputs.len() as u128) << 64);
-        self.reset_state(iv);
-        self.absorb(inputs);
-        self.squeeze()
+        self.start(inputs.len());
+        self.absorb_many(inputs);
+        self.finalize()
+    }
+
+    /// Computes the same hash as [`compute_hash`](Self::compute_hash) of
+    /// inputs that are already known to be field elements, skipping the
+    /// per-input comparison against the modulus.
+    ///
+    /// The output is itself a field element, so it can be fed into the next
+    /// hash (e.g. up a Merkle path) unchecked.
+    pub fn compute_hash_elements(&mut self, inputs: &[FieldElement<F>]) -> FieldElement<F> {
+        self.start(inputs.len() as u32);
+        for v in inputs {
+            self.absorb_checked(v.to_u256(), true);
+        }
+        FieldElement::from_canonical(self.finalize())
+    }
+
+    /// Computes the same hash as [`compute_hash`](Self::compute_hash), but
+    /// returns an error instead of panicking on invalid inputs.
+    ///
+    /// The inputs are checked before anything is absorbed, so the sponge is
+    /// left ready for the next message either way.
+    ///
+    /// # Errors
+    /// - [`PoseidonError::InputNotInField`] if any input value is greater than
+    ///   or equal to the field modulus.
+    pub fn try_compute_hash(&mut self, inputs: &Vec<U256>) -> Result<U256, PoseidonError> {
+        if inputs.iter().any(|v| v >= self.modulus) {
+            return Err(PoseidonError::InputNotInField);
+        }
+        Ok(self.compute_hash(inputs))
+    }
+
+    /// Computes a Poseidon2 hash of an arbitrary byte string, matching
+    /// Aztec's `poseidon2_hash_bytes` (`poseidon2HashBytes` in
+    /// `@aztec/foundation`) for `T = 4` over BN254.
+    ///
+    /// `msg` is split into 31-byte chunks, each read as a little-endian
+    /// integer, and the chunks are hashed with
+    /// [`compute_hash`](Self::compute_hash), so the IV commits to the number
+    /// of chunks.
+    ///
+    /// The byte length is not committed, so messages with the same number of
+    /// chunks that only differ by trailing zero bytes (e.g. `[1]` and
+    /// `[1, 0]`) collide. Only use this for fixed-length or self-delimiting
+    /// payloads, or append the length to the message.
+    pub fn compute_hash_bytes(&mut self, msg: &Bytes) -> U256 {
+        let chunks = pack_bytes(&self.env, msg, true);
+        self.compute_hash(&chunks)
+    }
+
+    /// Computes [`compute_hash`](Self::compute_hash) of each element of
+    /// `inputs` and returns the hashes in order.
+    ///
+    /// The parameters, the field modulus and the initial state are set up
+    /// once per sponge, so hashing many messages (e.g. the leaf pairs of a
+    /// Merkle tree) in one call only pays for the permutations.
+    ///
+    /// # Panics
+    /// Same conditions as [`compute_hash`](Self::compute_hash), for every
+    /// element of `inputs`.
+    pub fn compute_hash_batch(&mut self, inputs: &Vec<Vec<U256>>) -> Vec<U256> {
+        let mut outputs = vec![&self.env];
+        for message in inputs.iter() {
+            outputs.push_back(self.compute_hash(&message));
+        }
+        outputs
+    }
+
+    /// Computes the Poseidon2 compression function of exactly `T` inputs.
+    ///
+    /// This is the truncated permutation with feed-forward used by Poseidon2
+    /// Merkle trees (HorizenLabs / Plonky3 style): the inputs are the full
+    /// initial state (no IV), and output `i` is `P(inputs)[i] + inputs[i]`
+    /// for `i` in `0..n_outs`. E.g. with `T = 2`, `compress([a, b], 1)` is
+    /// `P(a || b)[0] + a`.
+    ///
+    /// The sponge is reset afterwards, as with
+    /// [`compute_hash`](Self::compute_hash).
+    ///
+    /// # Panics
+    /// - if `inputs.len() != T`.
+    /// - if `n_outs` is 0 or greater than `T`.
+    /// - if any input value is greater than or equal to the field modulus.
+    pub fn compute_compress(&mut self, inputs: &Vec<U256>, n_outs: u32) -> Vec<U256> {
+        assert!(
+            inputs.len() == T,
+            "Poseidon2: compress takes exactly T inputs"
+        );
+        assert!(
+            (1..=T).contains(&n_outs),
+            "Poseidon2: n_outs must be between 1 and T"
+        );
+        let permuted = self.compute_permutation(inputs);
+        let mut outputs = vec![&self.env];
+        for i in 0..n_outs {
+            let out = permuted
+                .get_unchecked(i)
+                .add(&inputs.get_unchecked(i))
+                .rem_euclid(&self.modulus);
+            outputs.push_back(out);
+        }
+        outputs
+    }
+
+    /// Applies the raw Poseidon2 permutation to a full `T`-element state and
+    /// returns the permuted state.
+    ///
+    /// No IV or padding convention is applied, which makes this the building
+    /// block for custom modes (e.g. compression functions or transcripts).
+    /// The sponge is reset afterwards, as with
+    /// [`compute_hash`](Self::compute_hash).
+    ///
+    /// # Panics
+    /// - if `state.len() != T`.
+    /// - if any state element is greater than or equal to the field modulus.
+    pub fn compute_permutation(&mut self, state: &Vec<U256>) -> Vec<U256> {
+        assert!(
+            state.len() == T,
+            "Poseidon2: permutation state must have exactly T elements"
+        );
+        for v in state.iter() {
+            assert!(v < self.modulus, "input exceeds field modulus");
+        }
+        self.state = state.clone();
+        self.perform_duplex();
+        let output = self.state.clone();
+        self.start(0);
+        output
+    }
+}
+
+impl<const T: u32, F: Field> sealed::Permutation for Poseidon2Sponge<T, F>
+where
+    Self: Poseidon2Config<T, F>,
+{
+    fn env(&self) -> &Env {
+        &self.env
+    }
+
+    fn modulus(&self) -> U256 {
+        self.modulus.clone()
+    }
+
+    fn rate(&self) -> u32 {
+        Self::RATE
+    }
+
+    fn reset_with_tag(&mut self, tag: U256) {
+        // Clears the streaming bookkeeping as well, then places the tag in
+        // the capacity element (last position)
+        self.start(0);
+        self.state.set(T - 1, tag);
+    }
+
+    fn rate_element(&self, i: u32) -> U256 {
+        self.state.get_unchecked(i)
+    }
+
+    fn set_rate_element(&mut self, i: u32, v: U256) {
+        self.state.set(i, v);
+    }
+
+    fn permute(&mut self) {
+        self.perform_duplex();
+    }
+}
+
+impl<const T: u32, F: Field> SafePermutation for Poseidon2Sponge<T, F> where
+    Self: Poseidon2Config<T, F>
+{
+}
+
+impl<const T: u32, F: Field> crate::FieldHasher for Poseidon2Sponge<T, F>
+where
+    Self: Poseidon2Config<T, F>,
+{
+    type Field = F;
+
+    const RATE: u32 = <Self as Poseidon2Config<T, F>>::RATE;
+
+    fn new(env: &Env) -> Self {
+        Self::new(env)
+    }
+
+    fn hash(&mut self, inputs: &Vec<U256>) -> U256 {
+        self.compute_hash(inputs)
     }
 }
@@ -0,0 +1,26 @@
+//! Client interface of the shared parameter registry contract.
+//!
+//! A registry stores every Poseidon / Poseidon2 parameter set keyed by
+//! (algorithm, field symbol, `T`), so a single deployment can serve all
+//! hashing contracts instead of each of them embedding or storing the
+//! constants. Sponges load a parameter set from a registry with
+//! [`PoseidonSponge::from_registry`](crate::PoseidonSponge::from_registry) and
+//! [`Poseidon2Sponge::from_registry`](crate::Poseidon2Sponge::from_registry).
+
+use crate::{Poseidon2Params, PoseidonParams};
+use soroban_sdk::{contractclient, Env, Symbol};
+
+/// Read API of the parameter registry. A client for it is generated as
+/// [`ParamsRegistryClient`].
+#[contractclient(name = "ParamsRegistryClient")]
+pub trait ParamsRegistryInterface {
+    /// Returns the Poseidon parameter set for `field` (see
+    /// [`Field::symbol`](crate::Field::symbol)) and state size `t`, if
+    /// installed.
+    fn poseidon_params(env: Env, field: Symbol, t: u32) -> Option<PoseidonParams>;
+
+    /// Returns the Poseidon2 parameter set for `field` (see
+    /// [`Field::symbol`](crate::Field::symbol)) and state size `t`, if
+    /// installed.
+    fn poseidon2_params(env: Env, field: Symbol, t: u32) -> Option<Poseidon2Params>;
+}
@@ -1,4 +1,27 @@
 #![cfg(test)]
 
+#[cfg(all(feature = "poseidon-bn254", feature = "poseidon2-bn254"))]
+mod budget;
+#[cfg(feature = "poseidon-bn254")]
+mod bytes;
+mod field;
+mod field_element;
+#[cfg(all(
+    feature = "poseidon-bn254",
+    feature = "poseidon-bls12-381",
+    feature = "poseidon2-bn254",
+    feature = "poseidon2-bls12-381"
+))]
+mod hasher;
+#[cfg(feature = "poseidon-bn254")]
+mod merkle;
+#[cfg(feature = "poseidon-bn254")]
 mod poseidon;
+#[cfg(feature = "poseidon2-bn254")]
 mod poseidon2;
+#[cfg(all(
+    feature = "poseidon-bn254",
+    feature = "poseidon2-bn254",
+    feature = "poseidon2-bls12-381"
+))]
+mod safe;
@@ -0,0 +1,334 @@
+extern crate std;
+
+use crate::{
+    constants::{u256_matrix, u256_vec},
+    poseidon::params::{MDS_BN254_T_3, MDS_BN254_T_6, RC_BN254_T_3, RC_BN254_T_6},
+    poseidon2::params::{MAT_DIAG_BN254_T_4, RC_BN254_T_4},
+    Poseidon2Sponge, PoseidonSponge,
+};
+#[cfg(any(feature = "poseidon-bls12-381", feature = "poseidon2-bls12-381"))]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
+use soroban_sdk::{
+    crypto::bn254::Bn254Fr,
+    xdr::{FromXdr, ToXdr},
+    Bytes, BytesN, Env, Vec, U256,
+};
+use std::{format, string::String, vec::Vec as StdVec};
+
+// Budget tests for building the built-in parameter sets
+//
+// The parameter sets used to be built from one `bytesn!` literal per constant,
+// which costs two host calls per constant (a `BytesN` and the `U256` parsed
+// from it). They are now decoded from a byte blob with one host call per
+// constant. `legacy_*` rebuild the same sets through one `BytesN` per constant,
+// and `xdr_*` decode them from an XDR-encoded `Vec` with a single `from_xdr`
+// call, so all three costs are measured side by side.
+//
+// Measured when the encoding changed (CPU instructions / memory bytes). The
+// XDR column was measured later, when the blob columns were within 5% of
+// these numbers:
+//
+// | Parameter set        | `bytesn!` literals | Byte blob        | XDR, one call     |
+// |----------------------|--------------------|------------------|-------------------|
+// | Poseidon BN254 T=3   | 435728 / 51744     | 190928 / 25632   | 545759 / 58144    |
+// | Poseidon BN254 T=6   | 869192 / 104616    | 336392 / 47784   | 943645 / 114736   |
+// | Poseidon BN254 T=17  | 2909498 / 354296   | 1012298 / 151928 | 2815971 / 381408  |
+// | Poseidon2 BN254 T=4  | 430738 / 50544     | 223738 / 30704   | 549363 / 57088    |
+// | Poseidon2 BN254 T=16 | 1422129 / 169784   | 689304 / 103016  | 1508378 / 186952  |
+//
+// Passing the whole set to the host in one call is the most expensive of the
+// three: the host meters the XDR decoding of every value on top of creating
+// the same objects, and the encoding adds a 4-byte tag per constant. The blob
+// is therefore kept, at one host call per constant.
+
+fn measure(f: impl FnOnce(&Env)) -> (u64, u64) {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    f(&env);
+    let budget = env.cost_estimate().budget();
+    (budget.cpu_instruction_cost(), budget.memory_bytes_cost())
+}
+
+fn legacy_vec(env: &Env, bytes: &[u8]) -> Vec<U256> {
+    let mut out = Vec::new(env);
+    for c in bytes.as_chunks::<32>().0 {
+        out.push_back(U256::from_be_bytes(env, &BytesN::from_array(env, c).into()));
+    }
+    out
+}
+
+fn legacy_matrix(env: &Env, bytes: &[u8], row_len: usize) -> Vec<Vec<U256>> {
+    let mut out = Vec::new(env);
+    for row in bytes.chunks(32 * row_len) {
+        out.push_back(legacy_vec(env, row));
+    }
+    out
+}
+
+// Encodes a decoded parameter set as XDR, outside of any measurement
+fn to_xdr_blob(env: &Env, value: impl ToXdr) -> StdVec<u8> {
+    let xdr = value.to_xdr(env);
+    let mut out = std::vec![0; xdr.len() as usize];
+    xdr.copy_into_slice(&mut out);
+    out
+}
+
+fn xdr_vec(env: &Env, xdr: &[u8]) -> Vec<U256> {
+    Vec::from_xdr(env, &Bytes::from_slice(env, xdr)).unwrap()
+}
+
+fn xdr_matrix(env: &Env, xdr: &[u8]) -> Vec<Vec<U256>> {
+    Vec::from_xdr(env, &Bytes::from_slice(env, xdr)).unwrap()
+}
+
+#[test]
+fn test_blob_decoding_matches_legacy() {
+    let env = Env::default();
+    let rc = u256_matrix(&env, RC_BN254_T_3, 3);
+    assert_eq!(rc, legacy_matrix(&env, RC_BN254_T_3, 3));
+    assert_eq!(rc, xdr_matrix(&env, &to_xdr_blob(&env, rc.clone())));
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_4);
+    assert_eq!(m_diag, legacy_vec(&env, MAT_DIAG_BN254_T_4));
+    assert_eq!(m_diag, xdr_vec(&env, &to_xdr_blob(&env, m_diag.clone())));
+}
+
+#[test]
+fn test_poseidon_params_budget() {
+    for (t, mds, rc) in [
+        (3, MDS_BN254_T_3, RC_BN254_T_3),
+        (6, MDS_BN254_T_6, RC_BN254_T_6),
+    ] {
+        let env = Env::default();
+        let mds_xdr = to_xdr_blob(&env, u256_matrix(&env, mds, t as u32));
+        let rc_xdr = to_xdr_blob(&env, u256_matrix(&env, rc, t as u32));
+
+        let (legacy_cpu, legacy_mem) = measure(|env| {
+            legacy_matrix(env, mds, t);
+            legacy_matrix(env, rc, t);
+        });
+        let (xdr_cpu, xdr_mem) = measure(|env| {
+            xdr_matrix(env, &mds_xdr);
+            xdr_matrix(env, &rc_xdr);
+        });
+        let (cpu, mem) = measure(|env| {
+            u256_matrix(env, mds, t as u32);
+            u256_matrix(env, rc, t as u32);
+        });
+        assert!(cpu * 2 < legacy_cpu, "T={t}: cpu {cpu} vs {legacy_cpu}");
+        assert!(mem * 3 < legacy_mem * 2, "T={t}: mem {mem} vs {legacy_mem}");
+        assert!(cpu < xdr_cpu, "T={t}: cpu {cpu} vs xdr {xdr_cpu}");
+        assert!(mem < xdr_mem, "T={t}: mem {mem} vs xdr {xdr_mem}");
+    }
+}
+
+#[test]
+fn test_poseidon2_params_budget() {
+    let env = Env::default();
+    let m_diag_xdr = to_xdr_blob(&env, u256_vec(&env, MAT_DIAG_BN254_T_4));
+    let rc_xdr = to_xdr_blob(&env, u256_matrix(&env, RC_BN254_T_4, 4));
+
+    let (legacy_cpu, legacy_mem) = measure(|env| {
+        legacy_vec(env, MAT_DIAG_BN254_T_4);
+        legacy_matrix(env, RC_BN254_T_4, 4);
+    });
+    let (xdr_cpu, xdr_mem) = measure(|env| {
+        xdr_vec(env, &m_diag_xdr);
+        xdr_matrix(env, &rc_xdr);
+    });
+    let (cpu, mem) = measure(|env| {
+        u256_vec(env, MAT_DIAG_BN254_T_4);
+        u256_matrix(env, RC_BN254_T_4, 4);
+    });
+    assert!(cpu * 3 < legacy_cpu * 2, "cpu {cpu} vs {legacy_cpu}");
+    assert!(mem * 3 < legacy_mem * 2, "mem {mem} vs {legacy_mem}");
+    assert!(cpu < xdr_cpu, "cpu {cpu} vs xdr {xdr_cpu}");
+    assert!(mem < xdr_mem, "mem {mem} vs xdr {xdr_mem}");
+}
+
+// Budget benchmarks for every built-in configuration
+//
+// `budget.md` at the repository root records, for each (algorithm, field, T),
+// the cost of building a sponge, of one hash of `T - 1` inputs, and of
+// `REPEATED_HASHES` more hashes in one `compute_hash_batch` call. `test_budget_table` fails if
+// any cost grows by more than `REGRESSION_THRESHOLD_PERCENT` over the table,
+// or if the table does not list every configuration.
+//
+// Regenerate the table after an intended change with `make budget`, which sets
+// `BUDGET_UPDATE`.
+
+const BUDGET_TABLE: &str = include_str!("../../budget.md");
+const REPEATED_HASHES: u32 = 10;
+const REGRESSION_THRESHOLD_PERCENT: u64 = 5;
+
+/// CPU instructions and memory bytes of: `new`, one hash, repeated hashes.
+type Costs = [u64; 6];
+
+fn measure_costs<S>(
+    t: u32,
+    new: impl Fn(&Env) -> S,
+    hash: impl Fn(&mut S, &Vec<U256>) -> U256,
+    hash_batch: impl Fn(&mut S, &Vec<Vec<U256>>) -> Vec<U256>,
+) -> Costs {
+    let env = Env::default();
+    let mut budget = env.cost_estimate().budget();
+    budget.reset_unlimited();
+    let inputs: Vec<Vec<U256>> = Vec::from_iter(
+        &env,
+        (0..=REPEATED_HASHES)
+            .map(|i| Vec::from_iter(&env, (1..t).map(|j| U256::from_u32(&env, i * t + j)))),
+    );
+    // The budget only counts up, so each step is the difference of the totals
+    let mut costs = [0; 6];
+    let mut last = (0, 0);
+    let mut record = |i: usize| {
+        let now = (budget.cpu_instruction_cost(), budget.memory_bytes_cost());
+        costs[i] = now.0 - last.0;
+        costs[i + 1] = now.1 - last.1;
+        last = now;
+    };
+
+    record(0);
+    let mut sponge = new(&env);
+    record(0);
+    hash(&mut sponge, &inputs.get_unchecked(0));
+    record(2);
+    hash_batch(&mut sponge, &inputs.slice(1..));
+    record(4);
+    costs
+}
+
+macro_rules! poseidon {
+    ($t:literal, $f:ty) => {
+        measure_costs(
+            $t,
+            |env| PoseidonSponge::<$t, $f>::new(env),
+            |sponge, inputs| sponge.compute_hash(inputs),
+            |sponge, inputs| sponge.compute_hash_batch(inputs),
+        )
+    };
+}
+
+macro_rules! poseidon2 {
+    ($t:literal, $f:ty) => {
+        measure_costs(
+            $t,
+            |env| Poseidon2Sponge::<$t, $f>::new(env),
+            |sponge, inputs| sponge.compute_hash(inputs),
+            |sponge, inputs| sponge.compute_hash_batch(inputs),
+        )
+    };
+}
+
+fn measure_all() -> StdVec<(&'static str, &'static str, u32, Costs)> {
+    // Only the configurations compiled in by the enabled features are measured
+    let mut rows = StdVec::new();
+    #[cfg(feature = "poseidon-bn254")]
+    rows.extend([
+        ("Poseidon", "BN254", 2, poseidon!(2, Bn254Fr)),
+        ("Poseidon", "BN254", 3, poseidon!(3, Bn254Fr)),
+        ("Poseidon", "BN254", 4, poseidon!(4, Bn254Fr)),
+        ("Poseidon", "BN254", 5, poseidon!(5, Bn254Fr)),
+        ("Poseidon", "BN254", 6, poseidon!(6, Bn254Fr)),
+    ]);
+    #[cfg(feature = "poseidon-wide")]
+    rows.extend([
+        ("Poseidon", "BN254", 7, poseidon!(7, Bn254Fr)),
+        ("Poseidon", "BN254", 8, poseidon!(8, Bn254Fr)),
+        ("Poseidon", "BN254", 9, poseidon!(9, Bn254Fr)),
+        ("Poseidon", "BN254", 10, poseidon!(10, Bn254Fr)),
+        ("Poseidon", "BN254", 11, poseidon!(11, Bn254Fr)),
+        ("Poseidon", "BN254", 12, poseidon!(12, Bn254Fr)),
+        ("Poseidon", "BN254", 13, poseidon!(13, Bn254Fr)),
+        ("Poseidon", "BN254", 14, poseidon!(14, Bn254Fr)),
+        ("Poseidon", "BN254", 15, poseidon!(15, Bn254Fr)),
+        ("Poseidon", "BN254", 16, poseidon!(16, Bn254Fr)),
+        ("Poseidon", "BN254", 17, poseidon!(17, Bn254Fr)),
+    ]);
+    #[cfg(feature = "poseidon-bls12-381")]
+    rows.extend([
+        ("Poseidon", "BLS12-381", 2, poseidon!(2, Bls12381Fr)),
+        ("Poseidon", "BLS12-381", 3, poseidon!(3, Bls12381Fr)),
+        ("Poseidon", "BLS12-381", 4, poseidon!(4, Bls12381Fr)),
+        ("Poseidon", "BLS12-381", 5, poseidon!(5, Bls12381Fr)),
+        ("Poseidon", "BLS12-381", 6, poseidon!(6, Bls12381Fr)),
+    ]);
+    #[cfg(feature = "poseidon2-bn254")]
+    rows.extend([
+        ("Poseidon2", "BN254", 2, poseidon2!(2, Bn254Fr)),
+        ("Poseidon2", "BN254", 3, poseidon2!(3, Bn254Fr)),
+        ("Poseidon2", "BN254", 4, poseidon2!(4, Bn254Fr)),
+    ]);
+    #[cfg(feature = "poseidon2-wide")]
+    rows.extend([
+        ("Poseidon2", "BN254", 8, poseidon2!(8, Bn254Fr)),
+        ("Poseidon2", "BN254", 12, poseidon2!(12, Bn254Fr)),
+        ("Poseidon2", "BN254", 16, poseidon2!(16, Bn254Fr)),
+    ]);
+    #[cfg(feature = "poseidon2-bls12-381")]
+    rows.extend([
+        ("Poseidon2", "BLS12-381", 2, poseidon2!(2, Bls12381Fr)),
+        ("Poseidon2", "BLS12-381", 3, poseidon2!(3, Bls12381Fr)),
+        ("Poseidon2", "BLS12-381", 4, poseidon2!(4, Bls12381Fr)),
+    ]);
+    #[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+    rows.extend([
+        ("Poseidon2", "BLS12-381", 8, poseidon2!(8, Bls12381Fr)),
+        ("Poseidon2", "BLS12-381", 12, poseidon2!(12, Bls12381Fr)),
+        ("Poseidon2", "BLS12-381", 16, poseidon2!(16, Bls12381Fr)),
+    ]);
+    rows
+}
+
+/// Returns the costs recorded in `BUDGET_TABLE` for a configuration.
+fn recorded_costs(algorithm: &str, field: &str, t: u32) -> Option<Costs> {
+    BUDGET_TABLE.lines().find_map(|line| {
+        let cells: StdVec<&str> = line.split('|').map(str::trim).collect();
+        if cells.len() != 11 || cells[1] != algorithm || cells[2] != field {
+            return None;
+        }
+        if cells[3].parse() != Ok(t) {
+            return None;
+        }
+        let mut costs = [0; 6];
+        for (cost, cell) in costs.iter_mut().zip(&cells[4..10]) {
+            *cost = cell.replace(',', "").parse().ok()?;
+        }
+        Some(costs)
+    })
+}
+
+fn render_table(rows: &[(&str, &str, u32, Costs)]) -> String {
+    let mut out = String::from(BUDGET_TABLE.split("\n| ").next().unwrap().trim_end());
+    out += "\n\n| Algorithm | Field | T | `new` CPU | `new` mem | Hash CPU | Hash mem ";
+    out += &format!("| {REPEATED_HASHES} hashes CPU | {REPEATED_HASHES} hashes mem |\n");
+    out += "|-----------|-------|---|----------:|----------:|---------:|---------:";
+    out += "|--------------:|--------------:|\n";
+    for (algorithm, field, t, costs) in rows {
+        out += &format!("| {algorithm} | {field} | {t} |");
+        for cost in costs {
+            out += &format!(" {cost} |");
+        }
+        out += "\n";
+    }
+    out
+}
+
+#[test]
+fn test_budget_table() {
+    let rows = measure_all();
+    if std::env::var_os("BUDGET_UPDATE").is_some() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/budget.md");
+        std::fs::write(path, render_table(&rows)).unwrap();
+        return;
+    }
+    for (algorithm, field, t, costs) in &rows {
+        let recorded = recorded_costs(algorithm, field, *t)
+            .unwrap_or_else(|| panic!("{algorithm} {field} T={t} is missing from budget.md"));
+        for (cost, limit) in costs.iter().zip(recorded) {
+            assert!(
+                *cost * 100 <= limit * (100 + REGRESSION_THRESHOLD_PERCENT),
+                "{algorithm} {field} T={t}: measured {costs:?}, recorded {recorded:?} in budget.md"
+            );
+        }
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(feature = "poseidon-bls12-381")]
+use crate::PoseidonSponge;
+#[cfg(feature = "poseidon2-bn254")]
+use crate::{poseidon2_hash, poseidon2_hash_bytes};
+use crate::{poseidon_hash, poseidon_hash_bytes, poseidon_hash_bytes_varlen, poseidon_hash_varlen};
+#[cfg(feature = "poseidon-bls12-381")]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
+#[cfg(feature = "poseidon2-bn254")]
+use soroban_sdk::String;
+use soroban_sdk::{bytesn, crypto::bn254::Bn254Fr, vec, Bytes, Env, U256};
+
+// Byte hashing tests
+//
+// Unless noted, expected values were computed with a Python model of
+// go-iden3-crypto's `HashBytesX` and Aztec's `poseidon2_hash_bytes`.
+
+const LOREM: &[u8] = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum.";
+
+fn u256(env: &Env, bytes: soroban_sdk::BytesN<32>) -> U256 {
+    U256::from_be_bytes(env, &bytes.into())
+}
+
+// `poseidon.HashBytes` hashes 16 chunks per frame, i.e. t=17. The 15 chunks
+// of the message fit in a single, zero-filled frame.
+//
+// This is the message of go-iden3-crypto's `TestHashBytes`, but the expected
+// value comes from the Python model (whose t=17 permutation matches circomlib,
+// see `test_poseidon_bn254_hash_1_to_16`), not from that test.
+#[cfg(feature = "poseidon-wide")]
+#[test]
+fn test_poseidon_hash_bytes_t17() {
+    let env = Env::default();
+
+    let msg = Bytes::from_slice(&env, LOREM);
+    let expected = bytesn!(
+        &env,
+        0x03322b67f2c0e11f3d5fba3915cbffc41952b09199f523e3fdada29da0494a33
+    );
+    assert_eq!(
+        poseidon_hash_bytes::<17, Bn254Fr>(&env, &msg),
+        u256(&env, expected)
+    );
+}
+
+// With t=6 (5 chunks per frame) the 15 chunks take four chained frames
+#[test]
+fn test_poseidon_hash_bytes_chained_frames() {
+    let env = Env::default();
+
+    let msg = Bytes::from_slice(&env, LOREM);
+    let expected = bytesn!(
+        &env,
+        0x18038f2466180c7560c3e9be4a9883c23d075286f15b96303ddae97be9c3e904
+    );
+    assert_eq!(
+        poseidon_hash_bytes::<6, Bn254Fr>(&env, &msg),
+        u256(&env, expected)
+    );
+
+    let expected = bytesn!(
+        &env,
+        0x063a6e2a3319f52874933ab15139d077bd69342d94cc48f06967334d32ce4aba
+    );
+    assert_eq!(
+        poseidon_hash_bytes::<3, Bn254Fr>(&env, &msg),
+        u256(&env, expected)
+    );
+}
+
+#[test]
+fn test_poseidon_hash_bytes_packing() {
+    let env = Env::default();
+
+    // 62 bytes are exactly two chunks, i.e. one full frame with t=3
+    let mut raw = [0u8; 62];
+    for (i, b) in raw.iter_mut().enumerate() {
+        *b = i as u8 + 1;
+    }
+    let expected = bytesn!(
+        &env,
+        0x1fb6a2d124a4fdae5ca47b988de06d26a24f950737b8eb71e58c0956b2a915b7
+    );
+    assert_eq!(
+        poseidon_hash_bytes::<3, Bn254Fr>(&env, &Bytes::from_slice(&env, &raw)),
+        u256(&env, expected)
+    );
+
+    // A short message is a single big-endian chunk, zero-padded at the end
+    // to 31 bytes
+    let chunk = bytesn!(
+        &env,
+        0x0061626300000000000000000000000000000000000000000000000000000000
+    );
+    assert_eq!(
+        poseidon_hash_bytes::<3, Bn254Fr>(&env, &Bytes::from_slice(&env, b"abc")),
+        poseidon_hash::<3, Bn254Fr>(
+            &env,
+            &vec![&env, u256(&env, chunk.clone()), U256::from_u32(&env, 0)]
+        )
+    );
+
+    // BLS12-381 uses the same packing
+    #[cfg(feature = "poseidon-bls12-381")]
+    {
+        let msg = Bytes::from_slice(&env, b"abc");
+        let mut sponge = PoseidonSponge::<3, Bls12381Fr>::new(&env);
+        assert_eq!(
+            sponge.compute_hash_bytes(&msg),
+            poseidon_hash::<3, Bls12381Fr>(
+                &env,
+                &vec![&env, u256(&env, chunk), U256::from_u32(&env, 0)]
+            )
+        );
+    }
+}
+
+#[test]
+fn test_poseidon_hash_bytes_trailing_zeros_collide() {
+    let env = Env::default();
+
+    // Trailing zero bytes are indistinguishable from the padding of the last
+    // chunk, and whole zero chunks from the zero fill of the last frame
+    let mut raw = [0u8; 32];
+    raw[0] = 1;
+    let a = Bytes::from_slice(&env, &raw[..1]);
+    let a_0 = Bytes::from_slice(&env, &raw[..2]);
+    let a_chunk_0 = Bytes::from_slice(&env, &raw);
+    let h = poseidon_hash_bytes::<3, Bn254Fr>(&env, &a);
+    assert_eq!(poseidon_hash_bytes::<3, Bn254Fr>(&env, &a_0), h);
+    assert_eq!(poseidon_hash_bytes::<3, Bn254Fr>(&env, &a_chunk_0), h);
+
+    // The length-committing variant separates all three
+    let h = poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &a);
+    let h_0 = poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &a_0);
+    let h_chunk_0 = poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &a_chunk_0);
+    assert_ne!(h, h_0);
+    assert_ne!(h, h_chunk_0);
+    assert_ne!(h_0, h_chunk_0);
+}
+
+#[test]
+fn test_poseidon_hash_bytes_varlen() {
+    let env = Env::default();
+
+    let msg = Bytes::from_slice(&env, LOREM);
+    let expected = bytesn!(
+        &env,
+        0x14c7a6d26b9a98774f07ae3a8ce486dfec0ada9913abd81fc80a39e6893f1e8e
+    );
+    assert_eq!(
+        poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &msg),
+        u256(&env, expected)
+    );
+
+    // The chunks are followed by the byte length
+    let chunk = bytesn!(
+        &env,
+        0x0061626300000000000000000000000000000000000000000000000000000000
+    );
+    assert_eq!(
+        poseidon_hash_bytes_varlen::<3, Bn254Fr>(&env, &Bytes::from_slice(&env, b"abc")),
+        poseidon_hash_varlen::<3, Bn254Fr>(
+            &env,
+            &vec![&env, u256(&env, chunk), U256::from_u32(&env, 3)]
+        )
+    );
+
+    // The empty message is just its length
+    assert_eq!(
+        poseidon_hash_bytes_varlen::<2, Bn254Fr>(&env, &Bytes::new(&env)),
+        poseidon_hash_varlen::<2, Bn254Fr>(&env, &vec![&env, U256::from_u32(&env, 0)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: inputs must not be empty")]
+fn test_poseidon_hash_bytes_empty() {
+    let env = Env::default();
+    poseidon_hash_bytes::<3, Bn254Fr>(&env, &Bytes::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: byte hashing requires at least 2 inputs per hash (T >= 3)")]
+fn test_poseidon_hash_bytes_rate_1() {
+    let env = Env::default();
+    poseidon_hash_bytes::<2, Bn254Fr>(&env, &Bytes::from_slice(&env, b"abc"));
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_poseidon2_hash_bytes_aztec() {
+    let env = Env::default();
+
+    let msg = Bytes::from_slice(&env, LOREM);
+    let expected = bytesn!(
+        &env,
+        0x1e4be9105c48d352aafd5a63e679d0b9aff42c9604e66a6d26dfe07d5aad5e9f
+    );
+    assert_eq!(
+        poseidon2_hash_bytes::<4, Bn254Fr>(&env, &msg),
+        u256(&env, expected)
+    );
+
+    // The empty message hashes no chunks
+    let expected = bytesn!(
+        &env,
+        0x18dfb8dc9b82229cff974efefc8df78b1ce96d9d844236b496785c698bc6732e
+    );
+    assert_eq!(
+        poseidon2_hash_bytes::<4, Bn254Fr>(&env, &Bytes::new(&env)),
+        u256(&env, expected)
+    );
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_poseidon2_hash_bytes_packing() {
+    let env = Env::default();
+
+    // A short message is a single little-endian chunk
+    let chunk = U256::from_u32(&env, 0x636261);
+    let expected = poseidon2_hash::<4, Bn254Fr>(&env, &vec![&env, chunk]);
+    assert_eq!(
+        poseidon2_hash_bytes::<4, Bn254Fr>(&env, &Bytes::from_slice(&env, b"abc")),
+        expected
+    );
+
+    // Strings are hashed through their bytes
+    let uri = String::from_str(&env, "abc");
+    assert_eq!(
+        poseidon2_hash_bytes::<4, Bn254Fr>(&env, &uri.to_bytes()),
+        expected
+    );
+}
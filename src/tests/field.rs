@@ -0,0 +1,120 @@
+use crate::Field;
+use soroban_sdk::{
+    bytesn,
+    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+    Bytes, Env, U256,
+};
+
+// Field reduction tests
+//
+// Expected values were computed with Python's arbitrary-precision integers.
+
+fn bytes_1_to_64(env: &Env) -> Bytes {
+    let mut bytes = [0u8; 64];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8 + 1;
+    }
+    Bytes::from_slice(env, &bytes)
+}
+
+fn reduce_near_modulus<F: Field>(env: &Env) {
+    let one = U256::from_u32(env, 1);
+    let modulus = F::modulus(env);
+    let below = modulus.sub(&one);
+    let above = modulus.add(&one);
+
+    assert_eq!(F::reduce(env, below.clone()), below);
+    assert_eq!(F::reduce(env, modulus.clone()), U256::from_u32(env, 0));
+    assert_eq!(F::reduce(env, above.clone()), one);
+
+    assert!(F::is_canonical(env, &U256::from_u32(env, 0)));
+    assert!(F::is_canonical(env, &below));
+    assert!(!F::is_canonical(env, &modulus));
+    assert!(!F::is_canonical(env, &above));
+}
+
+#[test]
+fn test_reduce_near_modulus_bn254() {
+    reduce_near_modulus::<Bn254Fr>(&Env::default());
+}
+
+#[test]
+fn test_reduce_near_modulus_bls12_381() {
+    reduce_near_modulus::<Bls12381Fr>(&Env::default());
+}
+
+#[test]
+fn test_reduce_max_u256() {
+    let env = Env::default();
+
+    let max = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+        )
+        .into(),
+    );
+    let expected = bytesn!(
+        &env,
+        0x0e0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffa
+    );
+    assert_eq!(
+        Bn254Fr::reduce(&env, max),
+        U256::from_be_bytes(&env, &expected.into())
+    );
+}
+
+#[test]
+fn test_from_be_bytes_mod_order_short() {
+    let env = Env::default();
+
+    assert_eq!(
+        Bn254Fr::from_be_bytes_mod_order(&env, &Bytes::new(&env)),
+        U256::from_u32(&env, 0)
+    );
+    assert_eq!(
+        Bn254Fr::from_be_bytes_mod_order(&env, &Bytes::from_array(&env, &[1, 2])),
+        U256::from_u32(&env, 0x0102)
+    );
+
+    // Exactly the modulus, and the modulus followed by one more byte
+    let modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+    let mut bytes: Bytes = modulus.into();
+    assert_eq!(
+        Bn254Fr::from_be_bytes_mod_order(&env, &bytes),
+        U256::from_u32(&env, 0)
+    );
+    bytes.push_back(5);
+    assert_eq!(
+        Bn254Fr::from_be_bytes_mod_order(&env, &bytes),
+        U256::from_u32(&env, 5)
+    );
+}
+
+#[test]
+fn test_from_be_bytes_mod_order_long() {
+    let env = Env::default();
+
+    // 0x0102...40 (64 bytes) mod r
+    let expected = bytesn!(
+        &env,
+        0x075d2a8d7d5b1f2ba879cfecd98a140e3a76e5ab71564b5061268cff531b44dc
+    );
+    assert_eq!(
+        Bn254Fr::from_be_bytes_mod_order(&env, &bytes_1_to_64(&env)),
+        U256::from_be_bytes(&env, &expected.into())
+    );
+
+    let expected = bytesn!(
+        &env,
+        0x0f1de3007dd74818a002ada9ee5b8a46ead5876813732f0a4c48df5f4f23eb4f
+    );
+    assert_eq!(
+        Bls12381Fr::from_be_bytes_mod_order(&env, &bytes_1_to_64(&env)),
+        U256::from_be_bytes(&env, &expected.into())
+    );
+}
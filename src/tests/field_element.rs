@@ -0,0 +1,188 @@
+#[cfg(feature = "poseidon2-bn254")]
+use crate::{poseidon2_hash, poseidon2_hash_elements, Poseidon2Sponge};
+#[cfg(feature = "poseidon-bn254")]
+use crate::{poseidon_hash, poseidon_hash_elements};
+use crate::{Bls12381FieldElement, Bn254FieldElement, Field, FieldElement, PoseidonError};
+use soroban_sdk::{
+    bytesn,
+    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+    vec, Env, FromVal, IntoVal, TryFromVal, Val, Vec, U256,
+};
+
+// FieldElement tests
+
+#[test]
+fn test_field_element_new_checked_boundary() {
+    let env = Env::default();
+
+    let modulus = Bn254Fr::modulus(&env);
+    let max = modulus.sub(&U256::from_u32(&env, 1));
+    assert_eq!(
+        FieldElement::<Bn254Fr>::new_checked(&env, max.clone()).map(|e| e.to_u256()),
+        Ok(max)
+    );
+    assert_eq!(
+        FieldElement::<Bn254Fr>::new_checked(&env, modulus),
+        Err(PoseidonError::InputNotInField)
+    );
+
+    // The BN254 modulus is a valid BLS12-381 element, but not the reverse
+    assert!(FieldElement::<Bls12381Fr>::new_checked(&env, Bn254Fr::modulus(&env)).is_ok());
+    assert!(FieldElement::<Bn254Fr>::new_checked(&env, Bls12381Fr::modulus(&env)).is_err());
+}
+
+#[test]
+fn test_field_element_from_be_bytes_reduced() {
+    let env = Env::default();
+
+    // The modulus itself reduces to 0
+    let modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+    assert_eq!(
+        FieldElement::<Bn254Fr>::from_be_bytes_reduced(&env, &modulus),
+        FieldElement::from_u32(&env, 0)
+    );
+
+    // 2^256 - 1 mod r
+    let max = bytesn!(
+        &env,
+        0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+    );
+    let expected = bytesn!(
+        &env,
+        0x0e0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffa
+    );
+    assert_eq!(
+        FieldElement::<Bn254Fr>::from_be_bytes_reduced(&env, &max).to_u256(),
+        U256::from_be_bytes(&env, &expected.into())
+    );
+}
+
+#[test]
+fn test_field_element_val_round_trip() {
+    let env = Env::default();
+
+    let element = FieldElement::<Bn254Fr>::from_u32(&env, 7);
+    let val: Val = element.clone().into_val(&env);
+    assert_eq!(U256::try_from_val(&env, &val), Ok(U256::from_u32(&env, 7)));
+    assert_eq!(
+        FieldElement::<Bn254Fr>::try_from_val(&env, &val),
+        Ok(element)
+    );
+
+    // Decoding rejects values outside the field
+    let val: Val = Bn254Fr::modulus(&env).into_val(&env);
+    assert!(FieldElement::<Bn254Fr>::try_from_val(&env, &val).is_err());
+}
+
+// The contract-interface wrappers decode any `U256`, so the conversion into
+// `FieldElement` is where the modulus is checked
+#[test]
+fn test_contract_field_element_conversions() {
+    let env = Env::default();
+
+    let element = FieldElement::<Bn254Fr>::from_u32(&env, 7);
+    let wrapped = Bn254FieldElement::from(element.clone());
+    assert_eq!(wrapped.to_u256(), U256::from_u32(&env, 7));
+    let val: Val = wrapped.into_val(&env);
+    let decoded = Bn254FieldElement::try_from_val(&env, &val).unwrap();
+    assert_eq!(FieldElement::try_from(decoded), Ok(element));
+
+    let element = FieldElement::<Bls12381Fr>::from_u32(&env, 7);
+    let wrapped = Bls12381FieldElement::from(element.clone());
+    assert_eq!(FieldElement::try_from(wrapped), Ok(element));
+
+    // Values outside the field decode, but do not convert
+    let val: Val = vec![&env, Bn254Fr::modulus(&env)].into_val(&env);
+    let decoded = Bn254FieldElement::try_from_val(&env, &val).unwrap();
+    assert_eq!(
+        FieldElement::<Bn254Fr>::try_from(decoded),
+        Err(PoseidonError::InputNotInField)
+    );
+    let val: Val = vec![&env, Bls12381Fr::modulus(&env)].into_val(&env);
+    let decoded = Bls12381FieldElement::try_from_val(&env, &val).unwrap();
+    assert_eq!(
+        FieldElement::<Bls12381Fr>::try_from(decoded),
+        Err(PoseidonError::InputNotInField)
+    );
+}
+
+#[cfg(feature = "poseidon-bn254")]
+#[test]
+fn test_poseidon_hash_elements_matches_poseidon_hash() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let elements = [
+        FieldElement::<Bn254Fr>::from_u32(&env, 1),
+        FieldElement::from_u32(&env, 2),
+    ];
+    assert_eq!(
+        poseidon_hash_elements::<3, Bn254Fr>(&env, &elements).to_u256(),
+        poseidon_hash::<3, Bn254Fr>(&env, &inputs)
+    );
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_poseidon2_hash_elements_matches_poseidon2_hash() {
+    let env = Env::default();
+
+    let mut inputs = Vec::new(&env);
+    for i in 1..=5 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+    let elements = [1, 2, 3, 4, 5].map(|i| FieldElement::<Bn254Fr>::from_u32(&env, i));
+    assert_eq!(
+        poseidon2_hash_elements::<4, Bn254Fr>(&env, &elements).to_u256(),
+        poseidon2_hash::<4, Bn254Fr>(&env, &inputs)
+    );
+
+    // Outputs chain into the next hash without a check
+    let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::new(&env);
+    let left = sponge.compute_hash_elements(&elements);
+    let root = sponge.compute_hash_elements(&[left.clone(), left.clone()]);
+    assert_eq!(
+        root.to_u256(),
+        poseidon2_hash::<3, Bn254Fr>(&env, &vec![&env, left.to_u256(), left.into()])
+    );
+}
+
+// A `Vec<FieldElement>` is a plain vector of `U256` on the host, so one built
+// from (or read back as) arbitrary values cannot reach the hashing APIs
+// without each element being checked again
+#[test]
+fn test_field_element_vec_rechecks_elements() {
+    let env = Env::default();
+
+    let raw = vec![&env, U256::from_u32(&env, 1), Bn254Fr::modulus(&env)];
+    let elements: Vec<FieldElement<Bn254Fr>> = Vec::from_val(&env, raw.as_val());
+    assert_eq!(elements.len(), 2);
+    assert!(elements.try_get(0).is_ok());
+    assert!(elements.try_get(1).is_err());
+
+    assert!(elements.try_iter().any(|element| element.is_err()));
+}
+
+#[cfg(feature = "poseidon-bn254")]
+#[test]
+#[should_panic]
+fn test_poseidon_hash_elements_rejects_non_canonical_vec() {
+    let env = Env::default();
+
+    let raw = vec![&env, U256::from_u32(&env, 1), Bn254Fr::modulus(&env)];
+    let elements: Vec<FieldElement<Bn254Fr>> = Vec::from_val(&env, raw.as_val());
+    let slice = [elements.get_unchecked(0), elements.get_unchecked(1)];
+    poseidon_hash_elements::<3, Bn254Fr>(&env, &slice);
+}
+
+#[cfg(feature = "poseidon-bn254")]
+#[test]
+#[should_panic(expected = "Poseidon: inputs.len() must equal rate (T - 1)")]
+fn test_poseidon_hash_elements_wrong_arity() {
+    let env = Env::default();
+
+    poseidon_hash_elements::<3, Bn254Fr>(&env, &[FieldElement::from_u32(&env, 1)]);
+}
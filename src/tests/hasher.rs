@@ -0,0 +1,103 @@
+use crate::{poseidon2_hash, poseidon_hash, FieldHasher, Poseidon2Sponge, PoseidonSponge};
+use soroban_sdk::{
+    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+    vec, Env, Vec, U256,
+};
+
+// FieldHasher tests
+//
+// The trait only forwards to the sponges, so the expected values are the
+// free hash functions' outputs.
+
+fn inputs(env: &Env, len: u32) -> Vec<U256> {
+    let mut inputs = Vec::new(env);
+    for i in 0..len {
+        inputs.push_back(U256::from_u32(env, i + 1));
+    }
+    inputs
+}
+
+// Hashes a full-rate message twice with one hasher, as generic gadgets do
+fn hash_twice<H: FieldHasher>(env: &Env) -> (U256, U256) {
+    let mut hasher = H::new(env);
+    let first = hasher.hash(&inputs(env, H::RATE));
+    let second = hasher.hash(&inputs(env, H::RATE));
+    (first, second)
+}
+
+// A Merkle root of four leaves, generic over the hash function
+fn merkle_root_4<H: FieldHasher>(env: &Env, leaves: &Vec<U256>) -> U256 {
+    let mut hasher = H::new(env);
+    let left = hasher.hash(&leaves.slice(0..2));
+    let right = hasher.hash(&leaves.slice(2..4));
+    hasher.hash(&vec![env, left, right])
+}
+
+#[test]
+fn test_field_hasher_rate() {
+    assert_eq!(<PoseidonSponge<2, Bn254Fr> as FieldHasher>::RATE, 1);
+    assert_eq!(<PoseidonSponge<3, Bn254Fr> as FieldHasher>::RATE, 2);
+    assert_eq!(<PoseidonSponge<6, Bls12381Fr> as FieldHasher>::RATE, 5);
+    assert_eq!(<Poseidon2Sponge<3, Bn254Fr> as FieldHasher>::RATE, 2);
+    assert_eq!(<Poseidon2Sponge<4, Bls12381Fr> as FieldHasher>::RATE, 3);
+}
+
+#[test]
+fn test_field_hasher_matches_poseidon_hash() {
+    let env = Env::default();
+    let (first, second) = hash_twice::<PoseidonSponge<3, Bn254Fr>>(&env);
+    let expected = poseidon_hash::<3, Bn254Fr>(&env, &inputs(&env, 2));
+    assert_eq!(first, expected);
+    assert_eq!(second, expected);
+
+    let (first, _) = hash_twice::<PoseidonSponge<5, Bls12381Fr>>(&env);
+    assert_eq!(
+        first,
+        poseidon_hash::<5, Bls12381Fr>(&env, &inputs(&env, 4))
+    );
+}
+
+#[test]
+fn test_field_hasher_matches_poseidon2_hash() {
+    let env = Env::default();
+    let (first, second) = hash_twice::<Poseidon2Sponge<4, Bn254Fr>>(&env);
+    let expected = poseidon2_hash::<4, Bn254Fr>(&env, &inputs(&env, 3));
+    assert_eq!(first, expected);
+    assert_eq!(second, expected);
+
+    let (first, _) = hash_twice::<Poseidon2Sponge<3, Bls12381Fr>>(&env);
+    assert_eq!(
+        first,
+        poseidon2_hash::<3, Bls12381Fr>(&env, &inputs(&env, 2))
+    );
+
+    // Poseidon2 also accepts messages shorter or longer than the rate
+    let mut hasher = <Poseidon2Sponge<4, Bn254Fr> as FieldHasher>::new(&env);
+    for len in [1, 5] {
+        assert_eq!(
+            hasher.hash(&inputs(&env, len)),
+            poseidon2_hash::<4, Bn254Fr>(&env, &inputs(&env, len))
+        );
+    }
+}
+
+#[test]
+fn test_field_hasher_generic_gadget() {
+    let env = Env::default();
+    let leaves = inputs(&env, 4);
+    let circom = merkle_root_4::<PoseidonSponge<3, Bn254Fr>>(&env, &leaves);
+    let noir = merkle_root_4::<Poseidon2Sponge<3, Bn254Fr>>(&env, &leaves);
+
+    let h = |l: U256, r: U256| poseidon_hash::<3, Bn254Fr>(&env, &vec![&env, l, r]);
+    let n = |i: u32| leaves.get_unchecked(i);
+    assert_eq!(circom, h(h(n(0), n(1)), h(n(2), n(3))));
+    assert_ne!(circom, noir);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: inputs.len() must equal rate (T - 1)")]
+fn test_field_hasher_poseidon_wrong_arity_panics() {
+    let env = Env::default();
+    let mut hasher = <PoseidonSponge<4, Bn254Fr> as FieldHasher>::new(&env);
+    hasher.hash(&inputs(&env, 2));
+}
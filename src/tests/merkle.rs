@@ -0,0 +1,1568 @@
+#[cfg(feature = "poseidon2-bn254")]
+use crate::{poseidon2_hash, verify_merkle_proof_poseidon2, IndexedLeaf, IndexedMerkleTree};
+use crate::{
+    poseidon_hash, verify_merkle_proof, verify_sparse_merkle_exclusion,
+    verify_sparse_merkle_inclusion, Field, IncrementalMerkleTree, LeanIncrementalMerkleTree,
+};
+use soroban_sdk::{
+    bytesn, contract, crypto::bn254::Bn254Fr, symbol_short, vec, Address, BytesN, Env, Symbol, Vec,
+    U256,
+};
+
+// Incremental Merkle tree and inclusion proof tests
+//
+// Expected roots are computed with a Python model of the tree on top of the
+// reference Poseidon and Poseidon2 permutations (empty leaves are 0,
+// node = H([l, r])).
+
+#[contract]
+struct MerkleTestContract;
+
+const TREE: Symbol = symbol_short!("TREE");
+
+fn setup() -> (Env, Address) {
+    let env = Env::default();
+    // The tests insert many leaves in a single invocation
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_id = env.register(MerkleTestContract, ());
+    (env, contract_id)
+}
+
+// Computes the root of a tree of the given depth holding `leaves` by hashing
+// every node.
+fn naive_root(env: &Env, leaves: &Vec<U256>, depth: u32) -> U256 {
+    let mut level = Vec::new(env);
+    for i in 0..1u32 << depth {
+        level.push_back(leaves.get(i).unwrap_or(U256::from_u32(env, 0)));
+    }
+    for _ in 0..depth {
+        let mut next = Vec::new(env);
+        for i in 0..level.len() / 2 {
+            let pair = vec![
+                env,
+                level.get_unchecked(2 * i),
+                level.get_unchecked(2 * i + 1),
+            ];
+            next.push_back(poseidon_hash::<3, Bn254Fr>(env, &pair));
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+// ============================================================================
+// Known-answer tests
+// ============================================================================
+
+#[test]
+fn test_merkle_zeros() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let tree = IncrementalMerkleTree::init(&env, &TREE, 32);
+        assert_eq!(tree.depth(), 32);
+        assert_eq!(tree.next_index(), 0);
+        assert_eq!(tree.zero(0), U256::from_u32(&env, 0));
+
+        // Poseidon([0, 0])
+        let zero_1 = U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2098f5fb9e239eab3ceac3f27b81e481dc3124d55ffed523a839ee8446b64864
+            )
+            .into(),
+        );
+        assert_eq!(tree.zero(1), zero_1);
+
+        // The empty tree's root is the empty subtree of height 32
+        let zero_32 = U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2f68a1c58e257e42a17a6c61dff5551ed560b9922ab119d5ac8e184c9734ead9
+            )
+            .into(),
+        );
+        assert_eq!(tree.zero(32), zero_32);
+        assert_eq!(tree.root(), zero_32);
+        assert!(tree.is_known_root(&zero_32));
+    });
+}
+
+#[test]
+fn test_merkle_insert_depth_3() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 3);
+        let expected = [
+            bytesn!(
+                &env,
+                0x19e3b716d4c5fea391da6f19dae08951fdfb8c1f7384684aa1a87acd8e1b12b1
+            ),
+            bytesn!(
+                &env,
+                0x2267bee7aae8ed55eb9aecff101145335ed1dd0a5a276a2b7eb3ae7d20e232d8
+            ),
+            bytesn!(
+                &env,
+                0x05c1e52b41a571293b30efacd2afdb7173b20cfaf1f646c4ac9f96eb75848270
+            ),
+        ];
+        for (i, root) in expected.iter().enumerate() {
+            let index = tree.insert(U256::from_u32(&env, i as u32 + 1));
+            assert_eq!(index, i as u64);
+            assert_eq!(tree.root(), U256::from_be_bytes(&env, &root.into()));
+        }
+        assert_eq!(tree.next_index(), 3);
+    });
+}
+
+#[test]
+fn test_merkle_matches_naive_root() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 4);
+        let mut leaves = vec![&env];
+        for i in 0..16u32 {
+            let leaf = U256::from_u32(&env, 1000 + i * i);
+            tree.insert(leaf.clone());
+            leaves.push_back(leaf);
+            assert_eq!(tree.root(), naive_root(&env, &leaves, 4));
+        }
+    });
+}
+
+// ============================================================================
+// Inclusion proofs
+// ============================================================================
+
+fn proof_siblings(env: &Env) -> Vec<U256> {
+    vec![
+        env,
+        U256::from_u32(env, 11),
+        U256::from_u32(env, 22),
+        U256::from_u32(env, 33),
+        U256::from_u32(env, 44),
+    ]
+}
+
+#[test]
+fn test_verify_merkle_proof_kat() {
+    let env = Env::default();
+    let leaf = U256::from_u32(&env, 7);
+    let siblings = proof_siblings(&env);
+    let expected = [
+        (
+            0,
+            bytesn!(
+                &env,
+                0x0d4163d9455a6a67fc6c3d26c492a64510028f75ef09ad95e381504c36004f84
+            ),
+        ),
+        (
+            5,
+            bytesn!(
+                &env,
+                0x2b67e3461a663a3fa7137be996c633d5fa778f8699cfea6f3cb2ffd920a50a1e
+            ),
+        ),
+        (
+            15,
+            bytesn!(
+                &env,
+                0x0182c134d5cc54e60d4edb170a5d0fbb627f384de358e9cf94a42c9263c82a93
+            ),
+        ),
+    ];
+    for (index, root) in expected {
+        assert_eq!(
+            verify_merkle_proof(&env, &leaf, &siblings, index),
+            U256::from_be_bytes(&env, &root.into()),
+            "index {index}"
+        );
+    }
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_verify_merkle_proof_poseidon2_kat() {
+    let env = Env::default();
+    let leaf = U256::from_u32(&env, 7);
+    let siblings = proof_siblings(&env);
+    let expected = [
+        (
+            0,
+            bytesn!(
+                &env,
+                0x2561a1c3b96d4ca77200f15d82d873278b08ea1a5f41a5998b7aca13de56b8cd
+            ),
+        ),
+        (
+            5,
+            bytesn!(
+                &env,
+                0x20a82ef75c54706eb1a870d56be4aa775fdfbb91e072cf29b022eaf08be006e1
+            ),
+        ),
+        (
+            15,
+            bytesn!(
+                &env,
+                0x1bee734e6b9207500c4556c57ded6ba1805716156e6ebaa352e27bff62e6fb5b
+            ),
+        ),
+    ];
+    for (index, root) in expected {
+        assert_eq!(
+            verify_merkle_proof_poseidon2(&env, &leaf, &siblings, index),
+            U256::from_be_bytes(&env, &root.into()),
+            "index {index}"
+        );
+    }
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_verify_merkle_proof_single_level() {
+    let env = Env::default();
+    let (leaf, sibling) = (U256::from_u32(&env, 1), U256::from_u32(&env, 2));
+    let siblings = vec![&env, sibling.clone()];
+    let left = vec![&env, leaf.clone(), sibling.clone()];
+    let right = vec![&env, sibling, leaf.clone()];
+
+    assert_eq!(
+        verify_merkle_proof(&env, &leaf, &siblings, 0),
+        poseidon_hash::<3, Bn254Fr>(&env, &left)
+    );
+    assert_eq!(
+        verify_merkle_proof(&env, &leaf, &siblings, 1),
+        poseidon_hash::<3, Bn254Fr>(&env, &right)
+    );
+    assert_eq!(
+        verify_merkle_proof_poseidon2(&env, &leaf, &siblings, 0),
+        poseidon2_hash::<3, Bn254Fr>(&env, &left)
+    );
+    assert_eq!(
+        verify_merkle_proof_poseidon2(&env, &leaf, &siblings, 1),
+        poseidon2_hash::<3, Bn254Fr>(&env, &right)
+    );
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_verify_merkle_proof_empty_path() {
+    let env = Env::default();
+    let leaf = U256::from_u32(&env, 7);
+    assert_eq!(verify_merkle_proof(&env, &leaf, &vec![&env], 0), leaf);
+    assert_eq!(
+        verify_merkle_proof_poseidon2(&env, &leaf, &vec![&env], 0),
+        leaf
+    );
+}
+
+#[test]
+fn test_verify_merkle_proof_against_incremental_tree() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let depth = 4;
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, depth);
+        let mut leaves = vec![&env];
+        for i in 0..6u32 {
+            let leaf = U256::from_u32(&env, 100 + i);
+            tree.insert(leaf.clone());
+            leaves.push_back(leaf);
+        }
+
+        // Build every level of the tree to collect each leaf's siblings
+        let mut levels = Vec::<Vec<U256>>::new(&env);
+        let mut level = Vec::new(&env);
+        for i in 0..1u32 << depth {
+            level.push_back(leaves.get(i).unwrap_or(tree.zero(0)));
+        }
+        for _ in 0..depth {
+            levels.push_back(level.clone());
+            let mut next = Vec::new(&env);
+            for i in 0..level.len() / 2 {
+                let pair = vec![
+                    &env,
+                    level.get_unchecked(2 * i),
+                    level.get_unchecked(2 * i + 1),
+                ];
+                next.push_back(poseidon_hash::<3, Bn254Fr>(&env, &pair));
+            }
+            level = next;
+        }
+
+        let siblings_of = |index: u32| {
+            let mut siblings = Vec::new(&env);
+            for (height, nodes) in levels.iter().enumerate() {
+                siblings.push_back(nodes.get_unchecked((index >> height) ^ 1));
+            }
+            siblings
+        };
+        for (index, leaf) in leaves.iter().enumerate() {
+            let siblings = siblings_of(index as u32);
+            let root = verify_merkle_proof(&env, &leaf, &siblings, index as u64);
+            assert_eq!(root, tree.root());
+        }
+
+        // The right siblings with the wrong position do not reach the root
+        let root = verify_merkle_proof(&env, &leaves.get_unchecked(2), &siblings_of(2), 3);
+        assert_ne!(root, tree.root());
+        assert!(!tree.is_known_root(&root));
+    });
+}
+
+// ============================================================================
+// Sparse Merkle tree proofs
+// ============================================================================
+//
+// The tree holds (1, 2), (33, 44) and (1234, 9876), the keys of
+// go-merkletree's `TestNewTree` (whose roots the Python model reproduces).
+// Key 1234 is the root's left child; keys 1 and 33 share their first five
+// path bits and split at depth 6.
+
+fn u256(env: &Env, bytes: BytesN<32>) -> U256 {
+    U256::from_be_bytes(env, &bytes.into())
+}
+
+fn smt_root(env: &Env) -> U256 {
+    u256(
+        env,
+        bytesn!(
+            env,
+            0x1f67757a73a24f89b6adaa36d780ce723f8c26e24d283a4a39bdf4a444b440a4
+        ),
+    )
+}
+
+// Leaf (1234, 9876)
+fn smt_left(env: &Env) -> U256 {
+    u256(
+        env,
+        bytesn!(
+            env,
+            0x1ed77f9703152f74597f6f385ee37877902ae1de20f56a58bebae1a6e4c5a258
+        ),
+    )
+}
+
+// Subtree holding keys 1 and 33
+fn smt_right(env: &Env) -> U256 {
+    u256(
+        env,
+        bytesn!(
+            env,
+            0x105742c55b7763d8a952c6fecd1da87922edb6f9f5c5fe47cd349925bd5590f4
+        ),
+    )
+}
+
+// Siblings of keys 1 and 65, whose path ends at leaf (1, 2)
+fn smt_siblings_of_1(env: &Env) -> Vec<U256> {
+    let zero = U256::from_u32(env, 0);
+    vec![
+        env,
+        smt_left(env),
+        zero.clone(),
+        zero.clone(),
+        zero.clone(),
+        zero,
+        // Leaf (33, 44)
+        u256(
+            env,
+            bytesn!(
+                env,
+                0x29b79f6ba2b5f1735503be3b9acfeabafaad07beda4a2ff48a6d5a4dacf84afe
+            ),
+        ),
+    ]
+}
+
+fn n(env: &Env, value: u32) -> U256 {
+    U256::from_u32(env, value)
+}
+
+#[test]
+fn test_sparse_merkle_single_leaf() {
+    let env = Env::default();
+    // go-merkletree `TestNewTree`: the root after adding (1, 2), i.e.
+    // Poseidon([1, 2, 1])
+    let root = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x1e05682c815341647510bf582454cca025584699f2419cbdea3205afb3506e5b
+        ),
+    );
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &n(&env, 2),
+        &vec![&env]
+    ));
+    assert!(!verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &n(&env, 3),
+        &vec![&env]
+    ));
+    // Any other key ends at the only leaf
+    assert!(verify_sparse_merkle_exclusion(
+        &env,
+        &root,
+        &n(&env, 2),
+        &vec![&env],
+        Some((n(&env, 1), n(&env, 2)))
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_empty_tree() {
+    let env = Env::default();
+    let root = n(&env, 0);
+    assert!(verify_sparse_merkle_exclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &vec![&env],
+        None
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_two_leaves() {
+    let env = Env::default();
+    // go-merkletree `TestNewTree`: the root after adding (1, 2) and (33, 44)
+    let root = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x0bf74e6725aec1f75572ad42659f047eaabd72f9a139980b4b33d3b9b9265c35
+        ),
+    );
+    // Both keys go right at the root, whose left subtree is empty
+    let mut siblings = smt_siblings_of_1(&env);
+    siblings.set(0, n(&env, 0));
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &n(&env, 2),
+        &siblings
+    ));
+
+    // Poseidon([1, 2, 1])
+    let leaf_1 = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x1e05682c815341647510bf582454cca025584699f2419cbdea3205afb3506e5b
+        ),
+    );
+    siblings.set(5, leaf_1);
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 33),
+        &n(&env, 44),
+        &siblings
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_inclusion() {
+    let env = Env::default();
+    let root = smt_root(&env);
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &n(&env, 2),
+        &smt_siblings_of_1(&env)
+    ));
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1234),
+        &n(&env, 9876),
+        &vec![&env, smt_right(&env)]
+    ));
+
+    // Wrong value, wrong key or wrong root
+    assert!(!verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1234),
+        &n(&env, 9877),
+        &vec![&env, smt_right(&env)]
+    ));
+    assert!(!verify_sparse_merkle_inclusion(
+        &env,
+        &root,
+        &n(&env, 1),
+        &n(&env, 9876),
+        &vec![&env, smt_right(&env)]
+    ));
+    assert!(!verify_sparse_merkle_inclusion(
+        &env,
+        &smt_right(&env),
+        &n(&env, 1234),
+        &n(&env, 9876),
+        &vec![&env, smt_right(&env)]
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_inclusion_padded_siblings() {
+    let env = Env::default();
+    // circomlib's `SMTVerifier` pads the siblings with zeros to `nLevels`
+    let mut siblings = smt_siblings_of_1(&env);
+    for _ in 0..10 {
+        siblings.push_back(n(&env, 0));
+    }
+    assert!(verify_sparse_merkle_inclusion(
+        &env,
+        &smt_root(&env),
+        &n(&env, 1),
+        &n(&env, 2),
+        &siblings
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_exclusion_aux_leaf() {
+    let env = Env::default();
+    let root = smt_root(&env);
+    // Key 2 goes left at the root, where leaf 1234 is
+    assert!(verify_sparse_merkle_exclusion(
+        &env,
+        &root,
+        &n(&env, 2),
+        &vec![&env, smt_right(&env)],
+        Some((n(&env, 1234), n(&env, 9876)))
+    ));
+    // Key 65 shares its first six path bits with key 1
+    assert!(verify_sparse_merkle_exclusion(
+        &env,
+        &root,
+        &n(&env, 65),
+        &smt_siblings_of_1(&env),
+        Some((n(&env, 1), n(&env, 2)))
+    ));
+    // Wrong auxiliary value
+    assert!(!verify_sparse_merkle_exclusion(
+        &env,
+        &root,
+        &n(&env, 2),
+        &vec![&env, smt_right(&env)],
+        Some((n(&env, 1234), n(&env, 9875)))
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_exclusion_empty_node() {
+    let env = Env::default();
+    // Key 5 (0b101) ends at the empty left child of the node at depth 2
+    let siblings = vec![
+        &env,
+        smt_left(&env),
+        n(&env, 0),
+        u256(
+            &env,
+            bytesn!(
+                &env,
+                0x0b8d2c64134e59b96dd8c7e2ea7b987f804517a6742d58fb799aba0abc138aca
+            ),
+        ),
+    ];
+    assert!(verify_sparse_merkle_exclusion(
+        &env,
+        &smt_root(&env),
+        &n(&env, 5),
+        &siblings,
+        None
+    ));
+    // Claiming a leaf there instead fails
+    assert!(!verify_sparse_merkle_exclusion(
+        &env,
+        &smt_root(&env),
+        &n(&env, 5),
+        &siblings,
+        Some((n(&env, 1), n(&env, 2)))
+    ));
+}
+
+#[test]
+fn test_sparse_merkle_exclusion_of_present_key() {
+    let env = Env::default();
+    // An inclusion proof cannot be passed off as an exclusion proof
+    assert!(!verify_sparse_merkle_exclusion(
+        &env,
+        &smt_root(&env),
+        &n(&env, 1),
+        &smt_siblings_of_1(&env),
+        Some((n(&env, 1), n(&env, 2)))
+    ));
+}
+
+// ============================================================================
+// LeanIMT
+// ============================================================================
+//
+// Expected roots are computed with a Python port of `@zk-kit/lean-imt`.
+
+// Computes the LeanIMT root of `leaves` by hashing every node, carrying
+// nodes without a right sibling up unhashed.
+fn naive_lean_root(env: &Env, leaves: &Vec<U256>) -> U256 {
+    if leaves.is_empty() {
+        return U256::from_u32(env, 0);
+    }
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        let mut next = Vec::new(env);
+        for i in (0..level.len()).step_by(2) {
+            next.push_back(match level.get(i + 1) {
+                Some(right) => {
+                    poseidon_hash::<3, Bn254Fr>(env, &vec![env, level.get_unchecked(i), right])
+                }
+                None => level.get_unchecked(i),
+            });
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+fn lean_leaves(env: &Env, count: u32) -> Vec<U256> {
+    let mut leaves = Vec::new(env);
+    for i in 0..count {
+        leaves.push_back(U256::from_u32(env, 1000 + i * i));
+    }
+    leaves
+}
+
+#[test]
+fn test_lean_imt_insert_kat() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        assert_eq!(tree.size(), 0);
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.root(), n(&env, 0));
+
+        // A single leaf is its own root
+        assert_eq!(tree.insert(n(&env, 1)), 0);
+        assert_eq!(tree.root(), n(&env, 1));
+
+        let expected = [
+            (
+                1,
+                bytesn!(
+                    &env,
+                    0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+                ),
+            ),
+            (
+                2,
+                bytesn!(
+                    &env,
+                    0x1e8c05563aa22ff357008db7a754ea0404695de07b950ce845b872a8bcff2ca9
+                ),
+            ),
+            (
+                2,
+                bytesn!(
+                    &env,
+                    0x075d30e28d48842bd6c1044b68f982d586e2892ae91c77f8f56111d8f55070ed
+                ),
+            ),
+            (
+                3,
+                bytesn!(
+                    &env,
+                    0x1973be9a0ac928df30c68c1698876c310c8246a3f215d33764045ec9da859b08
+                ),
+            ),
+        ];
+        for (i, (depth, root)) in expected.into_iter().enumerate() {
+            let index = tree.insert(n(&env, i as u32 + 2));
+            assert_eq!(index, i as u64 + 1);
+            assert_eq!(tree.depth(), depth);
+            assert_eq!(tree.root(), u256(&env, root));
+        }
+        assert_eq!(tree.size(), 5);
+    });
+}
+
+#[test]
+fn test_lean_imt_matches_naive_root() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        let leaves = lean_leaves(&env, 17);
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.insert(leaf);
+            assert_eq!(
+                tree.root(),
+                naive_lean_root(&env, &leaves.slice(0..=i as u32))
+            );
+        }
+        assert_eq!(tree.depth(), 5);
+    });
+}
+
+#[test]
+fn test_lean_imt_insert_many() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let leaves = lean_leaves(&env, 9);
+        let root = naive_lean_root(&env, &leaves);
+        let mut all = leaves.clone();
+        all.push_back(n(&env, 7));
+        let next_root = naive_lean_root(&env, &all);
+
+        // Every split into an initial sequence and a batch
+        for split in 0..=leaves.len() {
+            let key = (symbol_short!("SPLIT"), split);
+            let mut tree = LeanIncrementalMerkleTree::init(&env, &key);
+            for leaf in leaves.slice(0..split).iter() {
+                tree.insert(leaf);
+            }
+            let first = tree.insert_many(&leaves.slice(split..));
+            assert_eq!(first, split as u64);
+            assert_eq!(tree.size(), leaves.len() as u64);
+            assert_eq!(tree.root(), root, "split {split}");
+
+            // The side nodes are left as by single insertions
+            tree.insert(n(&env, 7));
+            assert_eq!(tree.root(), next_root, "split {split}");
+        }
+    });
+}
+
+#[test]
+fn test_lean_imt_insert_many_in_batches() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let leaves = lean_leaves(&env, 20);
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        assert_eq!(tree.insert_many(&vec![&env]), 0);
+        let mut start = 0;
+        for len in [1, 2, 3, 5, 1, 8] {
+            assert_eq!(
+                tree.insert_many(&leaves.slice(start..start + len)),
+                start as u64
+            );
+            start += len;
+            assert_eq!(tree.root(), naive_lean_root(&env, &leaves.slice(0..start)));
+        }
+    });
+}
+
+#[test]
+fn test_lean_imt_update_kat() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert_many(&vec![
+            &env,
+            n(&env, 1),
+            n(&env, 2),
+            n(&env, 3),
+            n(&env, 4),
+            n(&env, 5),
+        ]);
+
+        // Remove leaf 2 (value 3), as Semaphore does
+        let siblings = vec![
+            &env,
+            n(&env, 4),
+            u256(
+                &env,
+                bytesn!(
+                    &env,
+                    0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+                ),
+            ),
+            n(&env, 5),
+        ];
+        tree.update(2, n(&env, 3), n(&env, 0), &siblings);
+        assert_eq!(
+            tree.root(),
+            u256(
+                &env,
+                bytesn!(
+                    &env,
+                    0x201afc50112086eff418621028fbe049ff3bbc8620c150f4267eb23a7017b1c3
+                )
+            )
+        );
+
+        // Update the last leaf, which has no sibling below the root
+        let left = u256(
+            &env,
+            bytesn!(
+                &env,
+                0x21787464de5bb41e3eb0dd3f74ec79395c507b533eeb76c007f4070b009aaf63
+            ),
+        );
+        tree.update(4, n(&env, 5), n(&env, 42), &vec![&env, left.clone()]);
+        assert_eq!(
+            tree.root(),
+            u256(
+                &env,
+                bytesn!(
+                    &env,
+                    0x25e7a1fb13099a3b4e22df7d3e03694924fc1ee754cfcf54716ce49c911402f7
+                )
+            )
+        );
+
+        // Inserting after the update hashes with the updated side node
+        tree.insert(n(&env, 6));
+        let root = u256(
+            &env,
+            bytesn!(
+                &env,
+                0x2941c70c980f039181996d3fdc8d8bfeafd6776d67923494264482e3a18a79cf
+            ),
+        );
+        assert_eq!(tree.root(), root);
+        assert!(LeanIncrementalMerkleTree::verify_proof(
+            &env,
+            &root,
+            &n(&env, 6),
+            &vec![&env, n(&env, 42), left],
+            3
+        ));
+    });
+}
+
+#[test]
+fn test_lean_imt_update_matches_naive_root() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut leaves = lean_leaves(&env, 11);
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert_many(&leaves);
+
+        // Collects the siblings of a leaf as `generateProof` does
+        let proof = |leaves: &Vec<U256>, mut index: u32| {
+            let mut siblings = Vec::new(&env);
+            let mut level = leaves.clone();
+            while level.len() > 1 {
+                if let Some(sibling) = level.get(index ^ 1) {
+                    siblings.push_back(sibling);
+                }
+                let mut next = Vec::new(&env);
+                for i in (0..level.len()).step_by(2) {
+                    next.push_back(naive_lean_root(
+                        &env,
+                        &level.slice(i..(i + 2).min(level.len())),
+                    ));
+                }
+                level = next;
+                index /= 2;
+            }
+            siblings
+        };
+
+        for index in [10u32, 0, 7, 8, 10] {
+            let new_leaf = U256::from_u32(&env, 77 + index);
+            let siblings = proof(&leaves, index);
+            tree.update(
+                index as u64,
+                leaves.get_unchecked(index),
+                new_leaf.clone(),
+                &siblings,
+            );
+            leaves.set(index, new_leaf);
+            assert_eq!(tree.root(), naive_lean_root(&env, &leaves), "index {index}");
+        }
+
+        // Later insertions see the updated side nodes
+        let more = lean_leaves(&env, 6);
+        tree.insert_many(&more);
+        leaves.append(&more);
+        assert_eq!(tree.root(), naive_lean_root(&env, &leaves));
+    });
+}
+
+#[test]
+fn test_lean_imt_verify_proof_kat() {
+    let env = Env::default();
+    // The tree holding leaves 1..=5
+    let root = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x1973be9a0ac928df30c68c1698876c310c8246a3f215d33764045ec9da859b08
+        ),
+    );
+    let h_34 = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x20a3af0435914ccd84b806164531b0cd36e37d4efb93efab76913a93e1f30996
+        ),
+    );
+    let siblings = vec![&env, n(&env, 1), h_34, n(&env, 5)];
+    assert!(LeanIncrementalMerkleTree::verify_proof(
+        &env,
+        &root,
+        &n(&env, 2),
+        &siblings,
+        1
+    ));
+    assert!(!LeanIncrementalMerkleTree::verify_proof(
+        &env,
+        &root,
+        &n(&env, 2),
+        &siblings,
+        0
+    ));
+
+    // Leaf 5 only has a sibling at the root
+    let h_1234 = u256(
+        &env,
+        bytesn!(
+            &env,
+            0x075d30e28d48842bd6c1044b68f982d586e2892ae91c77f8f56111d8f55070ed
+        ),
+    );
+    assert!(LeanIncrementalMerkleTree::verify_proof(
+        &env,
+        &root,
+        &n(&env, 5),
+        &vec![&env, h_1234],
+        1
+    ));
+}
+
+#[test]
+fn test_lean_imt_persists_across_loads() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let leaves = lean_leaves(&env, 6);
+        LeanIncrementalMerkleTree::init(&env, &TREE).insert_many(&leaves.slice(0..3));
+        let mut tree = LeanIncrementalMerkleTree::load(&env, &TREE);
+        assert_eq!(tree.size(), 3);
+        assert_eq!(tree.depth(), 2);
+        tree.insert_many(&leaves.slice(3..));
+        assert_eq!(
+            LeanIncrementalMerkleTree::load(&env, &TREE).root(),
+            naive_lean_root(&env, &leaves)
+        );
+    });
+}
+
+// ============================================================================
+// Indexed Merkle tree
+// ============================================================================
+//
+// Expected roots are computed with a Python model of Aztec's nullifier tree
+// (leaf = Poseidon2([value, next_value, next_index]) or 0 if empty, node =
+// Poseidon2([l, r]), T = 4).
+
+#[cfg(feature = "poseidon2-bn254")]
+fn indexed_leaf(env: &Env, value: u32, next_value: u32, next_index: u64) -> IndexedLeaf {
+    IndexedLeaf {
+        value: n(env, value),
+        next_value: n(env, next_value),
+        next_index,
+    }
+}
+
+// Keeps every leaf of an indexed tree to produce witnesses, as an off-chain
+// indexer would.
+#[cfg(feature = "poseidon2-bn254")]
+struct IndexedModel {
+    leaves: Vec<IndexedLeaf>,
+    depth: u32,
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+impl IndexedModel {
+    fn new(env: &Env, depth: u32) -> Self {
+        Self {
+            leaves: vec![env, indexed_leaf(env, 0, 0, 0)],
+            depth,
+        }
+    }
+
+    // Returns the siblings of leaf `index`, leaf level first
+    fn siblings(&self, env: &Env, index: u32) -> Vec<U256> {
+        let mut level = Vec::new(env);
+        for i in 0..1u32 << self.depth {
+            level.push_back(match self.leaves.get(i) {
+                Some(leaf) => leaf.hash(env),
+                None => n(env, 0),
+            });
+        }
+        let mut siblings = Vec::new(env);
+        for height in 0..self.depth {
+            siblings.push_back(level.get_unchecked((index >> height) ^ 1));
+            let mut next = Vec::new(env);
+            for i in 0..level.len() / 2 {
+                let pair = vec![
+                    env,
+                    level.get_unchecked(2 * i),
+                    level.get_unchecked(2 * i + 1),
+                ];
+                next.push_back(poseidon2_hash::<4, Bn254Fr>(env, &pair));
+            }
+            level = next;
+        }
+        siblings
+    }
+
+    // Returns the index of the leaf with the largest value below `value`
+    fn low_index(&self, value: &U256) -> u32 {
+        let mut low = 0;
+        for (i, leaf) in self.leaves.iter().enumerate() {
+            if leaf.value < *value && leaf.value > self.leaves.get_unchecked(low).value {
+                low = i as u32;
+            }
+        }
+        low
+    }
+
+    fn insert(&mut self, env: &Env, tree: &mut IndexedMerkleTree, value: U256) -> u64 {
+        let low_index = self.low_index(&value);
+        let mut low_leaf = self.leaves.get_unchecked(low_index);
+        let siblings = self.siblings(env, low_index);
+        let index = tree.insert(value.clone(), low_leaf.clone(), low_index as u64, &siblings);
+
+        self.leaves.push_back(IndexedLeaf {
+            value: value.clone(),
+            next_value: low_leaf.next_value.clone(),
+            next_index: low_leaf.next_index,
+        });
+        low_leaf.next_value = value;
+        low_leaf.next_index = index;
+        self.leaves.set(low_index, low_leaf);
+        index
+    }
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_indexed_merkle_insert_kat() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        assert_eq!(tree.next_index(), 1);
+        assert_eq!(
+            tree.root(),
+            u256(
+                &env,
+                bytesn!(
+                    &env,
+                    0x21f9172d72fdcdafc312eee05cf5092980dda821da5b760a9fb8dbdf607c8a20
+                )
+            )
+        );
+
+        let expected = [
+            (
+                30,
+                bytesn!(
+                    &env,
+                    0x2e72dbdeee84f31d1da9c0f628da25ef715a373efaab05ce335580fbdc76c4d2
+                ),
+            ),
+            (
+                10,
+                bytesn!(
+                    &env,
+                    0x21a8b0ee242c276fff3a3bf469e9e0a8888f63955a3081abff6b44278712c266
+                ),
+            ),
+            (
+                20,
+                bytesn!(
+                    &env,
+                    0x1566913aa30e296895cf210f4196762af94ce701b9237660f4002681c46dafc1
+                ),
+            ),
+            (
+                50,
+                bytesn!(
+                    &env,
+                    0x0aa3bf1828b39f4d377aaf6d0987cfd177f41a1a464ab0b06033e1599677df46
+                ),
+            ),
+        ];
+        for (i, (value, root)) in expected.into_iter().enumerate() {
+            let index = model.insert(&env, &mut tree, n(&env, value));
+            assert_eq!(index, i as u64 + 1);
+            assert_eq!(tree.root(), u256(&env, root), "value {value}");
+        }
+
+        // The leaves form a sorted linked list
+        assert_eq!(model.leaves.get_unchecked(0), indexed_leaf(&env, 0, 10, 2));
+        assert_eq!(model.leaves.get_unchecked(1), indexed_leaf(&env, 30, 50, 4));
+        assert_eq!(model.leaves.get_unchecked(4), indexed_leaf(&env, 50, 0, 0));
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_indexed_leaf_hash() {
+    let env = Env::default();
+    assert_eq!(indexed_leaf(&env, 0, 0, 0).hash(&env), n(&env, 0));
+    assert_eq!(
+        indexed_leaf(&env, 10, 20, 3).hash(&env),
+        poseidon2_hash::<4, Bn254Fr>(&env, &vec![&env, n(&env, 10), n(&env, 20), n(&env, 3)])
+    );
+    assert_eq!(
+        indexed_leaf(&env, 10, 20, 3).hash(&env),
+        u256(
+            &env,
+            bytesn!(
+                &env,
+                0x0efd2b44855c3a5a92b70a8e0024e33d19cea7f7c4f85be3002539974d386fb8
+            )
+        )
+    );
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_indexed_merkle_non_membership() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        for value in [30, 10, 20, 50] {
+            model.insert(&env, &mut tree, n(&env, value));
+        }
+
+        // Between two values, above the largest and below the smallest
+        for (value, low_index) in [(25, 3), (60, 4), (5, 0)] {
+            let low_leaf = model.leaves.get_unchecked(low_index);
+            let siblings = model.siblings(&env, low_index);
+            assert!(
+                tree.verify_non_membership(&n(&env, value), &low_leaf, low_index as u64, &siblings),
+                "value {value}"
+            );
+        }
+
+        // A present value is not bounded by its predecessor
+        let siblings = model.siblings(&env, 2);
+        let low_leaf = model.leaves.get_unchecked(2);
+        assert!(!tree.verify_non_membership(&n(&env, 20), &low_leaf, 2, &siblings));
+        // A low leaf that is not in the tree
+        let fake = indexed_leaf(&env, 20, 0, 0);
+        assert!(!tree.verify_non_membership(&n(&env, 25), &fake, 3, &model.siblings(&env, 3)));
+        // An empty leaf past the end hashes like the initial leaf 0
+        let empty = indexed_leaf(&env, 0, 0, 0);
+        assert!(!tree.verify_non_membership(&n(&env, 25), &empty, 6, &model.siblings(&env, 6)));
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_indexed_merkle_matches_model() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        // Values inserted out of order, so low leaves are all over the tree
+        for i in 1..8u32 {
+            model.insert(&env, &mut tree, n(&env, (i * 5) % 8 * 100 + i));
+        }
+        assert_eq!(tree.next_index(), 8);
+
+        let mut level = Vec::new(&env);
+        for leaf in model.leaves.iter() {
+            level.push_back(leaf.hash(&env));
+        }
+        while level.len() > 1 {
+            let mut next = Vec::new(&env);
+            for i in 0..level.len() / 2 {
+                let pair = vec![
+                    &env,
+                    level.get_unchecked(2 * i),
+                    level.get_unchecked(2 * i + 1),
+                ];
+                next.push_back(poseidon2_hash::<4, Bn254Fr>(&env, &pair));
+            }
+            level = next;
+        }
+        assert_eq!(tree.root(), level.get_unchecked(0));
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+fn test_indexed_merkle_persists_across_loads() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        model.insert(&env, &mut tree, n(&env, 30));
+
+        let mut loaded = IndexedMerkleTree::load(&env, &TREE);
+        assert_eq!(loaded.depth(), 3);
+        assert_eq!(loaded.next_index(), 2);
+        assert_eq!(loaded.root(), tree.root());
+        model.insert(&env, &mut loaded, n(&env, 10));
+        assert_eq!(
+            IndexedMerkleTree::load(&env, &TREE).root(),
+            u256(
+                &env,
+                bytesn!(
+                    &env,
+                    0x21a8b0ee242c276fff3a3bf469e9e0a8888f63955a3081abff6b44278712c266
+                )
+            )
+        );
+    });
+}
+
+// ============================================================================
+// Storage and root history
+// ============================================================================
+
+#[test]
+fn test_merkle_persists_across_loads() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 8);
+        tree.insert(U256::from_u32(&env, 1));
+        tree.insert(U256::from_u32(&env, 2));
+        let root = tree.root();
+
+        let mut loaded = IncrementalMerkleTree::load(&env, &TREE);
+        assert_eq!(loaded.depth(), 8);
+        assert_eq!(loaded.next_index(), 2);
+        assert_eq!(loaded.root(), root);
+
+        // Inserting into the loaded tree continues where the first one left
+        assert_eq!(loaded.insert(U256::from_u32(&env, 3)), 2);
+        let leaves = vec![
+            &env,
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+            U256::from_u32(&env, 3),
+        ];
+        assert_eq!(loaded.root(), naive_root(&env, &leaves, 8));
+    });
+}
+
+#[test]
+fn test_merkle_independent_keys() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut a = IncrementalMerkleTree::init(&env, &symbol_short!("A"), 4);
+        let b = IncrementalMerkleTree::init(&env, &symbol_short!("B"), 4);
+        a.insert(U256::from_u32(&env, 1));
+        assert_eq!(
+            IncrementalMerkleTree::load(&env, &symbol_short!("A")).next_index(),
+            1
+        );
+        assert_eq!(
+            IncrementalMerkleTree::load(&env, &symbol_short!("B")).root(),
+            b.root()
+        );
+    });
+}
+
+#[test]
+fn test_merkle_root_history() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 6);
+        let empty_root = tree.root();
+        let mut roots = vec![&env];
+        for i in 0..IncrementalMerkleTree::ROOT_HISTORY_SIZE + 2 {
+            tree.insert(U256::from_u32(&env, i + 1));
+            roots.push_back(tree.root());
+        }
+
+        // The last ROOT_HISTORY_SIZE roots are known, older ones are evicted
+        let history = IncrementalMerkleTree::ROOT_HISTORY_SIZE;
+        for (i, root) in roots.iter().enumerate() {
+            let known = i as u32 + history >= roots.len();
+            assert_eq!(tree.is_known_root(&root), known, "root {i}");
+        }
+        assert!(!tree.is_known_root(&empty_root));
+        assert!(!tree.is_known_root(&U256::from_u32(&env, 0)));
+    });
+}
+
+// ============================================================================
+// Panics
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Merkle: tree is full")]
+fn test_merkle_full_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 2);
+        for i in 0..5 {
+            tree.insert(U256::from_u32(&env, i));
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: depth must be between 1 and 32")]
+fn test_merkle_depth_too_large_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        IncrementalMerkleTree::init(&env, &TREE, 33);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: depth must be between 1 and 32")]
+fn test_merkle_depth_zero_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        IncrementalMerkleTree::init(&env, &TREE, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: tree already initialized")]
+fn test_merkle_init_twice_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        IncrementalMerkleTree::init(&env, &TREE, 4);
+        IncrementalMerkleTree::init(&env, &TREE, 4);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: tree not found in storage")]
+fn test_merkle_load_missing_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        IncrementalMerkleTree::load(&env, &TREE);
+    });
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_merkle_leaf_exceeds_modulus_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IncrementalMerkleTree::init(&env, &TREE, 4);
+        let modulus = U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+            )
+            .into(),
+        );
+        tree.insert(modulus);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: path indices exceed the proof depth")]
+fn test_verify_merkle_proof_index_too_large_panics() {
+    let env = Env::default();
+    verify_merkle_proof(&env, &U256::from_u32(&env, 7), &proof_siblings(&env), 16);
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: path indices exceed the proof depth")]
+fn test_verify_merkle_proof_poseidon2_index_too_large_panics() {
+    let env = Env::default();
+    verify_merkle_proof_poseidon2(&env, &U256::from_u32(&env, 7), &proof_siblings(&env), 16);
+}
+
+#[test]
+#[should_panic(expected = "Merkle: proof depth must be at most 64")]
+fn test_verify_merkle_proof_too_deep_panics() {
+    let env = Env::default();
+    let mut siblings = Vec::new(&env);
+    for _ in 0..65 {
+        siblings.push_back(U256::from_u32(&env, 0));
+    }
+    verify_merkle_proof(&env, &U256::from_u32(&env, 7), &siblings, 0);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_verify_merkle_proof_sibling_exceeds_modulus_panics() {
+    let env = Env::default();
+    let modulus = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+        )
+        .into(),
+    );
+    verify_merkle_proof(&env, &U256::from_u32(&env, 7), &vec![&env, modulus], 0);
+}
+
+#[test]
+#[should_panic(expected = "Merkle: proof depth must be at most 256")]
+fn test_sparse_merkle_too_deep_panics() {
+    let env = Env::default();
+    let mut siblings = Vec::new(&env);
+    for _ in 0..257 {
+        siblings.push_back(n(&env, 0));
+    }
+    verify_sparse_merkle_exclusion(&env, &n(&env, 0), &n(&env, 1), &siblings, None);
+}
+
+#[test]
+#[should_panic(expected = "Merkle: siblings do not match the tree")]
+fn test_lean_imt_update_wrong_old_leaf_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert_many(&vec![&env, n(&env, 1), n(&env, 2)]);
+        tree.update(0, n(&env, 3), n(&env, 4), &vec![&env, n(&env, 2)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: wrong number of siblings")]
+fn test_lean_imt_update_too_many_siblings_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert_many(&vec![&env, n(&env, 1), n(&env, 2), n(&env, 3)]);
+        // Leaf 2 only has a sibling at the root
+        let siblings = vec![&env, n(&env, 0), n(&env, 0)];
+        tree.update(2, n(&env, 3), n(&env, 4), &siblings);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: leaf index out of bounds")]
+fn test_lean_imt_update_out_of_bounds_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert(n(&env, 1));
+        tree.update(1, n(&env, 1), n(&env, 2), &vec![&env]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_lean_imt_leaf_exceeds_modulus_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        // The first leaf is never hashed, so it is checked on insertion
+        tree.insert(Bn254Fr::modulus(&env));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: leaf cannot be zero")]
+fn test_lean_imt_zero_leaf_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert(n(&env, 1));
+        tree.insert(n(&env, 0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: leaf cannot be zero")]
+fn test_lean_imt_insert_many_zero_leaf_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = LeanIncrementalMerkleTree::init(&env, &TREE);
+        tree.insert_many(&vec![&env, n(&env, 1), n(&env, 0), n(&env, 2)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Merkle: tree already initialized")]
+fn test_lean_imt_init_twice_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        LeanIncrementalMerkleTree::init(&env, &TREE);
+        LeanIncrementalMerkleTree::init(&env, &TREE);
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: low leaf does not bound the value")]
+fn test_indexed_merkle_duplicate_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        model.insert(&env, &mut tree, n(&env, 30));
+        model.insert(&env, &mut tree, n(&env, 30));
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: low leaf does not bound the value")]
+fn test_indexed_merkle_zero_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        model.insert(&env, &mut tree, n(&env, 0));
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: low leaf is not in the tree")]
+fn test_indexed_merkle_fake_low_leaf_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        model.insert(&env, &mut tree, n(&env, 30));
+        // 30 is the low leaf of 40, but its pointer is forged
+        let fake = indexed_leaf(&env, 30, 50, 0);
+        let siblings = model.siblings(&env, 1);
+        tree.insert(n(&env, 40), fake, 1, &siblings);
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: low leaf is not in the tree")]
+fn test_indexed_merkle_empty_low_leaf_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 3);
+        let mut model = IndexedModel::new(&env, 3);
+        model.insert(&env, &mut tree, n(&env, 30));
+        // An empty slot hashes like the initial leaf 0
+        let empty = indexed_leaf(&env, 0, 0, 0);
+        let siblings = model.siblings(&env, 5);
+        tree.insert(n(&env, 40), empty, 5, &siblings);
+    });
+}
+
+#[cfg(feature = "poseidon2-bn254")]
+#[test]
+#[should_panic(expected = "Merkle: tree is full")]
+fn test_indexed_merkle_full_panics() {
+    let (env, contract_id) = setup();
+    env.as_contract(&contract_id, || {
+        let mut tree = IndexedMerkleTree::init(&env, &TREE, 2);
+        let mut model = IndexedModel::new(&env, 2);
+        for value in 1..=4 {
+            model.insert(&env, &mut tree, n(&env, value));
+        }
+    });
+}
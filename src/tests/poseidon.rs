@@ -1,14 +1,16 @@
 use crate::{
+    constants::u256_matrix,
     poseidon::{
-        params::{get_mds_bn254_t_3, get_rc_bn254_t_3, SBOX_D},
+        params::{MDS_BN254_T_3, RC_BN254_T_3, SBOX_D},
         PoseidonSponge,
     },
-    poseidon_hash,
+    poseidon_ex, poseidon_hash, poseidon_hash_varlen, poseidon_permutation, try_poseidon_hash,
+    Field, PoseidonError,
 };
+#[cfg(feature = "poseidon-bls12-381")]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
 use soroban_sdk::{
-    bytesn,
-    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
-    vec, Env, Symbol, U256,
+    bytesn, contract, contractimpl, crypto::bn254::Bn254Fr, vec, Env, Symbol, Vec, U256,
 };
 
 // Poseidon tests
@@ -305,7 +307,320 @@ fn test_poseidon_bn254_hash_1_2_3_4_5() {
     assert_eq!(result, expected);
 }
 
+// ============================================================================
+// Wide circom parameter sets (t = 7..17)
+// ============================================================================
+
+// This test case matches circom hash([1, 2, ..., 6]) with t=7 (N=6)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_6() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 6]
+    let mut inputs = vec![&env];
+    for i in 1..=6 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x2d1a03850084442813c8ebf094dea47538490a68b05f2239134a4cca2f6302e1
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<7, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 7]) with t=8 (N=7)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_7() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 7]
+    let mut inputs = vec![&env];
+    for i in 1..=7 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x1c2f3482dbb140c4ebb9ada49abdbc374a9a85fcfc6533ec2e9df45b4921c318
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<8, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 8]) with t=9 (N=8)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_8() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 8]
+    let mut inputs = vec![&env];
+    for i in 1..=8 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x2921ab9bd0140cbc98e40395c0fefb40337a4d54fbbecd9a4d43b3d8d0c4d8d1
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<9, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 9]) with t=10 (N=9)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_9() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 9]
+    let mut inputs = vec![&env];
+    for i in 1..=9 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x1e0b893aa2ad802275e749d260330b7675b22bb3aaa4461d204af32e60cd9078
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<10, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 10]) with t=11 (N=10)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_10() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 10]
+    let mut inputs = vec![&env];
+    for i in 1..=10 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x0816126a09c29ecfcc0628461dacfb9459816fc60d6738b78db9ad07206fdc21
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<11, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 11]) with t=12 (N=11)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_11() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 11]
+    let mut inputs = vec![&env];
+    for i in 1..=11 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x07e5b070aa2dba008f30a6b785b6c5ae2429e211f71cacdbdae0e07fc05b47a8
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<12, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 12]) with t=13 (N=12)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_12() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 12]
+    let mut inputs = vec![&env];
+    for i in 1..=12 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x058814945232937db248a01e7cc55b3d681cc08702c8168494e856c1ef7693b5
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<13, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 13]) with t=14 (N=13)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_13() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 13]
+    let mut inputs = vec![&env];
+    for i in 1..=13 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x0f918939632fadca6456a2fe6e65a124828d4c3920d379cc744e90a666887806
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<14, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 14]) with t=15 (N=14)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_14() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 14]
+    let mut inputs = vec![&env];
+    for i in 1..=14 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x1278779aaafc5ca58bf573151005830cdb4683fb26591c85a7464d4f0e527776
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<15, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 15]) with t=16 (N=15)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_15() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 15]
+    let mut inputs = vec![&env];
+    for i in 1..=15 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x094ae33b67a845998abb55e917642d4022d078d96f7c36ea11da4273ecf20f50
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<16, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// This test case matches circom hash([1, 2, ..., 16]) with t=17 (N=16)
+#[test]
+#[cfg(feature = "poseidon-wide")]
+fn test_poseidon_bn254_hash_1_to_16() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 16]
+    let mut inputs = vec![&env];
+    for i in 1..=16 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    // Expected output from circomlibjs
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x16159a551cbb66108281a48099fff949ae08afd7f1f2ec06de2ffb96b919b765
+        )
+        .into(),
+    );
+
+    let mut sponge = PoseidonSponge::<17, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_hash_1_2() {
     let env = Env::default();
 
@@ -348,6 +663,7 @@ fn test_poseidon_bls12_381_hash_1_2() {
 
 // This test case matches poseidon-bls12381-circom hash([1]) with t=2 (N=1)
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_hash_1() {
     let env = Env::default();
 
@@ -382,6 +698,7 @@ fn test_poseidon_bls12_381_hash_1() {
 
 // This test case matches poseidon-bls12381-circom hash([1, 2, 3]) with t=4 (N=3)
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_hash_1_2_3() {
     let env = Env::default();
 
@@ -432,6 +749,7 @@ fn test_poseidon_bls12_381_hash_1_2_3() {
 
 // This test case matches poseidon-bls12381-circom hash([1, 2, 3, 4]) with t=5 (N=4)
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_hash_1_2_3_4() {
     let env = Env::default();
 
@@ -490,6 +808,7 @@ fn test_poseidon_bls12_381_hash_1_2_3_4() {
 
 // This test case matches poseidon-bls12381-circom hash([1, 2, 3, 4, 5]) with t=6 (N=5)
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_hash_1_2_3_4_5() {
     let env = Env::default();
 
@@ -583,8 +902,8 @@ fn test_poseidon_permutation_t3() {
     // Get parameters
     let field = Symbol::new(&env, "BN254");
     let t = 3;
-    let mds = get_mds_bn254_t_3(&env);
-    let rc = get_rc_bn254_t_3(&env);
+    let mds = u256_matrix(&env, MDS_BN254_T_3, 3);
+    let rc = u256_matrix(&env, RC_BN254_T_3, 3);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 57;
@@ -632,6 +951,7 @@ fn test_poseidon_hash_bn254_t3() {
 }
 
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_hash_bls12_381_t3() {
     let env = Env::default();
 
@@ -694,6 +1014,55 @@ fn test_poseidon_sponge_reuse() {
     assert_eq!(result1_again, expected1);
 }
 
+#[test]
+fn test_poseidon_hash_batch() {
+    let env = Env::default();
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+
+    let batch = vec![
+        &env,
+        vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)],
+        vec![&env, U256::from_u32(&env, 3), U256::from_u32(&env, 4)],
+        vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)],
+    ];
+    let expected1 = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+        )
+        .into(),
+    );
+    let expected2 = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x20a3af0435914ccd84b806164531b0cd36e37d4efb93efab76913a93e1f30996
+        )
+        .into(),
+    );
+    assert_eq!(
+        sponge.compute_hash_batch(&batch),
+        vec![&env, expected1.clone(), expected2, expected1]
+    );
+
+    // An empty batch hashes nothing
+    assert_eq!(sponge.compute_hash_batch(&vec![&env]).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: inputs.len() must equal rate (T - 1)")]
+fn test_poseidon_hash_batch_wrong_length_panics() {
+    let env = Env::default();
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    let batch = vec![
+        &env,
+        vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)],
+        vec![&env, U256::from_u32(&env, 3)],
+    ];
+    sponge.compute_hash_batch(&batch);
+}
+
 #[test]
 fn test_poseidon_sponge_matches_hash_function() {
     let env = Env::default();
@@ -711,28 +1080,456 @@ fn test_poseidon_sponge_matches_hash_function() {
 }
 
 // ============================================================================
-// Partial rate rejection tests
+// Streaming API tests
 // ============================================================================
 
-// Poseidon V1 requires inputs.len() == RATE (full rate), matching circom's
-// behavior where nInputs determines T = nInputs + 1. Partial-rate inputs are
-// rejected to prevent suffix-zero collisions from implicit zero-padding.
-
 #[test]
-#[should_panic(expected = "inputs.len() must equal rate")]
-fn test_poseidon_bn254_rejects_partial_rate() {
+fn test_poseidon_streaming_matches_compute_hash() {
     let env = Env::default();
 
-    // 1 input with T=3 (rate=2) - partial rate must be rejected
-    let inputs = vec![&env, U256::from_u32(&env, 1)];
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+        U256::from_u32(&env, 4),
+    ];
 
-    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
-    sponge.compute_hash(&inputs); // should panic
-}
+    let mut sponge = PoseidonSponge::<5, Bn254Fr>::new(&env);
+    let expected = sponge.compute_hash(&inputs);
 
-// ============================================================================
-// Failure mode tests
-// ============================================================================
+    // One element at a time
+    for v in inputs.iter() {
+        sponge.absorb_one(v);
+    }
+    assert_eq!(sponge.finalize(), expected);
+
+    // Mixed absorb_one / absorb_many
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    sponge.absorb_many(&vec![
+        &env,
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ]);
+    sponge.absorb_one(U256::from_u32(&env, 4));
+    assert_eq!(sponge.finalize(), expected);
+}
+
+#[test]
+fn test_poseidon_squeeze_then_compute_hash() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    sponge.absorb_one(U256::from_u32(&env, 2));
+    let squeezed = sponge.squeeze();
+
+    // compute_hash resets a squeezed sponge
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    assert_eq!(sponge.compute_hash(&inputs), squeezed);
+}
+
+#[test]
+#[should_panic(expected = "inputs.len() must equal rate")]
+fn test_poseidon_streaming_rejects_partial_rate() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    let _ = sponge.finalize(); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "inputs.len() must equal rate")]
+fn test_poseidon_streaming_inputs_exceed_rate() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<2, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    sponge.absorb_one(U256::from_u32(&env, 2)); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: cannot absorb after squeeze")]
+fn test_poseidon_absorb_after_squeeze() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<2, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    let _ = sponge.squeeze();
+    sponge.absorb_one(U256::from_u32(&env, 2)); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: sponge already squeezed")]
+fn test_poseidon_squeeze_twice() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<2, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    let _ = sponge.squeeze();
+    let _ = sponge.squeeze(); // Should panic
+}
+
+// ============================================================================
+// PoseidonEx (initial state / multiple outputs) tests
+// ============================================================================
+
+// Reference: circomlibjs `poseidon([1, 2], 0, 3)`
+#[test]
+fn test_poseidon_ex_bn254_t3_zero_initial_state() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let result = poseidon_ex::<3, Bn254Fr>(&env, &inputs, U256::from_u32(&env, 0), 3);
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+
+    // The first output is the standard Poseidon hash
+    assert_eq!(
+        result.get_unchecked(0),
+        poseidon_hash::<3, Bn254Fr>(&env, &inputs)
+    );
+}
+
+// Reference: circomlibjs `poseidon([1, 2], 7, 3)`
+#[test]
+fn test_poseidon_ex_bn254_t3_initial_state() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let result = poseidon_ex::<3, Bn254Fr>(&env, &inputs, U256::from_u32(&env, 7), 3);
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x10ab908ccdeecd8a8223b102d69bf12c020b3b208d6562ec36e8880f43607900
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x14d15acb1742a29a19ea85367357215951cd67865a9640b38a1900995bce32b1
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x08ccdf382b490f1aadf6625fcb593c423ae65369e1131d2968e46cea80b50954
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+// Reference: circomlibjs `poseidon([1, 2, 3, 4], 42, 2)`
+#[test]
+fn test_poseidon_ex_bn254_t5_two_outputs() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+        U256::from_u32(&env, 4),
+    ];
+
+    let mut sponge = PoseidonSponge::<5, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash_ex(&inputs, U256::from_u32(&env, 42), 2);
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x20085d37ecfa15823816dde7a51da21d9b459f52700551b57d4464d42fa9831d
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0a965b85f042d2d91cff28751cc8614dbe45db4d3fdd17816233fe0c00fe802e
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+
+    // The initial state does not leak into subsequent hashes
+    assert_eq!(
+        sponge.compute_hash(&inputs),
+        poseidon_hash::<5, Bn254Fr>(&env, &inputs)
+    );
+}
+
+#[test]
+#[cfg(feature = "poseidon-bls12-381")]
+fn test_poseidon_ex_bls12_381_t3() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let result = poseidon_ex::<3, Bls12381Fr>(&env, &inputs, U256::from_u32(&env, 5), 3);
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x31bc250a5e25ce2739bcaf397b8ec4db5318a08b37b7ae97a852bf5394ae06e5
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1f3d05cb28657a4ac7ce32b1b3ca577ad9dcb39724020704815e54205bab430c
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0a0c480b736018a20ab50d5869f02295742fed296168647b1f82c3dcaa83eef1
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: n_outs must be between 1 and T")]
+fn test_poseidon_ex_too_many_outputs() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let _ = poseidon_ex::<3, Bn254Fr>(&env, &inputs, U256::from_u32(&env, 0), 4);
+}
+
+#[test]
+#[should_panic(expected = "initial state exceeds field modulus")]
+fn test_poseidon_ex_initial_state_exceeds_modulus() {
+    let env = Env::default();
+
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    let _ = poseidon_ex::<3, Bn254Fr>(
+        &env,
+        &inputs,
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
+        1,
+    );
+}
+
+// ============================================================================
+// Variable-length hashing tests
+// ============================================================================
+
+#[test]
+fn test_poseidon_varlen_bn254_t3() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+
+    // 1 input: partial chunk, zero-padded
+    let result = sponge.compute_hash_varlen(&vec![&env, U256::from_u32(&env, 1)]);
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x14b2e5484b232721d64f405caa487febbce835dd07c5de940f2a775dc9aa0da6
+        )
+        .into(),
+    );
+    assert_eq!(result, expected);
+
+    // 5 inputs: three permutations
+    let mut inputs = vec![&env];
+    for i in 1..=5 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+    let result = sponge.compute_hash_varlen(&inputs);
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x0090ceb82ebde42b9f24424d11fe5b5d3893ed6c874e53a8d32ea89d163f8e4b
+        )
+        .into(),
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_poseidon_varlen_bn254_t5() {
+    let env = Env::default();
+
+    let mut inputs = vec![&env];
+    for i in 1..=7 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x1128e7736fd415b91fdc4a1afc95b82cceef4c856c4104178304306e0796891b
+        )
+        .into(),
+    );
+    assert_eq!(poseidon_hash_varlen::<5, Bn254Fr>(&env, &inputs), expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon-bls12-381")]
+fn test_poseidon_varlen_bls12_381_t3() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x5b33c64a932a43ee2c2d922a6fbea82fd9ed39fdafbe6e3b2f63e0c268782253
+        )
+        .into(),
+    );
+    assert_eq!(
+        poseidon_hash_varlen::<3, Bls12381Fr>(&env, &inputs),
+        expected
+    );
+}
+
+// The length in the capacity prevents the suffix-zero collision that
+// zero-padding would otherwise introduce
+#[test]
+fn test_poseidon_varlen_no_suffix_zero_collision() {
+    let env = Env::default();
+
+    let one = vec![&env, U256::from_u32(&env, 1)];
+    let one_zero = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 0)];
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    let h1 = sponge.compute_hash_varlen(&one);
+    let h2 = sponge.compute_hash_varlen(&one_zero);
+    assert_ne!(h1, h2);
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x0a31b191a06296f8345501928757881b3208524d058f73807e84e4660f9c8602
+        )
+        .into(),
+    );
+    assert_eq!(h2, expected);
+
+    // Distinct from the fixed-length circom hash of the same full-rate input
+    assert_ne!(h2, sponge.compute_hash(&one_zero));
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: inputs must not be empty")]
+fn test_poseidon_varlen_empty_inputs_rejected() {
+    let env = Env::default();
+
+    let _ = poseidon_hash_varlen::<3, Bn254Fr>(&env, &vec![&env]);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_poseidon_varlen_input_exceeds_modulus() {
+    let env = Env::default();
+
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
+    ];
+    let _ = poseidon_hash_varlen::<3, Bn254Fr>(&env, &inputs);
+}
+
+// ============================================================================
+// Partial rate rejection tests
+// ============================================================================
+
+// Poseidon V1 requires inputs.len() == RATE (full rate), matching circom's
+// behavior where nInputs determines T = nInputs + 1. Partial-rate inputs are
+// rejected to prevent suffix-zero collisions from implicit zero-padding.
+
+#[test]
+#[should_panic(expected = "inputs.len() must equal rate")]
+fn test_poseidon_bn254_rejects_partial_rate() {
+    let env = Env::default();
+
+    // 1 input with T=3 (rate=2) - partial rate must be rejected
+    let inputs = vec![&env, U256::from_u32(&env, 1)];
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    sponge.compute_hash(&inputs); // should panic
+}
+
+// ============================================================================
+// Failure mode tests
+// ============================================================================
 
 #[test]
 #[should_panic(expected = "inputs.len() must equal rate")]
@@ -820,6 +1617,7 @@ fn test_poseidon_bn254_input_below_modulus_accepted() {
 // BLS12-381 modulus = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
 #[test]
 #[should_panic(expected = "input exceeds field modulus")]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_input_exceeds_modulus() {
     let env = Env::default();
 
@@ -842,6 +1640,7 @@ fn test_poseidon_bls12_381_input_exceeds_modulus() {
 // Test that a value exactly equal to the BLS12-381 modulus is rejected
 #[test]
 #[should_panic(expected = "input exceeds field modulus")]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_input_equals_modulus() {
     let env = Env::default();
 
@@ -858,6 +1657,7 @@ fn test_poseidon_bls12_381_input_equals_modulus() {
 
 // Test that values just below the BLS12-381 modulus are accepted
 #[test]
+#[cfg(feature = "poseidon-bls12-381")]
 fn test_poseidon_bls12_381_input_below_modulus_accepted() {
     let env = Env::default();
 
@@ -886,3 +1686,271 @@ fn test_poseidon_bn254_empty_inputs_rejected() {
     // This should panic
     let _ = sponge.compute_hash(&empty_inputs);
 }
+
+// ============================================================================
+// Tests for poseidon_permutation (raw permutation)
+// ============================================================================
+
+// The permutation of [0, 1, 2] yields circom's Poseidon([1, 2]) at position 0
+#[test]
+fn test_poseidon_permutation_bn254_t3() {
+    let env = Env::default();
+
+    let state = vec![
+        &env,
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ];
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c
+            )
+            .into(),
+        ),
+    ];
+
+    let result = poseidon_permutation::<3, Bn254Fr>(&env, &state);
+    assert_eq!(result, expected);
+
+    // Must match the host permutation called with the same parameters
+    let host = env.crypto_hazmat().poseidon_permutation(
+        &state,
+        Symbol::new(&env, "BN254"),
+        3,
+        SBOX_D,
+        8,
+        57,
+        &u256_matrix(&env, MDS_BN254_T_3, 3),
+        &u256_matrix(&env, RC_BN254_T_3, 3),
+    );
+    assert_eq!(result, host);
+}
+
+#[test]
+#[cfg(feature = "poseidon-bls12-381")]
+fn test_poseidon_permutation_bls12_381_t2() {
+    let env = Env::default();
+
+    let state = vec![&env, U256::from_u32(&env, 0), U256::from_u32(&env, 1)];
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x49a66f6b01dbc6440d1a5f920e027b94429916f2c821a920cf6203ad3de56cea
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x196cb1b8db9cf0ed6288a21db31e8ea3a606283d73277148783c17fbd3da53ce
+            )
+            .into(),
+        ),
+    ];
+
+    let mut sponge = PoseidonSponge::<2, Bls12381Fr>::new(&env);
+    assert_eq!(sponge.compute_permutation(&state), expected);
+
+    // The sponge is reset afterwards and can still hash
+    let inputs = vec![&env, U256::from_u32(&env, 1)];
+    assert_eq!(sponge.compute_hash(&inputs), expected.get_unchecked(0));
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: permutation state must have exactly T elements")]
+fn test_poseidon_permutation_wrong_state_size() {
+    let env = Env::default();
+    let state = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    poseidon_permutation::<3, Bn254Fr>(&env, &state);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_poseidon_permutation_state_exceeds_modulus() {
+    let env = Env::default();
+
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
+    let state = vec![
+        &env,
+        U256::from_u32(&env, 0),
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
+    ];
+    poseidon_permutation::<2, Bn254Fr>(&env, &state);
+}
+
+// ============================================================================
+// Tests for explicit / stored parameter sets
+// ============================================================================
+
+#[test]
+fn test_poseidon_from_params_matches_new() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    let params = sponge.params().clone();
+    assert_eq!(params.rounds_f, 8);
+    assert_eq!(params.rounds_p, 57);
+
+    let mut from_params = PoseidonSponge::<3, Bn254Fr>::from_params(&env, params);
+    assert_eq!(
+        from_params.compute_hash(&inputs),
+        sponge.compute_hash(&inputs)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: parameters do not match the state size T")]
+fn test_poseidon_from_params_wrong_size() {
+    let env = Env::default();
+
+    // t=3 parameters used for a t=4 sponge
+    let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+    let _ = PoseidonSponge::<4, Bn254Fr>::from_params(&env, params);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon: parameters do not match the state size T")]
+fn test_poseidon_from_params_wrong_round_count() {
+    let env = Env::default();
+
+    let mut params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+    params.rounds_p += 1;
+    let _ = PoseidonSponge::<3, Bn254Fr>::from_params(&env, params);
+}
+
+// ============================================================================
+// Tests for the fallible API
+// ============================================================================
+
+#[contract]
+struct TryHashContract;
+
+#[contractimpl]
+impl TryHashContract {
+    pub fn hash(env: Env, inputs: Vec<U256>) -> Result<U256, PoseidonError> {
+        try_poseidon_hash::<3, Bn254Fr>(&env, &inputs)
+    }
+}
+
+#[test]
+fn test_try_poseidon_hash_matches_poseidon_hash() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    assert_eq!(
+        try_poseidon_hash::<3, Bn254Fr>(&env, &inputs),
+        Ok(poseidon_hash::<3, Bn254Fr>(&env, &inputs))
+    );
+}
+
+#[test]
+fn test_try_poseidon_hash_wrong_arity() {
+    let env = Env::default();
+
+    let short = vec![&env, U256::from_u32(&env, 1)];
+    assert_eq!(
+        try_poseidon_hash::<3, Bn254Fr>(&env, &short),
+        Err(PoseidonError::WrongArity)
+    );
+    let long = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+    assert_eq!(
+        try_poseidon_hash::<3, Bn254Fr>(&env, &long),
+        Err(PoseidonError::WrongArity)
+    );
+}
+
+#[test]
+fn test_try_poseidon_hash_input_not_in_field() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), Bn254Fr::modulus(&env)];
+    assert_eq!(
+        try_poseidon_hash::<3, Bn254Fr>(&env, &inputs),
+        Err(PoseidonError::InputNotInField)
+    );
+}
+
+#[test]
+fn test_poseidon_try_compute_hash_leaves_sponge_usable() {
+    let env = Env::default();
+
+    let mut sponge = PoseidonSponge::<3, Bn254Fr>::new(&env);
+    let bad = vec![&env, Bn254Fr::modulus(&env), U256::from_u32(&env, 1)];
+    assert_eq!(
+        sponge.try_compute_hash(&bad),
+        Err(PoseidonError::InputNotInField)
+    );
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    assert_eq!(
+        sponge.try_compute_hash(&inputs),
+        Ok(poseidon_hash::<3, Bn254Fr>(&env, &inputs))
+    );
+}
+
+#[test]
+fn test_poseidon_try_from_params() {
+    let env = Env::default();
+
+    let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+    assert!(PoseidonSponge::<3, Bn254Fr>::try_from_params(&env, params.clone()).is_ok());
+    assert!(matches!(
+        PoseidonSponge::<4, Bn254Fr>::try_from_params(&env, params),
+        Err(PoseidonError::UnsupportedConfig)
+    ));
+}
+
+#[test]
+fn test_try_poseidon_hash_error_reaches_caller() {
+    let env = Env::default();
+    let contract_id = env.register(TryHashContract, ());
+    let client = TryHashContractClient::new(&env, &contract_id);
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    assert_eq!(
+        client.hash(&inputs),
+        poseidon_hash::<3, Bn254Fr>(&env, &inputs)
+    );
+    assert_eq!(
+        client.try_hash(&vec![&env, U256::from_u32(&env, 1)]),
+        Err(Ok(PoseidonError::WrongArity))
+    );
+}
@@ -1,34 +1,48 @@
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+use crate::poseidon2::params::{
+    MAT_DIAG_BLS12_381_T_12, MAT_DIAG_BLS12_381_T_16, MAT_DIAG_BLS12_381_T_8, RC_BLS12_381_T_12,
+    RC_BLS12_381_T_16, RC_BLS12_381_T_8,
+};
+#[cfg(feature = "poseidon2-bls12-381")]
+use crate::poseidon2::params::{
+    MAT_DIAG_BLS12_381_T_2, MAT_DIAG_BLS12_381_T_3, MAT_DIAG_BLS12_381_T_4, RC_BLS12_381_T_2,
+    RC_BLS12_381_T_3, RC_BLS12_381_T_4,
+};
+#[cfg(feature = "poseidon2-wide")]
+use crate::poseidon2::params::{
+    MAT_DIAG_BN254_T_12, MAT_DIAG_BN254_T_16, MAT_DIAG_BN254_T_8, RC_BN254_T_12, RC_BN254_T_16,
+    RC_BN254_T_8,
+};
 use crate::{
+    constants::{u256_matrix, u256_vec},
     poseidon2::{
         params::{
-            get_mat_diag_bls12_381_t_2, get_mat_diag_bls12_381_t_3, get_mat_diag_bls12_381_t_4,
-            get_mat_diag_bn254_t_2, get_mat_diag_bn254_t_3, get_mat_diag_bn254_t_4,
-            get_rc_bls12_381_t_2, get_rc_bls12_381_t_3, get_rc_bls12_381_t_4, get_rc_bn254_t_2,
-            get_rc_bn254_t_3, get_rc_bn254_t_4, SBOX_D,
+            MAT_DIAG_BN254_T_2, MAT_DIAG_BN254_T_3, MAT_DIAG_BN254_T_4, RC_BN254_T_2, RC_BN254_T_3,
+            RC_BN254_T_4, SBOX_D,
         },
         Poseidon2Sponge,
     },
-    poseidon2_hash,
-};
-use soroban_sdk::{
-    bytesn,
-    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
-    vec, Env, Symbol, U256,
+    poseidon2_compress, poseidon2_hash, poseidon2_permutation, try_poseidon2_hash, Field,
+    PoseidonError,
 };
+#[cfg(feature = "poseidon2-bls12-381")]
+use soroban_sdk::crypto::bls12_381::Bls12381Fr;
+use soroban_sdk::{bytesn, crypto::bn254::Bn254Fr, vec, Env, Symbol, U256};
 
 // This test matches barretenberg test case for hashing 4 inputs: https://github.com/AztecProtocol/aztec-packages/blob/b95e36c6c1a5a84ba488c720189102ecbb052d2c/barretenberg/cpp/src/barretenberg/crypto/poseidon2/poseidon2.test.cpp#L34
-// TODO: Re-enable once multi-round absorption is implemented
+// 4 inputs with t=4 (rate=3) requires two permutations.
 #[test]
-#[ignore]
 fn test_poseidon2_hash() {
     let env = Env::default();
 
-    // Input: 4 identical field elements
+    // Input: 4 identical field elements. barretenberg constructs the element
+    // from 0x9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789,
+    // which exceeds the modulus, so we pass its canonical (reduced) form.
     let input_value = U256::from_be_bytes(
         &env,
         &bytesn!(
             &env,
-            0x9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789
+            0x09539008b8b85db277c0f1b060715e72864101bf0927e05ce0280e4553456786
         )
         .into(),
     );
@@ -80,8 +94,8 @@ fn test_poseidon2_permutation() {
     // Get parameters
     let field = Symbol::new(&env, "BN254");
     let t = 4;
-    let m_diag = get_mat_diag_bn254_t_4(&env);
-    let rc = get_rc_bn254_t_4(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_4);
+    let rc = u256_matrix(&env, RC_BN254_T_4, 4);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -168,8 +182,8 @@ fn test_poseidon2_permutation_bn254_t4() {
     // Get parameters
     let field = Symbol::new(&env, "BN254");
     let t = 4u32;
-    let m_diag = get_mat_diag_bn254_t_4(&env);
-    let rc = get_rc_bn254_t_4(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_4);
+    let rc = u256_matrix(&env, RC_BN254_T_4, 4);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -220,6 +234,7 @@ fn test_poseidon2_permutation_bn254_t4() {
 }
 
 #[test]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_permutation_bls12_381_t4() {
     let env = Env::default();
 
@@ -256,8 +271,8 @@ fn test_poseidon2_permutation_bls12_381_t4() {
     // Get parameters
     let field = Symbol::new(&env, "BLS12_381");
     let t = 4u32;
-    let m_diag = get_mat_diag_bls12_381_t_4(&env);
-    let rc = get_rc_bls12_381_t_4(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_4);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_4, 4);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -328,8 +343,8 @@ fn test_poseidon2_permutation_bn254_t2() {
     // Get parameters
     let field = Symbol::new(&env, "BN254");
     let t = 2u32;
-    let m_diag = get_mat_diag_bn254_t_2(&env);
-    let rc = get_rc_bn254_t_2(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_2);
+    let rc = u256_matrix(&env, RC_BN254_T_2, 2);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -392,8 +407,8 @@ fn test_poseidon2_permutation_bn254_t3() {
     // Get parameters
     let field = Symbol::new(&env, "BN254");
     let t = 3u32;
-    let m_diag = get_mat_diag_bn254_t_3(&env);
-    let rc = get_rc_bn254_t_3(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_3);
+    let rc = u256_matrix(&env, RC_BN254_T_3, 3);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -436,6 +451,7 @@ fn test_poseidon2_permutation_bn254_t3() {
 }
 
 #[test]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_permutation_bls12_381_t2() {
     let env = Env::default();
 
@@ -456,8 +472,8 @@ fn test_poseidon2_permutation_bls12_381_t2() {
     // Get parameters
     let field = Symbol::new(&env, "BLS12_381");
     let t = 2u32;
-    let m_diag = get_mat_diag_bls12_381_t_2(&env);
-    let rc = get_rc_bls12_381_t_2(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_2);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_2, 2);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -492,6 +508,7 @@ fn test_poseidon2_permutation_bls12_381_t2() {
 }
 
 #[test]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_permutation_bls12_381_t3() {
     let env = Env::default();
 
@@ -520,8 +537,8 @@ fn test_poseidon2_permutation_bls12_381_t3() {
     // Get parameters
     let field = Symbol::new(&env, "BLS12_381");
     let t = 3u32;
-    let m_diag = get_mat_diag_bls12_381_t_3(&env);
-    let rc = get_rc_bls12_381_t_3(&env);
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_3);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_3, 3);
     let d = SBOX_D;
     let rounds_f = 8;
     let rounds_p = 56;
@@ -563,261 +580,47 @@ fn test_poseidon2_permutation_bls12_381_t3() {
     assert_eq!(result, expected);
 }
 
-// ============================================================================
-// Tests for Poseidon2Sponge::compute_hash
-// ============================================================================
-
-#[test]
-fn test_poseidon2_sponge_compute_hash_bn254_t4() {
-    let env = Env::default();
-
-    // 3 inputs fit in rate=3 for t=4
-    let inputs = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-
-    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
-
-    // Result should be deterministic - hash again and compare
-    let result2 = sponge.compute_hash(&inputs);
-    assert_eq!(result, result2);
-}
-
-#[test]
-fn test_poseidon2_sponge_compute_hash_bn254_t3() {
-    let env = Env::default();
-
-    // 2 inputs fit in rate=2 for t=3
-    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
-
-    let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
-
-    // Result should be deterministic
-    let result2 = sponge.compute_hash(&inputs);
-    assert_eq!(result, result2);
-}
-
-#[test]
-fn test_poseidon2_sponge_compute_hash_bn254_t2() {
-    let env = Env::default();
-
-    // 1 input fits in rate=1 for t=2
-    let inputs = vec![&env, U256::from_u32(&env, 1)];
-
-    let mut sponge = Poseidon2Sponge::<2, Bn254Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
-
-    // Result should be deterministic
-    let result2 = sponge.compute_hash(&inputs);
-    assert_eq!(result, result2);
-}
-
-#[test]
-fn test_poseidon2_sponge_compute_hash_bls12_381_t4() {
-    let env = Env::default();
-
-    let inputs = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-
-    let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
-
-    // Result should be deterministic
-    let result2 = sponge.compute_hash(&inputs);
-    assert_eq!(result, result2);
-}
-
-// ============================================================================
-// Tests for poseidon2_hash top-level function
-// ============================================================================
-
-#[test]
-fn test_poseidon2_hash_bn254_t4() {
-    let env = Env::default();
-
-    let inputs = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-
-    // Top-level function should work
-    let result = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
-
-    // Should match sponge directly
-    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-    let sponge_result = sponge.compute_hash(&inputs);
-
-    assert_eq!(result, sponge_result);
-}
-
-#[test]
-fn test_poseidon2_hash_bls12_381_t4() {
-    let env = Env::default();
-
-    let inputs = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-
-    let result = poseidon2_hash::<4, Bls12381Fr>(&env, &inputs);
-
-    // Should match sponge directly
-    let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::new(&env);
-    let sponge_result = sponge.compute_hash(&inputs);
-
-    assert_eq!(result, sponge_result);
-}
-
-#[test]
-fn test_poseidon2_hash_bn254_t2() {
-    let env = Env::default();
-
-    let inputs = vec![&env, U256::from_u32(&env, 1)];
-
-    let result = poseidon2_hash::<2, Bn254Fr>(&env, &inputs);
-
-    let mut sponge = Poseidon2Sponge::<2, Bn254Fr>::new(&env);
-    let sponge_result = sponge.compute_hash(&inputs);
-
-    assert_eq!(result, sponge_result);
-}
-
-#[test]
-fn test_poseidon2_hash_bn254_t3() {
-    let env = Env::default();
-
-    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
-
-    let result = poseidon2_hash::<3, Bn254Fr>(&env, &inputs);
-
-    let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::new(&env);
-    let sponge_result = sponge.compute_hash(&inputs);
-
-    assert_eq!(result, sponge_result);
-}
-
-// ============================================================================
-// Tests for sponge reuse (repeated hashing)
-// ============================================================================
-
-#[test]
-fn test_poseidon2_sponge_reuse() {
-    let env = Env::default();
-
-    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-
-    let inputs1 = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-    let inputs2 = vec![
-        &env,
-        U256::from_u32(&env, 4),
-        U256::from_u32(&env, 5),
-        U256::from_u32(&env, 6),
-    ];
-
-    // First hash
-    let result1 = sponge.compute_hash(&inputs1);
-
-    // Second hash - should be independent (different inputs, different result)
-    let result2 = sponge.compute_hash(&inputs2);
-    assert_ne!(result1, result2);
-
-    // Hash inputs1 again - should get the same result as the first time
-    let result1_again = sponge.compute_hash(&inputs1);
-    assert_eq!(result1, result1_again);
-}
-
 #[test]
-fn test_poseidon2_sponge_matches_hash_function() {
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_permutation_bn254_t8() {
     let env = Env::default();
 
-    let inputs = vec![
-        &env,
-        U256::from_u32(&env, 1),
-        U256::from_u32(&env, 2),
-        U256::from_u32(&env, 3),
-    ];
-
-    // Using top-level function
-    let hash_result = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
-
-    // Using sponge directly
-    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-    let sponge_result = sponge.compute_hash(&inputs);
-
-    assert_eq!(hash_result, sponge_result);
-}
+    // Input: [0, 1, ..., 7]
+    let mut input = vec![&env];
+    for i in 0..8 {
+        input.push_back(U256::from_u32(&env, i));
+    }
 
-// ============================================================================
-// Partial rate tests (inputs.len() < RATE)
-// ============================================================================
+    // Get parameters
+    let field = Symbol::new(&env, "BN254");
+    let t = 8u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_8);
+    let rc = u256_matrix(&env, RC_BN254_T_8, 8);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
 
-// Test hashing 1 input with T=4 (rate=3) - partial rate usage
-// Reference: noir circuit hash([1]) with message_size=1
-#[test]
-fn test_poseidon2_bn254_partial_rate_t4_1_input() {
-    let env = Env::default();
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
 
-    // 1 input with T=4 (rate=3) - only 1/3 of the rate is used
-    let inputs = vec![
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
         &env,
         U256::from_be_bytes(
             &env,
             &bytesn!(
                 &env,
-                0x0000000000000000000000000000000000000000000000000000000000000001
+                0x1d1a50bcde871247856df135d56a4ca61af575f1140ed9b1503c77528cf345df
             )
             .into(),
         ),
-    ];
-
-    // Expected from noir circuit: hash([1], 1)
-    let expected = U256::from_be_bytes(
-        &env,
-        &bytesn!(
-            &env,
-            0x168758332d5b3e2d13be8048c8011b454590e06c44bce7f702f09103eef5a373
-        )
-        .into(),
-    );
-
-    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
-
-    assert_eq!(result, expected);
-}
-
-// Test hashing 2 inputs with T=4 (rate=3) - partial rate usage
-// Reference: noir circuit hash([1, 2]) with message_size=2
-#[test]
-fn test_poseidon2_bn254_partial_rate_t4_2_inputs() {
-    let env = Env::default();
-
-    // 2 inputs with T=4 (rate=3) - 2/3 of the rate is used
-    let inputs = vec![
-        &env,
         U256::from_be_bytes(
             &env,
             &bytesn!(
                 &env,
-                0x0000000000000000000000000000000000000000000000000000000000000001
+                0x2d3943cf476ed49fd8a636660d8a76c83b55f07d06bc082005ad7eb1a21791c5
             )
             .into(),
         ),
@@ -825,13 +628,1220 @@ fn test_poseidon2_bn254_partial_rate_t4_2_inputs() {
             &env,
             &bytesn!(
                 &env,
-                0x0000000000000000000000000000000000000000000000000000000000000002
+                0x2fcda2dd846fadfde8104b1d05175dcf3cf8bd698ed8ea3ad2fbcf9c06e00310
             )
             .into(),
         ),
-    ];
-
-    // Expected from noir circuit: hash([1, 2], 2)
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x28811ac7e0829171f9d3d81f1c0ff8f34b360d407a16b331a1cb6b5d992de094
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2c07c1817cfccb67c1297935514885c07abad5a0e15477f6c076c0b0fb1ad6f3
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1b6114397199bc44e37437dd3ba1754dff007d3315bfcdcdc14ec27d02452f52
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1431250baf36fb61a07618caee4dd2f500da339a05c553e8f529a3349e617aa2
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0b19bfa00c8f1d505074130e7f8b49a8624b1905e280ceca5ba11099b081b265
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_permutation_bn254_t12() {
+    let env = Env::default();
+
+    // Input: [0, 1, ..., 11]
+    let mut input = vec![&env];
+    for i in 0..12 {
+        input.push_back(U256::from_u32(&env, i));
+    }
+
+    // Get parameters
+    let field = Symbol::new(&env, "BN254");
+    let t = 12u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_12);
+    let rc = u256_matrix(&env, RC_BN254_T_12, 12);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
+
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
+
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x3014e0ec17029f7e4f5cfe8c7c54fc3df6a5f7539f6aa304b2f3c747a9105618
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2f90753e7aaf46c158cd12346da7dd37c3136353ec51525cabbaaf2b2350f9b2
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2e28bdc8b2c68b09da0cb653ee7e54eca909cf2ae010784554aa3e165b1a105f
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1d6a97ef87dbd3476a848af45beebe6b5d79cb047b37212e3e5839f1e80b397a
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x24e23df24b19b75f44218a08d107709d35561bc1b982cfc317d54568cd496519
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x185a08e623b85e797844191a1f184f7b8fc486253919eb20f1186a8331757018
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x069ed78df853a105c8949dae5b4e81cbe370e8f6e25735a688aa8ff3df9659eb
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x284395d79b64123211a4a59b81a90f9cfa8d8314dccde4cef22ec1e31431efd3
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0f24be5a8c95e3504ead0da9e792b77d7056f94461d69b04b33ea5d239f8e444
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x022469ccfef0ce5a237518c38dec31fc2804e633b3b365c23a9f703ca31ef393
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1fcdcee218d5a0101bd233d572f184964854d445ca08d2bd6df6ceba5651e322
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0905469a776b7d5a3f18841edb90fa0d8c6de479c2789c042dafefb367ad1a2b
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_permutation_bn254_t16() {
+    let env = Env::default();
+
+    // Input: [0, 1, ..., 15]
+    let mut input = vec![&env];
+    for i in 0..16 {
+        input.push_back(U256::from_u32(&env, i));
+    }
+
+    // Get parameters
+    let field = Symbol::new(&env, "BN254");
+    let t = 16u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BN254_T_16);
+    let rc = u256_matrix(&env, RC_BN254_T_16, 16);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
+
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
+
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0fc2e6b758f493969e1d860f9a44ee3bdffdf796f382aa4ffb16fa4e9bcc333f
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0c118155a0dfeca3f91faf14a350511228ac33743be91249c6e0b3a635a50de4
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1a02b3a6571f22bb6392322d3f9f5de145b4f00bdf483072ce6188c30ba0f83d
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x26631df6b2522ecde57413cd680ed590ded356e1c680f865f45be8eb960d1e06
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x250ac4dfed40dc37bac9abe46f7bff3a80481d52a157ac80a1e5d39a5ed60e18
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x17160980d8e7d9cb31addaf294cf047768bffd9fe433e8903b4ed262ee913f5b
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1d708a9f0995c2e0cd2f55e5dc795126f7191a0eb934ac8172bf54e520361ff6
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x20721a18915e96e37e12c9697427f34d6a366787ea94ea65565c36813a0d77a3
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x08671a9e58105eed9ac673249dcf22f08f098e3c6eb28f9eaa55d67d755972d0
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x01e879484303c6d057128fbcc3a4222c779a62d3666df65d4e0b64c8031d7cc4
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x239e2ce87955ebe19aaad000b38725b729f51175ab7d688f15d997edf0e3b7fc
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x06be612f42b3ebdbade3fe199338c9118eb6b5fb760bda96e45443f130a8b2de
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x11b2c04b4eb9e4844e5ddbb19b56059a815ed5d69405ba51786961235d5f073c
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x006da33e2d57616c0ffc855b48d225a1237c3d80fc7e6b6e73b74e162b85c8a8
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0ef50c2615882523c6c73a69b4371332a066b2dc4b9630f186db47e3bfca88c8
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0e2ceb1f8fde5f80be1f41bd239fabdc2f6133a6a98920a55c42891c3a925152
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_permutation_bls12_381_t8() {
+    let env = Env::default();
+
+    // Input: [0, 1, ..., 7]
+    let mut input = vec![&env];
+    for i in 0..8 {
+        input.push_back(U256::from_u32(&env, i));
+    }
+
+    // Get parameters
+    let field = Symbol::new(&env, "BLS12_381");
+    let t = 8u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_8);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_8, 8);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
+
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
+
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x638a70e5a395c4473b2545d6cbb088d46587ddc9e1b2cff26d283d7c5557fd44
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x33d32173697316533e09bf9b6e9588a6a573a8ae56fbcb18379105c1d1bfcf51
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x09678cd632beacec9839a93d1c0d1792d7e485ffc6ed341a72bde371e368dda3
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x29887cd46b3afb1a62852742ac1426263a96be8b8b62888241955a735d9c5c3d
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1e094b81fdf0c1de5a8ae6d1ce47944c5111eb4973e773072b85812433ba9a45
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x6d4e3f00b1a85416c1917a9d3dbaaea81e9d9bf13890cc315337b5ad519ec592
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x3fd728e890970bc4501d7ae022884bd3845555120798d9a11e4ff43e3b40c521
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x31c246341ba23ed834987fa8494c7118a83ca625186525227758d7ee89421a48
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_permutation_bls12_381_t12() {
+    let env = Env::default();
+
+    // Input: [0, 1, ..., 11]
+    let mut input = vec![&env];
+    for i in 0..12 {
+        input.push_back(U256::from_u32(&env, i));
+    }
+
+    // Get parameters
+    let field = Symbol::new(&env, "BLS12_381");
+    let t = 12u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_12);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_12, 12);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
+
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
+
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x41ba1052b61408439c36739482b794b21afe4cd9b8602d5b199300f5a47a75a7
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x439df53d3ab9aca744628dab1b924d53a14ba8378422800f535136041460026d
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x554a29ab030c5f335f20445dd17dde18d1154b790ea9b86ac59f890989c8f99b
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x36072da8d1452cf24d092cfaf9ffed6858cc12c8c786f073be60e101dd754c77
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2b6efd8e6ddea2fec767a4ee2a3c58da32273156e860049064d3a6e7cfda6d7b
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x00a267ef617742623706fc03b35f2e0d2bacbf295a9e96067014c6f91f27cfbc
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x357ad832edefce1233d94285cd9c0ba686f51276bdef7f05e6e4cc58e61cccce
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x39901111fc5a65a8ca7d1c33d7dbaa9bff94710cc2f79160d9f0918d1017cc36
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x3d056868911036dd13eacc8faa1c553bcf31d2e6ac450f4fe3a5b4767b298ba8
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x5bbff091d21a637f4c663af80179c0f0dd47cb2efa13db7068451f030c7d082e
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x6e0c8c5bad849331f80708c161e2e8a279b8e071bd9fab8bffd189b325297f8a
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x5f7e7d1dfa5f7a8479a1b3695a5e8e58445890392ba0241959b2a432f306161f
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_permutation_bls12_381_t16() {
+    let env = Env::default();
+
+    // Input: [0, 1, ..., 15]
+    let mut input = vec![&env];
+    for i in 0..16 {
+        input.push_back(U256::from_u32(&env, i));
+    }
+
+    // Get parameters
+    let field = Symbol::new(&env, "BLS12_381");
+    let t = 16u32;
+    let m_diag = u256_vec(&env, MAT_DIAG_BLS12_381_T_16);
+    let rc = u256_matrix(&env, RC_BLS12_381_T_16, 16);
+    let d = SBOX_D;
+    let rounds_f = 8;
+    let rounds_p = 57;
+
+    // Call the permutation
+    let result = env
+        .crypto_hazmat()
+        .poseidon2_permutation(&input, field, t, d, rounds_f, rounds_p, &m_diag, &rc);
+
+    // Expected output (from the reference sage implementation)
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x463f84707d0cf95635b0d4052e1fccd7ee18f9f4486b45d4dc16b4f58b5a55c8
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x506ed18db741103618d3a35f7ded618e0f6cb5be8ce78358f696b73077795a56
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x077959f5da50a125950fdc5a3d70e8910e43933c7ea91cfe914605b85b64aad2
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x22f6cf8a2773870efac8e099429f311277ba37424b27491d0b27592df34b5d88
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x15159f5211e7804f2f128c8cf8cb9bd1ba467b2bea7f49b516978efa3b65e59e
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x59305fa19d76d3f6a9ce3868911d924dabfcd5d07fcb9322649b8e08ee7932c8
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x4942106777f3dc27433fcefdfc83f6b3632574cc9d8c0203bfe4c7f6b5528889
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x13b8a27b12b45c2eabc7a317ee1f2da9d6b93eb8f9b7d904c0b5f619b8f97841
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x55c731dd397442ae0224928cc6e3f6236b601590e96222b936e51f2074ab924f
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2c2fee65e80db647c625f9ab67ed356889c890ca935d7a5548918c1fd226c6f5
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2236faf675150c54074ef57c33fdd9e579c93ff037f7192e76bb7028f4ccbe89
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0803bb1999454ef437de3648c945541ee02982504b510a99415c5659f89b9466
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1403f911876c7fc2e0fef7b88146205ed5d674f5e52f74fab24de0d2e3fc0157
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x04c1c476b8fd006a2fd11b5e40a2384ca1b876e9560cfcdea580c7231ae42fd1
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x530789ae635151cd5fa61f421196a655734e1ce9c16a07d9230aebe3160d43f4
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0fc01eb625e9bd3054b607501e88c91287b7843456b4f47032b7f4b928601d96
+            )
+            .into(),
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+// ============================================================================
+// Tests for Poseidon2Sponge::compute_hash
+// ============================================================================
+
+#[test]
+fn test_poseidon2_sponge_compute_hash_bn254_t4() {
+    let env = Env::default();
+
+    // 3 inputs fit in rate=3 for t=4
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    // Result should be deterministic - hash again and compare
+    let result2 = sponge.compute_hash(&inputs);
+    assert_eq!(result, result2);
+}
+
+#[test]
+fn test_poseidon2_sponge_compute_hash_bn254_t3() {
+    let env = Env::default();
+
+    // 2 inputs fit in rate=2 for t=3
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    // Result should be deterministic
+    let result2 = sponge.compute_hash(&inputs);
+    assert_eq!(result, result2);
+}
+
+#[test]
+fn test_poseidon2_sponge_compute_hash_bn254_t2() {
+    let env = Env::default();
+
+    // 1 input fits in rate=1 for t=2
+    let inputs = vec![&env, U256::from_u32(&env, 1)];
+
+    let mut sponge = Poseidon2Sponge::<2, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    // Result should be deterministic
+    let result2 = sponge.compute_hash(&inputs);
+    assert_eq!(result, result2);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_sponge_compute_hash_bls12_381_t4() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    // Result should be deterministic
+    let result2 = sponge.compute_hash(&inputs);
+    assert_eq!(result, result2);
+}
+
+// ============================================================================
+// Tests for poseidon2_hash top-level function
+// ============================================================================
+
+#[test]
+fn test_poseidon2_hash_bn254_t4() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    // Top-level function should work
+    let result = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
+
+    // Should match sponge directly
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let sponge_result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, sponge_result);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_hash_bls12_381_t4() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let result = poseidon2_hash::<4, Bls12381Fr>(&env, &inputs);
+
+    // Should match sponge directly
+    let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::new(&env);
+    let sponge_result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, sponge_result);
+}
+
+#[test]
+fn test_poseidon2_hash_bn254_t2() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1)];
+
+    let result = poseidon2_hash::<2, Bn254Fr>(&env, &inputs);
+
+    let mut sponge = Poseidon2Sponge::<2, Bn254Fr>::new(&env);
+    let sponge_result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, sponge_result);
+}
+
+#[test]
+fn test_poseidon2_hash_bn254_t3() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let result = poseidon2_hash::<3, Bn254Fr>(&env, &inputs);
+
+    let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::new(&env);
+    let sponge_result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, sponge_result);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_hash_bn254_t8() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 7] fills the rate of t=8
+    let mut inputs = vec![&env];
+    for i in 1..8 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x1319ae47fc8f2799259d99273895f97a5669c28a0781258786fbbc5d8ebb480f
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<8, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<8, Bn254Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_hash_bn254_t12() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 11] fills the rate of t=12
+    let mut inputs = vec![&env];
+    for i in 1..12 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x29425dc76ca7377d9f60f088ce93fc3d18a7ba31b02b8bd3851d9b571ab02e7a
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<12, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<12, Bn254Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-wide")]
+fn test_poseidon2_hash_bn254_t16() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 15] fills the rate of t=16
+    let mut inputs = vec![&env];
+    for i in 1..16 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x2039bb445ac65a7810fe81c9bf351486ef2fc80f01a5026be38e2261f31fe4de
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<16, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<16, Bn254Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_hash_bls12_381_t8() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 7] fills the rate of t=8
+    let mut inputs = vec![&env];
+    for i in 1..8 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x4491a977436162a64e5aad738a0c28100ae1d5c363dc2ba5c70052d99857cf78
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<8, Bls12381Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<8, Bls12381Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_hash_bls12_381_t12() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 11] fills the rate of t=12
+    let mut inputs = vec![&env];
+    for i in 1..12 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x25a8835db20ef3c409472e22c550b3cc8a92aba37d3196cfe1db9ed3be35056f
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<12, Bls12381Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<12, Bls12381Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+#[test]
+#[cfg(all(feature = "poseidon2-bls12-381", feature = "poseidon2-wide"))]
+fn test_poseidon2_hash_bls12_381_t16() {
+    let env = Env::default();
+
+    // Input: [1, 2, ..., 15] fills the rate of t=16
+    let mut inputs = vec![&env];
+    for i in 1..16 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x23f5bec02bbc3496a22f68a6e28727ff966e1e4161554522b3db49b59ae67a5a
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<16, Bls12381Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+
+    let mut sponge = Poseidon2Sponge::<16, Bls12381Fr>::new(&env);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+// ============================================================================
+// Tests for sponge reuse (repeated hashing)
+// ============================================================================
+
+#[test]
+fn test_poseidon2_sponge_reuse() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+
+    let inputs1 = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+    let inputs2 = vec![
+        &env,
+        U256::from_u32(&env, 4),
+        U256::from_u32(&env, 5),
+        U256::from_u32(&env, 6),
+    ];
+
+    // First hash
+    let result1 = sponge.compute_hash(&inputs1);
+
+    // Second hash - should be independent (different inputs, different result)
+    let result2 = sponge.compute_hash(&inputs2);
+    assert_ne!(result1, result2);
+
+    // Hash inputs1 again - should get the same result as the first time
+    let result1_again = sponge.compute_hash(&inputs1);
+    assert_eq!(result1, result1_again);
+}
+
+#[test]
+fn test_poseidon2_hash_batch() {
+    let env = Env::default();
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+
+    // Messages of different lengths, including one spanning two chunks
+    let batch = vec![
+        &env,
+        vec![
+            &env,
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+            U256::from_u32(&env, 3),
+        ],
+        vec![&env, U256::from_u32(&env, 4)],
+        vec![
+            &env,
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+            U256::from_u32(&env, 3),
+            U256::from_u32(&env, 4),
+            U256::from_u32(&env, 5),
+        ],
+    ];
+    let hashes = sponge.compute_hash_batch(&batch);
+    assert_eq!(hashes.len(), 3);
+    for (message, hash) in batch.iter().zip(hashes.iter()) {
+        assert_eq!(hash, poseidon2_hash::<4, Bn254Fr>(&env, &message));
+    }
+
+    // An empty batch hashes nothing
+    assert_eq!(sponge.compute_hash_batch(&vec![&env]).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_poseidon2_hash_batch_exceeds_modulus_panics() {
+    let env = Env::default();
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let modulus = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+        )
+        .into(),
+    );
+    let batch = vec![
+        &env,
+        vec![&env, U256::from_u32(&env, 1)],
+        vec![&env, modulus],
+    ];
+    sponge.compute_hash_batch(&batch);
+}
+
+#[test]
+fn test_poseidon2_sponge_matches_hash_function() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    // Using top-level function
+    let hash_result = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
+
+    // Using sponge directly
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let sponge_result = sponge.compute_hash(&inputs);
+
+    assert_eq!(hash_result, sponge_result);
+}
+
+// ============================================================================
+// Partial rate tests (inputs.len() < RATE)
+// ============================================================================
+
+// Test hashing 1 input with T=4 (rate=3) - partial rate usage
+// Reference: noir circuit hash([1]) with message_size=1
+#[test]
+fn test_poseidon2_bn254_partial_rate_t4_1_input() {
+    let env = Env::default();
+
+    // 1 input with T=4 (rate=3) - only 1/3 of the rate is used
+    let inputs = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0000000000000000000000000000000000000000000000000000000000000001
+            )
+            .into(),
+        ),
+    ];
+
+    // Expected from noir circuit: hash([1], 1)
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x168758332d5b3e2d13be8048c8011b454590e06c44bce7f702f09103eef5a373
+        )
+        .into(),
+    );
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// Test hashing 2 inputs with T=4 (rate=3) - partial rate usage
+// Reference: noir circuit hash([1, 2]) with message_size=2
+#[test]
+fn test_poseidon2_bn254_partial_rate_t4_2_inputs() {
+    let env = Env::default();
+
+    // 2 inputs with T=4 (rate=3) - 2/3 of the rate is used
+    let inputs = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0000000000000000000000000000000000000000000000000000000000000001
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0000000000000000000000000000000000000000000000000000000000000002
+            )
+            .into(),
+        ),
+    ];
+
+    // Expected from noir circuit: hash([1, 2], 2)
     let expected = U256::from_be_bytes(
         &env,
         &bytesn!(
@@ -842,27 +1852,268 @@ fn test_poseidon2_bn254_partial_rate_t4_2_inputs() {
     );
 
     let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
-    let result = sponge.compute_hash(&inputs);
+    let result = sponge.compute_hash(&inputs);
+
+    assert_eq!(result, expected);
+}
+
+// ============================================================================
+// Multi-block absorption tests (inputs.len() > RATE)
+// ============================================================================
+
+// Reference: noir `Poseidon2::hash([1, 2, 3, 4, 5, 6, 7], 7)`
+#[test]
+fn test_poseidon2_bn254_multi_block_t4_7_inputs() {
+    let env = Env::default();
+
+    // 7 inputs with t=4 (rate=3) - two full chunks plus one partial chunk
+    let mut inputs = vec![&env];
+    for i in 1..=7 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x16f929bc0d216df4b05bdc44222463edf2b9791bd949ab926eebda06a502d238
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<4, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+}
+
+// Reference: noir `Poseidon2::hash([1, 2, 3, 4, 5, 6], 6)`
+#[test]
+fn test_poseidon2_bn254_multi_block_t4_6_inputs() {
+    let env = Env::default();
+
+    // 6 inputs with t=4 (rate=3) - exactly two full chunks
+    let mut inputs = vec![&env];
+    for i in 1..=6 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x07f57fcda925c06dc0a311f3f17fa0218e079b514552744a25ba8a74ee8c9e7a
+        )
+        .into(),
+    );
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let result = sponge.compute_hash(&inputs);
+    assert_eq!(result, expected);
+
+    // The sponge must reset between multi-block hashes
+    let result_again = sponge.compute_hash(&inputs);
+    assert_eq!(result, result_again);
+}
+
+#[test]
+fn test_poseidon2_bn254_multi_block_t3_5_inputs() {
+    let env = Env::default();
+
+    // 5 inputs with t=3 (rate=2)
+    let mut inputs = vec![&env];
+    for i in 1..=5 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x2bccb67e442fa0985c8b374ae03ab4ec838eb46c9a945656be2938b6293d5bf8
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<3, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_poseidon2_bn254_multi_block_t2_3_inputs() {
+    let env = Env::default();
+
+    // 3 inputs with t=2 (rate=1) - one permutation per input
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x08972d4863f9d027f5663520ac7ed5c1141ddf7a76efe1ff0be68900fa16fb4a
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<2, Bn254Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_bls12_381_multi_block_t4_5_inputs() {
+    let env = Env::default();
+
+    // 5 inputs with t=4 (rate=3)
+    let mut inputs = vec![&env];
+    for i in 1..=5 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let expected = U256::from_be_bytes(
+        &env,
+        &bytesn!(
+            &env,
+            0x4a5a51e580020d6ef9a5f37cb37e4e2af62bdd0f77030b744668863830c4323a
+        )
+        .into(),
+    );
+
+    let result = poseidon2_hash::<4, Bls12381Fr>(&env, &inputs);
+    assert_eq!(result, expected);
+}
+
+// ============================================================================
+// Streaming API tests
+// ============================================================================
+
+#[test]
+fn test_poseidon2_streaming_matches_compute_hash() {
+    let env = Env::default();
+
+    let mut inputs = vec![&env];
+    for i in 1..=7 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let expected = sponge.compute_hash(&inputs);
+
+    // One element at a time, crossing chunk boundaries
+    sponge.start(inputs.len());
+    for v in inputs.iter() {
+        sponge.absorb_one(v);
+    }
+    assert_eq!(sponge.finalize(), expected);
+
+    // Mixed absorb_one / absorb_many with chunk-unaligned splits
+    sponge.start(7);
+    sponge.absorb_many(&vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ]);
+    sponge.absorb_one(U256::from_u32(&env, 3));
+    sponge.absorb_one(U256::from_u32(&env, 4));
+    sponge.absorb_many(&vec![
+        &env,
+        U256::from_u32(&env, 5),
+        U256::from_u32(&env, 6),
+        U256::from_u32(&env, 7),
+    ]);
+    assert_eq!(sponge.finalize(), expected);
+}
+
+#[test]
+fn test_poseidon2_streaming_empty_message() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let expected = sponge.compute_hash(&vec![&env]);
+
+    // A fresh (or finalized) sponge is ready to hash the empty message
+    assert_eq!(sponge.finalize(), expected);
+    assert_eq!(sponge.finalize(), expected);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: absorbed more inputs than the declared message length")]
+fn test_poseidon2_streaming_exceeds_declared_length() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    sponge.start(1);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    sponge.absorb_one(U256::from_u32(&env, 2)); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: absorbed more inputs than the declared message length")]
+fn test_poseidon2_streaming_requires_start() {
+    let env = Env::default();
+
+    // Without `start`, the declared message length is 0
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    sponge.absorb_one(U256::from_u32(&env, 1)); // Should panic
+}
 
-    assert_eq!(result, expected);
+#[test]
+#[should_panic(expected = "Poseidon2: absorbed fewer inputs than the declared message length")]
+fn test_poseidon2_streaming_below_declared_length() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    sponge.start(3);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    let _ = sponge.finalize(); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: cannot absorb after squeeze")]
+fn test_poseidon2_absorb_after_squeeze() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    sponge.start(1);
+    sponge.absorb_one(U256::from_u32(&env, 1));
+    let _ = sponge.squeeze();
+    sponge.absorb_one(U256::from_u32(&env, 2)); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: sponge already squeezed")]
+fn test_poseidon2_squeeze_twice() {
+    let env = Env::default();
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let _ = sponge.squeeze();
+    let _ = sponge.squeeze(); // Should panic
 }
 
 // ============================================================================
 // Failure mode tests
 // ============================================================================
 
+// An out-of-range input in a later chunk must still be rejected
 #[test]
-#[should_panic(expected = "Poseidon2: inputs.len() must not exceed rate (T - 1)")]
-fn test_poseidon2_sponge_inputs_exceed_rate_t4() {
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_poseidon2_bn254_multi_block_input_exceeds_modulus() {
     let env = Env::default();
 
-    // t=4 means rate=3, so 4 inputs should panic
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
     let inputs = vec![
         &env,
         U256::from_u32(&env, 1),
         U256::from_u32(&env, 2),
         U256::from_u32(&env, 3),
-        U256::from_u32(&env, 4),
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
     ];
 
     let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
@@ -939,6 +2190,7 @@ fn test_poseidon2_bn254_input_below_modulus_accepted() {
 // BLS12-381 modulus = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
 #[test]
 #[should_panic(expected = "input exceeds field modulus")]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_bls12_381_input_exceeds_modulus() {
     let env = Env::default();
 
@@ -961,6 +2213,7 @@ fn test_poseidon2_bls12_381_input_exceeds_modulus() {
 // Test that a value exactly equal to the BLS12-381 modulus is rejected
 #[test]
 #[should_panic(expected = "input exceeds field modulus")]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_bls12_381_input_equals_modulus() {
     let env = Env::default();
 
@@ -977,6 +2230,7 @@ fn test_poseidon2_bls12_381_input_equals_modulus() {
 
 // Test that values just below the BLS12-381 modulus are accepted
 #[test]
+#[cfg(feature = "poseidon2-bls12-381")]
 fn test_poseidon2_bls12_381_input_below_modulus_accepted() {
     let env = Env::default();
 
@@ -1024,3 +2278,344 @@ fn test_poseidon2_bn254_empty_inputs() {
     // Verify domain separation: hash([]) != hash([0])
     assert_ne!(empty_hash, zero_hash);
 }
+
+// ============================================================================
+// Tests for poseidon2_compress (truncated permutation with feed-forward)
+// ============================================================================
+
+// compress([a, b]) = P(a || b)[0] + a
+#[test]
+fn test_poseidon2_compress_bn254_t2() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0e90c132311e864e0c8bca37976f28579a2dd9436bbc11326e21ec7c00cea5b3
+            )
+            .into(),
+        ),
+    ];
+
+    let result = poseidon2_compress::<2, Bn254Fr>(&env, &inputs, 1);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_compress_bls12_381_t2() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x4d2a9e26a168583c5cc2cdbf8db89e0b817705325e107b415dc2bea07001136b
+            )
+            .into(),
+        ),
+    ];
+
+    let result = poseidon2_compress::<2, Bls12381Fr>(&env, &inputs, 1);
+    assert_eq!(result, expected);
+}
+
+// With n_outs = T, the output is the full permuted state plus the input, so it
+// can be checked against the permutation vector of
+// test_poseidon2_permutation_bn254_t4.
+#[test]
+fn test_poseidon2_compress_bn254_t4_matches_permutation() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let permuted = env.crypto_hazmat().poseidon2_permutation(
+        &inputs,
+        Symbol::new(&env, "BN254"),
+        4,
+        SBOX_D,
+        8,
+        56,
+        &u256_vec(&env, MAT_DIAG_BN254_T_4),
+        &u256_matrix(&env, RC_BN254_T_4, 4),
+    );
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let result = sponge.compute_compress(&inputs, 4);
+
+    assert_eq!(result.len(), 4);
+    for i in 0..4 {
+        assert_eq!(
+            result.get_unchecked(i),
+            permuted.get_unchecked(i).add(&inputs.get_unchecked(i))
+        );
+    }
+
+    // Truncation returns a prefix of the same outputs
+    assert_eq!(sponge.compute_compress(&inputs, 2), result.slice(0..2));
+}
+
+#[test]
+fn test_poseidon2_compress_bn254_t3_2_outputs() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x0a799a621cac2cea1ec6fdbcd5dc92cadd31c210c912aaca009aca578d210769
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1570f61795255f02ce99b299edfd70fad14b1f5c6b1856a12191be0e1598876c
+            )
+            .into(),
+        ),
+    ];
+
+    let result = poseidon2_compress::<3, Bn254Fr>(&env, &inputs, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: compress takes exactly T inputs")]
+fn test_poseidon2_compress_wrong_input_count() {
+    let env = Env::default();
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    poseidon2_compress::<4, Bn254Fr>(&env, &inputs, 1);
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: n_outs must be between 1 and T")]
+fn test_poseidon2_compress_zero_outputs() {
+    let env = Env::default();
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    poseidon2_compress::<2, Bn254Fr>(&env, &inputs, 0);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_poseidon2_compress_input_exceeds_modulus() {
+    let env = Env::default();
+
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
+    ];
+    poseidon2_compress::<2, Bn254Fr>(&env, &inputs, 1);
+}
+
+// ============================================================================
+// Tests for poseidon2_permutation (raw permutation)
+// ============================================================================
+
+// Same vector as test_poseidon2_permutation_bn254_t4, through the public API
+#[test]
+fn test_poseidon2_permutation_public_api_bn254_t4() {
+    let env = Env::default();
+
+    let state = vec![
+        &env,
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let host = env.crypto_hazmat().poseidon2_permutation(
+        &state,
+        Symbol::new(&env, "BN254"),
+        4,
+        SBOX_D,
+        8,
+        56,
+        &u256_vec(&env, MAT_DIAG_BN254_T_4),
+        &u256_matrix(&env, RC_BN254_T_4, 4),
+    );
+
+    let result = poseidon2_permutation::<4, Bn254Fr>(&env, &state);
+    assert_eq!(result, host);
+    assert_eq!(
+        result.get_unchecked(0),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x01bd538c2ee014ed5141b29e9ae240bf8db3fe5b9a38629a9647cf8d76c01737
+            )
+            .into(),
+        )
+    );
+}
+
+// The permutation of [0, 0, 0, 0] is the hash of the empty message
+#[test]
+fn test_poseidon2_permutation_sponge_reuse() {
+    let env = Env::default();
+
+    let zeros = vec![
+        &env,
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 0),
+        U256::from_u32(&env, 0),
+    ];
+
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    let permuted = sponge.compute_permutation(&zeros);
+    assert_eq!(sponge.compute_hash(&vec![&env]), permuted.get_unchecked(0));
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: permutation state must have exactly T elements")]
+fn test_poseidon2_permutation_wrong_state_size() {
+    let env = Env::default();
+    let state = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    poseidon2_permutation::<4, Bn254Fr>(&env, &state);
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_permutation_state_exceeds_modulus() {
+    let env = Env::default();
+
+    let bls_modulus = bytesn!(
+        &env,
+        0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+    );
+
+    let state = vec![
+        &env,
+        U256::from_be_bytes(&env, &bls_modulus.into()),
+        U256::from_u32(&env, 0),
+    ];
+    poseidon2_permutation::<2, Bls12381Fr>(&env, &state);
+}
+
+// ============================================================================
+// Tests for explicit / stored parameter sets
+// ============================================================================
+
+#[test]
+#[cfg(feature = "poseidon2-bls12-381")]
+fn test_poseidon2_from_params_matches_new() {
+    let env = Env::default();
+
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+    ];
+
+    let mut sponge = Poseidon2Sponge::<4, Bls12381Fr>::new(&env);
+    let params = sponge.params().clone();
+
+    let mut from_params = Poseidon2Sponge::<4, Bls12381Fr>::from_params(&env, params);
+    assert_eq!(
+        from_params.compute_hash(&inputs),
+        sponge.compute_hash(&inputs)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Poseidon2: parameters do not match the state size T")]
+fn test_poseidon2_from_params_wrong_size() {
+    let env = Env::default();
+
+    // t=4 parameters used for a t=3 sponge
+    let params = Poseidon2Sponge::<4, Bn254Fr>::new(&env).params().clone();
+    let _ = Poseidon2Sponge::<3, Bn254Fr>::from_params(&env, params);
+}
+
+// ============================================================================
+// Tests for the fallible API
+// ============================================================================
+
+#[test]
+fn test_try_poseidon2_hash_matches_poseidon2_hash() {
+    let env = Env::default();
+
+    // Any input length is accepted
+    let inputs = vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+        U256::from_u32(&env, 3),
+        U256::from_u32(&env, 4),
+    ];
+    assert_eq!(
+        try_poseidon2_hash::<4, Bn254Fr>(&env, &inputs),
+        Ok(poseidon2_hash::<4, Bn254Fr>(&env, &inputs))
+    );
+}
+
+#[test]
+fn test_try_poseidon2_hash_input_not_in_field() {
+    let env = Env::default();
+
+    let inputs = vec![&env, U256::from_u32(&env, 1), Bn254Fr::modulus(&env)];
+    assert_eq!(
+        try_poseidon2_hash::<4, Bn254Fr>(&env, &inputs),
+        Err(PoseidonError::InputNotInField)
+    );
+
+    // The sponge is still usable after the error
+    let mut sponge = Poseidon2Sponge::<4, Bn254Fr>::new(&env);
+    assert_eq!(
+        sponge.try_compute_hash(&inputs),
+        Err(PoseidonError::InputNotInField)
+    );
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+    assert_eq!(
+        sponge.try_compute_hash(&inputs),
+        Ok(poseidon2_hash::<4, Bn254Fr>(&env, &inputs))
+    );
+}
+
+#[test]
+fn test_poseidon2_try_from_params() {
+    let env = Env::default();
+
+    let params = Poseidon2Sponge::<4, Bn254Fr>::new(&env).params().clone();
+    assert!(Poseidon2Sponge::<4, Bn254Fr>::try_from_params(&env, params.clone()).is_ok());
+    assert!(matches!(
+        Poseidon2Sponge::<3, Bn254Fr>::try_from_params(&env, params),
+        Err(PoseidonError::UnsupportedConfig)
+    ));
+}
@@ -0,0 +1,302 @@
+use crate::{Poseidon2Sponge, PoseidonSponge, SafeCall, SafeSponge};
+use soroban_sdk::{
+    bytesn,
+    crypto::{bls12_381::Bls12381Fr, bn254::Bn254Fr},
+    vec, Bytes, Env, U256,
+};
+
+// SAFE tests
+//
+// Expected values are computed with a Python model of the SAFE construction
+// (tag = first 128 bits of SHA-256 over the big-endian encoded IO pattern
+// followed by the domain separator) on top of the reference permutations.
+
+// ============================================================================
+// Known-answer tests
+// ============================================================================
+
+#[test]
+fn test_safe_poseidon_bn254_t3() {
+    let env = Env::default();
+    let domain = Bytes::from_slice(&env, b"soroban-poseidon");
+    let pattern = [SafeCall::Absorb(3), SafeCall::Squeeze(2)];
+
+    let mut safe = SafeSponge::start(PoseidonSponge::<3, Bn254Fr>::new(&env), &pattern, &domain);
+    // 3 inputs with rate=2 spans two permutations
+    safe.absorb(&vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ]);
+    safe.absorb(&vec![&env, U256::from_u32(&env, 3)]);
+    let result = safe.squeeze(2);
+    safe.finish();
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x17a8408d1c40c482068187a41db39d2ba13f3c14295ad23a6ed0f78ad3093cd4
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x1fdacfc6ee3d5050c5a48d336045d4388eafa8e204cce59598ba6c7faddcffe2
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_safe_poseidon2_bn254_t4() {
+    let env = Env::default();
+    let domain = Bytes::from_slice(&env, b"soroban-poseidon");
+    let pattern = [SafeCall::Absorb(5), SafeCall::Squeeze(1)];
+
+    let mut inputs = vec![&env];
+    for i in 1..=5 {
+        inputs.push_back(U256::from_u32(&env, i));
+    }
+
+    let mut safe = SafeSponge::start(Poseidon2Sponge::<4, Bn254Fr>::new(&env), &pattern, &domain);
+    safe.absorb(&inputs);
+    let result = safe.squeeze(1);
+    safe.finish();
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x086673e5b7536b4a256c36284ea372bca4b16fae6247cc9e1ae87da99be136a6
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_safe_poseidon2_bls12_381_t3() {
+    let env = Env::default();
+    let domain = Bytes::from_slice(&env, b"soroban-poseidon");
+    let pattern = [SafeCall::Absorb(2), SafeCall::Squeeze(3)];
+
+    let mut safe = SafeSponge::start(
+        Poseidon2Sponge::<3, Bls12381Fr>::new(&env),
+        &pattern,
+        &domain,
+    );
+    safe.absorb(&vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ]);
+    // 3 outputs with rate=2 requires a second permutation
+    let result = safe.squeeze(3);
+    safe.finish();
+
+    let expected = vec![
+        &env,
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x04a249d12dbfab3ca174bb92a9203a4bd7e36501e57e9630b9f2787af411e1a0
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x2644b393e168858b7d10e9fdb28504976eb05b2529a202ddeed7714e73dc3853
+            )
+            .into(),
+        ),
+        U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x59b5d354e976b4687776148c2a505aea23df30ca16c9c0b845a6e6e5beb57ba4
+            )
+            .into(),
+        ),
+    ];
+    assert_eq!(result, expected);
+}
+
+// ============================================================================
+// Domain separation tests
+// ============================================================================
+
+fn safe_first_output(env: &Env, pattern: &[SafeCall], domain: &Bytes, n_outs: u32) -> U256 {
+    let inputs = vec![env, U256::from_u32(env, 1), U256::from_u32(env, 2)];
+    let mut safe = SafeSponge::start(PoseidonSponge::<3, Bn254Fr>::new(env), pattern, domain);
+    safe.absorb(&inputs);
+    let out = safe.squeeze(n_outs).get_unchecked(0);
+    safe.finish();
+    out
+}
+
+#[test]
+fn test_safe_domain_and_pattern_separation() {
+    let env = Env::default();
+    let domain_a = Bytes::from_slice(&env, b"domain-a");
+    let domain_b = Bytes::from_slice(&env, b"domain-b");
+    let pattern = [SafeCall::Absorb(2), SafeCall::Squeeze(1)];
+
+    let base = safe_first_output(&env, &pattern, &domain_a, 1);
+
+    // Deterministic
+    assert_eq!(base, safe_first_output(&env, &pattern, &domain_a, 1));
+
+    // Consecutive calls of the same kind are aggregated into the same pattern
+    let split = [
+        SafeCall::Absorb(1),
+        SafeCall::Absorb(1),
+        SafeCall::Squeeze(1),
+    ];
+    assert_eq!(base, safe_first_output(&env, &split, &domain_a, 1));
+
+    // Different domain separator
+    assert_ne!(base, safe_first_output(&env, &pattern, &domain_b, 1));
+
+    // Different IO pattern
+    let longer = [SafeCall::Absorb(2), SafeCall::Squeeze(2)];
+    assert_ne!(base, safe_first_output(&env, &longer, &domain_a, 2));
+}
+
+#[test]
+fn test_safe_finish_returns_reusable_sponge() {
+    let env = Env::default();
+    let domain = Bytes::from_slice(&env, b"soroban-poseidon");
+    let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+    let mut safe = SafeSponge::start(
+        PoseidonSponge::<3, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(2), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    safe.absorb(&inputs);
+    let _ = safe.squeeze(1);
+    let mut sponge = safe.finish();
+
+    // The SAFE tag does not leak into regular hashing
+    let expected = PoseidonSponge::<3, Bn254Fr>::new(&env).compute_hash(&inputs);
+    assert_eq!(sponge.compute_hash(&inputs), expected);
+}
+
+// ============================================================================
+// Misuse tests
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "SAFE: call does not match the IO pattern")]
+fn test_safe_absorb_exceeds_pattern() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let mut safe = SafeSponge::start(
+        PoseidonSponge::<3, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(1), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    safe.absorb(&vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "SAFE: call does not match the IO pattern")]
+fn test_safe_squeeze_before_absorb() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let mut safe = SafeSponge::start(
+        Poseidon2Sponge::<4, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(1), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    let _ = safe.squeeze(1);
+}
+
+#[test]
+#[should_panic(expected = "SAFE: call does not match the IO pattern")]
+fn test_safe_call_after_pattern_consumed() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let mut safe = SafeSponge::start(
+        Poseidon2Sponge::<4, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(1), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    safe.absorb(&vec![&env, U256::from_u32(&env, 1)]);
+    let _ = safe.squeeze(1);
+    let _ = safe.squeeze(1);
+}
+
+#[test]
+#[should_panic(expected = "SAFE: IO pattern not completed")]
+fn test_safe_finish_before_pattern_consumed() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let mut safe = SafeSponge::start(
+        Poseidon2Sponge::<4, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(2), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    safe.absorb(&vec![
+        &env,
+        U256::from_u32(&env, 1),
+        U256::from_u32(&env, 2),
+    ]);
+    safe.finish();
+}
+
+#[test]
+#[should_panic(expected = "SAFE: IO pattern lengths must be in 1..2^31")]
+fn test_safe_rejects_zero_length_call() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let _ = SafeSponge::start(
+        PoseidonSponge::<3, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(0), SafeCall::Squeeze(1)],
+        &domain,
+    );
+}
+
+#[test]
+#[should_panic(expected = "input exceeds field modulus")]
+fn test_safe_input_exceeds_modulus() {
+    let env = Env::default();
+    let domain = Bytes::new(&env);
+
+    let bn254_modulus = bytesn!(
+        &env,
+        0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    );
+
+    let mut safe = SafeSponge::start(
+        PoseidonSponge::<3, Bn254Fr>::new(&env),
+        &[SafeCall::Absorb(1), SafeCall::Squeeze(1)],
+        &domain,
+    );
+    safe.absorb(&vec![
+        &env,
+        U256::from_be_bytes(&env, &bn254_modulus.into()),
+    ]);
+}
@@ -1,13 +1,23 @@
 #![no_std]
 
-use soroban_poseidon::{poseidon2_hash, Poseidon2Sponge};
-use soroban_sdk::{contract, contractimpl, crypto::bn254::Bn254Fr, vec, Env, U256};
+use soroban_poseidon::{poseidon2_hash, Poseidon2Params, Poseidon2Sponge};
+use soroban_sdk::{
+    contract, contractimpl, crypto::bn254::Bn254Fr, symbol_short, vec, Address, Env, Symbol, U256,
+};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PARAMS: Symbol = symbol_short!("PARAMS");
 
 #[contract]
 pub struct Poseidon2Contract;
 
 #[contractimpl]
 impl Poseidon2Contract {
+    /// Sets the admin allowed to install the stored parameter set.
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN, &admin);
+    }
+
     /// Computes a Poseidon2 hash of [a, b] using the top-level function.
     /// Uses t=3 (rate=2) to hash 2 inputs.
     pub fn hash_two(env: Env, a: U256, b: U256) -> U256 {
@@ -27,18 +37,34 @@ impl Poseidon2Contract {
         let inputs = vec![&env, a, b, c];
         poseidon2_hash::<4, Bn254Fr>(&env, &inputs)
     }
+
+    /// Validates and stores a t=3 parameter set in persistent storage. Only
+    /// the admin can call it, and only once.
+    pub fn set_params(env: Env, params: Poseidon2Params) {
+        let admin: Address = env.storage().instance().get(&ADMIN).expect("admin not set");
+        admin.require_auth();
+        Poseidon2Sponge::<3, Bn254Fr>::from_params(&env, params).store_params(&PARAMS);
+    }
+
+    /// Computes a Poseidon2 hash of [a, b] with the stored parameter set.
+    pub fn hash_two_stored(env: Env, a: U256, b: U256) -> U256 {
+        let mut sponge = Poseidon2Sponge::<3, Bn254Fr>::from_storage(&env, &PARAMS);
+        let inputs = vec![&env, a, b];
+        sponge.compute_hash(&inputs)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use soroban_sdk::{Env, U256};
+    use soroban_poseidon::Poseidon2Sponge;
+    use soroban_sdk::{crypto::bn254::Bn254Fr, testutils::Address as _, Address, Env, U256};
 
     use crate::{Poseidon2Contract, Poseidon2ContractClient};
 
     #[test]
     fn test_hash_two() {
         let env = Env::default();
-        let contract_id = env.register(Poseidon2Contract, ());
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
         let client = Poseidon2ContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
@@ -54,7 +80,7 @@ mod test {
     #[test]
     fn test_hash_two_sponge() {
         let env = Env::default();
-        let contract_id = env.register(Poseidon2Contract, ());
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
         let client = Poseidon2ContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
@@ -75,7 +101,7 @@ mod test {
     #[test]
     fn test_hash_three() {
         let env = Env::default();
-        let contract_id = env.register(Poseidon2Contract, ());
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
         let client = Poseidon2ContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
@@ -89,10 +115,50 @@ mod test {
         assert_eq!(hash, hash2);
     }
 
+    #[test]
+    fn test_hash_two_stored() {
+        let env = Env::default();
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
+        let client = Poseidon2ContractClient::new(&env, &contract_id);
+
+        env.mock_all_auths();
+        // The parameters are built off-chain and passed in once
+        let params = Poseidon2Sponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+
+        let a = U256::from_u32(&env, 1);
+        let b = U256::from_u32(&env, 2);
+        assert_eq!(client.hash_two_stored(&a, &b), client.hash_two(&a, &b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_params_requires_admin() {
+        let env = Env::default();
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
+        let client = Poseidon2ContractClient::new(&env, &contract_id);
+
+        let params = Poseidon2Sponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_params_only_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
+        let client = Poseidon2ContractClient::new(&env, &contract_id);
+
+        let params = Poseidon2Sponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+        client.set_params(&params);
+    }
+
     #[test]
     fn test_hash_deterministic() {
         let env = Env::default();
-        let contract_id = env.register(Poseidon2Contract, ());
+        let contract_id = env.register(Poseidon2Contract, (Address::generate(&env),));
         let client = Poseidon2ContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 42);
@@ -108,7 +174,7 @@ mod test {
 /// WASM contract tests - imports the compiled WASM and tests it
 #[cfg(test)]
 mod wasm_test {
-    use soroban_sdk::{Env, U256};
+    use soroban_sdk::{testutils::Address as _, Address, Env, U256};
 
     mod poseidon2_contract {
         soroban_sdk::contractimport!(
@@ -121,7 +187,7 @@ mod wasm_test {
         let env = Env::default();
         env.cost_estimate().budget().reset_unlimited();
 
-        let contract_id = env.register(poseidon2_contract::WASM, ());
+        let contract_id = env.register(poseidon2_contract::WASM, (Address::generate(&env),));
         let client = poseidon2_contract::Client::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
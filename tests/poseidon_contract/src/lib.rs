@@ -1,13 +1,26 @@
 #![no_std]
 
-use soroban_poseidon::{poseidon_hash, PoseidonSponge};
-use soroban_sdk::{contract, contractimpl, crypto::bn254::Bn254Fr, vec, Env, U256};
+use soroban_poseidon::{
+    poseidon_hash, poseidon_hash_elements, Bn254FieldElement, PoseidonError, PoseidonParams,
+    PoseidonSponge,
+};
+use soroban_sdk::{
+    contract, contractimpl, crypto::bn254::Bn254Fr, symbol_short, vec, Address, Env, Symbol, U256,
+};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PARAMS: Symbol = symbol_short!("PARAMS");
 
 #[contract]
 pub struct PoseidonContract;
 
 #[contractimpl]
 impl PoseidonContract {
+    /// Sets the admin allowed to install the stored parameter set.
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN, &admin);
+    }
+
     /// Computes a Poseidon hash of [a, b] using the top-level function.
     pub fn hash_two(env: Env, a: U256, b: U256) -> U256 {
         let inputs = vec![&env, a, b];
@@ -20,18 +33,48 @@ impl PoseidonContract {
         let inputs = vec![&env, a, b];
         sponge.compute_hash(&inputs)
     }
+
+    /// Computes a Poseidon hash of [a, b], checking each input against the
+    /// modulus once when converting it.
+    pub fn hash_two_elements(
+        env: Env,
+        a: Bn254FieldElement,
+        b: Bn254FieldElement,
+    ) -> Result<Bn254FieldElement, PoseidonError> {
+        let inputs = [a.try_into()?, b.try_into()?];
+        Ok(poseidon_hash_elements::<3, Bn254Fr>(&env, &inputs).into())
+    }
+
+    /// Validates and stores a t=3 parameter set in persistent storage. Only
+    /// the admin can call it, and only once.
+    pub fn set_params(env: Env, params: PoseidonParams) {
+        let admin: Address = env.storage().instance().get(&ADMIN).expect("admin not set");
+        admin.require_auth();
+        PoseidonSponge::<3, Bn254Fr>::from_params(&env, params).store_params(&PARAMS);
+    }
+
+    /// Computes a Poseidon hash of [a, b] with the stored parameter set.
+    pub fn hash_two_stored(env: Env, a: U256, b: U256) -> U256 {
+        let mut sponge = PoseidonSponge::<3, Bn254Fr>::from_storage(&env, &PARAMS);
+        let inputs = vec![&env, a, b];
+        sponge.compute_hash(&inputs)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use soroban_sdk::{bytesn, Env, U256};
+    use soroban_poseidon::{Bn254FieldElement, Field, FieldElement, PoseidonError, PoseidonSponge};
+    use soroban_sdk::{
+        bytesn, crypto::bn254::Bn254Fr, testutils::Address as _, vec, Address, Env, IntoVal,
+        TryFromVal, Val, U256,
+    };
 
     use crate::{PoseidonContract, PoseidonContractClient};
 
     #[test]
     fn test_hash_two() {
         let env = Env::default();
-        let contract_id = env.register(PoseidonContract, ());
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
         let client = PoseidonContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
@@ -58,7 +101,7 @@ mod test {
     #[test]
     fn test_hash_two_sponge() {
         let env = Env::default();
-        let contract_id = env.register(PoseidonContract, ());
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
         let client = PoseidonContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
@@ -76,10 +119,85 @@ mod test {
         assert_eq!(hash_reversed, expected_reversed);
     }
 
+    #[test]
+    fn test_hash_two_elements() {
+        let env = Env::default();
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
+        let client = PoseidonContractClient::new(&env, &contract_id);
+
+        let a = FieldElement::<Bn254Fr>::from_u32(&env, 1);
+        let b = FieldElement::<Bn254Fr>::from_u32(&env, 2);
+        let hash = client.hash_two_elements(&a.into(), &b.into());
+        assert_eq!(
+            hash.to_u256(),
+            client.hash_two(&U256::from_u32(&env, 1), &U256::from_u32(&env, 2))
+        );
+
+        // An element outside the field is rejected with an error
+        let val: Val = vec![&env, Bn254Fr::modulus(&env)].into_val(&env);
+        let outside = Bn254FieldElement::try_from_val(&env, &val).unwrap();
+        assert_eq!(
+            client.try_hash_two_elements(&outside, &hash),
+            Err(Ok(PoseidonError::InputNotInField))
+        );
+    }
+
+    #[test]
+    fn test_hash_two_stored() {
+        let env = Env::default();
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
+        let client = PoseidonContractClient::new(&env, &contract_id);
+
+        env.mock_all_auths();
+        // The parameters are built off-chain and passed in once
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+
+        let a = U256::from_u32(&env, 1);
+        let b = U256::from_u32(&env, 2);
+        assert_eq!(client.hash_two_stored(&a, &b), client.hash_two(&a, &b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_params_requires_admin() {
+        let env = Env::default();
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
+        let client = PoseidonContractClient::new(&env, &contract_id);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_params_only_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
+        let client = PoseidonContractClient::new(&env, &contract_id);
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        client.set_params(&params);
+        client.set_params(&params);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_two_stored_without_params() {
+        let env = Env::default();
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
+        let client = PoseidonContractClient::new(&env, &contract_id);
+
+        let a = U256::from_u32(&env, 1);
+        let b = U256::from_u32(&env, 2);
+        client.hash_two_stored(&a, &b);
+    }
+
     #[test]
     fn test_hash_deterministic() {
         let env = Env::default();
-        let contract_id = env.register(PoseidonContract, ());
+        let contract_id = env.register(PoseidonContract, (Address::generate(&env),));
         let client = PoseidonContractClient::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 42);
@@ -95,7 +213,7 @@ mod test {
 /// WASM contract tests - imports the compiled WASM and tests it
 #[cfg(test)]
 mod wasm_test {
-    use soroban_sdk::{bytesn, Env, U256};
+    use soroban_sdk::{bytesn, testutils::Address as _, Address, Env, U256};
 
     mod poseidon_contract {
         soroban_sdk::contractimport!(
@@ -108,7 +226,7 @@ mod wasm_test {
         let env = Env::default();
         env.cost_estimate().budget().reset_unlimited();
 
-        let contract_id = env.register(poseidon_contract::WASM, ());
+        let contract_id = env.register(poseidon_contract::WASM, (Address::generate(&env),));
         let client = poseidon_contract::Client::new(&env, &contract_id);
 
         let a = U256::from_u32(&env, 1);
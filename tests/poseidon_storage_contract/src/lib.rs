@@ -0,0 +1,100 @@
+#![no_std]
+
+use soroban_poseidon::{PoseidonParams, PoseidonSponge};
+use soroban_sdk::{
+    contract, contractimpl, crypto::bn254::Bn254Fr, symbol_short, vec, Env, Symbol, U256,
+};
+
+const PARAMS: Symbol = symbol_short!("PARAMS");
+
+#[contract]
+pub struct PoseidonStorageContract;
+
+#[contractimpl]
+impl PoseidonStorageContract {
+    /// Validates and stores the t=3 parameter set passed in by the deployer.
+    pub fn __constructor(env: Env, params: PoseidonParams) {
+        PoseidonSponge::<3, Bn254Fr>::from_params(&env, params).store_params(&PARAMS);
+    }
+
+    /// Computes a Poseidon hash of [a, b] with the stored parameter set.
+    pub fn hash_two(env: Env, a: U256, b: U256) -> U256 {
+        let mut sponge = PoseidonSponge::<3, Bn254Fr>::from_storage(&env, &PARAMS);
+        let inputs = vec![&env, a, b];
+        sponge.compute_hash(&inputs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_poseidon::PoseidonSponge;
+    use soroban_sdk::{bytesn, crypto::bn254::Bn254Fr, Env, U256};
+
+    use crate::{PoseidonStorageContract, PoseidonStorageContractClient};
+
+    #[test]
+    fn test_hash_two() {
+        let env = Env::default();
+        // The parameters are built off-chain and passed in at deployment
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        let contract_id = env.register(PoseidonStorageContract, (params,));
+        let client = PoseidonStorageContractClient::new(&env, &contract_id);
+
+        let a = U256::from_u32(&env, 1);
+        let b = U256::from_u32(&env, 2);
+
+        // hash([1, 2]) - known value from circom
+        let expected = U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+            )
+            .into(),
+        );
+        assert_eq!(client.hash_two(&a, &b), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_constructor_rejects_wrong_size() {
+        let env = Env::default();
+        let params = PoseidonSponge::<4, Bn254Fr>::new(&env).params().clone();
+        env.register(PoseidonStorageContract, (params,));
+    }
+}
+
+/// WASM contract tests - imports the compiled WASM and tests it
+#[cfg(test)]
+mod wasm_test {
+    use soroban_poseidon::PoseidonSponge;
+    use soroban_sdk::{bytesn, crypto::bn254::Bn254Fr, Env, U256};
+
+    mod poseidon_storage_contract {
+        soroban_sdk::contractimport!(
+            file = "../../target/wasm32v1-none/release/test_poseidon_storage_contract.wasm"
+        );
+    }
+
+    #[test]
+    fn test_wasm_poseidon_storage() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+
+        let params = PoseidonSponge::<3, Bn254Fr>::new(&env).params().clone();
+        let contract_id = env.register(poseidon_storage_contract::WASM, (params,));
+        let client = poseidon_storage_contract::Client::new(&env, &contract_id);
+
+        let a = U256::from_u32(&env, 1);
+        let b = U256::from_u32(&env, 2);
+        let expected = U256::from_be_bytes(
+            &env,
+            &bytesn!(
+                &env,
+                0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+            )
+            .into(),
+        );
+        assert_eq!(client.hash_two(&a, &b), expected);
+    }
+}